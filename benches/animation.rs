@@ -0,0 +1,88 @@
+//! Measures update time of a large set of animations, most of which are paused. Paused animations reuse their
+//! cached poses, the benchmark compares it with recalculation of every pose on every update.
+//!
+//! Run with `cargo bench --bench animation`.
+
+use fyrox::{
+    animation::{track::Track, Animation},
+    core::{
+        curve::{Curve, CurveKey, CurveKeyKind},
+        pool::Handle,
+    },
+};
+use std::time::{Duration, Instant};
+
+const ANIMATION_COUNT: usize = 1000;
+const TRACK_COUNT: usize = 30;
+const KEY_COUNT: usize = 60;
+const PAUSED_FRACTION: f32 = 0.9;
+const FRAME_COUNT: usize = 100;
+
+fn make_animation(index: usize) -> Animation {
+    let mut animation = Animation::default();
+    for track_index in 0..TRACK_COUNT {
+        let mut track = Track::new_position();
+        track.set_target(Handle::new(track_index as u32 + 1, 1));
+        for curve in track.data_container_mut().curves_mut() {
+            *curve = Curve::from(
+                (0..KEY_COUNT)
+                    .map(|i| {
+                        CurveKey::new(
+                            i as f32 / KEY_COUNT as f32,
+                            (i as f32 * 0.1).sin(),
+                            CurveKeyKind::CatmullRom,
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            );
+        }
+        animation.add_track(track);
+    }
+    animation.fit_length_to_content();
+    if (index as f32) < ANIMATION_COUNT as f32 * PAUSED_FRACTION {
+        animation.set_time_position(0.5).pause();
+    }
+    animation
+}
+
+fn run(animations: &mut [Animation], invalidate: bool) -> Duration {
+    let start = Instant::now();
+    for _ in 0..FRAME_COUNT {
+        for animation in animations.iter_mut() {
+            if invalidate {
+                animation.invalidate_pose();
+            }
+            animation.tick(1.0 / 60.0);
+        }
+    }
+    start.elapsed()
+}
+
+fn main() {
+    let mut animations = (0..ANIMATION_COUNT).map(make_animation).collect::<Vec<_>>();
+
+    // Warm up.
+    run(&mut animations, true);
+
+    let uncached = run(&mut animations, true);
+    let cached = run(&mut animations, false);
+
+    println!(
+        "{} animations ({}% paused), {} frames:",
+        ANIMATION_COUNT,
+        (PAUSED_FRACTION * 100.0) as u32,
+        FRAME_COUNT
+    );
+    println!(
+        "  without pose cache: {:?} per frame",
+        uncached / FRAME_COUNT as u32
+    );
+    println!(
+        "  with pose cache:    {:?} per frame",
+        cached / FRAME_COUNT as u32
+    );
+    println!(
+        "  speedup: {:.2}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
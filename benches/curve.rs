@@ -0,0 +1,55 @@
+//! Measures sampling time of a curve with a large number of keys. Monotonic sampling (as in animation playback) uses
+//! cached span of the previous sample, the benchmark compares it with sampling in scattered order, which falls back
+//! to the binary search on every sample.
+//!
+//! Run with `cargo bench --bench curve`.
+
+use fyrox::core::curve::{Curve, CurveKey, CurveKeyKind};
+use std::time::{Duration, Instant};
+
+const KEY_COUNT: usize = 1000;
+const SAMPLE_COUNT: usize = 1_000_000;
+// Co-prime with the sample count, so every sample is visited exactly once in scattered order.
+const SCATTER_STRIDE: usize = 7919;
+
+fn run(curve: &Curve, locations: &[f32]) -> (Duration, f64) {
+    let start = Instant::now();
+    let mut sum = 0.0;
+    for &location in locations {
+        sum += curve.value_at(location) as f64;
+    }
+    (start.elapsed(), sum)
+}
+
+fn main() {
+    let curve = Curve::from(
+        (0..KEY_COUNT)
+            .map(|i| CurveKey::new(i as f32, (i % 7) as f32, CurveKeyKind::Linear))
+            .collect::<Vec<_>>(),
+    );
+
+    let step = (KEY_COUNT - 1) as f32 / SAMPLE_COUNT as f32;
+    let monotonic = (0..SAMPLE_COUNT)
+        .map(|i| i as f32 * step)
+        .collect::<Vec<_>>();
+    let scattered = (0..SAMPLE_COUNT)
+        .map(|i| monotonic[i * SCATTER_STRIDE % SAMPLE_COUNT])
+        .collect::<Vec<_>>();
+
+    // Warm up.
+    run(&curve, &scattered);
+
+    let (uncached, uncached_sum) = run(&curve, &scattered);
+    let (cached, cached_sum) = run(&curve, &monotonic);
+
+    // Both orders visit the same locations, only the summation order differs.
+    assert!((uncached_sum - cached_sum).abs() <= 1.0e-6 * cached_sum);
+
+    println!("{} samples of {}-key curve:", SAMPLE_COUNT, KEY_COUNT);
+    println!("  scattered (binary search): {:?}", uncached);
+    println!("  monotonic (span cursor):   {:?}", cached);
+    println!(
+        "  speedup: {:.2}x",
+        uncached.as_secs_f64() / cached.as_secs_f64()
+    );
+}
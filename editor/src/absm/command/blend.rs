@@ -5,6 +5,7 @@ use crate::{
 use fyrox::{
     animation::machine::node::{
         blend::{BlendPose, IndexedBlendInput},
+        blend_space::{BlendSpacePoint1D, BlendSpacePoint2D},
         PoseNode,
     },
     core::pool::Handle,
@@ -23,6 +24,23 @@ define_push_element_to_collection_command!(AddPoseSourceCommand<Handle<PoseNode>
     let machine = fetch_machine(context, self.node_handle);
     match &mut machine.layers_mut()[self.layer_index].nodes_mut()[self.handle] {
         PoseNode::BlendAnimations(definition) => &mut definition.pose_sources,
+        PoseNode::BlendTree(definition) => &mut definition.pose_sources,
+        _ => unreachable!(),
+    }
+});
+
+define_push_element_to_collection_command!(AddBlendSpace1DPointCommand<Handle<PoseNode>, BlendSpacePoint1D>(self, context) {
+    let machine = fetch_machine(context, self.node_handle);
+    match &mut machine.layers_mut()[self.layer_index].nodes_mut()[self.handle] {
+        PoseNode::BlendSpace1D(definition) => &mut definition.points,
+        _ => unreachable!(),
+    }
+});
+
+define_push_element_to_collection_command!(AddBlendSpace2DPointCommand<Handle<PoseNode>, BlendSpacePoint2D>(self, context) {
+    let machine = fetch_machine(context, self.node_handle);
+    match &mut machine.layers_mut()[self.layer_index].nodes_mut()[self.handle] {
+        PoseNode::BlendSpace2D(definition) => &mut definition.points,
         _ => unreachable!(),
     }
 });
@@ -46,6 +64,33 @@ define_set_collection_element_command!(
             PoseNode::BlendAnimations(ref mut definition) => {
                 &mut definition.pose_sources[self.index].pose_source
             }
+            PoseNode::BlendTree(ref mut definition) => {
+                &mut definition.pose_sources[self.index].pose_source
+            }
+            _ => unreachable!(),
+        }
+    }
+);
+
+define_set_collection_element_command!(
+    SetBlendSpace1DPoseSourceCommand<Handle<PoseNode>, Handle<PoseNode>>(self, context) {
+        let machine = fetch_machine(context, self.node_handle);
+        match machine.layers_mut()[self.layer_index].nodes_mut()[self.handle] {
+            PoseNode::BlendSpace1D(ref mut definition) => {
+                &mut definition.points[self.index].pose_source
+            }
+            _ => unreachable!(),
+        }
+    }
+);
+
+define_set_collection_element_command!(
+    SetBlendSpace2DPoseSourceCommand<Handle<PoseNode>, Handle<PoseNode>>(self, context) {
+        let machine = fetch_machine(context, self.node_handle);
+        match machine.layers_mut()[self.layer_index].nodes_mut()[self.handle] {
+            PoseNode::BlendSpace2D(ref mut definition) => {
+                &mut definition.points[self.index].pose_source
+            }
             _ => unreachable!(),
         }
     }
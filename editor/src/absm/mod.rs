@@ -1,6 +1,9 @@
 use crate::{
     absm::{
-        command::blend::{AddInputCommand, AddPoseSourceCommand},
+        command::blend::{
+            AddBlendSpace1DPointCommand, AddBlendSpace2DPointCommand, AddInputCommand,
+            AddPoseSourceCommand,
+        },
         node::{AbsmNode, AbsmNodeMessage},
         parameter::ParameterPanel,
         selection::AbsmSelection,
@@ -13,7 +16,10 @@ use crate::{
 };
 use fyrox::fxhash::FxHashSet;
 use fyrox::{
-    animation::machine::{BlendPose, Event, IndexedBlendInput, Machine, PoseNode, State},
+    animation::machine::{
+        BlendPose, BlendSpacePoint1D, BlendSpacePoint2D, Event, IndexedBlendInput, Machine,
+        PoseNode, State,
+    },
     core::{color::Color, pool::Handle},
     engine::Engine,
     gui::{
@@ -486,7 +492,7 @@ impl AbsmEditor {
                                     PoseNode::PlayAnimation(_) => {
                                         // No input sockets
                                     }
-                                    PoseNode::BlendAnimations(_) => {
+                                    PoseNode::BlendAnimations(_) | PoseNode::BlendTree(_) => {
                                         sender
                                             .send(Message::do_scene_command(
                                                 AddPoseSourceCommand::new(
@@ -508,6 +514,30 @@ impl AbsmEditor {
                                             )))
                                             .unwrap();
                                     }
+                                    PoseNode::BlendSpace1D(_) => {
+                                        sender
+                                            .send(Message::do_scene_command(
+                                                AddBlendSpace1DPointCommand::new(
+                                                    selection.absm_node_handle,
+                                                    node.model_handle,
+                                                    layer_index,
+                                                    BlendSpacePoint1D::default(),
+                                                ),
+                                            ))
+                                            .unwrap();
+                                    }
+                                    PoseNode::BlendSpace2D(_) => {
+                                        sender
+                                            .send(Message::do_scene_command(
+                                                AddBlendSpace2DPointCommand::new(
+                                                    selection.absm_node_handle,
+                                                    node.model_handle,
+                                                    layer_index,
+                                                    BlendSpacePoint2D::default(),
+                                                ),
+                                            ))
+                                            .unwrap();
+                                    }
                                 }
                             }
                         }
@@ -79,6 +79,7 @@ impl CanvasContextMenu {
                             position: ui.node(self.canvas).screen_to_local(screen_position),
                             name: "New State".to_string(),
                             root: Default::default(),
+                            ..Default::default()
                         },
                     )))
                     .unwrap();
@@ -2,7 +2,9 @@ use crate::{
     absm::{
         command::{
             blend::{
-                SetBlendAnimationByIndexInputPoseSourceCommand, SetBlendAnimationsPoseSourceCommand,
+                SetBlendAnimationByIndexInputPoseSourceCommand,
+                SetBlendAnimationsPoseSourceCommand, SetBlendSpace1DPoseSourceCommand,
+                SetBlendSpace2DPoseSourceCommand,
             },
             AddPoseNodeCommand, DeletePoseNodeCommand, SetStateRootPoseCommand,
         },
@@ -19,8 +21,8 @@ use crate::{
 };
 use fyrox::{
     animation::machine::{
-        node::BasePoseNode, BlendAnimations, BlendAnimationsByIndex, MachineLayer, PlayAnimation,
-        PoseNode, State,
+        node::BasePoseNode, BlendAnimations, BlendAnimationsByIndex, BlendSpace1D, BlendSpace2D,
+        BlendTree, MachineLayer, PlayAnimation, PoseNode, State,
     },
     core::pool::Handle,
     gui::{
@@ -39,6 +41,9 @@ pub struct CanvasContextMenu {
     create_play_animation: Handle<UiNode>,
     create_blend_animations: Handle<UiNode>,
     create_blend_by_index: Handle<UiNode>,
+    create_blend_space_1d: Handle<UiNode>,
+    create_blend_space_2d: Handle<UiNode>,
+    create_blend_tree: Handle<UiNode>,
     pub menu: Handle<UiNode>,
     pub canvas: Handle<UiNode>,
     pub node_context_menu: Handle<UiNode>,
@@ -49,6 +54,9 @@ impl CanvasContextMenu {
         let create_play_animation;
         let create_blend_animations;
         let create_blend_by_index;
+        let create_blend_space_1d;
+        let create_blend_space_2d;
+        let create_blend_tree;
         let menu = PopupBuilder::new(
             WidgetBuilder::new()
                 .with_enabled(false) // Disabled by default.
@@ -68,6 +76,18 @@ impl CanvasContextMenu {
                     .with_child({
                         create_blend_by_index = create_menu_item("Blend By Index", vec![], ctx);
                         create_blend_by_index
+                    })
+                    .with_child({
+                        create_blend_space_1d = create_menu_item("Blend Space 1D", vec![], ctx);
+                        create_blend_space_1d
+                    })
+                    .with_child({
+                        create_blend_space_2d = create_menu_item("Blend Space 2D", vec![], ctx);
+                        create_blend_space_2d
+                    })
+                    .with_child({
+                        create_blend_tree = create_menu_item("Blend Tree", vec![], ctx);
+                        create_blend_tree
                     }),
             )
             .build(ctx),
@@ -78,6 +98,9 @@ impl CanvasContextMenu {
             create_play_animation,
             create_blend_animations,
             create_blend_by_index,
+            create_blend_space_1d,
+            create_blend_space_2d,
+            create_blend_tree,
             menu,
             canvas: Default::default(),
             node_context_menu: Default::default(),
@@ -128,6 +151,31 @@ impl CanvasContextMenu {
                     blend_time: Default::default(),
                     output_pose: Default::default(),
                 }))
+            } else if message.destination() == self.create_blend_space_1d {
+                Some(PoseNode::BlendSpace1D(BlendSpace1D {
+                    base: BasePoseNode {
+                        position,
+                        parent_state: current_state,
+                    },
+                    parameter: "".to_string(),
+                    points: Default::default(),
+                    output_pose: Default::default(),
+                }))
+            } else if message.destination() == self.create_blend_space_2d {
+                Some(PoseNode::BlendSpace2D(BlendSpace2D::new(
+                    "".to_string(),
+                    "".to_string(),
+                    Default::default(),
+                )))
+            } else if message.destination() == self.create_blend_tree {
+                Some(PoseNode::BlendTree(BlendTree {
+                    base: BasePoseNode {
+                        position,
+                        parent_state: current_state,
+                    },
+                    pose_sources: Default::default(),
+                    output_pose: Default::default(),
+                }))
             } else {
                 None
             };
@@ -303,7 +351,7 @@ impl ConnectionContextMenu {
                     PoseNode::PlayAnimation(_) => {
                         // No connections
                     }
-                    PoseNode::BlendAnimations(_) => sender
+                    PoseNode::BlendAnimations(_) | PoseNode::BlendTree(_) => sender
                         .send(Message::do_scene_command(
                             SetBlendAnimationsPoseSourceCommand {
                                 node_handle: absm_node_handle,
@@ -325,6 +373,28 @@ impl ConnectionContextMenu {
                             },
                         ))
                         .unwrap(),
+                    PoseNode::BlendSpace1D(_) => sender
+                        .send(Message::do_scene_command(
+                            SetBlendSpace1DPoseSourceCommand {
+                                node_handle: absm_node_handle,
+                                layer_index,
+                                handle: model_handle,
+                                index,
+                                value: Default::default(),
+                            },
+                        ))
+                        .unwrap(),
+                    PoseNode::BlendSpace2D(_) => sender
+                        .send(Message::do_scene_command(
+                            SetBlendSpace2DPoseSourceCommand {
+                                node_handle: absm_node_handle,
+                                layer_index,
+                                handle: model_handle,
+                                index,
+                                value: Default::default(),
+                            },
+                        ))
+                        .unwrap(),
                 }
             }
         } else if let Some(PopupMessage::Placement(Placement::Cursor(target))) = message.data() {
@@ -3,7 +3,9 @@ use crate::{
         canvas::{AbsmCanvasBuilder, AbsmCanvasMessage},
         command::{
             blend::{
-                SetBlendAnimationByIndexInputPoseSourceCommand, SetBlendAnimationsPoseSourceCommand,
+                SetBlendAnimationByIndexInputPoseSourceCommand,
+                SetBlendAnimationsPoseSourceCommand, SetBlendSpace1DPoseSourceCommand,
+                SetBlendSpace2DPoseSourceCommand,
             },
             MovePoseNodeCommand,
         },
@@ -126,6 +128,15 @@ fn make_pose_node_name(
             "Blend {} Animations By Index",
             blend_animations_by_index.inputs.len()
         ),
+        PoseNode::BlendSpace1D(blend_space) => {
+            format!("Blend Space 1D ({} Points)", blend_space.points.len())
+        }
+        PoseNode::BlendSpace2D(blend_space) => {
+            format!("Blend Space 2D ({} Points)", blend_space.points.len())
+        }
+        PoseNode::BlendTree(blend_tree) => {
+            format!("Blend Tree ({} Poses)", blend_tree.pose_sources.len())
+        }
     }
 }
 
@@ -312,7 +323,7 @@ impl StateViewer {
                             let dest_node_ref = &layer.nodes()[dest_node];
                             match dest_node_ref {
                                 PoseNode::PlayAnimation(_) => {}
-                                PoseNode::BlendAnimations(_) => {
+                                PoseNode::BlendAnimations(_) | PoseNode::BlendTree(_) => {
                                     sender
                                         .send(Message::do_scene_command(
                                             SetBlendAnimationsPoseSourceCommand {
@@ -338,6 +349,32 @@ impl StateViewer {
                                         ))
                                         .unwrap();
                                 }
+                                PoseNode::BlendSpace1D(_) => {
+                                    sender
+                                        .send(Message::do_scene_command(
+                                            SetBlendSpace1DPoseSourceCommand {
+                                                node_handle: absm_node_handle,
+                                                layer_index,
+                                                handle: dest_node,
+                                                index: dest_socket_ref.index,
+                                                value: source_node,
+                                            },
+                                        ))
+                                        .unwrap();
+                                }
+                                PoseNode::BlendSpace2D(_) => {
+                                    sender
+                                        .send(Message::do_scene_command(
+                                            SetBlendSpace2DPoseSourceCommand {
+                                                node_handle: absm_node_handle,
+                                                layer_index,
+                                                handle: dest_node,
+                                                index: dest_socket_ref.index,
+                                                value: source_node,
+                                            },
+                                        ))
+                                        .unwrap();
+                                }
                             }
                         }
                         _ => (),
@@ -462,6 +499,15 @@ impl StateViewer {
                                     "Blend Animations By Index",
                                     true,
                                 ),
+                                PoseNode::BlendSpace1D(blend_space) => {
+                                    (blend_space.points.len(), "Blend Space 1D", true)
+                                }
+                                PoseNode::BlendSpace2D(blend_space) => {
+                                    (blend_space.points.len(), "Blend Space 2D", true)
+                                }
+                                PoseNode::BlendTree(blend_tree) => {
+                                    (blend_tree.pose_sources.len(), "Blend Tree", true)
+                                }
                             };
 
                             let node_view = AbsmNodeBuilder::new(
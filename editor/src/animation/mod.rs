@@ -609,12 +609,23 @@ impl AnimationEditor {
 
                 // TODO: Support multi-selection.
                 if let Some(SelectedEntity::Curve(selected_curve_id)) = selection.entities.first() {
-                    if let Some(selected_curve) = animation.tracks().iter().find_map(|t| {
+                    if let Some((track, selected_curve)) = animation.tracks().iter().find_map(|t| {
                         t.data_container()
                             .curves_ref()
                             .iter()
                             .find(|c| &c.id() == selected_curve_id)
+                            .map(|c| (t, c))
                     }) {
+                        // New keys must match the kind of the track value, booleans and integers
+                        // should be stepped instead of being smeared between the keys.
+                        send_sync_message(
+                            &engine.user_interface,
+                            CurveEditorMessage::default_key_kind(
+                                self.curve_editor,
+                                MessageDirection::ToWidget,
+                                track.data_container().value_kind().default_key_kind(),
+                            ),
+                        );
                         send_sync_message(
                             &engine.user_interface,
                             CurveEditorMessage::sync(
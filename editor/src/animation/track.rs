@@ -26,6 +26,7 @@ use fyrox::{
     },
     core::{
         algebra::{UnitQuaternion, Vector2, Vector3, Vector4},
+        color::Color,
         pool::Handle,
         reflect::ResolvePath,
         uuid::Uuid,
@@ -581,23 +582,23 @@ impl TrackList {
                                 } else if property_type == TypeId::of::<f64>() {
                                     Some((TrackValueKind::Real, ValueType::F64))
                                 } else if property_type == TypeId::of::<u64>() {
-                                    Some((TrackValueKind::Real, ValueType::U64))
+                                    Some((TrackValueKind::Integer, ValueType::U64))
                                 } else if property_type == TypeId::of::<i64>() {
-                                    Some((TrackValueKind::Real, ValueType::I64))
+                                    Some((TrackValueKind::Integer, ValueType::I64))
                                 } else if property_type == TypeId::of::<u32>() {
-                                    Some((TrackValueKind::Real, ValueType::U32))
+                                    Some((TrackValueKind::Integer, ValueType::U32))
                                 } else if property_type == TypeId::of::<i32>() {
-                                    Some((TrackValueKind::Real, ValueType::I32))
+                                    Some((TrackValueKind::Integer, ValueType::I32))
                                 } else if property_type == TypeId::of::<u16>() {
-                                    Some((TrackValueKind::Real, ValueType::U16))
+                                    Some((TrackValueKind::Integer, ValueType::U16))
                                 } else if property_type == TypeId::of::<i16>() {
-                                    Some((TrackValueKind::Real, ValueType::I16))
+                                    Some((TrackValueKind::Integer, ValueType::I16))
                                 } else if property_type == TypeId::of::<u8>() {
-                                    Some((TrackValueKind::Real, ValueType::U8))
+                                    Some((TrackValueKind::Integer, ValueType::U8))
                                 } else if property_type == TypeId::of::<i8>() {
-                                    Some((TrackValueKind::Real, ValueType::I8))
+                                    Some((TrackValueKind::Integer, ValueType::I8))
                                 } else if property_type == TypeId::of::<bool>() {
-                                    Some((TrackValueKind::Real, ValueType::Bool))
+                                    Some((TrackValueKind::Bool, ValueType::Bool))
                                 } else if property_type == TypeId::of::<Vector2<f32>>() {
                                     Some((TrackValueKind::Vector2, ValueType::Vector2F32))
                                 } else if property_type == TypeId::of::<Vector2<f64>>() {
@@ -674,6 +675,8 @@ impl TrackList {
                                         TrackValueKind::UnitQuaternion,
                                         ValueType::UnitQuaternionF64,
                                     ))
+                                } else if property_type == TypeId::of::<Color>() {
+                                    Some((TrackValueKind::Color, ValueType::Color))
                                 } else {
                                     None
                                 };
@@ -947,6 +950,13 @@ impl TrackList {
 
                         let ctx = &mut ui.build_ctx();
 
+                        let component_names =
+                            if model_track.data_container().value_kind() == TrackValueKind::Color {
+                                ["R", "G", "B", "A"]
+                            } else {
+                                ["X", "Y", "Z", "W"]
+                            };
+
                         let curves = model_track
                             .data_container()
                             .curves_ref()
@@ -961,7 +971,7 @@ impl TrackList {
                                     TextBuilder::new(WidgetBuilder::new())
                                         .with_text(format!(
                                             "Curve - {}",
-                                            ["X", "Y", "Z", "W"].get(i).unwrap_or(&"_"),
+                                            component_names.get(i).unwrap_or(&"_"),
                                         ))
                                         .build(ctx),
                                 )
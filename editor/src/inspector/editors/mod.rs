@@ -18,8 +18,10 @@ use fyrox::animation::machine::{PoseNode, State};
 use fyrox::{
     animation::{
         machine::{
-            node::BasePoseNode, BlendAnimations, BlendAnimationsByIndex, BlendPose,
-            IndexedBlendInput, Machine, PlayAnimation, PoseWeight,
+            node::BasePoseNode, BlendAnimations, BlendAnimationsByIndex, BlendPose, BlendSpace1D,
+            BlendSpace2D, BlendSpacePoint1D, BlendSpacePoint2D, IndexedBlendInput,
+            InterruptionSource, Machine, ParameterComparison, PlayAnimation, PoseWeight,
+            TransitionCondition,
         },
         AnimationContainer,
     },
@@ -67,7 +69,7 @@ use fyrox::{
         },
         mesh::{
             surface::{Surface, SurfaceSharedData},
-            RenderPath,
+            BlendShape, RenderPath,
         },
         node::{Node, NodeHandle},
         particle_system::{
@@ -138,6 +140,7 @@ pub fn make_property_editors_container(
     container.register_inheritable_vec_collection::<NodeHandle>();
 
     container.register_inheritable_vec_collection::<Surface>();
+    container.register_inheritable_vec_collection::<BlendShape>();
     container.register_inheritable_vec_collection::<Layer>();
     container.register_inheritable_vec_collection::<EmitterWrapper>();
     container.register_inheritable_vec_collection::<LevelOfDetail>();
@@ -153,6 +156,7 @@ pub fn make_property_editors_container(
     container.insert(InheritablePropertyEditorDefinition::<Option<LodGroup>>::new());
 
     container.register_inheritable_enum::<fyrox::animation::spritesheet::Status, _>();
+    container.register_inheritable_enum::<fyrox::scene::animation::UpdateMode, _>();
 
     container.register_inheritable_inspectable::<LodGroup>();
 
@@ -293,9 +297,18 @@ pub fn make_property_editors_container(
     container.insert(InspectablePropertyEditorDefinition::<BlendPose>::new());
     container.insert(VecCollectionPropertyEditorDefinition::<BlendPose>::new());
     container.insert(EnumPropertyEditorDefinition::<PoseWeight>::new());
+    container.insert(EnumPropertyEditorDefinition::<TransitionCondition>::new());
+    container.insert(EnumPropertyEditorDefinition::<ParameterComparison>::new());
+    container.insert(EnumPropertyEditorDefinition::<InterruptionSource>::new());
     container.insert(InspectablePropertyEditorDefinition::<BlendAnimationsByIndex>::new());
     container.insert(InspectablePropertyEditorDefinition::<BlendAnimations>::new());
     container.insert(InspectablePropertyEditorDefinition::<PlayAnimation>::new());
+    container.insert(InspectablePropertyEditorDefinition::<BlendSpacePoint1D>::new());
+    container.insert(VecCollectionPropertyEditorDefinition::<BlendSpacePoint1D>::new());
+    container.insert(InspectablePropertyEditorDefinition::<BlendSpace1D>::new());
+    container.insert(InspectablePropertyEditorDefinition::<BlendSpacePoint2D>::new());
+    container.insert(VecCollectionPropertyEditorDefinition::<BlendSpacePoint2D>::new());
+    container.insert(InspectablePropertyEditorDefinition::<BlendSpace2D>::new());
 
     container.insert(InspectablePropertyEditorDefinition::<Handle<PoseNode>>::new());
     container.insert(InspectablePropertyEditorDefinition::<Handle<State>>::new());
@@ -1,11 +1,33 @@
 use crate::{
-    math::{cubicf, lerpf},
+    algebra::Vector2,
+    math::{cubicf, cubicf_derivative, lerpf},
     reflect::prelude::*,
     visitor::prelude::*,
 };
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    f32::consts::PI,
+    fmt::{Debug, Formatter},
+    sync::atomic::{self, AtomicUsize},
+};
 use uuid::Uuid;
 
+// Slope of a handle, the handle must point away from the key along the time axis. Vertical handles are clamped to a
+// steep slope.
+fn handle_slope(dt: f32, dv: f32) -> f32 {
+    dv / dt.max(f32::EPSILON)
+}
+
+// Converts a slope in curve space (`dv/dt`) to a tangent of a cubic key, see `CurveKey::left_tangent`.
+fn slope_to_tangent(slope: f32, span: f32, value_delta: f32) -> f32 {
+    let scale = value_delta.abs();
+    if scale > 0.0 {
+        slope * span / scale
+    } else {
+        0.0
+    }
+}
+
 fn stepf(p0: f32, p1: f32, t: f32) -> f32 {
     if t.eq(&1.0) {
         p1
@@ -14,6 +36,27 @@ fn stepf(p0: f32, p1: f32, t: f32) -> f32 {
     }
 }
 
+// Cubic Hermite spline with tangents already scaled to the span length (unlike `cubicf`).
+fn hermitef(p0: f32, p1: f32, t: f32, m0: f32, m1: f32) -> f32 {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    (2.0 * t3 - 3.0 * t2 + 1.0) * p0
+        + (t3 - 2.0 * t2 + t) * m0
+        + (-2.0 * t3 + 3.0 * t2) * p1
+        + (t3 - t2) * m1
+}
+
+// Derivative (by `t`) of `hermitef`.
+fn hermitef_derivative(p0: f32, p1: f32, t: f32, m0: f32, m1: f32) -> f32 {
+    let t2 = t * t;
+
+    (6.0 * t2 - 6.0 * t) * p0
+        + (3.0 * t2 - 4.0 * t + 1.0) * m0
+        + (6.0 * t - 6.0 * t2) * p1
+        + (3.0 * t2 - 2.0 * t) * m1
+}
+
 #[derive(Visit, Clone, Debug, PartialEq)]
 pub enum CurveKeyKind {
     Constant,
@@ -24,6 +67,16 @@ pub enum CurveKeyKind {
         /// A `tan(angle)` of right tangent.
         right_tangent: f32,
     },
+    /// Catmull-Rom spline that passes through the neighboring keys. Tangents are calculated automatically from
+    /// the neighbors of each key, so the curve is smooth (C1-continuous) without any manual tangent editing. The
+    /// first and the last keys of a curve use one-sided tangents (as if the end key was duplicated).
+    CatmullRom,
+    /// Smooth spline with automatic tangents (similar to "auto" tangents of Maya). Tangents are calculated from the
+    /// slopes of the adjacent spans of each key, like Catmull-Rom tangents, but they are clamped so the curve never
+    /// overshoots its keys: a key that is a local extremum (or the end of a plateau) gets a flat tangent and the
+    /// curve is monotonic between keys with monotonic values. The first and the last keys of a curve use one-sided
+    /// tangents. Tangents are recalculated on every evaluation, so they always follow the keys after editing.
+    Smooth,
 }
 
 impl CurveKeyKind {
@@ -69,24 +122,136 @@ impl CurveKey {
         self.location
     }
 
+    /// Returns left tangent of the key, if the key is cubic (see [`CurveKeyKind::Cubic`]).
+    ///
+    /// # Tangent convention
+    ///
+    /// Tangents of cubic keys are **not** slopes in curve space (`dv/dt`). A tangent is a slope in normalized space of
+    /// a span, where the span between two keys is mapped to `[0; 1]` range by location and the difference of the
+    /// values of the keys is used as the unit of value. It means that the actual slope of the curve at a key is
+    /// `tangent * |v1 - v0| / (t1 - t0)`, where `(t0, v0)` and `(t1, v1)` are the keys of the span; left tangent is
+    /// used in the span that ends at the key, right tangent - in the span that starts at the key. Use
+    /// [`Self::left_handle`] and [`Self::right_handle`] (and respective setters) to work with the tangents in curve
+    /// space.
+    #[inline]
+    pub fn left_tangent(&self) -> Option<f32> {
+        match self.kind {
+            CurveKeyKind::Cubic { left_tangent, .. } => Some(left_tangent),
+            _ => None,
+        }
+    }
+
+    /// Returns right tangent of the key, if the key is cubic. See [`Self::left_tangent`] for the tangent convention.
+    #[inline]
+    pub fn right_tangent(&self) -> Option<f32> {
+        match self.kind {
+            CurveKeyKind::Cubic { right_tangent, .. } => Some(right_tangent),
+            _ => None,
+        }
+    }
+
+    /// Sets left tangent of the key. If the key is not cubic, it becomes cubic with flat (zero) right tangent. See
+    /// [`Self::left_tangent`] for the tangent convention.
+    #[inline]
+    pub fn set_left_tangent(&mut self, tangent: f32) {
+        let right_tangent = self.right_tangent().unwrap_or_default();
+        self.kind = CurveKeyKind::Cubic {
+            left_tangent: tangent,
+            right_tangent,
+        };
+    }
+
+    /// Sets right tangent of the key. If the key is not cubic, it becomes cubic with flat (zero) left tangent. See
+    /// [`Self::left_tangent`] for the tangent convention.
+    #[inline]
+    pub fn set_right_tangent(&mut self, tangent: f32) {
+        let left_tangent = self.left_tangent().unwrap_or_default();
+        self.kind = CurveKeyKind::Cubic {
+            left_tangent,
+            right_tangent: tangent,
+        };
+    }
+
+    /// Returns a position (`x` - location, `y` - value) of the left Bezier handle of the key in curve space, `prev` is
+    /// the previous key of the curve. Cubic spans are equivalent to cubic Bezier curves with the handles placed at
+    /// one third of the span by location, so the handle is placed at `location - (t1 - t0) / 3`. Returns `None` if
+    /// the key is not cubic.
+    #[inline]
+    pub fn left_handle(&self, prev: &CurveKey) -> Option<Vector2<f32>> {
+        self.left_tangent().map(|tangent| {
+            let span = self.location - prev.location;
+            Vector2::new(
+                self.location - span / 3.0,
+                self.value - tangent * (self.value - prev.value).abs() / 3.0,
+            )
+        })
+    }
+
+    /// Returns a position (`x` - location, `y` - value) of the right Bezier handle of the key in curve space, `next`
+    /// is the next key of the curve. See [`Self::left_handle`] for more info.
+    #[inline]
+    pub fn right_handle(&self, next: &CurveKey) -> Option<Vector2<f32>> {
+        self.right_tangent().map(|tangent| {
+            let span = next.location - self.location;
+            Vector2::new(
+                self.location + span / 3.0,
+                self.value + tangent * (next.value - self.value).abs() / 3.0,
+            )
+        })
+    }
+
+    /// Sets the left tangent of the key from a position of the left Bezier handle in curve space (`x` - location,
+    /// `y` - value), `prev` is the previous key of the curve. Only the direction from the key to the handle matters,
+    /// the handle is placed at one third of the span after the conversion (see [`Self::left_handle`]). The key becomes
+    /// cubic, if it was not. If the values of both keys are equal, the span is flat regardless of the tangent and the
+    /// tangent is set to zero.
+    #[inline]
+    pub fn set_left_handle(&mut self, prev: &CurveKey, handle: Vector2<f32>) {
+        let slope = handle_slope(self.location - handle.x, self.value - handle.y);
+        self.set_left_tangent(slope_to_tangent(
+            slope,
+            self.location - prev.location,
+            self.value - prev.value,
+        ));
+    }
+
+    /// Sets the right tangent of the key from a position of the right Bezier handle in curve space, `next` is the next
+    /// key of the curve. See [`Self::set_left_handle`] for more info.
+    #[inline]
+    pub fn set_right_handle(&mut self, next: &CurveKey, handle: Vector2<f32>) {
+        let slope = handle_slope(handle.x - self.location, handle.y - self.value);
+        self.set_right_tangent(slope_to_tangent(
+            slope,
+            next.location - self.location,
+            next.value - self.value,
+        ));
+    }
+
     #[inline]
     pub fn interpolate(&self, other: &Self, t: f32) -> f32 {
         match (&self.kind, &other.kind) {
             // Constant-to-any
             (CurveKeyKind::Constant, CurveKeyKind::Constant)
             | (CurveKeyKind::Constant, CurveKeyKind::Linear)
-            | (CurveKeyKind::Constant, CurveKeyKind::Cubic { .. }) => {
-                stepf(self.value, other.value, t)
-            }
+            | (CurveKeyKind::Constant, CurveKeyKind::Cubic { .. })
+            | (CurveKeyKind::Constant, CurveKeyKind::CatmullRom)
+            | (CurveKeyKind::Constant, CurveKeyKind::Smooth) => stepf(self.value, other.value, t),
 
             // Linear-to-any
             (CurveKeyKind::Linear, CurveKeyKind::Constant)
             | (CurveKeyKind::Linear, CurveKeyKind::Linear)
-            | (CurveKeyKind::Linear, CurveKeyKind::Cubic { .. }) => {
-                lerpf(self.value, other.value, t)
+            | (CurveKeyKind::Linear, CurveKeyKind::Cubic { .. })
+            | (CurveKeyKind::Linear, CurveKeyKind::CatmullRom)
+            | (CurveKeyKind::Linear, CurveKeyKind::Smooth) => lerpf(self.value, other.value, t),
+
+            // Catmull-Rom-to-any or smooth-to-any. There is no information about neighbors of the keys, so the
+            // tangents are calculated as if both keys were duplicated. [`Curve::value_at`] uses actual neighbors.
+            (CurveKeyKind::CatmullRom, _) | (CurveKeyKind::Smooth, _) => {
+                let slope = other.value - self.value;
+                hermitef(self.value, other.value, t, slope, slope)
             }
 
-            // Cubic-to-constant or cubic-to-linear
+            // Cubic-to-constant, cubic-to-linear, cubic-to-catmull-rom or cubic-to-smooth
             (
                 CurveKeyKind::Cubic {
                     right_tangent: left_tangent,
@@ -100,6 +265,20 @@ impl CurveKey {
                     ..
                 },
                 CurveKeyKind::Linear,
+            )
+            | (
+                CurveKeyKind::Cubic {
+                    right_tangent: left_tangent,
+                    ..
+                },
+                CurveKeyKind::CatmullRom,
+            )
+            | (
+                CurveKeyKind::Cubic {
+                    right_tangent: left_tangent,
+                    ..
+                },
+                CurveKeyKind::Smooth,
             ) => cubicf(self.value, other.value, t, *left_tangent, 0.0),
 
             // Cubic-to-cubic
@@ -115,6 +294,358 @@ impl CurveKey {
             ) => cubicf(self.value, other.value, t, *left_tangent, *right_tangent),
         }
     }
+
+    /// Calculates a derivative of [`Self::interpolate`] by `t` (which means that it is not scaled by the length of
+    /// the span).
+    #[inline]
+    pub fn interpolate_derivative(&self, other: &Self, t: f32) -> f32 {
+        match (&self.kind, &other.kind) {
+            // Constant-to-any
+            (CurveKeyKind::Constant, _) => 0.0,
+
+            // Linear-to-any
+            (CurveKeyKind::Linear, _) => other.value - self.value,
+
+            // Catmull-Rom-to-any or smooth-to-any, see `interpolate` for more info.
+            (CurveKeyKind::CatmullRom, _) | (CurveKeyKind::Smooth, _) => {
+                let slope = other.value - self.value;
+                hermitef_derivative(self.value, other.value, t, slope, slope)
+            }
+
+            // Cubic-to-cubic
+            (
+                CurveKeyKind::Cubic {
+                    right_tangent: left_tangent,
+                    ..
+                },
+                CurveKeyKind::Cubic {
+                    left_tangent: right_tangent,
+                    ..
+                },
+            ) => cubicf_derivative(self.value, other.value, t, *left_tangent, *right_tangent),
+
+            // Cubic-to-constant, cubic-to-linear, cubic-to-catmull-rom or cubic-to-smooth
+            (
+                CurveKeyKind::Cubic {
+                    right_tangent: left_tangent,
+                    ..
+                },
+                _,
+            ) => cubicf_derivative(self.value, other.value, t, *left_tangent, 0.0),
+        }
+    }
+}
+
+/// A standard easing function, the functions follow the definitions of Robert Penner (the same ones are used by CSS
+/// and most of the tweening libraries). Every function maps normalized time `[0; 1]` to normalized progress, that
+/// starts at `0.0` and ends at `1.0`, but could go out of the `[0; 1]` range in between (see `Back` and `Elastic`
+/// families). See [`Curve::from_easing`] to create a curve from an easing function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Visit)]
+pub enum EasingKind {
+    Linear,
+    InQuad,
+    OutQuad,
+    InOutQuad,
+    InCubic,
+    OutCubic,
+    InOutCubic,
+    InQuart,
+    OutQuart,
+    InOutQuart,
+    InQuint,
+    OutQuint,
+    InOutQuint,
+    InSine,
+    OutSine,
+    InOutSine,
+    InExpo,
+    OutExpo,
+    InOutExpo,
+    InCirc,
+    OutCirc,
+    InOutCirc,
+    InBack,
+    OutBack,
+    InOutBack,
+    InElastic,
+    OutElastic,
+    InOutElastic,
+    InBounce,
+    OutBounce,
+    InOutBounce,
+}
+
+impl Default for EasingKind {
+    #[inline]
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+fn ease_in_pow(t: f32, power: i32) -> f32 {
+    t.powi(power)
+}
+
+fn ease_out_pow(t: f32, power: i32) -> f32 {
+    1.0 - (1.0 - t).powi(power)
+}
+
+fn ease_in_out_pow(t: f32, power: i32) -> f32 {
+    if t < 0.5 {
+        2.0f32.powi(power - 1) * t.powi(power)
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(power) * 0.5
+    }
+}
+
+const BOUNCE_N: f32 = 7.5625;
+const BOUNCE_D: f32 = 2.75;
+// Locations of the "hits" of the out-bounce function, where its derivative is discontinuous.
+const BOUNCE_HITS: [f32; 3] = [1.0 / BOUNCE_D, 2.0 / BOUNCE_D, 2.5 / BOUNCE_D];
+
+fn ease_out_bounce(t: f32) -> f32 {
+    if t < 1.0 / BOUNCE_D {
+        BOUNCE_N * t * t
+    } else if t < 2.0 / BOUNCE_D {
+        let t = t - 1.5 / BOUNCE_D;
+        BOUNCE_N * t * t + 0.75
+    } else if t < 2.5 / BOUNCE_D {
+        let t = t - 2.25 / BOUNCE_D;
+        BOUNCE_N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / BOUNCE_D;
+        BOUNCE_N * t * t + 0.984375
+    }
+}
+
+impl EasingKind {
+    /// Returns normalized locations, where the derivative of the easing function is discontinuous. Sampling of the
+    /// function must include these locations, otherwise the sharp corners will be cut.
+    fn corners(self) -> Vec<f32> {
+        match self {
+            EasingKind::OutBounce => BOUNCE_HITS.to_vec(),
+            EasingKind::InBounce => BOUNCE_HITS.iter().map(|t| 1.0 - t).collect(),
+            EasingKind::InOutBounce => BOUNCE_HITS
+                .iter()
+                .flat_map(|t| [(1.0 - t) * 0.5, (1.0 + t) * 0.5])
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Calculates progress for the given normalized time, the time is clamped to `[0; 1]` range.
+    pub fn ease(self, t: f32) -> f32 {
+        const BACK: f32 = 1.70158;
+        const BACK_IN_OUT: f32 = BACK * 1.525;
+        const ELASTIC: f32 = 2.0 * PI / 3.0;
+        const ELASTIC_IN_OUT: f32 = 2.0 * PI / 4.5;
+
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            EasingKind::Linear => t,
+            EasingKind::InQuad => ease_in_pow(t, 2),
+            EasingKind::OutQuad => ease_out_pow(t, 2),
+            EasingKind::InOutQuad => ease_in_out_pow(t, 2),
+            EasingKind::InCubic => ease_in_pow(t, 3),
+            EasingKind::OutCubic => ease_out_pow(t, 3),
+            EasingKind::InOutCubic => ease_in_out_pow(t, 3),
+            EasingKind::InQuart => ease_in_pow(t, 4),
+            EasingKind::OutQuart => ease_out_pow(t, 4),
+            EasingKind::InOutQuart => ease_in_out_pow(t, 4),
+            EasingKind::InQuint => ease_in_pow(t, 5),
+            EasingKind::OutQuint => ease_out_pow(t, 5),
+            EasingKind::InOutQuint => ease_in_out_pow(t, 5),
+            EasingKind::InSine => 1.0 - (t * PI * 0.5).cos(),
+            EasingKind::OutSine => (t * PI * 0.5).sin(),
+            EasingKind::InOutSine => -((PI * t).cos() - 1.0) * 0.5,
+            EasingKind::InExpo => {
+                if t == 0.0 {
+                    0.0
+                } else {
+                    2.0f32.powf(10.0 * t - 10.0)
+                }
+            }
+            EasingKind::OutExpo => {
+                if t == 1.0 {
+                    1.0
+                } else {
+                    1.0 - 2.0f32.powf(-10.0 * t)
+                }
+            }
+            EasingKind::InOutExpo => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    2.0f32.powf(20.0 * t - 10.0) * 0.5
+                } else {
+                    (2.0 - 2.0f32.powf(-20.0 * t + 10.0)) * 0.5
+                }
+            }
+            EasingKind::InCirc => 1.0 - (1.0 - t * t).sqrt(),
+            EasingKind::OutCirc => (1.0 - (t - 1.0).powi(2)).sqrt(),
+            EasingKind::InOutCirc => {
+                if t < 0.5 {
+                    (1.0 - (1.0 - (2.0 * t).powi(2)).sqrt()) * 0.5
+                } else {
+                    ((1.0 - (-2.0 * t + 2.0).powi(2)).sqrt() + 1.0) * 0.5
+                }
+            }
+            EasingKind::InBack => (BACK + 1.0) * t * t * t - BACK * t * t,
+            EasingKind::OutBack => {
+                1.0 + (BACK + 1.0) * (t - 1.0).powi(3) + BACK * (t - 1.0).powi(2)
+            }
+            EasingKind::InOutBack => {
+                if t < 0.5 {
+                    (2.0 * t).powi(2) * ((BACK_IN_OUT + 1.0) * 2.0 * t - BACK_IN_OUT) * 0.5
+                } else {
+                    ((2.0 * t - 2.0).powi(2)
+                        * ((BACK_IN_OUT + 1.0) * (2.0 * t - 2.0) + BACK_IN_OUT)
+                        + 2.0)
+                        * 0.5
+                }
+            }
+            EasingKind::InElastic => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    -2.0f32.powf(10.0 * t - 10.0) * ((10.0 * t - 10.75) * ELASTIC).sin()
+                }
+            }
+            EasingKind::OutElastic => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else {
+                    2.0f32.powf(-10.0 * t) * ((10.0 * t - 0.75) * ELASTIC).sin() + 1.0
+                }
+            }
+            EasingKind::InOutElastic => {
+                if t == 0.0 || t == 1.0 {
+                    t
+                } else if t < 0.5 {
+                    -(2.0f32.powf(20.0 * t - 10.0) * ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin())
+                        * 0.5
+                } else {
+                    2.0f32.powf(-20.0 * t + 10.0)
+                        * ((20.0 * t - 11.125) * ELASTIC_IN_OUT).sin()
+                        * 0.5
+                        + 1.0
+                }
+            }
+            EasingKind::InBounce => 1.0 - ease_out_bounce(1.0 - t),
+            EasingKind::OutBounce => ease_out_bounce(t),
+            EasingKind::InOutBounce => {
+                if t < 0.5 {
+                    (1.0 - ease_out_bounce(1.0 - 2.0 * t)) * 0.5
+                } else {
+                    (1.0 + ease_out_bounce(2.0 * t - 1.0)) * 0.5
+                }
+            }
+        }
+    }
+}
+
+macro_rules! define_easing_constructors {
+    ($($name:ident => $kind:ident),* $(,)?) => {
+        impl Curve {
+            $(
+                #[doc = concat!("Creates a new normalized curve for [`EasingKind::", stringify!($kind), "`] easing function. See [`Curve::from_easing`] for more info.")]
+                #[inline]
+                pub fn $name(duration: f32) -> Curve {
+                    Curve::from_easing(EasingKind::$kind, duration)
+                }
+            )*
+        }
+    };
+}
+
+define_easing_constructors! {
+    ease_linear => Linear,
+    ease_in_quad => InQuad,
+    ease_out_quad => OutQuad,
+    ease_in_out_quad => InOutQuad,
+    ease_in_cubic => InCubic,
+    ease_out_cubic => OutCubic,
+    ease_in_out_cubic => InOutCubic,
+    ease_in_quart => InQuart,
+    ease_out_quart => OutQuart,
+    ease_in_out_quart => InOutQuart,
+    ease_in_quint => InQuint,
+    ease_out_quint => OutQuint,
+    ease_in_out_quint => InOutQuint,
+    ease_in_sine => InSine,
+    ease_out_sine => OutSine,
+    ease_in_out_sine => InOutSine,
+    ease_in_expo => InExpo,
+    ease_out_expo => OutExpo,
+    ease_in_out_expo => InOutExpo,
+    ease_in_circ => InCirc,
+    ease_out_circ => OutCirc,
+    ease_in_out_circ => InOutCirc,
+    ease_in_back => InBack,
+    ease_out_back => OutBack,
+    ease_in_out_back => InOutBack,
+    ease_in_elastic => InElastic,
+    ease_out_elastic => OutElastic,
+    ease_in_out_elastic => InOutElastic,
+    ease_in_bounce => InBounce,
+    ease_out_bounce => OutBounce,
+    ease_in_out_bounce => InOutBounce,
+}
+
+/// Defines how a curve is evaluated outside of the range of locations of its keys. See [`Curve::set_extrapolation`]
+/// docs for more info.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Visit)]
+pub enum Extrapolation {
+    /// The value of the nearest end key is used.
+    Clamp,
+    /// The curve continues as a straight line with the slope of the curve at the nearest end key.
+    Linear,
+    /// The curve is repeated infinitely.
+    Cycle,
+}
+
+impl Default for Extrapolation {
+    #[inline]
+    fn default() -> Self {
+        Self::Clamp
+    }
+}
+
+/// Remembers an index of the span of keys that was used in the last [`Curve::value_at`] call. Playback time usually
+/// advances monotonically, so the next call will most likely use the same or the next span. It is just a cache, so
+/// it does not affect comparison of curves.
+#[derive(Default)]
+struct SpanCursor(AtomicUsize);
+
+impl SpanCursor {
+    #[inline]
+    fn get(&self) -> usize {
+        self.0.load(atomic::Ordering::Relaxed)
+    }
+
+    #[inline]
+    fn set(&self, index: usize) {
+        self.0.store(index, atomic::Ordering::Relaxed)
+    }
+}
+
+impl Clone for SpanCursor {
+    fn clone(&self) -> Self {
+        Self(AtomicUsize::new(self.get()))
+    }
+}
+
+impl PartialEq for SpanCursor {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl Debug for SpanCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SpanCursor({})", self.get())
+    }
 }
 
 #[derive(Visit, Reflect, Clone, Debug, PartialEq)]
@@ -127,6 +658,15 @@ pub struct Curve {
     name: String,
 
     keys: Vec<CurveKey>,
+
+    #[visit(optional)] // Backward compatibility
+    pre_extrapolation: Extrapolation,
+
+    #[visit(optional)] // Backward compatibility
+    post_extrapolation: Extrapolation,
+
+    #[visit(skip)]
+    cursor: SpanCursor,
 }
 
 impl Default for Curve {
@@ -135,6 +675,9 @@ impl Default for Curve {
             id: Uuid::new_v4(),
             name: Default::default(),
             keys: Default::default(),
+            pre_extrapolation: Default::default(),
+            post_extrapolation: Default::default(),
+            cursor: Default::default(),
         }
     }
 }
@@ -158,6 +701,9 @@ impl From<Vec<CurveKey>> for Curve {
             id: Uuid::new_v4(),
             name: Default::default(),
             keys,
+            pre_extrapolation: Default::default(),
+            post_extrapolation: Default::default(),
+            cursor: Default::default(),
         }
     }
 }
@@ -185,7 +731,8 @@ impl Curve {
 
     #[inline]
     pub fn clear(&mut self) {
-        self.keys.clear()
+        self.keys.clear();
+        self.cursor.set(0);
     }
 
     #[inline]
@@ -202,6 +749,7 @@ impl Curve {
     pub fn add_key(&mut self, new_key: CurveKey) {
         let pos = self.keys.partition_point(|k| k.location < new_key.location);
         self.keys.insert(pos, new_key);
+        self.cursor.set(0);
     }
 
     #[inline]
@@ -209,6 +757,7 @@ impl Curve {
         if let Some(key) = self.keys.get_mut(key_id) {
             key.location = location;
             sort_keys(&mut self.keys);
+            self.cursor.set(0);
         }
     }
 
@@ -217,32 +766,647 @@ impl Curve {
         self.keys.last().map(|k| k.location).unwrap_or_default()
     }
 
+    /// Sets how the curve is evaluated before the first key (`pre`) and after the last key (`post`). By default, the
+    /// curve is clamped on both sides - the value of the nearest end key is used. [`Extrapolation::Linear`] continues
+    /// the curve with the slope at the end key, so the curve is continuous (both by value and by slope) at the end
+    /// key. [`Extrapolation::Cycle`] repeats the curve infinitely, it is useful for procedural motion that extends
+    /// beyond the authored range (for example, infinite rotation from a short spin curve). Extrapolation modes are
+    /// respected by [`Self::value_at`] and [`Self::derivative_at`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind, Extrapolation};
+    ///
+    /// let mut curve = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(1.0, 2.0, CurveKeyKind::Linear),
+    /// ]);
+    ///
+    /// curve.set_extrapolation(Extrapolation::Linear, Extrapolation::Cycle);
+    ///
+    /// assert_eq!(curve.value_at(-1.0), -2.0);
+    /// assert_eq!(curve.value_at(1.5), 1.0);
+    /// ```
+    #[inline]
+    pub fn set_extrapolation(&mut self, pre: Extrapolation, post: Extrapolation) {
+        self.pre_extrapolation = pre;
+        self.post_extrapolation = post;
+    }
+
+    /// Returns extrapolation mode of the curve before its first key. See [`Self::set_extrapolation`] docs for more
+    /// info.
+    #[inline]
+    pub fn pre_extrapolation(&self) -> Extrapolation {
+        self.pre_extrapolation
+    }
+
+    /// Returns extrapolation mode of the curve after its last key. See [`Self::set_extrapolation`] docs for more
+    /// info.
+    #[inline]
+    pub fn post_extrapolation(&self) -> Extrapolation {
+        self.post_extrapolation
+    }
+
+    /// Calculates a value of the curve at the given location. Outside of the range of the keys, the curve is
+    /// extrapolated according to its extrapolation modes (see [`Self::set_extrapolation`]).
     #[inline]
     pub fn value_at(&self, location: f32) -> f32 {
+        if let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) {
+            if location < first.location {
+                self.extrapolated_value_at(location, first, self.pre_extrapolation)
+            } else if location > last.location {
+                self.extrapolated_value_at(location, last, self.post_extrapolation)
+            } else {
+                self.value_in_range(location)
+            }
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates the rate of change (derivative, `dv/dt`) of the curve at the given location. The derivative is
+    /// calculated analytically for every kind of keys, constant keys have zero derivative. Outside of the range of the
+    /// keys, the derivative depends on extrapolation modes of the curve (see [`Self::set_extrapolation`]), for example
+    /// it is zero for clamped curves. It could be used to drive various effects by the velocity of an animated value
+    /// without finite differencing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind};
+    ///
+    /// let curve = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(2.0, 1.0, CurveKeyKind::Linear),
+    /// ]);
+    ///
+    /// assert_eq!(curve.derivative_at(1.0), 0.5);
+    /// assert_eq!(curve.derivative_at(3.0), 0.0);
+    /// ```
+    #[inline]
+    pub fn derivative_at(&self, location: f32) -> f32 {
+        if let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) {
+            let extrapolation = if location < first.location {
+                Some((first, self.pre_extrapolation))
+            } else if location > last.location {
+                Some((last, self.post_extrapolation))
+            } else {
+                None
+            };
+
+            match extrapolation {
+                None => self.derivative_in_range(location),
+                Some((_, Extrapolation::Clamp)) => 0.0,
+                Some((end, Extrapolation::Linear)) => self.derivative_in_range(end.location),
+                Some((_, Extrapolation::Cycle)) => {
+                    self.derivative_in_range(self.cycle_location(location))
+                }
+            }
+        } else {
+            0.0
+        }
+    }
+
+    /// Calculates a value of the curve at the given location, the location is clamped to the range of the keys.
+    /// The curve must have at least one key.
+    #[inline]
+    fn value_in_range(&self, location: f32) -> f32 {
+        let first = &self.keys[0];
+        let last = &self.keys[self.keys.len() - 1];
+        if location <= first.location {
+            first.value
+        } else if location >= last.location {
+            last.value
+        } else {
+            span_value_at(&self.keys, self.span_index(location), location)
+        }
+    }
+
+    /// Calculates an extrapolated value of the curve at the given location outside of the range of the keys, `end` is
+    /// the nearest end key.
+    #[inline]
+    fn extrapolated_value_at(
+        &self,
+        location: f32,
+        end: &CurveKey,
+        extrapolation: Extrapolation,
+    ) -> f32 {
+        match extrapolation {
+            Extrapolation::Clamp => end.value,
+            Extrapolation::Linear => {
+                end.value + (location - end.location) * self.derivative_in_range(end.location)
+            }
+            Extrapolation::Cycle => self.value_in_range(self.cycle_location(location)),
+        }
+    }
+
+    /// Maps the given location into the range of the keys, as if the curve is repeated infinitely.
+    #[inline]
+    fn cycle_location(&self, location: f32) -> f32 {
+        let first = self.keys.first().map_or(0.0, |key| key.location);
+        let period = self.max_location() - first;
+        if period > 0.0 {
+            first + (location - first).rem_euclid(period)
+        } else {
+            first
+        }
+    }
+
+    /// Calculates the derivative of the curve at the given location, the location must be inside the range of the
+    /// keys (inclusively). The derivative at the end keys is one-sided.
+    #[inline]
+    fn derivative_in_range(&self, location: f32) -> f32 {
+        if self.keys.len() < 2 {
+            return 0.0;
+        }
+
+        let pos = self.span_index(location).max(1);
+        let left_index = pos - 1;
+        let left = &self.keys[left_index];
+        let right = &self.keys[pos];
+        let span = right.location - left.location;
+        if span <= 0.0 {
+            return 0.0;
+        }
+        let t = (location - left.location) / span;
+        let derivative = if let Some((m0, m1)) = auto_tangents(&self.keys, pos, &left.kind) {
+            hermitef_derivative(left.value, right.value, t, m0, m1)
+        } else {
+            left.interpolate_derivative(right, t)
+        };
+        derivative / span
+    }
+
+    /// Same as [`Self::value_at`], but every span of the curve is evaluated as if both of its keys have the given
+    /// kind, kinds of the keys themselves are ignored. It could be used to force a specific interpolation for a whole
+    /// curve (for example, stepped interpolation) without editing every key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind};
+    ///
+    /// let curve = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(1.0, 1.0, CurveKeyKind::Linear),
+    /// ]);
+    ///
+    /// assert_eq!(curve.value_at(0.5), 0.5);
+    /// assert_eq!(curve.value_at_with_kind(0.5, &CurveKeyKind::Constant), 0.0);
+    /// ```
+    #[inline]
+    pub fn value_at_with_kind(&self, location: f32, kind: &CurveKeyKind) -> f32 {
         if let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) {
             if location <= first.location {
                 first.value
             } else if location >= last.location {
                 last.value
             } else {
-                // Use binary search for multiple spans.
-                let pos = self.keys.partition_point(|k| k.location < location);
-                let left = self.keys.get(pos.saturating_sub(1)).unwrap();
-                let right = self.keys.get(pos).unwrap();
-                left.interpolate(
-                    right,
-                    (location - left.location) / (right.location - left.location),
-                )
+                span_value_with_kind(&self.keys, self.span_index(location), location, kind)
             }
         } else {
             0.0
         }
     }
+
+    /// Removes keys that are not needed to represent the curve with the given precision. A key is removed if the
+    /// curve without it differs from the source curve by no more than `tolerance` (the difference is measured at the
+    /// locations of all source keys and in the middle of each source span). The first and the last keys are always
+    /// preserved. Keys are checked one-by-one from left to right, so the result is deterministic. It is useful to reduce
+    /// the memory footprint and evaluation cost of dense (baked or imported) curves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind};
+    ///
+    /// let mut curve = Curve::from(
+    ///     (0..=100)
+    ///         .map(|i| CurveKey::new(i as f32 / 100.0, i as f32 / 50.0, CurveKeyKind::Linear))
+    ///         .collect::<Vec<_>>(),
+    /// );
+    ///
+    /// curve.simplify(0.001);
+    ///
+    /// assert_eq!(curve.keys().len(), 2);
+    /// ```
+    pub fn simplify(&mut self, tolerance: f32) {
+        if self.keys.len() <= 2 {
+            return;
+        }
+
+        // Reference samples of the source curve.
+        let mut samples = Vec::with_capacity(self.keys.len() * 2);
+        for (i, key) in self.keys.iter().enumerate() {
+            samples.push((key.location, key.value));
+            if let Some(next) = self.keys.get(i + 1) {
+                let location = (key.location + next.location) * 0.5;
+                samples.push((location, self.value_at(location)));
+            }
+        }
+
+        let mut keys = std::mem::take(&mut self.keys);
+        let mut i = 1;
+        while i + 1 < keys.len() {
+            // Removal of a key affects the shape of the curve up to two spans away from it (because of Catmull-Rom
+            // slopes), evaluation of that region requires one more key on each side.
+            let window_begin = i.saturating_sub(3);
+            let window_end = (i + 4).min(keys.len());
+            let window = keys[window_begin..window_end]
+                .iter()
+                .enumerate()
+                .filter(|(n, _)| window_begin + n != i)
+                .map(|(_, key)| key.clone())
+                .collect::<Vec<_>>();
+
+            let from = keys[i.saturating_sub(2)].location;
+            let to = keys[(i + 2).min(keys.len() - 1)].location;
+            let first_sample = samples.partition_point(|(location, _)| *location < from);
+
+            let removable = samples[first_sample..]
+                .iter()
+                .take_while(|(location, _)| *location <= to)
+                .all(|(location, value)| {
+                    (value_at(&window, *location) - *value).abs() <= tolerance
+                });
+
+            if removable {
+                keys.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        self.keys = keys;
+        self.cursor.set(0);
+    }
+
+    /// Bakes the curve into a new curve of linear keys that are evenly spaced with the given time step (`dt`). The first
+    /// and the last keys of the new curve are placed exactly at the locations of the first and the last keys of the
+    /// source curve, so the last interval could be shorter than `dt`. Empty and single-key curves (as well as
+    /// non-positive time steps) produce a curve with the same keys of linear kind.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind};
+    ///
+    /// let curve = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::new_cubic(0.0, 0.0)),
+    ///     CurveKey::new(1.0, 1.0, CurveKeyKind::new_cubic(0.0, 0.0)),
+    /// ]);
+    ///
+    /// let baked = curve.resample(1.0 / 30.0);
+    ///
+    /// assert_eq!(baked.keys().len(), 31);
+    /// ```
+    pub fn resample(&self, dt: f32) -> Curve {
+        let mut keys = Vec::new();
+
+        if let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) {
+            if dt > 0.0 && last.location > first.location {
+                let count = ((last.location - first.location) / dt).ceil() as usize;
+                keys.reserve(count + 1);
+                for i in 0..count {
+                    let location = first.location + i as f32 * dt;
+                    // Protect from floating-point errors near the end.
+                    if location >= last.location - dt * 0.001 {
+                        break;
+                    }
+                    keys.push(CurveKey::new(
+                        location,
+                        self.value_at(location),
+                        CurveKeyKind::Linear,
+                    ));
+                }
+                keys.push(CurveKey::new(
+                    last.location,
+                    last.value,
+                    CurveKeyKind::Linear,
+                ));
+            } else {
+                keys.extend(
+                    self.keys
+                        .iter()
+                        .map(|k| CurveKey::new(k.location, k.value, CurveKeyKind::Linear)),
+                );
+            }
+        }
+
+        let mut curve = Curve::from(keys);
+        curve.set_name(&self.name);
+        curve.set_extrapolation(self.pre_extrapolation, self.post_extrapolation);
+        curve
+    }
+
+    /// Creates a new normalized curve for the given easing function. The curve starts at `0.0` at zero location and
+    /// ends at `1.0` at `duration` location. The easing function is sampled densely and the samples are simplified
+    /// afterwards, so the curve matches the analytic function within `1e-3` for every kind. Non-positive durations
+    /// produce a curve with a single key with `1.0` value (as if the easing is already finished).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, EasingKind};
+    ///
+    /// let curve = Curve::from_easing(EasingKind::InOutQuad, 2.0);
+    ///
+    /// assert_eq!(curve.value_at(0.0), 0.0);
+    /// assert!((curve.value_at(1.0) - 0.5).abs() < 1.0e-3);
+    /// assert_eq!(curve.value_at(2.0), 1.0);
+    /// ```
+    pub fn from_easing(kind: EasingKind, duration: f32) -> Curve {
+        const SAMPLES: usize = 512;
+
+        if duration <= 0.0 {
+            return Curve::from(vec![CurveKey::new(0.0, 1.0, CurveKeyKind::Linear)]);
+        }
+
+        let mut locations = (0..=SAMPLES)
+            .map(|i| i as f32 / SAMPLES as f32)
+            .chain(kind.corners())
+            .collect::<Vec<_>>();
+        locations.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        locations.dedup();
+
+        let mut curve = Curve::from(
+            locations
+                .into_iter()
+                .map(|t| CurveKey::new(t * duration, kind.ease(t), CurveKeyKind::Linear))
+                .collect::<Vec<_>>(),
+        );
+        curve.simplify(1.0e-4);
+        curve
+    }
+
+    /// Creates a new curve with the values of the keys multiplied by the given `factor`. The shape of the curve is
+    /// preserved exactly for every kind of keys (tangents of cubic keys are flipped for negative factors).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind};
+    ///
+    /// let bounce = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(0.5, 1.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(1.0, 0.0, CurveKeyKind::Linear),
+    /// ]);
+    ///
+    /// let amplified = bounce.scaled(2.0);
+    ///
+    /// assert_eq!(amplified.value_at(0.25), 1.0);
+    /// ```
+    pub fn scaled(&self, factor: f32) -> Curve {
+        self.map_keys(|key| {
+            let kind = match key.kind {
+                CurveKeyKind::Cubic {
+                    left_tangent,
+                    right_tangent,
+                } if factor < 0.0 => CurveKeyKind::Cubic {
+                    left_tangent: -left_tangent,
+                    right_tangent: -right_tangent,
+                },
+                ref kind => kind.clone(),
+            };
+            CurveKey::new(key.location, key.value * factor, kind)
+        })
+    }
+
+    /// Creates a new curve with the keys moved by `dt` along the time axis and by `dv` along the value axis. The shape
+    /// of the curve is preserved exactly for every kind of keys.
+    pub fn offset(&self, dt: f32, dv: f32) -> Curve {
+        self.map_keys(|key| CurveKey::new(key.location + dt, key.value + dv, key.kind.clone()))
+    }
+
+    /// Creates a new curve, which is a sum of the current curve and the `other`. The new curve has keys at the union of
+    /// the locations of the keys of both curves, each key contains the sum of the values of both curves at its location.
+    /// Kinds of the keys are preserved only when it is unambiguous: constant keys of both curves produce a constant key,
+    /// Catmull-Rom keys of both curves produce a Catmull-Rom key, any other combination produces a linear key. The sum is
+    /// exact for constant and linear curves and approximate for others (use [`Self::resample`] on the source curves
+    /// first, if you need higher precision).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox_core::curve::{Curve, CurveKey, CurveKeyKind};
+    ///
+    /// let bounce = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(0.5, 1.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(1.0, 0.0, CurveKeyKind::Linear),
+    /// ]);
+    /// let drift = Curve::from(vec![
+    ///     CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+    ///     CurveKey::new(1.0, 2.0, CurveKeyKind::Linear),
+    /// ]);
+    ///
+    /// let sum = bounce.add(&drift);
+    ///
+    /// assert_eq!(sum.keys().len(), 3);
+    /// assert_eq!(sum.value_at(0.5), 2.0);
+    /// ```
+    pub fn add(&self, other: &Curve) -> Curve {
+        let mut locations = self
+            .keys
+            .iter()
+            .chain(other.keys.iter())
+            .map(|key| key.location)
+            .collect::<Vec<_>>();
+        locations.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        locations.dedup();
+
+        let keys = locations
+            .into_iter()
+            .map(|location| {
+                let kind = match (self.kind_at(location), other.kind_at(location)) {
+                    (Some(CurveKeyKind::Constant), Some(CurveKeyKind::Constant))
+                    | (Some(CurveKeyKind::Constant), None)
+                    | (None, Some(CurveKeyKind::Constant)) => CurveKeyKind::Constant,
+                    (Some(CurveKeyKind::CatmullRom), Some(CurveKeyKind::CatmullRom))
+                    | (Some(CurveKeyKind::CatmullRom), None)
+                    | (None, Some(CurveKeyKind::CatmullRom)) => CurveKeyKind::CatmullRom,
+                    (Some(CurveKeyKind::Smooth), Some(CurveKeyKind::Smooth))
+                    | (Some(CurveKeyKind::Smooth), None)
+                    | (None, Some(CurveKeyKind::Smooth)) => CurveKeyKind::Smooth,
+                    _ => CurveKeyKind::Linear,
+                };
+                CurveKey::new(
+                    location,
+                    self.value_at(location) + other.value_at(location),
+                    kind,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut curve = Curve::from(keys);
+        curve.set_name(&self.name);
+        curve
+    }
+
+    /// Returns a kind of the key that defines the shape of the curve at the given location - it is the right-most key
+    /// that is not further than the location (or the first key, if the location is before the first key).
+    fn kind_at(&self, location: f32) -> Option<&CurveKeyKind> {
+        let pos = self.keys.partition_point(|k| k.location <= location);
+        self.keys.get(pos.saturating_sub(1)).map(|key| &key.kind)
+    }
+
+    fn map_keys<F>(&self, func: F) -> Curve
+    where
+        F: FnMut(&CurveKey) -> CurveKey,
+    {
+        let mut curve = Curve::from(self.keys.iter().map(func).collect::<Vec<_>>());
+        curve.set_name(&self.name);
+        curve.set_extrapolation(self.pre_extrapolation, self.post_extrapolation);
+        curve
+    }
+
+    /// Returns an index of the right key of the span that contains the given location, the location must be strictly
+    /// inside the range of locations of the curve keys. Cached index of the previous span is checked first (as well as
+    /// the next one), because playback time usually advances monotonically. Binary search is used as a fallback.
+    #[inline]
+    fn span_index(&self, location: f32) -> usize {
+        let contains = |pos: usize| {
+            pos > 0
+                && pos < self.keys.len()
+                && self.keys[pos - 1].location < location
+                && location <= self.keys[pos].location
+        };
+
+        let cached = self.cursor.get();
+        let pos = if contains(cached) {
+            cached
+        } else if contains(cached + 1) {
+            cached + 1
+        } else {
+            // Use binary search for multiple spans.
+            self.keys.partition_point(|k| k.location < location)
+        };
+
+        self.cursor.set(pos);
+
+        pos
+    }
+}
+
+/// Calculates a slope (`dv/dt`) of Catmull-Rom spline at a key with given index. The slope is a finite difference
+/// of the neighboring keys, the first and the last keys use one-sided difference.
+fn catmull_rom_slope(keys: &[CurveKey], index: usize) -> f32 {
+    let prev = &keys[index.saturating_sub(1)];
+    let next = &keys[(index + 1).min(keys.len() - 1)];
+    let span = next.location - prev.location;
+    if span > 0.0 {
+        (next.value - prev.value) / span
+    } else {
+        0.0
+    }
+}
+
+/// Calculates a slope (`dv/dt`) of smooth spline (see [`CurveKeyKind::Smooth`]) at a key with given index. The
+/// slope is the slope of Catmull-Rom spline clamped to at most three times the smallest slope of the adjacent spans
+/// (which is enough to keep the spline monotonic between the keys), it is zero if the key is an extremum. The first
+/// and the last keys use the slope of their only span.
+fn smooth_slope(keys: &[CurveKey], index: usize) -> f32 {
+    let secant = |left: &CurveKey, right: &CurveKey| {
+        let span = right.location - left.location;
+        if span > 0.0 {
+            (right.value - left.value) / span
+        } else {
+            0.0
+        }
+    };
+
+    if keys.len() < 2 {
+        return 0.0;
+    }
+
+    let last = keys.len() - 1;
+    if index == 0 {
+        return secant(&keys[0], &keys[1]);
+    }
+    if index >= last {
+        return secant(&keys[last - 1], &keys[last]);
+    }
+
+    let left = secant(&keys[index - 1], &keys[index]);
+    let right = secant(&keys[index], &keys[index + 1]);
+    if left * right <= 0.0 {
+        return 0.0;
+    }
+
+    let limit = 3.0 * left.abs().min(right.abs());
+    catmull_rom_slope(keys, index).clamp(-limit, limit)
+}
+
+/// Calculates tangents (in the space of the span, as expected by `hermitef`) of the keys of a span with automatic
+/// tangents, `pos` is an index of the right key of the span. The type of the tangents is defined by the given kind,
+/// returns `None` if the kind does not have automatic tangents.
+fn auto_tangents(keys: &[CurveKey], pos: usize, kind: &CurveKeyKind) -> Option<(f32, f32)> {
+    let slope: fn(&[CurveKey], usize) -> f32 = match kind {
+        CurveKeyKind::CatmullRom => catmull_rom_slope,
+        CurveKeyKind::Smooth => smooth_slope,
+        _ => return None,
+    };
+    let left_index = pos.saturating_sub(1);
+    let span = keys[pos].location - keys[left_index].location;
+    Some((slope(keys, left_index) * span, slope(keys, pos) * span))
+}
+
+/// Calculates a value of a span of keys, `pos` is an index of the right key of the span, that must contain the
+/// given location.
+fn span_value_at(keys: &[CurveKey], pos: usize, location: f32) -> f32 {
+    let left = &keys[pos.saturating_sub(1)];
+    let right = &keys[pos];
+    let t = (location - left.location) / (right.location - left.location);
+    if let Some((m0, m1)) = auto_tangents(keys, pos, &left.kind) {
+        hermitef(left.value, right.value, t, m0, m1)
+    } else {
+        left.interpolate(right, t)
+    }
+}
+
+/// Same as [`span_value_at`], but the span is evaluated as if both keys have the given kind.
+fn span_value_with_kind(keys: &[CurveKey], pos: usize, location: f32, kind: &CurveKeyKind) -> f32 {
+    let left = &keys[pos.saturating_sub(1)];
+    let right = &keys[pos];
+    let t = (location - left.location) / (right.location - left.location);
+    if let Some((m0, m1)) = auto_tangents(keys, pos, kind) {
+        hermitef(left.value, right.value, t, m0, m1)
+    } else {
+        let with_kind = |key: &CurveKey| CurveKey {
+            kind: kind.clone(),
+            ..key.clone()
+        };
+        with_kind(left).interpolate(&with_kind(right), t)
+    }
+}
+
+/// Same as [`Curve::value_at`], but works with a sorted slice of keys and does not use span cache.
+fn value_at(keys: &[CurveKey], location: f32) -> f32 {
+    if let (Some(first), Some(last)) = (keys.first(), keys.last()) {
+        if location <= first.location {
+            first.value
+        } else if location >= last.location {
+            last.value
+        } else {
+            span_value_at(
+                keys,
+                keys.partition_point(|k| k.location < location),
+                location,
+            )
+        }
+    } else {
+        0.0
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::curve::{Curve, CurveKey, CurveKeyKind};
+    use crate::{
+        algebra::Vector2,
+        curve::{Curve, CurveKey, CurveKeyKind, EasingKind, Extrapolation},
+    };
 
     #[test]
     fn test_curve_key_insertion_order() {
@@ -302,4 +1466,395 @@ mod test {
         // Check interpolation.
         assert_eq!(curve.value_at(0.5), 0.5);
     }
+
+    #[test]
+    fn test_catmull_rom_curve() {
+        let curve = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(2.0, 0.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(4.0, 2.0, CurveKeyKind::CatmullRom),
+        ]);
+
+        // The spline must pass through every key.
+        for key in curve.keys() {
+            assert_eq!(curve.value_at(key.location()), key.value);
+        }
+
+        // The key between two neighbors with equal values is an extremum of the spline.
+        assert!(curve.value_at(0.9) < 1.0);
+        assert!(curve.value_at(1.1) < 1.0);
+
+        // Check C1 continuity at the inner keys.
+        let eps = 1.0e-3;
+        for location in [1.0, 2.0] {
+            let left = (curve.value_at(location) - curve.value_at(location - eps)) / eps;
+            let right = (curve.value_at(location + eps) - curve.value_at(location)) / eps;
+            assert!((left - right).abs() < 5.0e-2);
+        }
+
+        // Two-key Catmull-Rom curve is a straight line, because the end keys are duplicated.
+        let curve = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(2.0, 1.0, CurveKeyKind::CatmullRom),
+        ]);
+        assert!((curve.value_at(0.5) - 0.25).abs() < 1.0e-5);
+        assert!((curve.value_at(1.5) - 0.75).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn test_smooth_curve() {
+        let curve = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Smooth),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::Smooth),
+            CurveKey::new(2.0, 1.0, CurveKeyKind::Smooth),
+            CurveKey::new(3.0, 4.0, CurveKeyKind::Smooth),
+            CurveKey::new(4.0, 4.5, CurveKeyKind::Smooth),
+        ]);
+
+        for key in curve.keys() {
+            assert_eq!(curve.value_at(key.location()), key.value);
+        }
+
+        // The curve is monotonic, it does not overshoot the plateau between the keys with equal values.
+        let mut prev = curve.value_at(0.0);
+        for i in 1..=400 {
+            let value = curve.value_at(i as f32 / 100.0);
+            assert!(value >= prev - 1.0e-6);
+            assert!(value <= 4.5);
+            prev = value;
+        }
+        assert!((curve.value_at(1.5) - 1.0).abs() < 1.0e-6);
+
+        // Check C1 continuity at the inner keys.
+        let eps = 1.0e-3;
+        for location in [1.0, 2.0, 3.0] {
+            let left = (curve.value_at(location) - curve.value_at(location - eps)) / eps;
+            let right = (curve.value_at(location + eps) - curve.value_at(location)) / eps;
+            assert!((left - right).abs() < 5.0e-2);
+        }
+
+        // The end keys use one-sided tangents, so a two-key smooth curve is a straight line.
+        let curve = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Smooth),
+            CurveKey::new(2.0, 1.0, CurveKeyKind::Smooth),
+        ]);
+        assert!((curve.value_at(0.5) - 0.25).abs() < 1.0e-5);
+        assert!((curve.derivative_at(1.0) - 0.5).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_curve_resample() {
+        // Empty and single-key curves.
+        assert!(Curve::default().resample(0.1).is_empty());
+        let single = Curve::from(vec![CurveKey::new(1.0, 2.0, CurveKeyKind::Constant)]);
+        let baked = single.resample(0.1);
+        assert_eq!(baked.keys().len(), 1);
+        assert_eq!(baked.keys()[0].location(), 1.0);
+        assert_eq!(baked.keys()[0].value, 2.0);
+        assert_eq!(baked.keys()[0].kind, CurveKeyKind::Linear);
+
+        let curve = Curve::from(vec![
+            CurveKey::new(0.5, 0.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(1.75, -1.0, CurveKeyKind::CatmullRom),
+        ]);
+        let baked = curve.resample(0.1);
+
+        // First and last key times are preserved exactly.
+        assert_eq!(baked.keys().first().unwrap().location(), 0.5);
+        assert_eq!(baked.keys().last().unwrap().location(), 1.75);
+        assert_eq!(baked.keys().len(), 14);
+
+        for key in baked.keys() {
+            assert_eq!(key.kind, CurveKeyKind::Linear);
+            assert!((key.value - curve.value_at(key.location())).abs() < 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn test_curve_span_cursor() {
+        let curve = Curve::from(
+            (0..100)
+                .map(|i| CurveKey::new(i as f32, (i * i) as f32, CurveKeyKind::Linear))
+                .collect::<Vec<_>>(),
+        );
+
+        // Forward, backward and random access must give the same results as the binary search.
+        let locations = [0.5, 0.7, 1.0, 1.5, 2.5, 50.25, 10.5, 0.1, 98.9, 3.0, 2.0];
+        for location in locations {
+            let pos = curve.keys.partition_point(|k| k.location < location);
+            assert_eq!(curve.span_index(location), pos);
+            assert_eq!(
+                curve.value_at(location),
+                curve.keys[pos - 1]
+                    .interpolate(&curve.keys[pos], location - curve.keys[pos - 1].location)
+            );
+        }
+    }
+
+    #[test]
+    fn test_curve_span_cursor_seeks_and_key_changes() {
+        let mut curve = Curve::from(
+            (0..10)
+                .map(|i| CurveKey::new(i as f32, i as f32, CurveKeyKind::Linear))
+                .collect::<Vec<_>>(),
+        );
+
+        // Forward seek to the next span moves the cursor by one.
+        assert_eq!(curve.value_at(2.5), 2.5);
+        assert_eq!(curve.cursor.get(), 3);
+        assert_eq!(curve.value_at(3.5), 3.5);
+        assert_eq!(curve.cursor.get(), 4);
+
+        // Forward seek over multiple spans.
+        assert_eq!(curve.value_at(7.5), 7.5);
+        assert_eq!(curve.cursor.get(), 8);
+
+        // Backward seek.
+        assert_eq!(curve.value_at(1.5), 1.5);
+        assert_eq!(curve.cursor.get(), 2);
+
+        // Inserting a key into the cached span must split it.
+        curve.add_key(CurveKey::new(1.5, 10.0, CurveKeyKind::Linear));
+        assert_eq!(curve.value_at(1.75), 6.0);
+        assert_eq!(curve.cursor.get(), 3);
+        assert_eq!(curve.value_at(1.25), 5.5);
+        assert_eq!(curve.cursor.get(), 2);
+
+        // Removal of keys must merge the spans, even if the keys were removed without cursor reset.
+        curve.keys.remove(2);
+        assert_eq!(curve.value_at(1.25), 1.25);
+        assert_eq!(curve.cursor.get(), 2);
+        curve.keys.truncate(4);
+        assert_eq!(curve.value_at(2.5), 2.5);
+        assert_eq!(curve.cursor.get(), 3);
+        assert_eq!(curve.value_at(5.0), 3.0);
+
+        // Removal of collinear keys by simplification.
+        curve.simplify(1.0e-4);
+        assert_eq!(curve.keys().len(), 2);
+        assert_eq!(curve.value_at(1.5), 1.5);
+        assert_eq!(curve.cursor.get(), 1);
+    }
+
+    #[test]
+    fn test_curve_simplify() {
+        // Collinear keys must be removed, except the end keys.
+        let mut curve = Curve::from(
+            (0..=10)
+                .map(|i| CurveKey::new(i as f32, 2.0 * i as f32, CurveKeyKind::Linear))
+                .collect::<Vec<_>>(),
+        );
+        curve.simplify(1.0e-4);
+        assert_eq!(curve.keys().len(), 2);
+        assert_eq!(curve.keys()[0].location(), 0.0);
+        assert_eq!(curve.keys()[1].location(), 10.0);
+
+        // A dense baked curve must stay within the tolerance.
+        let source = Curve::from(
+            (0..=200)
+                .map(|i| {
+                    let t = i as f32 / 100.0;
+                    CurveKey::new(t, (t * 3.0).sin(), CurveKeyKind::Linear)
+                })
+                .collect::<Vec<_>>(),
+        );
+        let tolerance = 1.0e-3;
+        let mut simplified = source.clone();
+        simplified.simplify(tolerance);
+        assert!(simplified.keys().len() < source.keys().len() / 2);
+        assert_eq!(simplified.keys().first(), source.keys().first());
+        assert_eq!(simplified.keys().last(), source.keys().last());
+        for key in source.keys() {
+            assert!((simplified.value_at(key.location()) - key.value).abs() <= tolerance);
+        }
+
+        // Sharp corners must be preserved.
+        let mut corner = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::Linear),
+            CurveKey::new(2.0, 0.0, CurveKeyKind::Linear),
+        ]);
+        corner.simplify(0.1);
+        assert_eq!(corner.keys().len(), 3);
+    }
+
+    #[test]
+    fn test_curve_arithmetic() {
+        let curve = Curve::from(vec![
+            CurveKey::new(0.0, 1.0, CurveKeyKind::new_cubic(0.3, -0.2)),
+            CurveKey::new(1.0, 3.0, CurveKeyKind::new_cubic(0.5, 0.1)),
+            CurveKey::new(2.0, 2.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(3.0, 0.0, CurveKeyKind::CatmullRom),
+        ]);
+
+        // Scaling and offset must preserve the shape of the curve exactly.
+        for factor in [2.0, -0.5] {
+            let scaled = curve.scaled(factor);
+            for i in 0..=30 {
+                let t = i as f32 * 0.1;
+                assert!((scaled.value_at(t) - curve.value_at(t) * factor).abs() < 1.0e-5);
+            }
+        }
+        let offset = curve.offset(0.5, -1.0);
+        for i in 0..=30 {
+            let t = i as f32 * 0.1;
+            assert!((offset.value_at(t + 0.5) - (curve.value_at(t) - 1.0)).abs() < 1.0e-5);
+        }
+
+        // Sum of the linear curves is exact.
+        let a = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(2.0, 2.0, CurveKeyKind::Linear),
+        ]);
+        let b = Curve::from(vec![
+            CurveKey::new(1.0, 1.0, CurveKeyKind::Linear),
+            CurveKey::new(3.0, -1.0, CurveKeyKind::Linear),
+        ]);
+        let sum = a.add(&b);
+        assert_eq!(sum.keys().len(), 4);
+        for i in 0..=30 {
+            let t = i as f32 * 0.1;
+            assert!((sum.value_at(t) - (a.value_at(t) + b.value_at(t))).abs() < 1.0e-5);
+        }
+
+        // Mixed kinds degrade to linear.
+        let mixed = curve.add(&a);
+        assert!(mixed
+            .keys()
+            .iter()
+            .all(|key| key.kind == CurveKeyKind::Linear));
+    }
+
+    #[test]
+    fn test_curve_derivative() {
+        for kind in [
+            CurveKeyKind::Constant,
+            CurveKeyKind::Linear,
+            CurveKeyKind::new_cubic(0.3, -0.2),
+            CurveKeyKind::CatmullRom,
+        ] {
+            let curve = Curve::from(vec![
+                CurveKey::new(0.0, 0.0, kind.clone()),
+                CurveKey::new(1.0, 2.0, kind.clone()),
+                CurveKey::new(3.0, -1.0, kind.clone()),
+            ]);
+
+            // Compare with finite differences.
+            for location in [0.25, 0.5, 0.75, 1.5, 2.0, 2.5] {
+                let delta = 1.0e-3;
+                let expected = (curve.value_at(location + delta)
+                    - curve.value_at(location - delta))
+                    / (2.0 * delta);
+                assert!((curve.derivative_at(location) - expected).abs() < 1.0e-2);
+            }
+
+            // The curve is clamped outside of its range.
+            assert_eq!(curve.derivative_at(-1.0), 0.0);
+            assert_eq!(curve.derivative_at(4.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_easing_curves() {
+        for kind in [
+            EasingKind::Linear,
+            EasingKind::InOutQuad,
+            EasingKind::OutCubic,
+            EasingKind::InOutSine,
+            EasingKind::InOutExpo,
+            EasingKind::InOutCirc,
+            EasingKind::InOutBack,
+            EasingKind::OutElastic,
+            EasingKind::InOutElastic,
+            EasingKind::OutBounce,
+            EasingKind::InOutBounce,
+        ] {
+            let curve = Curve::from_easing(kind, 2.0);
+
+            assert!(curve.value_at(0.0).abs() < 1.0e-5);
+            assert!((curve.value_at(2.0) - 1.0).abs() < 1.0e-5);
+            for i in 0..=100 {
+                let t = i as f32 / 100.0;
+                assert!((curve.value_at(t * 2.0) - kind.ease(t)).abs() < 1.0e-3);
+            }
+        }
+
+        // Reference values.
+        assert_eq!(EasingKind::InOutQuad.ease(0.25), 0.125);
+        assert!((EasingKind::OutBounce.ease(0.5) - 0.765625).abs() < 1.0e-5);
+        assert!(EasingKind::InBack.ease(0.5) < 0.0);
+        assert!(Curve::ease_out_quad(1.0).value_at(0.5) > 0.5);
+    }
+
+    #[test]
+    fn test_curve_extrapolation() {
+        let mut curve = Curve::from(vec![
+            CurveKey::new(1.0, 0.0, CurveKeyKind::new_cubic(0.0, 0.3)),
+            CurveKey::new(2.0, 1.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(3.0, 4.0, CurveKeyKind::Linear),
+        ]);
+
+        // Clamp is the default.
+        assert_eq!(curve.value_at(0.0), 0.0);
+        assert_eq!(curve.value_at(5.0), 4.0);
+        assert_eq!(curve.derivative_at(5.0), 0.0);
+
+        curve.set_extrapolation(Extrapolation::Linear, Extrapolation::Linear);
+        for (end, direction) in [(1.0, -1.0), (3.0, 1.0)] {
+            let slope = curve.derivative_at(end);
+            // Continuous by value and by slope at the boundary.
+            let epsilon = 1.0e-3;
+            let outside = curve.value_at(end + direction * epsilon);
+            let inside = curve.value_at(end - direction * epsilon);
+            assert!((outside - inside - 2.0 * direction * epsilon * slope).abs() < 1.0e-4);
+            assert_eq!(curve.derivative_at(end + direction * 10.0), slope);
+            assert!(
+                (curve.value_at(end + direction * 2.0)
+                    - (curve.value_at(end) + direction * 2.0 * slope))
+                    .abs()
+                    < 1.0e-4
+            );
+        }
+
+        curve.set_extrapolation(Extrapolation::Cycle, Extrapolation::Cycle);
+        for location in [1.25, 1.5, 2.0, 2.75] {
+            let value = curve.value_at(location);
+            assert!((curve.value_at(location + 2.0) - value).abs() < 1.0e-4);
+            assert!((curve.value_at(location + 20.0) - value).abs() < 1.0e-3);
+            assert!((curve.value_at(location - 4.0) - value).abs() < 1.0e-4);
+        }
+        assert_eq!(curve.value_at(3.0), 4.0);
+
+        // Derived curves keep extrapolation.
+        assert_eq!(curve.scaled(2.0).post_extrapolation(), Extrapolation::Cycle);
+    }
+
+    #[test]
+    fn test_curve_key_handles() {
+        let prev = CurveKey::new(0.0, 1.0, CurveKeyKind::Linear);
+        let next = CurveKey::new(3.0, -1.0, CurveKeyKind::Linear);
+        let mut key = CurveKey::new(1.0, 2.0, CurveKeyKind::Linear);
+        assert_eq!(key.left_tangent(), None);
+        assert_eq!(key.left_handle(&prev), None);
+
+        // Slopes of 2.0 on the left side and -0.5 on the right side.
+        key.set_left_handle(&prev, Vector2::new(0.5, 1.0));
+        key.set_right_handle(&next, Vector2::new(2.0, 1.5));
+        assert!(key.left_tangent().is_some());
+
+        let left = key.left_handle(&prev).unwrap();
+        assert!((left - Vector2::new(2.0 / 3.0, 2.0 - 2.0 / 3.0)).norm() < 1.0e-5);
+        let right = key.right_handle(&next).unwrap();
+        assert!((right - Vector2::new(1.0 + 2.0 / 3.0, 2.0 - 1.0 / 3.0)).norm() < 1.0e-5);
+
+        let mut prev = prev;
+        prev.set_right_tangent(0.0);
+        let mut next = next;
+        next.set_left_tangent(0.0);
+        let curve = Curve::from(vec![prev, key, next]);
+        assert!((curve.derivative_at(1.0 - 1.0e-4) - 2.0).abs() < 1.0e-2);
+        assert!((curve.derivative_at(1.0 + 1.0e-4) + 0.5).abs() < 1.0e-2);
+    }
 }
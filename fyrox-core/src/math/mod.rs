@@ -970,6 +970,47 @@ pub fn quat_from_euler<T: SimdRealField + RealField + Copy + Clone>(
     }
 }
 
+/// Returns `b` or `-b` (whichever is closer to `a`). Both quaternions represent the same rotation, but interpolation
+/// between `a` and `b` takes the long way (more than 180 degrees), if the quaternions are in the opposite hemispheres
+/// (`dot(a, b) < 0`).
+#[inline]
+pub fn quat_closest<T: SimdRealField + RealField + Copy + Clone>(
+    a: &UnitQuaternion<T>,
+    b: &UnitQuaternion<T>,
+) -> UnitQuaternion<T> {
+    if a.coords.dot(&b.coords) < T::zero() {
+        UnitQuaternion::new_unchecked(-b.into_inner())
+    } else {
+        *b
+    }
+}
+
+/// Normalized linear interpolation between two rotations along the shortest path. Unlike [`UnitQuaternion::nlerp`]
+/// it never takes the long way (see [`quat_closest`]) and it does not degenerate if `a` and `b` are (almost)
+/// opposite quaternions of the same rotation.
+#[inline]
+pub fn quat_nlerp_shortest<T: SimdRealField + RealField + Copy + Clone>(
+    a: &UnitQuaternion<T>,
+    b: &UnitQuaternion<T>,
+    t: T,
+) -> UnitQuaternion<T> {
+    a.nlerp(&quat_closest(a, b), t)
+}
+
+/// Spherical linear interpolation between two rotations along the shortest path (see [`quat_closest`]). Falls back
+/// to [`quat_nlerp_shortest`] if the rotations are too close to each other (closer than `epsilon`).
+#[inline]
+pub fn quat_slerp_shortest<T: SimdRealField + RealField + Copy + Clone>(
+    a: &UnitQuaternion<T>,
+    b: &UnitQuaternion<T>,
+    t: T,
+    epsilon: T,
+) -> UnitQuaternion<T> {
+    let b = quat_closest(a, b);
+    a.try_slerp(&b, t, epsilon)
+        .unwrap_or_else(|| a.nlerp(&b, t))
+}
+
 pub trait Matrix4Ext<T: Scalar> {
     fn side(&self) -> Vector3<T>;
     fn up(&self) -> Vector3<T>;
@@ -1132,4 +1173,26 @@ mod test {
             println!("{}", angle.update(1.0).angle().to_degrees());
         }
     }
+
+    #[test]
+    fn quat_shortest_path() {
+        use crate::algebra::{UnitQuaternion, Vector3};
+
+        // Keys straddle 180 degrees boundary.
+        let a = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), 170.0f32.to_radians());
+        let b = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), -170.0f32.to_radians());
+
+        for q in [
+            super::quat_nlerp_shortest(&a, &b, 0.5),
+            super::quat_slerp_shortest(&a, &b, 0.5, f32::EPSILON),
+        ] {
+            assert!((q.angle() - 180.0f32.to_radians()).abs() < 1.0e-3);
+            assert!((q.norm() - 1.0).abs() < 1.0e-5);
+        }
+
+        // Opposite quaternions of the same rotation do not degenerate.
+        let c = UnitQuaternion::new_unchecked(-a.into_inner());
+        let q = super::quat_nlerp_shortest(&a, &c, 0.5);
+        assert!(q.angle_to(&a) < 1.0e-3);
+    }
 }
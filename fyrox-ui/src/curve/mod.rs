@@ -38,6 +38,8 @@ pub enum CurveEditorMessage {
     Zoom(Vector2<f32>),
     ZoomToFit,
     HighlightZones(Vec<HighlightZone>),
+    // Kind of the keys that will be added by the user.
+    DefaultKeyKind(CurveKeyKind),
 
     // Internal messages. Use only when you know what you're doing.
     // These are internal because you must use Sync message to request changes
@@ -56,6 +58,7 @@ impl CurveEditorMessage {
     define_constructor!(CurveEditorMessage:Zoom => fn zoom(Vector2<f32>), layout: false);
     define_constructor!(CurveEditorMessage:ZoomToFit => fn zoom_to_fit(), layout: false);
     define_constructor!(CurveEditorMessage:HighlightZones => fn hightlight_zones(Vec<HighlightZone>), layout: false);
+    define_constructor!(CurveEditorMessage:DefaultKeyKind => fn default_key_kind(CurveKeyKind), layout: false);
     // Internal. Use only when you know what you're doing.
     define_constructor!(CurveEditorMessage:RemoveSelection => fn remove_selection(), layout: false);
     define_constructor!(CurveEditorMessage:ChangeSelectedKeysKind => fn change_selected_keys_kind(CurveKeyKind), layout: false);
@@ -103,6 +106,7 @@ pub struct CurveEditor {
     min_zoom: Vector2<f32>,
     max_zoom: Vector2<f32>,
     highlight_zones: Vec<HighlightZone>,
+    default_key_kind: CurveKeyKind,
 }
 
 crate::define_widget_deref!(CurveEditor);
@@ -116,6 +120,8 @@ struct ContextMenu {
     make_constant: Handle<UiNode>,
     make_linear: Handle<UiNode>,
     make_cubic: Handle<UiNode>,
+    make_catmull_rom: Handle<UiNode>,
+    make_smooth: Handle<UiNode>,
     zoom_to_fit: Handle<UiNode>,
     key_properties: Handle<UiNode>,
     key_value: Handle<UiNode>,
@@ -470,7 +476,7 @@ impl Control for CurveEditor {
                             let local_pos = self.point_to_local_space(*screen_pos);
                             self.key_container.add(CurveKeyView {
                                 position: local_pos,
-                                kind: CurveKeyKind::Linear,
+                                kind: self.default_key_kind.clone(),
                                 id: Uuid::new_v4(),
                             });
                             self.set_selection(None, ui);
@@ -587,6 +593,9 @@ impl Control for CurveEditor {
                         CurveEditorMessage::HighlightZones(zones) => {
                             self.highlight_zones = zones.clone();
                         }
+                        CurveEditorMessage::DefaultKeyKind(kind) => {
+                            self.default_key_kind = kind.clone();
+                        }
                     }
                 }
             }
@@ -621,6 +630,18 @@ impl Control for CurveEditor {
                         right_tangent: 0.0,
                     },
                 ));
+            } else if message.destination() == self.context_menu.make_catmull_rom {
+                ui.send_message(CurveEditorMessage::change_selected_keys_kind(
+                    self.handle,
+                    MessageDirection::ToWidget,
+                    CurveKeyKind::CatmullRom,
+                ));
+            } else if message.destination() == self.context_menu.make_smooth {
+                ui.send_message(CurveEditorMessage::change_selected_keys_kind(
+                    self.handle,
+                    MessageDirection::ToWidget,
+                    CurveKeyKind::Smooth,
+                ));
             } else if message.destination() == self.context_menu.add_key {
                 let screen_pos = ui.node(self.context_menu.widget).screen_position();
                 ui.send_message(CurveEditorMessage::add_key(
@@ -1039,6 +1060,7 @@ impl CurveEditor {
             );
         }
 
+        let mut sampling_curve = None;
         for pair in draw_keys.windows(2) {
             let left = &pair[0];
             let right = &pair[1];
@@ -1052,7 +1074,9 @@ impl CurveEditor {
                 // Constant-to-any is depicted as two straight lines.
                 (CurveKeyKind::Constant, CurveKeyKind::Constant)
                 | (CurveKeyKind::Constant, CurveKeyKind::Linear)
-                | (CurveKeyKind::Constant, CurveKeyKind::Cubic { .. }) => {
+                | (CurveKeyKind::Constant, CurveKeyKind::Cubic { .. })
+                | (CurveKeyKind::Constant, CurveKeyKind::CatmullRom)
+                | (CurveKeyKind::Constant, CurveKeyKind::Smooth) => {
                     ctx.push_line(left_pos, Vector2::new(right_pos.x, left_pos.y), 1.0);
                     ctx.push_line(Vector2::new(right_pos.x, left_pos.y), right_pos, 1.0);
                 }
@@ -1060,7 +1084,9 @@ impl CurveEditor {
                 // Linear-to-any is depicted as a straight line.
                 (CurveKeyKind::Linear, CurveKeyKind::Constant)
                 | (CurveKeyKind::Linear, CurveKeyKind::Linear)
-                | (CurveKeyKind::Linear, CurveKeyKind::Cubic { .. }) => {
+                | (CurveKeyKind::Linear, CurveKeyKind::Cubic { .. })
+                | (CurveKeyKind::Linear, CurveKeyKind::CatmullRom)
+                | (CurveKeyKind::Linear, CurveKeyKind::Smooth) => {
                     ctx.push_line(left_pos, right_pos, 1.0)
                 }
 
@@ -1078,8 +1104,39 @@ impl CurveEditor {
                         ..
                     },
                     CurveKeyKind::Linear,
+                )
+                | (
+                    CurveKeyKind::Cubic {
+                        right_tangent: left_tangent,
+                        ..
+                    },
+                    CurveKeyKind::CatmullRom,
+                )
+                | (
+                    CurveKeyKind::Cubic {
+                        right_tangent: left_tangent,
+                        ..
+                    },
+                    CurveKeyKind::Smooth,
                 ) => draw_cubic(left_pos, *left_tangent, right_pos, 0.0, steps, ctx),
 
+                // Catmull-Rom-to-any and smooth-to-any depend on neighboring keys, so they're sampled from the
+                // actual curve.
+                (CurveKeyKind::CatmullRom, _) | (CurveKeyKind::Smooth, _) => {
+                    let curve = sampling_curve.get_or_insert_with(|| self.key_container.curve());
+                    let mut prev = left_pos;
+                    for i in 0..steps {
+                        let t = i as f32 / (steps - 1) as f32;
+                        let location = lerpf(left.position.x, right.position.x, t);
+                        let pt = self.point_to_screen_space(Vector2::new(
+                            location,
+                            curve.value_at(location),
+                        ));
+                        ctx.push_line(prev, pt, 1.0);
+                        prev = pt;
+                    }
+                }
+
                 // Cubic-to-cubic is depicted as Hermite spline.
                 (
                     CurveKeyKind::Cubic {
@@ -1228,6 +1285,7 @@ pub struct CurveEditorBuilder {
     min_zoom: Vector2<f32>,
     max_zoom: Vector2<f32>,
     highlight_zones: Vec<HighlightZone>,
+    default_key_kind: CurveKeyKind,
 }
 
 impl CurveEditorBuilder {
@@ -1244,6 +1302,7 @@ impl CurveEditorBuilder {
             min_zoom: Vector2::new(0.001, 0.001),
             max_zoom: Vector2::new(1000.0, 1000.0),
             highlight_zones: Default::default(),
+            default_key_kind: CurveKeyKind::Linear,
         }
     }
 
@@ -1298,6 +1357,12 @@ impl CurveEditorBuilder {
         self
     }
 
+    /// Kind of the keys that will be added by the user.
+    pub fn with_default_key_kind(mut self, kind: CurveKeyKind) -> Self {
+        self.default_key_kind = kind;
+        self
+    }
+
     pub fn build(mut self, ctx: &mut BuildContext) -> Handle<UiNode> {
         let keys = KeyContainer::from(&self.curve);
 
@@ -1306,6 +1371,8 @@ impl CurveEditorBuilder {
         let make_constant;
         let make_linear;
         let make_cubic;
+        let make_catmull_rom;
+        let make_smooth;
         let key;
         let zoom_to_fit;
         let key_properties;
@@ -1403,6 +1470,19 @@ impl CurveEditorBuilder {
                                             .build(ctx);
                                         make_cubic
                                     },
+                                    {
+                                        make_catmull_rom =
+                                            MenuItemBuilder::new(WidgetBuilder::new())
+                                                .with_content(MenuItemContent::text("Catmull-Rom"))
+                                                .build(ctx);
+                                        make_catmull_rom
+                                    },
+                                    {
+                                        make_smooth = MenuItemBuilder::new(WidgetBuilder::new())
+                                            .with_content(MenuItemContent::text("Smooth"))
+                                            .build(ctx);
+                                        make_smooth
+                                    },
                                 ])
                                 .build(ctx);
                             key
@@ -1453,6 +1533,8 @@ impl CurveEditorBuilder {
                 make_constant,
                 make_linear,
                 make_cubic,
+                make_catmull_rom,
+                make_smooth,
                 key,
                 zoom_to_fit,
                 key_properties,
@@ -1466,6 +1548,7 @@ impl CurveEditorBuilder {
             min_zoom: self.min_zoom,
             max_zoom: self.max_zoom,
             highlight_zones: self.highlight_zones,
+            default_key_kind: self.default_key_kind,
         };
 
         ctx.add_node(UiNode::new(editor))
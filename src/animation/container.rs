@@ -4,17 +4,19 @@
 use crate::{
     animation::value::TrackValue,
     core::{
-        algebra::{Vector2, Vector3, Vector4},
-        curve::Curve,
+        algebra::{UnitQuaternion, Vector2, Vector3, Vector4},
+        curve::{Curve, CurveKey, CurveKeyKind},
         math::{quat_from_euler, RotationOrder},
         reflect::prelude::*,
         visitor::prelude::*,
     },
 };
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
 
 /// The kind of track output value, the animation system works only with numeric properties and the number
 /// of variants is small.
-#[derive(Clone, Copy, Debug, Visit, Reflect, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Visit, Reflect, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TrackValueKind {
     /// A real number. Requires only 1 parametric curve.
     Real,
@@ -32,6 +34,21 @@ pub enum TrackValueKind {
     /// `ZAngle = 2`. The order of rotations is `XYZ`. This triple of curves forms Euler angles which are interpolated
     /// and then converted to a quaternion.
     UnitQuaternion,
+
+    /// A boolean value. Requires only 1 parametric curve, its value is quantized: values greater or equal to `0.5`
+    /// are treated as `true` (`1.0`), others - as `false` (`0.0`). Keys of such tracks are stepped by default
+    /// (see [`Self::default_key_kind`]), so the value switches exactly at the key locations.
+    Bool,
+
+    /// An integer value. Requires only 1 parametric curve, its value is rounded to the nearest integer. Keys of such
+    /// tracks are stepped by default (see [`Self::default_key_kind`]), so the value switches exactly at the key
+    /// locations.
+    Integer,
+
+    /// An RGBA color. Requires 4 parametric curves, where `R = 0`, `G = 1`, `B = 2`, `A = 3`. Each component is in
+    /// `0..1` range, values outside of the range are clamped. The value could be bound to [`crate::core::color::Color`]
+    /// properties (see [`crate::animation::value::ValueType::Color`]) or to any other 4-dimensional vector property.
+    Color,
 }
 
 impl TrackValueKind {
@@ -42,11 +59,23 @@ impl TrackValueKind {
             TrackValueKind::Real => 1,
             TrackValueKind::Vector2 => 2,
             TrackValueKind::Vector3 => 3,
-            TrackValueKind::Vector4 => 4,
+            TrackValueKind::Vector4 | TrackValueKind::Color => 4,
             TrackValueKind::UnitQuaternion => {
                 // Euler angles
                 3
             }
+            TrackValueKind::Bool | TrackValueKind::Integer => 1,
+        }
+    }
+
+    /// Returns a kind of curve keys that suits the value kind best. Discrete values (booleans and integers) use
+    /// stepping ([`CurveKeyKind::Constant`]), while any other values are interpolated linearly. This kind is used
+    /// for every key that is created for a track without an explicit kind (by the editor, the animation recorder,
+    /// [`TrackDataContainer::add_key`], etc.).
+    pub fn default_key_kind(self) -> CurveKeyKind {
+        match self {
+            TrackValueKind::Bool | TrackValueKind::Integer => CurveKeyKind::Constant,
+            _ => CurveKeyKind::Linear,
         }
     }
 }
@@ -93,6 +122,49 @@ impl TrackDataContainer {
         self.curves.push(curve)
     }
 
+    /// Adds a new key to a curve at a given index. The key has the default kind of the value kind of the container
+    /// (see [`TrackValueKind::default_key_kind`]). Returns `false` if there's no curve at the given index.
+    pub fn add_key(&mut self, index: usize, location: f32, value: f32) -> bool {
+        let kind = self.kind.default_key_kind();
+        if let Some(curve) = self.curves.get_mut(index) {
+            curve.add_key(CurveKey::new(location, value, kind));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Adds a new rotation key to the container of [`TrackValueKind::UnitQuaternion`] kind. The rotation is converted
+    /// to Euler angles, which are "unwrapped" relative to the previous keys (or the next ones, if there are no previous
+    /// keys), so there are no sudden jumps of 360 degrees between adjacent keys and the rotation is interpolated along
+    /// the shortest path. For example, rotations of 170 and -170 degrees around an axis are stored as 170 and 190
+    /// degrees. Returns `false` if the container has a different kind or there are not enough curves.
+    pub fn add_rotation_key(&mut self, location: f32, rotation: &UnitQuaternion<f32>) -> bool {
+        if self.kind != TrackValueKind::UnitQuaternion || self.curves.len() < 3 {
+            return false;
+        }
+
+        let kind = self.kind.default_key_kind();
+        let (roll, pitch, yaw) = rotation.euler_angles();
+        for (curve, angle) in self.curves.iter_mut().zip([roll, pitch, yaw]) {
+            let keys = curve.keys();
+            let angle = match keys
+                .iter()
+                .rev()
+                .find(|key| key.location() <= location)
+                .or_else(|| keys.first())
+            {
+                Some(reference) => {
+                    angle + ((reference.value - angle) / (2.0 * PI)).round() * 2.0 * PI
+                }
+                None => angle,
+            };
+            curve.add_key(CurveKey::new(location, angle, kind.clone()));
+        }
+
+        true
+    }
+
     /// Tries to borrow a curve at a given index.
     pub fn curve(&self, index: usize) -> Option<&Curve> {
         self.curves.get(index)
@@ -128,34 +200,58 @@ impl TrackDataContainer {
     /// and cannot produce a desired value (for example, [`Vector3`] can be fetched only if the amount of curves
     /// is 3).
     pub fn fetch(&self, time: f32) -> Option<TrackValue> {
+        self.fetch_with_kind(time, None)
+    }
+
+    /// Same as [`Self::fetch`], but if `kind` is specified, every curve of the container is evaluated as if all its
+    /// keys have the given kind (see [`Curve::value_at_with_kind`]).
+    pub fn fetch_with_kind(&self, time: f32, kind: Option<&CurveKeyKind>) -> Option<TrackValue> {
+        let value_at = |index: usize| {
+            self.curves.get(index).map(|curve| match kind {
+                Some(kind) => curve.value_at_with_kind(time, kind),
+                None => curve.value_at(time),
+            })
+        };
+
         match self.kind {
-            TrackValueKind::Real => Some(TrackValue::Real(self.curves.get(0)?.value_at(time))),
+            TrackValueKind::Real => Some(TrackValue::Real(value_at(0)?)),
             TrackValueKind::Vector2 => Some(TrackValue::Vector2(Vector2::new(
-                self.curves.get(0)?.value_at(time),
-                self.curves.get(1)?.value_at(time),
+                value_at(0)?,
+                value_at(1)?,
             ))),
             TrackValueKind::Vector3 => Some(TrackValue::Vector3(Vector3::new(
-                self.curves.get(0)?.value_at(time),
-                self.curves.get(1)?.value_at(time),
-                self.curves.get(2)?.value_at(time),
+                value_at(0)?,
+                value_at(1)?,
+                value_at(2)?,
             ))),
             TrackValueKind::Vector4 => Some(TrackValue::Vector4(Vector4::new(
-                self.curves.get(0)?.value_at(time),
-                self.curves.get(1)?.value_at(time),
-                self.curves.get(2)?.value_at(time),
-                self.curves.get(3)?.value_at(time),
+                value_at(0)?,
+                value_at(1)?,
+                value_at(2)?,
+                value_at(3)?,
             ))),
             TrackValueKind::UnitQuaternion => {
                 // Convert Euler angles to quaternion
-                let x = self.curves.get(0)?.value_at(time);
-                let y = self.curves.get(1)?.value_at(time);
-                let z = self.curves.get(2)?.value_at(time);
+                let x = value_at(0)?;
+                let y = value_at(1)?;
+                let z = value_at(2)?;
 
                 Some(TrackValue::UnitQuaternion(quat_from_euler(
                     Vector3::new(x, y, z),
                     RotationOrder::XYZ,
                 )))
             }
+            TrackValueKind::Bool => {
+                let value = value_at(0)?;
+                Some(TrackValue::Real(if value >= 0.5 { 1.0 } else { 0.0 }))
+            }
+            TrackValueKind::Integer => Some(TrackValue::Real(value_at(0)?.round())),
+            TrackValueKind::Color => Some(TrackValue::Vector4(Vector4::new(
+                value_at(0)?.clamp(0.0, 1.0),
+                value_at(1)?.clamp(0.0, 1.0),
+                value_at(2)?.clamp(0.0, 1.0),
+                value_at(3)?.clamp(0.0, 1.0),
+            ))),
         }
     }
 
@@ -0,0 +1,675 @@
+//! Human-readable (RON) representation of animations, that is independent of the binary [`crate::core::visitor::Visitor`]
+//! format and of the handles of scene nodes. See [`Animation::save_to_ron`] and [`Animation::load_from_ron`] docs for
+//! more info.
+
+use crate::{
+    animation::{
+        container::{TrackDataContainer, TrackValueKind},
+        event_track::{EventKey, EventTrack},
+        signal::AnimationSignal,
+        track::{Track, TrackInterpolation},
+        value::{ComponentMask, ScaleBlending, TransformSpace, ValueBinding, ValueType},
+        Animation, AnimationBlendMode, AnimationMask, LoopMode, PlaybackDirection,
+        RootMotionRotation, RootMotionSettings, SyncGroupId,
+    },
+    core::{
+        curve::{Curve, CurveKey, CurveKeyKind, Extrapolation},
+        pool::Handle,
+        uuid::Uuid,
+    },
+    scene::{graph::Graph, node::Node},
+    utils::log::Log,
+};
+use fxhash::FxHashMap;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// All possible errors that can occur during loading of an animation from its RON representation.
+#[derive(Debug)]
+pub enum AnimationDefinitionError {
+    /// The source string is not a valid RON representation of an animation.
+    Parse(ron::error::SpannedError),
+}
+
+impl Display for AnimationDefinitionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnimationDefinitionError::Parse(v) => {
+                write!(f, "A parsing error has occurred {v}")
+            }
+        }
+    }
+}
+
+impl From<ron::error::SpannedError> for AnimationDefinitionError {
+    fn from(e: ron::error::SpannedError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+enum KeyKindDefinition {
+    Constant,
+    Linear,
+    Cubic {
+        left_tangent: f32,
+        right_tangent: f32,
+    },
+    CatmullRom,
+    Smooth,
+}
+
+#[derive(Serialize, Deserialize)]
+enum ExtrapolationDefinition {
+    Clamp,
+    Linear,
+    Cycle,
+}
+
+impl Default for ExtrapolationDefinition {
+    fn default() -> Self {
+        Self::Clamp
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct KeyDefinition {
+    #[serde(default)]
+    id: String,
+    location: f32,
+    value: f32,
+    kind: KeyKindDefinition,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CurveDefinition {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    pre_extrapolation: ExtrapolationDefinition,
+    #[serde(default)]
+    post_extrapolation: ExtrapolationDefinition,
+    keys: Vec<KeyDefinition>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum BindingDefinition {
+    Position,
+    Scale,
+    Rotation,
+    Property { name: String, value_type: ValueType },
+    BlendShape { name: String },
+}
+
+#[derive(Serialize, Deserialize)]
+struct TrackDefinition {
+    #[serde(default)]
+    id: String,
+    target: String,
+    binding: BindingDefinition,
+    kind: TrackValueKind,
+    #[serde(default = "default_true")]
+    enabled: bool,
+    #[serde(default)]
+    interpolation: TrackInterpolation,
+    #[serde(default)]
+    components: ComponentMask,
+    #[serde(default)]
+    scale_blending: ScaleBlending,
+    #[serde(default)]
+    space: TransformSpace,
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(default)]
+    quantization: Option<f32>,
+    curves: Vec<CurveDefinition>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SignalDefinition {
+    #[serde(default)]
+    id: String,
+    name: String,
+    time: f32,
+    #[serde(default = "default_true")]
+    enabled: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EventKeyDefinition {
+    time: f32,
+    name: String,
+    #[serde(default)]
+    payload: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EventTrackDefinition {
+    #[serde(default)]
+    id: String,
+    name: String,
+    #[serde(default = "default_true")]
+    enabled: bool,
+    #[serde(default)]
+    keys: Vec<EventKeyDefinition>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RootMotionDefinition {
+    node: String,
+    extract_x: bool,
+    extract_y: bool,
+    extract_z: bool,
+    #[serde(default)]
+    extract_rotation: RootMotionRotation,
+}
+
+#[derive(Serialize, Deserialize)]
+struct AnimationDefinition {
+    name: String,
+    time_slice: (f32, f32),
+    #[serde(default = "default_one")]
+    speed: f32,
+    #[serde(default)]
+    loop_mode: LoopMode,
+    #[serde(default)]
+    direction: PlaybackDirection,
+    #[serde(default = "default_true")]
+    enabled: bool,
+    #[serde(default = "default_one")]
+    weight: f32,
+    #[serde(default)]
+    blend_mode: AnimationBlendMode,
+    #[serde(default)]
+    additive_base_time: Option<f32>,
+    #[serde(default)]
+    sync_group: Option<SyncGroupId>,
+    #[serde(default = "default_true")]
+    hold_on_end: bool,
+    #[serde(default)]
+    auto_apply: bool,
+    #[serde(default)]
+    time_offset: f32,
+    #[serde(default)]
+    loop_range: Option<(f32, f32)>,
+    #[serde(default)]
+    mask: Option<Vec<String>>,
+    #[serde(default)]
+    mask_weights: Option<Vec<f32>>,
+    #[serde(default)]
+    root_motion: Option<RootMotionDefinition>,
+    #[serde(default)]
+    signals: Vec<SignalDefinition>,
+    #[serde(default)]
+    event_tracks: Vec<EventTrackDefinition>,
+    #[serde(default)]
+    tracks: Vec<TrackDefinition>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_one() -> f32 {
+    1.0
+}
+
+fn parse_id(id: &str) -> Uuid {
+    if id.is_empty() {
+        Uuid::new_v4()
+    } else {
+        Uuid::parse_str(id).unwrap_or_else(|err| {
+            Log::warn(format!(
+                "Invalid id {id} in animation definition ({err}), a new one will be generated!"
+            ));
+            Uuid::new_v4()
+        })
+    }
+}
+
+fn node_name(graph: &Graph, handle: Handle<Node>) -> String {
+    graph
+        .try_get(handle)
+        .map(|node| node.name_owned())
+        .unwrap_or_default()
+}
+
+impl From<&CurveKeyKind> for KeyKindDefinition {
+    fn from(kind: &CurveKeyKind) -> Self {
+        match *kind {
+            CurveKeyKind::Constant => Self::Constant,
+            CurveKeyKind::Linear => Self::Linear,
+            CurveKeyKind::Cubic {
+                left_tangent,
+                right_tangent,
+            } => Self::Cubic {
+                left_tangent,
+                right_tangent,
+            },
+            CurveKeyKind::CatmullRom => Self::CatmullRom,
+            CurveKeyKind::Smooth => Self::Smooth,
+        }
+    }
+}
+
+impl From<KeyKindDefinition> for CurveKeyKind {
+    fn from(kind: KeyKindDefinition) -> Self {
+        match kind {
+            KeyKindDefinition::Constant => Self::Constant,
+            KeyKindDefinition::Linear => Self::Linear,
+            KeyKindDefinition::Cubic {
+                left_tangent,
+                right_tangent,
+            } => Self::Cubic {
+                left_tangent,
+                right_tangent,
+            },
+            KeyKindDefinition::CatmullRom => Self::CatmullRom,
+            KeyKindDefinition::Smooth => Self::Smooth,
+        }
+    }
+}
+
+impl From<Extrapolation> for ExtrapolationDefinition {
+    fn from(extrapolation: Extrapolation) -> Self {
+        match extrapolation {
+            Extrapolation::Clamp => Self::Clamp,
+            Extrapolation::Linear => Self::Linear,
+            Extrapolation::Cycle => Self::Cycle,
+        }
+    }
+}
+
+impl From<ExtrapolationDefinition> for Extrapolation {
+    fn from(extrapolation: ExtrapolationDefinition) -> Self {
+        match extrapolation {
+            ExtrapolationDefinition::Clamp => Self::Clamp,
+            ExtrapolationDefinition::Linear => Self::Linear,
+            ExtrapolationDefinition::Cycle => Self::Cycle,
+        }
+    }
+}
+
+impl From<&Curve> for CurveDefinition {
+    fn from(curve: &Curve) -> Self {
+        Self {
+            id: curve.id().to_string(),
+            name: curve.name().to_owned(),
+            pre_extrapolation: curve.pre_extrapolation().into(),
+            post_extrapolation: curve.post_extrapolation().into(),
+            keys: curve
+                .keys()
+                .iter()
+                .map(|key| KeyDefinition {
+                    id: key.id.to_string(),
+                    location: key.location(),
+                    value: key.value,
+                    kind: KeyKindDefinition::from(&key.kind),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<CurveDefinition> for Curve {
+    fn from(definition: CurveDefinition) -> Self {
+        let mut curve = Curve::from(
+            definition
+                .keys
+                .into_iter()
+                .map(|key| {
+                    let mut curve_key = CurveKey::new(key.location, key.value, key.kind.into());
+                    curve_key.id = parse_id(&key.id);
+                    curve_key
+                })
+                .collect::<Vec<_>>(),
+        );
+        curve.set_id(parse_id(&definition.id));
+        curve.set_name(definition.name);
+        curve.set_extrapolation(
+            definition.pre_extrapolation.into(),
+            definition.post_extrapolation.into(),
+        );
+        curve
+    }
+}
+
+impl From<&ValueBinding> for BindingDefinition {
+    fn from(binding: &ValueBinding) -> Self {
+        match binding {
+            ValueBinding::Position => Self::Position,
+            ValueBinding::Scale => Self::Scale,
+            ValueBinding::Rotation => Self::Rotation,
+            ValueBinding::Property { name, value_type } => Self::Property {
+                name: name.clone(),
+                value_type: *value_type,
+            },
+            ValueBinding::BlendShape { name } => Self::BlendShape { name: name.clone() },
+        }
+    }
+}
+
+impl From<BindingDefinition> for ValueBinding {
+    fn from(binding: BindingDefinition) -> Self {
+        match binding {
+            BindingDefinition::Position => Self::Position,
+            BindingDefinition::Scale => Self::Scale,
+            BindingDefinition::Rotation => Self::Rotation,
+            BindingDefinition::Property { name, value_type } => Self::Property { name, value_type },
+            BindingDefinition::BlendShape { name } => Self::BlendShape { name },
+        }
+    }
+}
+
+impl Animation {
+    /// Saves the animation to a human-readable [RON](https://github.com/ron-rs/ron) string. Unlike the binary
+    /// [`crate::core::visitor::Visitor`] format, the string refers to animated nodes by their names (taken from the
+    /// given `graph`), which makes it suitable for diffing in version control systems and for sharing animations
+    /// between scenes. The string contains tracks (with their bindings, curves and keys), signals and all playback
+    /// settings of the animation. Runtime state (playback position, events, pose, etc.) is not saved.
+    ///
+    /// Use [`Self::load_from_ron`] to load the animation back.
+    pub fn save_to_ron(&self, graph: &Graph) -> String {
+        let definition = AnimationDefinition {
+            name: self.name.clone(),
+            time_slice: (self.time_slice.start, self.time_slice.end),
+            speed: self.speed,
+            loop_mode: self.loop_mode,
+            direction: self.direction,
+            enabled: self.enabled,
+            weight: self.weight,
+            blend_mode: self.blend_mode,
+            additive_base_time: self.additive_base_time,
+            sync_group: self.sync_group,
+            hold_on_end: self.hold_on_end,
+            auto_apply: self.auto_apply,
+            time_offset: self.time_offset,
+            loop_range: self
+                .loop_range
+                .as_ref()
+                .map(|range| (range.start, range.end)),
+            mask: self.mask.as_ref().map(|mask| {
+                mask.inner()
+                    .iter()
+                    .map(|handle| node_name(graph, *handle))
+                    .collect()
+            }),
+            mask_weights: self
+                .mask
+                .as_ref()
+                .filter(|mask| mask.is_weighted())
+                .map(|mask| mask.weighted_nodes().map(|(_, weight)| weight).collect()),
+            root_motion: self
+                .root_motion_settings
+                .as_ref()
+                .map(|settings| RootMotionDefinition {
+                    node: node_name(graph, settings.node),
+                    extract_x: settings.extract_x,
+                    extract_y: settings.extract_y,
+                    extract_z: settings.extract_z,
+                    extract_rotation: settings.extract_rotation,
+                }),
+            signals: self
+                .signals
+                .iter()
+                .map(|signal| SignalDefinition {
+                    id: signal.id.to_string(),
+                    name: signal.name.clone(),
+                    time: signal.time,
+                    enabled: signal.enabled,
+                })
+                .collect(),
+            event_tracks: self
+                .event_tracks
+                .iter()
+                .map(|event_track| EventTrackDefinition {
+                    id: event_track.id().to_string(),
+                    name: event_track.name().to_owned(),
+                    enabled: event_track.is_enabled(),
+                    keys: event_track
+                        .keys()
+                        .iter()
+                        .map(|key| EventKeyDefinition {
+                            time: key.time,
+                            name: key.name.clone(),
+                            payload: key.payload.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            tracks: self
+                .tracks
+                .iter()
+                .map(|track| TrackDefinition {
+                    id: track.id().to_string(),
+                    target: node_name(graph, track.target()),
+                    binding: BindingDefinition::from(track.binding()),
+                    kind: track.data_container().value_kind(),
+                    enabled: track.is_enabled(),
+                    interpolation: track.interpolation(),
+                    components: track.components(),
+                    scale_blending: track.scale_blending(),
+                    space: track.space(),
+                    group: track.group().map(ToOwned::to_owned),
+                    quantization: track.quantization(),
+                    curves: track
+                        .data_container()
+                        .curves_ref()
+                        .iter()
+                        .map(CurveDefinition::from)
+                        .collect(),
+                })
+                .collect(),
+        };
+
+        // The definition consists of plain structures, strings and numbers only, so it is always serializable.
+        ron::ser::to_string_pretty(&definition, PrettyConfig::default())
+            .expect("Animation definition must be serializable!")
+    }
+
+    /// Loads an animation from a RON string produced by [`Self::save_to_ron`] (or written by hand, or by some
+    /// external tool). Animated nodes are searched by their names in the hierarchy of the given graph, starting from
+    /// `root`. If there are multiple nodes with the same name, the first one (in depth-first order) is used. Tracks
+    /// whose target nodes cannot be found are skipped with a warning, the same applies to the nodes of the animation
+    /// mask. Ids of tracks, curves, keys and signals are optional, missing ones are generated.
+    ///
+    /// Saving the animation back (using the same graph) produces exactly the same string, as long as the names of
+    /// animated nodes are unique.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::Animation,
+    ///     core::pool::Handle,
+    ///     scene::{graph::Graph, node::Node},
+    /// };
+    ///
+    /// fn copy_animation(
+    ///     animation: &Animation,
+    ///     source_graph: &Graph,
+    ///     dest_graph: &Graph,
+    ///     dest_root: Handle<Node>,
+    /// ) -> Animation {
+    ///     let text = animation.save_to_ron(source_graph);
+    ///     Animation::load_from_ron(&text, dest_graph, dest_root).unwrap()
+    /// }
+    /// ```
+    pub fn load_from_ron(
+        source: &str,
+        graph: &Graph,
+        root: Handle<Node>,
+    ) -> Result<Animation, AnimationDefinitionError> {
+        let definition = ron::de::from_str::<AnimationDefinition>(source)?;
+
+        let mut nodes = FxHashMap::default();
+        for handle in graph.traverse_handle_iter(root) {
+            nodes.entry(graph[handle].name_owned()).or_insert(handle);
+        }
+        let find_node = |name: &str| {
+            let handle = nodes.get(name).cloned();
+            if handle.is_none() {
+                Log::warn(format!(
+                    "Animation {}: there is no node {} in the graph!",
+                    definition.name, name
+                ));
+            }
+            handle
+        };
+
+        let mut animation = Animation {
+            name: definition.name.clone(),
+            speed: definition.speed,
+            loop_mode: definition.loop_mode,
+            direction: definition.direction,
+            enabled: definition.enabled,
+            weight: definition.weight,
+            blend_mode: definition.blend_mode,
+            additive_base_time: definition.additive_base_time,
+            sync_group: definition.sync_group,
+            hold_on_end: definition.hold_on_end,
+            auto_apply: definition.auto_apply,
+            ..Default::default()
+        };
+        animation.set_time_slice(definition.time_slice.0..definition.time_slice.1);
+        animation.set_time_offset(definition.time_offset);
+        animation.set_loop_range(definition.loop_range.map(|(start, end)| start..end));
+
+        if let Some(mask) = definition.mask.as_ref() {
+            if let Some(weights) = definition.mask_weights.as_ref() {
+                animation.set_mask(Some(AnimationMask::from_weights(
+                    mask.iter()
+                        .zip(weights.iter())
+                        .filter_map(|(name, weight)| find_node(name).map(|node| (node, *weight)))
+                        .collect::<Vec<_>>(),
+                )));
+            } else {
+                animation.set_mask(Some(AnimationMask::from(
+                    mask.iter()
+                        .filter_map(|name| find_node(name))
+                        .collect::<Vec<_>>(),
+                )));
+            }
+        }
+
+        if let Some(root_motion) = definition.root_motion.as_ref() {
+            animation.set_root_motion_settings(Some(RootMotionSettings {
+                node: find_node(&root_motion.node).unwrap_or_default(),
+                extract_x: root_motion.extract_x,
+                extract_y: root_motion.extract_y,
+                extract_z: root_motion.extract_z,
+                extract_rotation: root_motion.extract_rotation,
+            }));
+        }
+
+        for signal in definition.signals.iter() {
+            animation.add_signal(AnimationSignal {
+                id: parse_id(&signal.id),
+                name: signal.name.clone(),
+                time: signal.time,
+                enabled: signal.enabled,
+            });
+        }
+
+        for event_track_definition in definition.event_tracks.iter() {
+            let mut event_track = EventTrack::new(&event_track_definition.name);
+            event_track.set_id(parse_id(&event_track_definition.id));
+            event_track.set_enabled(event_track_definition.enabled);
+            for key in event_track_definition.keys.iter() {
+                event_track.add_key(EventKey::new(key.time, &key.name, &key.payload));
+            }
+            animation.add_event_track(event_track);
+        }
+
+        for track_definition in definition.tracks {
+            let target = if let Some(target) = find_node(&track_definition.target) {
+                target
+            } else {
+                continue;
+            };
+
+            let mut container = TrackDataContainer::new(track_definition.kind);
+            for (curve, curve_definition) in container
+                .curves_mut()
+                .iter_mut()
+                .zip(track_definition.curves)
+            {
+                *curve = Curve::from(curve_definition);
+            }
+
+            let mut track = Track::new(container, track_definition.binding.into());
+            track.set_id(parse_id(&track_definition.id));
+            track.set_target(target);
+            track.set_enabled(track_definition.enabled);
+            track.set_interpolation(track_definition.interpolation);
+            track.set_components(track_definition.components);
+            track.set_scale_blending(track_definition.scale_blending);
+            track.set_space(track_definition.space);
+            track.set_group(track_definition.group);
+            track.set_quantization(track_definition.quantization);
+            animation.add_track(track);
+        }
+
+        Ok(animation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{
+            event_track::{EventKey, EventTrack},
+            track::Track,
+            Animation, AnimationSignal, LoopMode,
+        },
+        core::curve::{Curve, CurveKey, CurveKeyKind, Extrapolation},
+        scene::{base::BaseBuilder, graph::Graph, pivot::PivotBuilder},
+    };
+
+    #[test]
+    fn test_ron_round_trip() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new().with_name("Hips")).build(&mut graph);
+
+        let mut track = Track::new_position();
+        track.set_target(node);
+        track.data_container_mut().curves_mut()[1] = Curve::from(vec![
+            CurveKey::new(0.0, 0.1, CurveKeyKind::Linear),
+            CurveKey::new(
+                0.3,
+                -1.0 / 3.0,
+                CurveKeyKind::Cubic {
+                    left_tangent: 0.25,
+                    right_tangent: 1.0e-7,
+                },
+            ),
+            CurveKey::new(1.1, 2.0, CurveKeyKind::Constant),
+        ]);
+        track.data_container_mut().curves_mut()[1]
+            .set_extrapolation(Extrapolation::Linear, Extrapolation::Cycle);
+
+        let mut animation = Animation::default();
+        animation.set_name("Run");
+        animation.add_track(track);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Step", 0.7));
+        let mut event_track = EventTrack::new("Sounds");
+        event_track.add_key(EventKey::new(0.4, "Play", "footstep.ogg"));
+        animation.add_event_track(event_track);
+        animation.set_time_slice(0.0..1.1);
+        animation.set_loop_mode(LoopMode::PingPong);
+
+        let text = animation.save_to_ron(&graph);
+        let loaded = Animation::load_from_ron(&text, &graph, graph.get_root()).unwrap();
+
+        assert_eq!(loaded.tracks(), animation.tracks());
+        let curve = &loaded.tracks()[0].data_container().curves_ref()[1];
+        assert_eq!(curve.pre_extrapolation(), Extrapolation::Linear);
+        assert_eq!(curve.post_extrapolation(), Extrapolation::Cycle);
+        assert_eq!(loaded.signals(), animation.signals());
+        assert_eq!(loaded.event_tracks(), animation.event_tracks());
+        assert_eq!(loaded.save_to_ron(&graph), text);
+    }
+}
@@ -0,0 +1,203 @@
+//! Event track is a named stream of events with string payloads placed on the animation timeline. See [`EventTrack`]
+//! docs for more info.
+
+use crate::{
+    core::{reflect::prelude::*, uuid::Uuid, visitor::prelude::*},
+    utils::NameProvider,
+};
+use std::collections::VecDeque;
+
+/// An event emitted by an event track of an animation.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct TrackEvent {
+    /// An id of the event track that emitted the event.
+    pub track_id: Uuid,
+
+    /// Name of the event track that emitted the event.
+    pub track_name: String,
+
+    /// Name of the event key.
+    pub name: String,
+
+    /// Payload of the event key.
+    pub payload: String,
+}
+
+/// A single event on an event track.
+#[derive(Clone, Debug, Default, Visit, Reflect, PartialEq)]
+pub struct EventKey {
+    /// A position (in seconds) on an animation time line.
+    pub time: f32,
+
+    /// Name of the event.
+    pub name: String,
+
+    /// An arbitrary string payload of the event (for example, a name of a sound, or some serialized data).
+    pub payload: String,
+}
+
+impl EventKey {
+    /// Creates a new event key.
+    pub fn new(time: f32, name: &str, payload: &str) -> Self {
+        Self {
+            time,
+            name: name.to_owned(),
+            payload: payload.to_owned(),
+        }
+    }
+}
+
+/// Event track is a named stream of events placed on the animation timeline. Unlike signals (see
+/// [`super::AnimationSignal`]), event tracks carry a string payload for each event and allow an animation to have
+/// multiple categorized streams of events (for example, "Footsteps", "Sounds", "Effects") that are independent of
+/// the tracks that animate scene nodes. An event is emitted each time the playback position of the animation crosses
+/// the time of an event key, in either direction. Looping, ping-pong and reverse playback are handled correctly - events
+/// are emitted in playback order.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::animation::{
+///     event_track::{EventKey, EventTrack},
+///     Animation,
+/// };
+///
+/// fn add_footsteps(animation: &mut Animation) {
+///     let mut footsteps = EventTrack::new("Footsteps");
+///     footsteps.add_key(EventKey::new(0.2, "Step", "left_foot"));
+///     footsteps.add_key(EventKey::new(0.7, "Step", "right_foot"));
+///     animation.add_event_track(footsteps);
+/// }
+///
+/// fn play_footsteps(animation: &mut Animation) {
+///     while let Some(event) = animation.pop_track_event() {
+///         println!("{}: {} ({})", event.track_name, event.name, event.payload);
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Visit, Reflect, PartialEq)]
+pub struct EventTrack {
+    id: Uuid,
+    name: String,
+    enabled: bool,
+    keys: Vec<EventKey>,
+}
+
+impl Default for EventTrack {
+    fn default() -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            name: Default::default(),
+            enabled: true,
+            keys: Default::default(),
+        }
+    }
+}
+
+impl NameProvider for EventTrack {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl EventTrack {
+    /// Creates a new empty event track with the given name.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the id of the track.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+
+    /// Sets new id of the track.
+    pub fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    /// Sets new name of the track.
+    pub fn set_name<S: AsRef<str>>(&mut self, name: S) {
+        self.name = name.as_ref().to_owned();
+    }
+
+    /// Returns name of the track.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Enables or disables the track. Disabled tracks do not emit any events.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Returns `true` if the track is enabled, `false` - otherwise.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Adds a new key to the track. Keys are always sorted by their time.
+    pub fn add_key(&mut self, key: EventKey) {
+        let pos = self.keys.partition_point(|k| k.time <= key.time);
+        self.keys.insert(pos, key);
+    }
+
+    /// Removes a key at the given index and returns it.
+    pub fn remove_key(&mut self, index: usize) -> EventKey {
+        self.keys.remove(index)
+    }
+
+    /// Removes every key from the track.
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+
+    /// Returns a reference to the keys of the track, sorted by their time.
+    pub fn keys(&self) -> &[EventKey] {
+        &self.keys
+    }
+
+    /// Emits events for every key in the `[from, to)` range (or `(to, from]` range when playing backwards) in
+    /// playback order, `inclusive` adds `to` to the range.
+    pub(crate) fn fire_events(
+        &self,
+        from: f32,
+        to: f32,
+        forward: bool,
+        inclusive: bool,
+        events: &mut VecDeque<TrackEvent>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let mut emit = |key: &EventKey| {
+            events.push_back(TrackEvent {
+                track_id: self.id,
+                track_name: self.name.clone(),
+                name: key.name.clone(),
+                payload: key.payload.clone(),
+            });
+        };
+
+        if forward {
+            let begin = self.keys.partition_point(|k| k.time < from);
+            let end = self
+                .keys
+                .partition_point(|k| k.time < to || inclusive && k.time == to);
+            self.keys[begin..end.max(begin)].iter().for_each(&mut emit);
+        } else {
+            let begin = self
+                .keys
+                .partition_point(|k| k.time < to || !inclusive && k.time == to);
+            let end = self.keys.partition_point(|k| k.time <= from);
+            self.keys[begin..end.max(begin)]
+                .iter()
+                .rev()
+                .for_each(&mut emit);
+        }
+    }
+}
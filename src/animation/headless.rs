@@ -0,0 +1,115 @@
+//! Headless update of animations advances animations and collects their events and root motion without applying
+//! their poses to a scene graph. It is intended for dedicated servers, that need animation timing (for example, for
+//! hit detection), but do not render anything. See [`AnimationContainer::update_headless`] docs for more info.
+
+use crate::{
+    animation::{Animation, AnimationContainer, AnimationEvent, TrackEvent},
+    core::{
+        algebra::{UnitQuaternion, Vector3},
+        pool::Handle,
+    },
+};
+
+/// Output of a headless update of a single animation, see [`AnimationContainer::update_headless`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnimationUpdate {
+    /// A handle of the animation.
+    pub animation: Handle<Animation>,
+
+    /// Events of the signals, that were crossed during the update, in playback order.
+    pub events: Vec<AnimationEvent>,
+
+    /// Events of the event tracks, that were crossed during the update, in playback order.
+    pub track_events: Vec<TrackEvent>,
+
+    /// Movement of the root node during the update (if the animation has root motion, see
+    /// [`Animation::set_root_motion_settings`]).
+    pub root_motion: Option<Vector3<f32>>,
+
+    /// Rotation of the root node during the update (if the animation extracts root motion rotation).
+    pub root_motion_rotation: Option<UnitQuaternion<f32>>,
+}
+
+impl AnimationContainer {
+    /// Advances every enabled animation in the container by the given time delta, just like the animation player does,
+    /// but does not apply any poses, so no scene graph is needed. Cross-fades and sync groups are updated as usual.
+    /// Events of signals and event tracks, as well as root motion, are taken from the animations and returned for every
+    /// animation that has any of them, so the queues of the animations are empty after the update. Output poses of the
+    /// animations are still calculated and could be fetched using [`Animation::pose`], if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// // Called every server tick.
+    /// fn update_character(animations: &mut AnimationContainer, dt: f32) {
+    ///     for update in animations.update_headless(dt) {
+    ///         for event in update.events {
+    ///             if event.name == "Hit" {
+    ///                 // Do hit detection.
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn update_headless(&mut self, dt: f32) -> Vec<AnimationUpdate> {
+        self.tick_animations(dt);
+
+        if let Some(cross_fade) = self.cross_fade.as_mut() {
+            cross_fade.elapsed += dt;
+        }
+        self.finish_cross_fade();
+
+        let mut updates = Vec::new();
+        for (handle, animation) in self.pool.pair_iter_mut() {
+            let update = AnimationUpdate {
+                animation: handle,
+                events: animation.take_events().into(),
+                track_events: animation.take_track_events().into(),
+                root_motion: animation.pop_root_motion(),
+                root_motion_rotation: animation.pop_root_motion_rotation(),
+            };
+
+            if !update.events.is_empty()
+                || !update.track_events.is_empty()
+                || update.root_motion.is_some()
+                || update.root_motion_rotation.is_some()
+            {
+                updates.push(update);
+            }
+        }
+        updates
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::animation::{Animation, AnimationContainer, AnimationSignal};
+
+    #[test]
+    fn test_headless_update() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Hit", 0.5));
+
+        let mut idle = Animation::default();
+        idle.set_time_slice(0.0..1.0);
+
+        let mut container = AnimationContainer::new();
+        let attack = container.add(animation);
+        container.add(idle);
+
+        assert!(container.update_headless(0.4).is_empty());
+
+        let updates = container.update_headless(0.2);
+        assert_eq!(updates.len(), 1);
+        assert_eq!(updates[0].animation, attack);
+        assert_eq!(updates[0].events.len(), 1);
+        assert_eq!(updates[0].events[0].name, "Hit");
+
+        // Events are taken from the animation.
+        assert!(container[attack].events_ref().is_empty());
+        assert!((container[attack].time_position() - 0.6).abs() < 1.0e-5);
+    }
+}
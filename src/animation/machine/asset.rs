@@ -0,0 +1,309 @@
+//! Machine asset is a standalone, reusable representation of an animation blending state machine. See [`MachineAsset`]
+//! docs for more info.
+
+use crate::{
+    animation::{
+        machine::{LayerMask, Machine, PoseNode},
+        Animation, AnimationContainer,
+    },
+    core::{
+        pool::Handle,
+        visitor::{Visit, VisitResult, Visitor},
+    },
+    scene::{graph::Graph, node::Node},
+    utils::log::Log,
+};
+use fxhash::FxHashMap;
+use std::path::Path;
+
+/// A name of an animation, that was referenced by the machine at the moment of saving.
+#[derive(Default, Debug, Clone, Visit, PartialEq)]
+struct AnimationReference {
+    handle: Handle<Animation>,
+    name: String,
+}
+
+/// A name of a scene node, that was referenced by a layer mask of the machine at the moment of saving.
+#[derive(Default, Debug, Clone, Visit, PartialEq)]
+struct NodeReference {
+    handle: Handle<Node>,
+    name: String,
+}
+
+/// Machine asset is a standalone representation of an animation blending state machine (states, transitions,
+/// parameters, layers and pose nodes with their blend trees), that is independent of a particular scene. Unlike a
+/// machine, that is embedded in a scene, the asset refers to animations and to the nodes of layer masks by their names,
+/// so it could be shared across multiple characters with the same set of animations (the same names of animations and
+/// bones). It enables "animation controller" workflow - the machine is authored once, saved to a file and then
+/// instantiated for every character that needs it.
+///
+/// The asset could be saved to a file using [`Self::save`] and loaded back using [`Self::load`], or it could be loaded
+/// using resource manager (see [`crate::engine::resource_manager::ResourceManager::request_absm`]).
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     animation::machine::{asset::MachineAsset, Machine},
+///     core::pool::Handle,
+///     scene::{
+///         animation::{absm::AnimationBlendingStateMachine, AnimationPlayer},
+///         graph::Graph,
+///         node::Node,
+///     },
+/// };
+///
+/// fn share_controller(
+///     graph: &mut Graph,
+///     source_absm: Handle<Node>,
+///     target_player: Handle<Node>,
+///     target_root: Handle<Node>,
+/// ) -> Machine {
+///     let absm = graph[source_absm]
+///         .query_component_ref::<AnimationBlendingStateMachine>()
+///         .unwrap();
+///     let source_player = graph[absm.animation_player()]
+///         .query_component_ref::<AnimationPlayer>()
+///         .unwrap();
+///
+///     let asset = MachineAsset::new(absm.machine(), source_player.animations(), graph);
+///
+///     let target_player = graph[target_player]
+///         .query_component_ref::<AnimationPlayer>()
+///         .unwrap();
+///     asset.instantiate(target_player.animations(), graph, target_root)
+/// }
+/// ```
+#[derive(Default, Debug, Clone, Visit, PartialEq)]
+pub struct MachineAsset {
+    machine: Machine,
+    animations: Vec<AnimationReference>,
+    nodes: Vec<NodeReference>,
+}
+
+impl MachineAsset {
+    /// Creates a new asset from the given machine. Names of the animations used by the machine are taken from the
+    /// given animation container, names of the nodes of layer masks are taken from the given graph. Runtime state of
+    /// the machine (active states and transitions, evaluated poses) is not preserved.
+    pub fn new(machine: &Machine, animations: &AnimationContainer, graph: &Graph) -> Self {
+        let mut asset = Self {
+            machine: machine.clone(),
+            animations: Default::default(),
+            nodes: Default::default(),
+        };
+
+        for layer in asset.machine.layers_mut() {
+            layer.reset();
+
+            for node in layer.nodes().iter() {
+                if let PoseNode::PlayAnimation(play_animation) = node {
+                    let handle = play_animation.animation;
+                    if asset.animations.iter().any(|r| r.handle == handle) {
+                        continue;
+                    }
+                    if let Some(animation) = animations.try_get(handle) {
+                        asset.animations.push(AnimationReference {
+                            handle,
+                            name: animation.name().to_owned(),
+                        });
+                    }
+                }
+            }
+
+            for &handle in layer.mask().inner() {
+                if asset.nodes.iter().any(|r| r.handle == handle) {
+                    continue;
+                }
+                if let Some(node) = graph.try_get(handle) {
+                    asset.nodes.push(NodeReference {
+                        handle,
+                        name: node.name_owned(),
+                    });
+                }
+            }
+        }
+
+        asset
+    }
+
+    /// Returns a reference to the machine of the asset. Keep in mind, that the handles of animations and nodes in the
+    /// machine are not valid, use [`Self::instantiate`] to get a machine that could be used with a particular animation
+    /// player.
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    /// Creates a new machine from the asset. Animations are searched by their names in the given animation container
+    /// (usually it is the container of the animation player, that will be used with the machine). The nodes of layer
+    /// masks are searched by their names in the hierarchy of the given graph starting from `root`. If there are
+    /// multiple nodes with the same name, the first one (in depth-first order) is used. Animations and nodes that
+    /// cannot be found are replaced with [`Handle::NONE`] with a warning.
+    pub fn instantiate(
+        &self,
+        animations: &AnimationContainer,
+        graph: &Graph,
+        root: Handle<Node>,
+    ) -> Machine {
+        let animation_map = self
+            .animations
+            .iter()
+            .map(|reference| {
+                let handle = animations
+                    .find_by_name_ref(&reference.name)
+                    .map(|(handle, _)| handle)
+                    .unwrap_or_else(|| {
+                        Log::warn(format!(
+                            "Machine asset: there is no animation {} in the animation container!",
+                            reference.name
+                        ));
+                        Handle::NONE
+                    });
+                (reference.handle, handle)
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut nodes = FxHashMap::default();
+        if !self.nodes.is_empty() {
+            for handle in graph.traverse_handle_iter(root) {
+                nodes.entry(graph[handle].name()).or_insert(handle);
+            }
+        }
+        let node_map = self
+            .nodes
+            .iter()
+            .map(|reference| {
+                let handle = nodes
+                    .get(reference.name.as_str())
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        Log::warn(format!(
+                            "Machine asset: there is no node {} in the graph!",
+                            reference.name
+                        ));
+                        Handle::NONE
+                    });
+                (reference.handle, handle)
+            })
+            .collect::<FxHashMap<_, _>>();
+
+        let mut machine = self.machine.clone();
+        for layer in machine.layers_mut() {
+            for node in layer.nodes_mut().iter_mut() {
+                if let PoseNode::PlayAnimation(play_animation) = node {
+                    play_animation.animation = animation_map
+                        .get(&play_animation.animation)
+                        .cloned()
+                        .unwrap_or_default();
+                }
+            }
+
+            let mask = layer
+                .mask()
+                .inner()
+                .iter()
+                .filter_map(|handle| node_map.get(handle).cloned())
+                .filter(|handle| handle.is_some())
+                .collect::<Vec<_>>();
+            layer.set_mask(LayerMask::from(mask));
+        }
+
+        machine
+    }
+
+    /// Saves the asset to the given file using binary format of [`Visitor`].
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> VisitResult {
+        let mut visitor = Visitor::new();
+        self.visit("MachineAsset", &mut visitor)?;
+        visitor.save_binary(path)
+    }
+
+    /// Loads an asset from the given file, that was previously saved using [`Self::save`].
+    pub async fn load<P: AsRef<Path>>(path: P) -> Result<Self, crate::core::visitor::VisitError> {
+        let mut visitor = Visitor::load_binary(path).await?;
+        let mut asset = Self::default();
+        asset.visit("MachineAsset", &mut visitor)?;
+        Ok(asset)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{
+            machine::{asset::MachineAsset, LayerMask, Machine, PlayAnimation, PoseNode, State},
+            Animation, AnimationContainer,
+        },
+        core::{
+            pool::Handle,
+            visitor::{Visit, Visitor},
+        },
+        scene::{base::BaseBuilder, graph::Graph, pivot::PivotBuilder},
+    };
+
+    fn make_animation(name: &str) -> Animation {
+        let mut animation = Animation::default();
+        animation.set_name(name);
+        animation
+    }
+
+    #[test]
+    fn test_machine_asset() {
+        let mut source_graph = Graph::new();
+        let source_hand =
+            PivotBuilder::new(BaseBuilder::new().with_name("Hand")).build(&mut source_graph);
+        let mut source_animations = AnimationContainer::new();
+        let walk = source_animations.add(make_animation("Walk"));
+
+        let mut machine = Machine::new();
+        let layer = &mut machine.layers_mut()[0];
+        let node = layer.add_node(PoseNode::PlayAnimation(PlayAnimation::new(walk)));
+        let state = layer.add_state(State::new("Walk", node));
+        layer.set_entry_state(state);
+        layer.set_mask(LayerMask::from(vec![source_hand]));
+
+        let mut asset = MachineAsset::new(&machine, &source_animations, &source_graph);
+
+        let mut visitor = Visitor::new();
+        asset.visit("MachineAsset", &mut visitor).unwrap();
+        let data = visitor.save_binary_to_vec().unwrap();
+        let mut visitor = Visitor::load_from_memory(data).unwrap();
+        let mut loaded = MachineAsset::default();
+        loaded.visit("MachineAsset", &mut visitor).unwrap();
+
+        // Animations and nodes of another character have different handles.
+        let mut target_graph = Graph::new();
+        PivotBuilder::new(BaseBuilder::new().with_name("Spine")).build(&mut target_graph);
+        let target_hand =
+            PivotBuilder::new(BaseBuilder::new().with_name("Hand")).build(&mut target_graph);
+        let mut target_animations = AnimationContainer::new();
+        target_animations.add(make_animation("Idle"));
+        let target_walk = target_animations.add(make_animation("Walk"));
+        assert_ne!(target_hand, source_hand);
+        assert_ne!(target_walk, walk);
+
+        let instance =
+            loaded.instantiate(&target_animations, &target_graph, target_graph.get_root());
+        let layer = &instance.layers()[0];
+        match layer.node(node) {
+            PoseNode::PlayAnimation(play_animation) => {
+                assert_eq!(play_animation.animation, target_walk)
+            }
+            _ => panic!("Node must be PlayAnimation!"),
+        }
+        assert_eq!(layer.entry_state(), state);
+        assert_eq!(layer.mask().inner(), &vec![target_hand]);
+
+        // Missing animations are replaced with invalid handles.
+        let instance = loaded.instantiate(
+            &AnimationContainer::new(),
+            &target_graph,
+            target_graph.get_root(),
+        );
+        match instance.layers()[0].node(node) {
+            PoseNode::PlayAnimation(play_animation) => {
+                assert_eq!(play_animation.animation, Handle::NONE)
+            }
+            _ => panic!("Node must be PlayAnimation!"),
+        }
+    }
+}
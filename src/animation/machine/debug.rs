@@ -0,0 +1,67 @@
+//! Debug information of an animation blending state machine, that could be used to visualize the machine at runtime.
+//! See [`MachineDebugInfo`] docs for more info.
+
+use crate::{
+    animation::machine::{parameter::ParameterDefinition, State, Transition},
+    core::pool::Handle,
+};
+
+/// Debug information of a transition, that is currently active on a layer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransitionDebugInfo {
+    /// A handle of the transition.
+    pub handle: Handle<Transition>,
+
+    /// A state that is being left. It differs from the source of the transition for "any state" transitions.
+    pub source: Handle<State>,
+
+    /// A state that is being entered.
+    pub dest: Handle<State>,
+
+    /// Current blend factor of the transition, 0 - the pose of the source state, 1 - the pose of the destination
+    /// state.
+    pub weight: f32,
+}
+
+/// Blend weight of a state of a layer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StateWeight {
+    /// A handle of the state.
+    pub state: Handle<State>,
+
+    /// Current weight of the pose of the state in the pose of the layer.
+    pub weight: f32,
+}
+
+/// Debug information of a layer of a state machine.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayerDebugInfo<'a> {
+    /// Name of the layer.
+    pub name: &'a str,
+
+    /// Weight of the layer.
+    pub weight: f32,
+
+    /// Currently active state of the layer.
+    pub active_state: Handle<State>,
+
+    /// Currently active transition of the layer (if any).
+    pub active_transition: Option<TransitionDebugInfo>,
+
+    /// Weights of the states, that contribute to the pose of the layer. There's at most two states - the active state
+    /// or the source and the destination states of the active transition.
+    pub state_weights: Vec<StateWeight>,
+}
+
+/// A snapshot of the runtime state of an animation blending state machine - active states, transitions and their
+/// weights on every layer and current values of the parameters. It is intended to be used in debug overlays, the
+/// snapshot borrows names from the machine and it is cheap enough to be taken every frame. See
+/// [`crate::animation::machine::Machine::debug_snapshot`] for more info.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MachineDebugInfo<'a> {
+    /// Debug information of every layer of the machine, in the same order as the layers.
+    pub layers: Vec<LayerDebugInfo<'a>>,
+
+    /// Current values of the parameters of the machine.
+    pub parameters: &'a [ParameterDefinition],
+}
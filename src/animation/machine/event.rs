@@ -10,10 +10,12 @@ use std::collections::VecDeque;
 /// Specific state machine event.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Event {
-    /// Occurs when enter some state. See module docs for example.
+    /// Occurs when enter some state (destination state of a transition), right when the transition begins. See
+    /// [`super::MachineLayer::pop_event`] docs for more info about the order of events.
     StateEnter(Handle<State>),
 
-    /// Occurs when leaving some state. See module docs for example.
+    /// Occurs when leaving some state (source state of a transition), right when the transition begins. See
+    /// [`super::MachineLayer::pop_event`] docs for more info about the order of events.
     StateLeave(Handle<State>),
 
     /// Occurs when a transition is done and a new active state was set.
@@ -25,8 +27,19 @@ pub enum Event {
         new: Handle<State>,
     },
 
-    /// Occurs when active transition was changed.
+    /// Occurs when active transition was changed. It holds a handle of the transition that has just begun, or
+    /// [`Handle::NONE`] when the active transition is done.
     ActiveTransitionChanged(Handle<Transition>),
+
+    /// Occurs when the normalized time of an active transition crosses a marker of the transition. See
+    /// [`Transition::add_marker`] docs for more info.
+    TransitionMarker {
+        /// A handle of the transition.
+        transition: Handle<Transition>,
+
+        /// Name of the marker.
+        name: String,
+    },
 }
 
 /// A simple event queue with fixed capacity. It is used to store a fixed amount of events and discard any
@@ -5,10 +5,12 @@ use crate::utils::NameProvider;
 use crate::{
     animation::{
         machine::{
-            event::FixedEventQueue, Event, LayerMask, Parameter, ParameterContainer, PoseNode,
-            State, Transition,
+            debug::{LayerDebugInfo, StateWeight, TransitionDebugInfo},
+            event::FixedEventQueue,
+            Event, InterruptionSource, LayerMask, Parameter, ParameterContainer, PoseNode, State,
+            Transition, TransitionCondition,
         },
-        AnimationContainer, AnimationPose,
+        Animation, AnimationContainer, AnimationPose,
     },
     core::{
         pool::{Handle, Pool},
@@ -20,6 +22,30 @@ use crate::{
         log::{Log, MessageKind},
     },
 };
+use fxhash::FxHashMap;
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
+
+/// Defines how a pose of a layer is combined with the poses of previous layers of a state machine.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Visit, Reflect, AsRefStr, EnumString, EnumVariantNames,
+)]
+pub enum LayerBlendMode {
+    /// The pose of the layer is added to the result of previous layers with the weight of the layer (positions and scales
+    /// are summed with the weight, rotations are interpolated). It is the default mode, and it works best if every layer
+    /// animates its own set of nodes (which is usually guaranteed by layer masks).
+    Blend,
+
+    /// The pose of the layer overrides the result of previous layers, the weight of the layer defines how much of the
+    /// previous result is replaced (`1.0` - replaced completely, `0.5` - halfway between the previous result and the pose
+    /// of the layer). It is useful to layer animations of upper body (for example, aiming) over full-body locomotion.
+    Override,
+}
+
+impl Default for LayerBlendMode {
+    fn default() -> Self {
+        Self::Blend
+    }
+}
 
 /// Layer is a separate state graph. Layers mainly used to animate different parts of humanoid (but not only) characters. For
 /// example there could a layer for upper body and a layer for lower body. Upper body layer could contain animations for aiming,
@@ -73,6 +99,9 @@ pub struct MachineLayer {
 
     mask: LayerMask,
 
+    #[visit(optional)] // Backward compatibility
+    blend_mode: LayerBlendMode,
+
     #[reflect(hidden)]
     nodes: Pool<PoseNode>,
 
@@ -91,10 +120,22 @@ pub struct MachineLayer {
     #[reflect(hidden)]
     active_transition: Handle<Transition>,
 
+    /// Actual source state of the active transition. It differs from the source of the transition only for "any
+    /// state" transitions, that have no source state.
+    #[visit(optional)] // Backward compatibility
+    #[reflect(hidden)]
+    active_transition_source: Handle<State>,
+
     #[visit(skip)]
     #[reflect(hidden)]
     final_pose: AnimationPose,
 
+    /// A pose that was captured when the previous transition was interrupted, it is used as a source pose of the
+    /// active transition.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    interrupted_pose: Option<AnimationPose>,
+
     #[visit(skip)]
     #[reflect(hidden)]
     events: FixedEventQueue,
@@ -110,6 +151,80 @@ impl NameProvider for MachineLayer {
     }
 }
 
+/// Checks whether the condition of the transition is met. Returns `Some(true)` if the transition is activated by a
+/// trigger (which must be reset), `Some(false)` if it is activated by some other condition, `None` - if the transition
+/// cannot be activated.
+fn check_transition(
+    transition: &Transition,
+    current_state: Handle<State>,
+    states: &Pool<State>,
+    nodes: &Pool<PoseNode>,
+    parameters: &ParameterContainer,
+    animations: &AnimationContainer,
+) -> Option<bool> {
+    let mut consumed_trigger = false;
+    let active = match transition.condition {
+        TransitionCondition::Rule => match parameters.get(transition.rule()) {
+            Some(Parameter::Rule(active)) => *active != transition.invert_rule,
+            Some(Parameter::Trigger(active)) => {
+                consumed_trigger = *active != transition.invert_rule;
+                consumed_trigger
+            }
+            _ => false,
+        },
+        TransitionCondition::AnimationEnded { remaining } => {
+            let source = if transition.is_from_any_state() {
+                current_state
+            } else {
+                transition.source()
+            };
+            states.try_borrow(source).map_or(false, |state| {
+                is_animation_ended(state, nodes, animations, remaining)
+            })
+        }
+        TransitionCondition::Compare { comparison, value } => {
+            let parameter = match parameters.get(transition.rule()) {
+                Some(Parameter::Weight(weight)) => Some(*weight),
+                Some(Parameter::Index(index)) => Some(*index as f32),
+                _ => None,
+            };
+            parameter.map_or(false, |parameter| {
+                comparison.compare(parameter, value) != transition.invert_rule
+            })
+        }
+    };
+
+    if active {
+        Some(consumed_trigger)
+    } else {
+        None
+    }
+}
+
+fn is_animation_ended(
+    state: &State,
+    nodes: &Pool<PoseNode>,
+    animations: &AnimationContainer,
+    remaining: f32,
+) -> bool {
+    let mut any = false;
+    for handle in state.animations(nodes) {
+        if let Some(animation) = animations.try_get(handle) {
+            let time_slice = animation.time_slice();
+            let remaining_time = if animation.playback_speed() >= 0.0 {
+                time_slice.end - animation.time_position()
+            } else {
+                animation.time_position() - time_slice.start
+            };
+            if remaining_time > remaining {
+                return false;
+            }
+            any = true;
+        }
+    }
+    any
+}
+
 impl MachineLayer {
     /// Creates a new machine layer. See examples in [`MachineLayer`] docs.
     #[inline]
@@ -120,13 +235,16 @@ impl MachineLayer {
             states: Default::default(),
             transitions: Default::default(),
             final_pose: Default::default(),
+            interrupted_pose: None,
             active_state: Default::default(),
             entry_state: Default::default(),
             active_transition: Default::default(),
+            active_transition_source: Default::default(),
             weight: 1.0,
             events: FixedEventQueue::new(2048),
             debug: false,
             mask: Default::default(),
+            blend_mode: Default::default(),
         }
     }
 
@@ -200,6 +318,18 @@ impl MachineLayer {
     /// Tries to extract a next event from the inner event queue. You should use this method if you need to react
     /// to layer events somehow. For example you might want to do some action when `jump` state had become active.
     ///
+    /// Events are stored in the order they've happened. Every transition produces the following sequence of events:
+    ///
+    /// 1. [`Event::StateLeave`] with the source state of the transition.
+    /// 2. [`Event::ActiveTransitionChanged`] with the handle of the transition that caused the state change.
+    /// 3. [`Event::StateEnter`] with the destination state of the transition.
+    /// 4. [`Event::TransitionMarker`] for each marker of the transition (if any), when the transition crosses it.
+    /// 5. [`Event::ActiveTransitionChanged`] with [`Handle::NONE`], when the transition is done.
+    /// 6. [`Event::ActiveStateChanged`], when the transition is done.
+    ///
+    /// If multiple transitions have happened between two subsequent reads of the queue, their sequences will follow
+    /// each other in the same order as the transitions.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -221,6 +351,9 @@ impl MachineLayer {
     ///         Event::ActiveTransitionChanged(transition_handle) => {
     ///             // Occurs when active transition has changed.
     ///         }
+    ///         Event::TransitionMarker { transition, name } => {
+    ///             // Occurs when active transition has crossed a marker.
+    ///         }
     ///     }
     /// }
     /// ```
@@ -236,6 +369,7 @@ impl MachineLayer {
             transition.reset();
         }
 
+        self.interrupted_pose = None;
         self.active_state = self.entry_state;
     }
 
@@ -387,11 +521,241 @@ impl MachineLayer {
         &self.mask
     }
 
+    /// Sets new blend mode of the layer. See docs of [`LayerBlendMode`] for more info.
+    #[inline]
+    pub fn set_blend_mode(&mut self, blend_mode: LayerBlendMode) {
+        self.blend_mode = blend_mode;
+    }
+
+    /// Returns current blend mode of the layer.
+    #[inline]
+    pub fn blend_mode(&self) -> LayerBlendMode {
+        self.blend_mode
+    }
+
+    /// Returns debug information of the layer - its active state, active transition and the weights of the states.
+    /// See [`crate::animation::machine::Machine::debug_snapshot`] for more info.
+    pub fn debug_info(&self) -> LayerDebugInfo<'_> {
+        let mut state_weights = Vec::new();
+
+        let active_transition =
+            self.transitions
+                .try_borrow(self.active_transition)
+                .map(|transition| {
+                    let source = self.active_transition_source();
+                    let weight = transition.blend_factor();
+                    if source.is_some() {
+                        state_weights.push(StateWeight {
+                            state: source,
+                            weight: 1.0 - weight,
+                        });
+                    }
+                    state_weights.push(StateWeight {
+                        state: transition.dest(),
+                        weight,
+                    });
+                    TransitionDebugInfo {
+                        handle: self.active_transition,
+                        source,
+                        dest: transition.dest(),
+                        weight,
+                    }
+                });
+
+        if active_transition.is_none() && self.active_state.is_some() {
+            state_weights.push(StateWeight {
+                state: self.active_state,
+                weight: 1.0,
+            });
+        }
+
+        LayerDebugInfo {
+            name: &self.name,
+            weight: self.weight,
+            active_state: self.active_state,
+            active_transition,
+            state_weights,
+        }
+    }
+
+    /// Returns actual source state of the active transition. See [`Transition::new_any_state`] docs for more info.
+    fn active_transition_source(&self) -> Handle<State> {
+        if self.active_transition_source.is_some() {
+            self.active_transition_source
+        } else {
+            self.transitions[self.active_transition].source()
+        }
+    }
+
+    /// Collects speed multipliers of the animations, that are played by the states of the layer. Animations of the
+    /// states that contribute to the pose of the layer get the effective speed of their state (the destination state
+    /// of a transition overrides the source state), animations of other states get `1.0`, unless they were already
+    /// collected from a previous layer.
+    pub(super) fn collect_animation_speeds(
+        &self,
+        parameters: &ParameterContainer,
+        speeds: &mut FxHashMap<Handle<Animation>, f32>,
+    ) {
+        for state in self.states.iter() {
+            for animation in state.animations(&self.nodes) {
+                speeds.entry(animation).or_insert(1.0);
+            }
+        }
+
+        let active_states = if self.active_transition.is_some() {
+            vec![
+                self.active_transition_source(),
+                self.transitions[self.active_transition].dest(),
+            ]
+        } else {
+            vec![self.active_state]
+        };
+
+        for handle in active_states {
+            if let Some(state) = self.states.try_borrow(handle) {
+                let speed = state.effective_speed(parameters);
+                for animation in state.animations(&self.nodes) {
+                    speeds.insert(animation, speed);
+                }
+            }
+        }
+    }
+
+    /// Searches for a transition with the highest priority, that satisfies the filter and whose condition is met.
+    /// `current_state` is the state that will be left, if a transition is found.
+    fn find_transition<F>(
+        &self,
+        parameters: &ParameterContainer,
+        animations: &AnimationContainer,
+        current_state: Handle<State>,
+        filter: F,
+    ) -> Option<(Handle<Transition>, bool)>
+    where
+        F: Fn(&Transition) -> bool,
+    {
+        let mut result: Option<(Handle<Transition>, i32, bool)> = None;
+        for (handle, transition) in self.transitions.pair_iter() {
+            if !filter(transition)
+                || result.map_or(false, |(_, priority, _)| priority >= transition.priority())
+            {
+                continue;
+            }
+
+            if let Some(consumed_trigger) = check_transition(
+                transition,
+                current_state,
+                &self.states,
+                &self.nodes,
+                parameters,
+                animations,
+            ) {
+                result = Some((handle, transition.priority(), consumed_trigger));
+            }
+        }
+        result.map(|(handle, _, consumed_trigger)| (handle, consumed_trigger))
+    }
+
+    /// Makes the given transition active. If there is an active transition already (in case of interruption), its
+    /// destination state is left.
+    fn activate_transition(
+        &mut self,
+        handle: Handle<Transition>,
+        consumed_trigger: bool,
+        consumed_triggers: &mut Vec<String>,
+    ) {
+        let transition = &self.transitions[handle];
+        if consumed_trigger {
+            consumed_triggers.push(transition.rule().to_owned());
+        }
+        let dest = transition.dest();
+
+        let leaving = if self.active_transition.is_some() {
+            self.transitions[self.active_transition].dest()
+        } else {
+            self.active_state
+        };
+
+        self.events.push(Event::StateLeave(leaving));
+        if self.debug {
+            Log::writeln(
+                MessageKind::Information,
+                format!("Leaving state: {}", self.states[leaving].name),
+            );
+        }
+
+        self.active_state = Handle::NONE;
+        self.active_transition_source = if transition.is_from_any_state() {
+            leaving
+        } else {
+            transition.source()
+        };
+
+        // The transition must be reported before entering the destination state, so the user
+        // could find out which transition has caused the state change.
+        self.active_transition = handle;
+        self.events
+            .push(Event::ActiveTransitionChanged(self.active_transition));
+
+        self.events.push(Event::StateEnter(dest));
+        if self.debug {
+            Log::writeln(
+                MessageKind::Information,
+                format!("Entering state: {}", self.states[dest].name),
+            );
+        }
+    }
+
+    /// Tries to interrupt the active transition by a transition with higher priority, according to the interruption
+    /// source of the active transition. See [`InterruptionSource`] docs for more info.
+    fn try_interrupt_transition(
+        &mut self,
+        parameters: &ParameterContainer,
+        animations: &AnimationContainer,
+        consumed_triggers: &mut Vec<String>,
+    ) {
+        let active_transition = self.active_transition;
+        let source = self.active_transition_source();
+        let current = &self.transitions[active_transition];
+        let dest = current.dest();
+        let priority = current.priority();
+        let interruption_source = current.interruption_source();
+
+        if interruption_source == InterruptionSource::None {
+            return;
+        }
+
+        if let Some((handle, consumed_trigger)) =
+            self.find_transition(parameters, animations, dest, |transition| {
+                let allowed = match interruption_source {
+                    InterruptionSource::None => false,
+                    // Any state transitions could start from either state.
+                    _ if transition.is_from_any_state() => true,
+                    InterruptionSource::Source => transition.source() == source,
+                    InterruptionSource::Destination => transition.source() == dest,
+                    InterruptionSource::Any => {
+                        transition.source() == source || transition.source() == dest
+                    }
+                };
+                allowed && transition.dest() != dest && transition.priority() > priority
+            })
+        {
+            // The new transition blends from the current intermediate pose. The pose is frozen, so it must not
+            // produce any root motion.
+            let interrupted_pose = self.interrupted_pose.get_or_insert_with(Default::default);
+            self.final_pose.clone_into(interrupted_pose);
+            interrupted_pose.set_root_motion(None);
+
+            self.transitions[active_transition].reset();
+            self.activate_transition(handle, consumed_trigger, consumed_triggers);
+        }
+    }
+
     #[inline]
     pub(super) fn evaluate_pose(
         &mut self,
         animations: &AnimationContainer,
         parameters: &ParameterContainer,
+        consumed_triggers: &mut Vec<String>,
         dt: f32,
     ) -> &AnimationPose {
         self.final_pose.reset();
@@ -403,61 +767,31 @@ impl MachineLayer {
             }
 
             if self.active_transition.is_none() {
-                // Find transition.
-                for (handle, transition) in self.transitions.pair_iter_mut() {
-                    if transition.dest() == self.active_state
-                        || transition.source() != self.active_state
-                    {
-                        continue;
-                    }
-                    if let Some(Parameter::Rule(mut active)) =
-                        parameters.get(transition.rule()).cloned()
-                    {
-                        if transition.invert_rule {
-                            active = !active;
-                        }
-
-                        if active {
-                            self.events.push(Event::StateLeave(self.active_state));
-                            if self.debug {
-                                Log::writeln(
-                                    MessageKind::Information,
-                                    format!(
-                                        "Leaving state: {}",
-                                        self.states[self.active_state].name
-                                    ),
-                                );
-                            }
-
-                            self.events.push(Event::StateEnter(transition.source()));
-                            if self.debug {
-                                Log::writeln(
-                                    MessageKind::Information,
-                                    format!(
-                                        "Entering state: {}",
-                                        self.states[transition.source()].name
-                                    ),
-                                );
-                            }
-
-                            self.active_state = Handle::NONE;
-
-                            self.active_transition = handle;
-                            self.events
-                                .push(Event::ActiveTransitionChanged(self.active_transition));
-
-                            break;
-                        }
-                    }
+                // Find transition with the highest priority.
+                let active_state = self.active_state;
+                if let Some((handle, consumed_trigger)) =
+                    self.find_transition(parameters, animations, active_state, |transition| {
+                        transition.can_leave(active_state)
+                    })
+                {
+                    self.activate_transition(handle, consumed_trigger, consumed_triggers);
                 }
             }
 
             // Double check for active transition because we can have empty machine.
             if self.active_transition.is_some() {
+                let source = self.active_transition_source();
                 let transition = &mut self.transitions[self.active_transition];
 
-                // Blend between source and dest states.
-                if let Some(source_pose) = self.states[transition.source()].pose(&self.nodes) {
+                // Blend between source (or the pose of interrupted transition) and dest states.
+                if let Some(interrupted_pose) = self.interrupted_pose.as_ref() {
+                    self.final_pose
+                        .blend_with(interrupted_pose, 1.0 - transition.blend_factor());
+                } else if let Some(source_pose) = self
+                    .states
+                    .try_borrow(source)
+                    .and_then(|state| state.pose(&self.nodes))
+                {
                     self.final_pose
                         .blend_with(&source_pose, 1.0 - transition.blend_factor());
                 }
@@ -466,18 +800,27 @@ impl MachineLayer {
                         .blend_with(&dest_pose, transition.blend_factor());
                 }
 
-                transition.update(dt);
+                let handle = self.active_transition;
+                let events = &mut self.events;
+                transition.update(dt, |marker| {
+                    events.push(Event::TransitionMarker {
+                        transition: handle,
+                        name: marker.name.clone(),
+                    })
+                });
 
                 if transition.is_done() {
                     transition.reset();
 
+                    self.interrupted_pose = None;
                     self.active_transition = Handle::NONE;
+                    self.active_transition_source = Handle::NONE;
                     self.events
                         .push(Event::ActiveTransitionChanged(self.active_transition));
 
                     self.active_state = transition.dest();
                     self.events.push(Event::ActiveStateChanged {
-                        prev: transition.source(),
+                        prev: source,
                         new: transition.dest(),
                     });
 
@@ -490,6 +833,8 @@ impl MachineLayer {
                             ),
                         );
                     }
+                } else {
+                    self.try_interrupt_transition(parameters, animations, consumed_triggers);
                 }
             } else {
                 // We must have active state all the time when we do not have any active transition.
@@ -6,26 +6,37 @@
 #![warn(missing_docs)]
 
 use crate::{
-    animation::{AnimationContainer, AnimationPose},
+    animation::{
+        machine::debug::MachineDebugInfo, pose::WeightCheck, AnimationContainer, AnimationPose,
+        RootMotion,
+    },
     core::{
         reflect::prelude::*,
         visitor::{Visit, VisitResult, Visitor},
     },
     utils,
 };
+use fxhash::FxHashMap;
 
 pub use event::Event;
-pub use layer::MachineLayer;
+pub use layer::{LayerBlendMode, MachineLayer};
 pub use mask::LayerMask;
 pub use node::{
-    blend::{BlendAnimations, BlendAnimationsByIndex, BlendPose, IndexedBlendInput},
+    blend::{BlendAnimations, BlendAnimationsByIndex, BlendPose, BlendTree, IndexedBlendInput},
+    blend_space::{BlendSpace1D, BlendSpace2D, BlendSpacePoint1D, BlendSpacePoint2D},
     play::PlayAnimation,
     EvaluatePose, PoseNode,
 };
-pub use parameter::{Parameter, ParameterContainer, PoseWeight};
+pub use parameter::{
+    Parameter, ParameterContainer, ParameterError, ParameterHandle, ParameterType, PoseWeight,
+};
 pub use state::State;
-pub use transition::Transition;
+pub use transition::{
+    InterruptionSource, ParameterComparison, Transition, TransitionCondition, TransitionMarker,
+};
 
+pub mod asset;
+pub mod debug;
 pub mod event;
 pub mod layer;
 pub mod mask;
@@ -59,12 +70,16 @@ pub mod transition;
 /// # Parameters
 ///
 /// Parameter is a named variable of a fixed type. Parameters are used as a data source in various places in the animation
-/// blending state machines. There are three main types of parameters:
+/// blending state machines. There are four main types of parameters:
 ///
 /// `Rule` - boolean value that used as a trigger for transitions. When transition is using some rule, it checks the value
 /// of the parameter and if it is `true` transition starts.
 /// `Weight` - real number (`f32`) that is used a weight when you blending multiple animations into one.
 /// `Index` - natural number (`i32`) that is used as an animation selector.
+/// `Trigger` - momentary boolean value, that is automatically reset after a transition consumes it.
+///
+/// Transitions could also compare `Weight` and `Index` parameters with some value (see
+/// [`TransitionCondition::Compare`]).
 ///
 /// Each parameter has a name, it could be pretty much any string.
 ///
@@ -173,6 +188,9 @@ pub struct Machine {
     #[visit(optional)]
     layers: Vec<MachineLayer>,
 
+    #[visit(optional)] // Backward compatibility
+    weight_check: WeightCheck,
+
     #[visit(skip)]
     #[reflect(hidden)]
     final_pose: AnimationPose,
@@ -185,6 +203,7 @@ impl Machine {
         Self {
             parameters: Default::default(),
             layers: vec![MachineLayer::new()],
+            weight_check: Default::default(),
             final_pose: Default::default(),
         }
     }
@@ -278,18 +297,472 @@ impl Machine {
         utils::find_by_name_mut(self.layers.iter_mut().enumerate(), name)
     }
 
+    /// Sets what to do with the nodes of the final pose, whose accumulated weight exceeds 1.0. Such nodes usually mean
+    /// that blend weights of the machine are misconfigured. By default, the weights are not checked. See
+    /// [`WeightCheck`] docs for more info.
+    pub fn set_weight_check(&mut self, weight_check: WeightCheck) {
+        self.weight_check = weight_check;
+    }
+
+    /// Returns current weight check mode of the machine. See [`Self::set_weight_check`] for more info.
+    pub fn weight_check(&self) -> WeightCheck {
+        self.weight_check
+    }
+
     /// Computes final animation pose that could be then applied to a scene graph.
     #[inline]
     pub fn evaluate_pose(&mut self, animations: &AnimationContainer, dt: f32) -> &AnimationPose {
         self.final_pose.reset();
 
+        let mut consumed_triggers = Vec::new();
+
         for layer in self.layers.iter_mut() {
             let weight = layer.weight();
-            let pose = layer.evaluate_pose(animations, &self.parameters, dt);
+            let blend_mode = layer.blend_mode();
+            let pose =
+                layer.evaluate_pose(animations, &self.parameters, &mut consumed_triggers, dt);
+
+            match blend_mode {
+                LayerBlendMode::Blend => self.final_pose.blend_with(pose, weight),
+                LayerBlendMode::Override => self.final_pose.interpolate_with(pose, weight),
+            }
+        }
+
+        self.final_pose.check_weights(self.weight_check);
 
-            self.final_pose.blend_with(pose, weight);
+        // Triggers are reset only after every layer was evaluated, so multiple transitions could be activated by
+        // the same trigger in a single frame.
+        for name in consumed_triggers {
+            if let Some(Parameter::Trigger(value)) = self.parameters.get_mut(&name) {
+                *value = false;
+            }
         }
 
         &self.final_pose
     }
+
+    /// Returns the final pose of the last update of the machine (see [`Self::evaluate_pose`]).
+    pub fn pose(&self) -> &AnimationPose {
+        &self.final_pose
+    }
+
+    /// Applies speed multipliers of the states (see [`State::speed`]) to the animations they play, using the current
+    /// state of the machine. Animations of the states that contribute to the final pose are scaled by the effective
+    /// speed of their state, animations of other states are reset to normal speed (see
+    /// [`crate::animation::Animation::set_speed_scale`]). It must be called after [`Self::evaluate_pose`], so that the
+    /// animations are advanced with new speeds on next update. Animation blending state machine scene nodes do this
+    /// automatically.
+    pub fn apply_state_speeds(&self, animations: &mut AnimationContainer) {
+        let mut speeds = FxHashMap::default();
+        for layer in self.layers.iter() {
+            layer.collect_animation_speeds(&self.parameters, &mut speeds);
+        }
+        for (handle, speed) in speeds {
+            if let Some(animation) = animations.try_get_mut(handle) {
+                animation.set_speed_scale(speed);
+            }
+        }
+    }
+
+    /// Returns blended root motion of the last update of the machine (if any). Root motions of the animations are
+    /// blended with the same weights that are used to blend their poses, including the weights of transitions between
+    /// states. It produces a single smooth root motion delta per frame, that could be used to move a character
+    /// controller. See [`crate::animation::RootMotionSettings`] and [`AnimationPose::root_motion`] docs for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{animation::machine::Machine, core::algebra::Vector3};
+    ///
+    /// fn move_character(machine: &Machine, character_position: &mut Vector3<f32>) {
+    ///     if let Some(root_motion) = machine.root_motion() {
+    ///         *character_position += root_motion.delta_position;
+    ///     }
+    /// }
+    /// ```
+    pub fn root_motion(&self) -> Option<&RootMotion> {
+        self.final_pose.root_motion()
+    }
+
+    /// Takes a snapshot of the runtime state of the machine - active state, active transition (with its blend factor)
+    /// and the weights of the states on every layer, as well as the current values of the parameters. The snapshot
+    /// reflects the last [`Self::evaluate_pose`] call. It does not copy any names, so it could be taken every frame to
+    /// feed a debug overlay.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{animation::machine::Machine, utils::log::Log};
+    ///
+    /// fn print_machine_state(machine: &Machine) {
+    ///     let snapshot = machine.debug_snapshot();
+    ///     for (layer, info) in machine.layers().iter().zip(snapshot.layers.iter()) {
+    ///         let state = layer.states().try_borrow(info.active_state);
+    ///         Log::info(format!(
+    ///             "Layer {}: state {:?}, transition {:?}",
+    ///             info.name,
+    ///             state.map(|state| state.name.as_str()),
+    ///             info.active_transition
+    ///         ));
+    ///     }
+    ///     for parameter in snapshot.parameters {
+    ///         Log::info(format!("{} = {:?}", parameter.name, parameter.value));
+    ///     }
+    /// }
+    /// ```
+    pub fn debug_snapshot(&self) -> MachineDebugInfo<'_> {
+        MachineDebugInfo {
+            layers: self.layers.iter().map(|layer| layer.debug_info()).collect(),
+            parameters: self.parameters.definitions(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{
+            machine::{
+                debug::StateWeight, BlendPose, Event, InterruptionSource, Machine, MachineLayer,
+                Parameter, PoseNode, State, Transition,
+            },
+            track::Track,
+            Animation, AnimationContainer,
+        },
+        core::{
+            curve::{Curve, CurveKey, CurveKeyKind},
+            pool::Handle,
+        },
+        scene::{base::BaseBuilder, graph::Graph, node::Node, pivot::PivotBuilder},
+    };
+
+    #[test]
+    fn test_trigger_reset() {
+        let mut machine = Machine::new();
+        machine.set_parameter("Jump", Parameter::Trigger(false));
+
+        // Both layers react on the same trigger.
+        for _ in 0..2 {
+            let mut layer = MachineLayer::new();
+            let idle = layer.add_state(State::new("Idle", Handle::NONE));
+            let jump = layer.add_state(State::new("Jump", Handle::NONE));
+            layer.add_transition(Transition::new("IdleToJump", idle, jump, 0.5, "Jump"));
+            machine.add_layer(layer);
+        }
+
+        let animations = AnimationContainer::new();
+
+        machine.evaluate_pose(&animations, 0.1);
+        assert!(machine
+            .layers()
+            .iter()
+            .all(|layer| layer.active_transition().is_none()));
+
+        machine.parameters_mut().set_trigger("Jump").unwrap();
+        machine.evaluate_pose(&animations, 0.1);
+        assert!(machine
+            .layers()
+            .iter()
+            .all(|layer| layer.active_transition().is_some()));
+        assert_eq!(machine.parameters().is_trigger_set("Jump"), Ok(false));
+        assert!(machine.parameters().get_bool("Jump").is_err());
+    }
+
+    #[test]
+    fn test_transition_interruption() {
+        // Both transitions are activated at the same time, but the one with higher priority wins.
+        let mut priority_layer = MachineLayer::new();
+        let idle = priority_layer.add_state(State::new("Idle", Handle::NONE));
+        let attack = priority_layer.add_state(State::new("Attack", Handle::NONE));
+        let block = priority_layer.add_state(State::new("Block", Handle::NONE));
+        priority_layer.add_transition(Transition::new("IdleToAttack", idle, attack, 1.0, "Attack"));
+        let mut idle_to_block = Transition::new("IdleToBlock", idle, block, 1.0, "Attack");
+        idle_to_block.set_priority(1);
+        let idle_to_block = priority_layer.add_transition(idle_to_block);
+
+        // Hit reaction interrupts the attack while it is still blending in.
+        let mut combat_layer = MachineLayer::new();
+        let idle = combat_layer.add_state(State::new("Idle", Handle::NONE));
+        let attack = combat_layer.add_state(State::new("Attack", Handle::NONE));
+        let hit = combat_layer.add_state(State::new("Hit", Handle::NONE));
+        let mut idle_to_attack = Transition::new("IdleToAttack", idle, attack, 1.0, "Attack");
+        idle_to_attack.set_interruption_source(InterruptionSource::Destination);
+        let idle_to_attack = combat_layer.add_transition(idle_to_attack);
+        let mut attack_to_hit = Transition::new("AttackToHit", attack, hit, 1.0, "Hit");
+        attack_to_hit.set_priority(10);
+        let attack_to_hit = combat_layer.add_transition(attack_to_hit);
+
+        let mut machine = Machine::new();
+        machine
+            .set_parameter("Attack", Parameter::Rule(true))
+            .set_parameter("Hit", Parameter::Rule(false));
+        machine.add_layer(priority_layer);
+        machine.add_layer(combat_layer);
+
+        let animations = AnimationContainer::new();
+
+        machine.evaluate_pose(&animations, 0.1);
+        assert_eq!(machine.layers()[0].active_transition(), idle_to_block);
+        assert_eq!(machine.layers()[1].active_transition(), idle_to_attack);
+
+        machine.set_parameter("Hit", Parameter::Rule(true));
+        machine.evaluate_pose(&animations, 0.1);
+        assert_eq!(machine.layers()[1].active_transition(), attack_to_hit);
+        assert_eq!(
+            machine.layers()[1].transitions()[idle_to_attack].blend_factor(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_transition_markers() {
+        let mut layer = MachineLayer::new();
+        let idle = layer.add_state(State::new("Idle", Handle::NONE));
+        let draw = layer.add_state(State::new("Draw", Handle::NONE));
+        let mut transition = Transition::new("IdleToDraw", idle, draw, 1.0, "Draw");
+        transition.add_marker("End", 1.0);
+        transition.add_marker("Half", 0.5);
+        transition.add_marker("Start", 0.0);
+        transition.add_marker("Quarter", 0.25);
+        let transition = layer.add_transition(transition);
+
+        let mut machine = Machine::new();
+        machine.set_parameter("Draw", Parameter::Rule(true));
+        machine.add_layer(layer);
+
+        let animations = AnimationContainer::new();
+
+        let mut markers = Vec::new();
+        for dt in [0.1, 0.3, 0.05, 0.0, 0.2, 0.5, 0.1, 0.1] {
+            machine.evaluate_pose(&animations, dt);
+            while let Some(event) = machine.layers_mut()[1].pop_event() {
+                if let Event::TransitionMarker {
+                    transition: marker_transition,
+                    name,
+                } = event
+                {
+                    assert_eq!(marker_transition, transition);
+                    markers.push((name, machine.layers()[1].active_transition().is_some()));
+                }
+            }
+        }
+
+        // Every marker is emitted exactly once, in the order of their times.
+        assert_eq!(
+            markers,
+            vec![
+                ("Start".to_owned(), true),
+                ("Quarter".to_owned(), true),
+                ("Half".to_owned(), true),
+                ("End".to_owned(), false)
+            ]
+        );
+        assert_eq!(machine.layers()[1].active_state(), draw);
+    }
+
+    #[test]
+    fn test_any_state_transition() {
+        let mut layer = MachineLayer::new();
+        let idle = layer.add_state(State::new("Idle", Handle::NONE));
+        let walk = layer.add_state(State::new("Walk", Handle::NONE));
+        let die = layer.add_state(State::new("Die", Handle::NONE));
+        let mut idle_to_walk = Transition::new("IdleToWalk", idle, walk, 0.5, "Walk");
+        idle_to_walk.set_interruption_source(InterruptionSource::Source);
+        let idle_to_walk = layer.add_transition(idle_to_walk);
+        let mut any_to_die = Transition::new_any_state("AnyToDie", die, 0.5, "Die");
+        any_to_die.set_priority(10);
+        let any_to_die = layer.add_transition(any_to_die);
+
+        let mut machine = Machine::new();
+        machine
+            .set_parameter("Walk", Parameter::Rule(true))
+            .set_parameter("Die", Parameter::Rule(false));
+        machine.add_layer(layer);
+
+        let animations = AnimationContainer::new();
+
+        machine.evaluate_pose(&animations, 0.1);
+        assert_eq!(machine.layers()[1].active_transition(), idle_to_walk);
+
+        // Any state transition interrupts the active one.
+        machine.set_parameter("Die", Parameter::Rule(true));
+        machine.evaluate_pose(&animations, 0.1);
+        assert_eq!(machine.layers()[1].active_transition(), any_to_die);
+
+        for _ in 0..10 {
+            machine.evaluate_pose(&animations, 0.1);
+        }
+        // Self transitions are excluded by default.
+        assert_eq!(machine.layers()[1].active_state(), die);
+        assert!(machine.layers()[1].active_transition().is_none());
+
+        machine.layers_mut()[1]
+            .transition_mut(any_to_die)
+            .set_allow_self_transition(true);
+        machine.evaluate_pose(&animations, 0.1);
+        assert_eq!(machine.layers()[1].active_transition(), any_to_die);
+    }
+
+    #[test]
+    fn test_debug_snapshot() {
+        let mut layer = MachineLayer::new();
+        layer.set_name("Base");
+        let idle = layer.add_state(State::new("Idle", Handle::NONE));
+        let walk = layer.add_state(State::new("Walk", Handle::NONE));
+        let idle_to_walk =
+            layer.add_transition(Transition::new("IdleToWalk", idle, walk, 1.0, "Walk"));
+
+        let mut machine = Machine::new();
+        machine.set_parameter("Walk", Parameter::Rule(false));
+        machine.add_layer(layer);
+
+        let animations = AnimationContainer::new();
+
+        machine.evaluate_pose(&animations, 0.1);
+        let snapshot = machine.debug_snapshot();
+        assert_eq!(snapshot.layers.len(), 2);
+        let info = &snapshot.layers[1];
+        assert_eq!(info.name, "Base");
+        assert_eq!(info.active_state, idle);
+        assert_eq!(info.active_transition, None);
+        assert_eq!(
+            info.state_weights,
+            vec![StateWeight {
+                state: idle,
+                weight: 1.0
+            }]
+        );
+        assert_eq!(snapshot.parameters.len(), 1);
+        assert_eq!(snapshot.parameters[0].name, "Walk");
+        assert_eq!(snapshot.parameters[0].value, Parameter::Rule(false));
+
+        machine.set_parameter("Walk", Parameter::Rule(true));
+        machine.evaluate_pose(&animations, 0.5);
+        let snapshot = machine.debug_snapshot();
+        let transition = snapshot.layers[1].active_transition.clone().unwrap();
+        assert_eq!(transition.handle, idle_to_walk);
+        assert_eq!(transition.source, idle);
+        assert_eq!(transition.dest, walk);
+        let weights = &snapshot.layers[1].state_weights;
+        assert_eq!(weights.len(), 2);
+        assert!((weights[0].weight + weights[1].weight - 1.0).abs() < 1.0e-5);
+        assert_eq!(weights[1].weight, transition.weight);
+    }
+
+    #[test]
+    fn test_blend_tree() {
+        let mut graph = Graph::new();
+        let body = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let arm = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut animations = AnimationContainer::new();
+        let mut add_animation = |node: Handle<Node>, x: f32| {
+            let mut track = Track::new_position();
+            track.set_target(node);
+            track.data_container_mut().curves_mut()[0] =
+                Curve::from(vec![CurveKey::new(0.0, x, CurveKeyKind::Linear)]);
+            let mut animation = Animation::default();
+            animation.add_track(track);
+            animation.set_time_slice(0.0..1.0);
+            animation.tick(0.0);
+            animations.add(animation)
+        };
+        let walk = add_animation(body, 1.0);
+        let run = add_animation(body, 3.0);
+        let aim = add_animation(arm, 7.0);
+
+        let mut machine = Machine::new();
+        machine.set_parameter("Aim", Parameter::Weight(1.0));
+        let layer = &mut machine.layers_mut()[0];
+        let walk = layer.add_node(PoseNode::make_play_animation(walk));
+        let run = layer.add_node(PoseNode::make_play_animation(run));
+        let aim = layer.add_node(PoseNode::make_play_animation(aim));
+        let tree = layer.add_node(PoseNode::make_blend_tree(vec![
+            BlendPose::with_constant_weight(1.0, walk),
+            BlendPose::with_constant_weight(3.0, run),
+            BlendPose::with_param_weight("Aim", aim),
+        ]));
+        let state = layer.add_state(State::new("Locomotion", tree));
+        layer.set_entry_state(state);
+
+        machine.evaluate_pose(&animations, 0.1).apply(&mut graph);
+
+        // The body is averaged only among the poses that animate it.
+        assert!((graph[body].local_transform().position().x - 2.5).abs() < 1.0e-5);
+        assert!((graph[arm].local_transform().position().x - 7.0).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn test_state_speed() {
+        let mut animations = AnimationContainer::new();
+        let mut add_animation = || {
+            let mut animation = Animation::default();
+            animation.set_time_slice(0.0..10.0);
+            animations.add(animation)
+        };
+        let attack = add_animation();
+        let idle = add_animation();
+
+        let mut machine = Machine::new();
+        machine.set_parameter("AttackSpeed", Parameter::Weight(1.5));
+        let layer = &mut machine.layers_mut()[0];
+        let attack_node = layer.add_node(PoseNode::make_play_animation(attack));
+        let idle_node = layer.add_node(PoseNode::make_play_animation(idle));
+        let mut attack_state = State::new("Attack", attack_node);
+        attack_state.speed = 2.0;
+        attack_state.speed_parameter = "AttackSpeed".to_owned();
+        let attack_state = layer.add_state(attack_state);
+        let mut idle_state = State::new("Idle", idle_node);
+        idle_state.speed = 4.0;
+        layer.add_state(idle_state);
+        layer.set_entry_state(attack_state);
+
+        machine.evaluate_pose(&animations, 0.1);
+        machine.apply_state_speeds(&mut animations);
+        for animation in animations.iter_mut() {
+            animation.tick(0.1);
+        }
+
+        // The clip of the active state is advanced `speed * AttackSpeed` times faster, inactive states do not affect
+        // their clips.
+        assert!((animations[attack].time_position() - 0.3).abs() < 1.0e-5);
+        assert!((animations[idle].time_position() - 0.1).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn test_zero_length_transition() {
+        let mut transition = Transition::new("IdleToWalk", Handle::NONE, Handle::NONE, 0.0, "Walk");
+        transition.add_marker("End", 1.0);
+        transition.add_marker("Start", 0.0);
+
+        // The transition is finished instantly, every marker is emitted at once.
+        let mut markers = Vec::new();
+        transition.update(0.0, |marker| markers.push(marker.name.clone()));
+        assert!(transition.is_done());
+        assert_eq!(transition.blend_factor(), 1.0);
+        assert_eq!(markers, vec!["Start".to_owned(), "End".to_owned()]);
+
+        // Blend curves are sampled at the end too.
+        transition.reset();
+        transition.set_blend_curve(Some(Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 0.8, CurveKeyKind::Linear),
+        ])));
+        transition.update(0.1, |_| ());
+        assert_eq!(transition.blend_factor(), 0.8);
+
+        let mut layer = MachineLayer::new();
+        let idle = layer.add_state(State::new("Idle", Handle::NONE));
+        let walk = layer.add_state(State::new("Walk", Handle::NONE));
+        layer.add_transition(Transition::new("IdleToWalk", idle, walk, 0.0, "Walk"));
+
+        let mut machine = Machine::new();
+        machine.set_parameter("Walk", Parameter::Rule(true));
+        machine.add_layer(layer);
+
+        let animations = AnimationContainer::new();
+        machine.evaluate_pose(&animations, 0.1);
+        assert_eq!(machine.layers()[1].active_state(), walk);
+        assert!(machine.layers()[1].active_transition().is_none());
+    }
 }
@@ -31,6 +31,21 @@ pub struct BlendPose {
 }
 
 impl BlendPose {
+    /// Returns current value of the weight. Parametrized weight is zero, if there is no such parameter or if it is not
+    /// a weight parameter.
+    pub fn fetch_weight(&self, params: &ParameterContainer) -> f32 {
+        match self.weight {
+            PoseWeight::Constant(value) => value,
+            PoseWeight::Parameter(ref param_id) => {
+                if let Some(Parameter::Weight(weight)) = params.get(param_id) {
+                    *weight
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
     /// Creates new instance of blend pose with given weight and animation pose.
     pub fn new(weight: PoseWeight, pose_source: Handle<PoseNode>) -> Self {
         Self {
@@ -119,16 +134,7 @@ impl EvaluatePose for BlendAnimations {
     ) -> Ref<AnimationPose> {
         self.output_pose.borrow_mut().reset();
         for blend_pose in self.pose_sources.iter() {
-            let weight = match blend_pose.weight {
-                PoseWeight::Constant(value) => value,
-                PoseWeight::Parameter(ref param_id) => {
-                    if let Some(Parameter::Weight(weight)) = params.get(param_id) {
-                        *weight
-                    } else {
-                        0.0
-                    }
-                }
-            };
+            let weight = blend_pose.fetch_weight(params);
 
             if let Some(pose_source) = nodes
                 .try_borrow(blend_pose.pose_source)
@@ -147,6 +153,123 @@ impl EvaluatePose for BlendAnimations {
     }
 }
 
+/// Blend tree node. It takes any number of weighted input poses and calculates their weighted average. Unlike
+/// [`BlendAnimations`], the weights are normalized (so they don't have to sum up to 1.0) and the poses are averaged
+/// per-track (see [`AnimationPose::set_weighted_average`]). Weights are usually driven by parameters, which makes the
+/// node suitable for the cases that do not fit parametric blend spaces - for example to mix a few aim offsets. Input
+/// poses could come from any pose nodes, including blend spaces and other blend trees.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     animation::machine::{BlendPose, MachineLayer, PoseNode},
+///     core::pool::Handle,
+/// };
+///
+/// fn add_aim_offsets(
+///     layer: &mut MachineLayer,
+///     aim_up: Handle<PoseNode>,
+///     aim_forward: Handle<PoseNode>,
+///     aim_down: Handle<PoseNode>,
+/// ) -> Handle<PoseNode> {
+///     layer.add_node(PoseNode::make_blend_tree(vec![
+///         BlendPose::with_param_weight("AimUp", aim_up),
+///         BlendPose::with_param_weight("AimForward", aim_forward),
+///         BlendPose::with_param_weight("AimDown", aim_down),
+///     ]))
+/// }
+/// ```
+#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+pub struct BlendTree {
+    /// Base node.
+    pub base: BasePoseNode,
+
+    /// A list of children pose sources with their weights. See [`BlendPose`] docs for more info.
+    pub pose_sources: Vec<BlendPose>,
+
+    /// Output pose of the node, contains the weighted average of all input poses.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    pub output_pose: RefCell<AnimationPose>,
+}
+
+impl Deref for BlendTree {
+    type Target = BasePoseNode;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for BlendTree {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl BlendTree {
+    /// Creates new blend tree node with given children poses.
+    pub fn new(poses: Vec<BlendPose>) -> Self {
+        Self {
+            base: Default::default(),
+            pose_sources: poses,
+            output_pose: Default::default(),
+        }
+    }
+
+    /// Returns a set of handles to children pose nodes.
+    pub fn children(&self) -> Vec<Handle<PoseNode>> {
+        self.pose_sources.iter().map(|s| s.pose_source).collect()
+    }
+}
+
+impl EvaluatePose for BlendTree {
+    fn eval_pose(
+        &self,
+        nodes: &Pool<PoseNode>,
+        params: &ParameterContainer,
+        animations: &AnimationContainer,
+        dt: f32,
+    ) -> Ref<AnimationPose> {
+        // Weights of the same source used multiple times are merged, so every source is evaluated only once.
+        let mut weights: Vec<(Handle<PoseNode>, f32)> = Vec::with_capacity(self.pose_sources.len());
+        for blend_pose in self.pose_sources.iter() {
+            let weight = blend_pose.fetch_weight(params).max(0.0);
+            if let Some(entry) = weights
+                .iter_mut()
+                .find(|(source, _)| *source == blend_pose.pose_source)
+            {
+                entry.1 += weight;
+            } else {
+                weights.push((blend_pose.pose_source, weight));
+            }
+        }
+
+        let poses = weights
+            .iter()
+            .filter_map(|(source, weight)| {
+                nodes
+                    .try_borrow(*source)
+                    .map(|source| (source.eval_pose(nodes, params, animations, dt), *weight))
+            })
+            .collect::<Vec<_>>();
+
+        self.output_pose.borrow_mut().set_weighted_average(
+            &poses
+                .iter()
+                .map(|(pose, weight)| (&**pose, *weight))
+                .collect::<Vec<_>>(),
+        );
+
+        self.output_pose.borrow()
+    }
+
+    fn pose(&self) -> Ref<AnimationPose> {
+        self.output_pose.borrow()
+    }
+}
+
 /// An animation pose with specific blend time. Blend time tells the engine how many time it should use to perform
 /// blending to this pose.
 #[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
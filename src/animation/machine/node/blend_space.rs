@@ -0,0 +1,584 @@
+//! Blend spaces are pose nodes that blend a set of poses placed in a parameter space. See [`BlendSpace1D`] and
+//! [`BlendSpace2D`] docs for more info.
+
+use crate::{
+    animation::{
+        machine::{node::BasePoseNode, EvaluatePose, Parameter, ParameterContainer, PoseNode},
+        AnimationContainer, AnimationPose,
+    },
+    core::{
+        algebra::Vector2,
+        math::get_barycentric_coords_2d,
+        pool::{Handle, Pool},
+        reflect::prelude::*,
+        visitor::prelude::*,
+    },
+};
+use std::{
+    cell::{Ref, RefCell},
+    ops::{Deref, DerefMut},
+};
+
+/// A point on a one-dimensional blend space axis. It has a threshold (its position on the axis) and a pose source
+/// that provides the pose for the point.
+#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+pub struct BlendSpacePoint1D {
+    /// Position of the point on the blend space axis.
+    pub threshold: f32,
+
+    /// A source of animation pose.
+    #[reflect(hidden)]
+    pub pose_source: Handle<PoseNode>,
+}
+
+impl BlendSpacePoint1D {
+    /// Creates new blend space point with given threshold and pose source.
+    pub fn new(threshold: f32, pose_source: Handle<PoseNode>) -> Self {
+        Self {
+            threshold,
+            pose_source,
+        }
+    }
+}
+
+/// One-dimensional blend space. It takes a set of poses placed on an axis (each at its own threshold) and blends two
+/// closest poses around the current value of a `Weight` parameter. The typical use case is locomotion: place idle,
+/// walk and run animations at 0.0, 1.5 and 4.0 (speeds of a character in m/s) and set the parameter to the actual speed
+/// of the character - the node will smoothly blend between the animations. Values outside of the range of the thresholds
+/// are clamped, so the closest edge pose is used.
+///
+/// Points could be stored in any order, the node finds the closest pair of points on every evaluation.
+#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+pub struct BlendSpace1D {
+    /// Base node.
+    pub base: BasePoseNode,
+
+    /// A name of `Weight` parameter that defines current position on the blend space axis.
+    pub parameter: String,
+
+    /// A set of points of the blend space. See [`BlendSpacePoint1D`] docs for more info.
+    pub points: Vec<BlendSpacePoint1D>,
+
+    /// Output pose of the node, contains final result of blending.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    pub output_pose: RefCell<AnimationPose>,
+}
+
+impl Deref for BlendSpace1D {
+    type Target = BasePoseNode;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for BlendSpace1D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl BlendSpace1D {
+    /// Creates new blend space node that uses a `Weight` parameter with given name and a set of points.
+    pub fn new(parameter: String, points: Vec<BlendSpacePoint1D>) -> Self {
+        Self {
+            base: Default::default(),
+            parameter,
+            points,
+            output_pose: Default::default(),
+        }
+    }
+
+    /// Returns a set of handles to children pose nodes.
+    pub fn children(&self) -> Vec<Handle<PoseNode>> {
+        self.points.iter().map(|p| p.pose_source).collect()
+    }
+
+    /// Finds a pair of points (their indices) around given value and an interpolation coefficient between them. If the
+    /// value lies outside of the range of the thresholds, both indices point to the closest edge point.
+    pub fn find_span(&self, value: f32) -> Option<(usize, usize, f32)> {
+        let mut lower: Option<usize> = None;
+        let mut upper: Option<usize> = None;
+
+        for (index, point) in self.points.iter().enumerate() {
+            if point.threshold <= value {
+                if lower.map_or(true, |l| point.threshold > self.points[l].threshold) {
+                    lower = Some(index);
+                }
+            } else if upper.map_or(true, |u| point.threshold < self.points[u].threshold) {
+                upper = Some(index);
+            }
+        }
+
+        match (lower, upper) {
+            (Some(lower), Some(upper)) => {
+                let a = self.points[lower].threshold;
+                let b = self.points[upper].threshold;
+                Some((lower, upper, (value - a) / (b - a)))
+            }
+            (Some(index), None) | (None, Some(index)) => Some((index, index, 0.0)),
+            (None, None) => None,
+        }
+    }
+}
+
+impl EvaluatePose for BlendSpace1D {
+    fn eval_pose(
+        &self,
+        nodes: &Pool<PoseNode>,
+        params: &ParameterContainer,
+        animations: &AnimationContainer,
+        dt: f32,
+    ) -> Ref<AnimationPose> {
+        self.output_pose.borrow_mut().reset();
+
+        let value = if let Some(Parameter::Weight(value)) = params.get(&self.parameter) {
+            *value
+        } else {
+            0.0
+        };
+
+        if let Some((first, second, t)) = self.find_span(value) {
+            if let Some(pose_source) = nodes.try_borrow(self.points[first].pose_source) {
+                pose_source
+                    .eval_pose(nodes, params, animations, dt)
+                    .clone_into(&mut self.output_pose.borrow_mut());
+            }
+
+            if first != second {
+                if let Some(pose_source) = nodes.try_borrow(self.points[second].pose_source) {
+                    self.output_pose
+                        .borrow_mut()
+                        .interpolate_with(&pose_source.eval_pose(nodes, params, animations, dt), t);
+                }
+            }
+        }
+
+        self.output_pose.borrow()
+    }
+
+    fn pose(&self) -> Ref<AnimationPose> {
+        self.output_pose.borrow()
+    }
+}
+
+/// Blends poses of a node using a set of weights, the sum of the weights must be 1.0.
+fn blend_weighted_poses(
+    output_pose: &RefCell<AnimationPose>,
+    sources: &[(Handle<PoseNode>, f32)],
+    nodes: &Pool<PoseNode>,
+    params: &ParameterContainer,
+    animations: &AnimationContainer,
+    dt: f32,
+) {
+    let mut accumulated_weight = 0.0;
+    for &(pose_source, weight) in sources {
+        if let Some(pose_source) = nodes.try_borrow(pose_source) {
+            let pose = pose_source.eval_pose(nodes, params, animations, dt);
+            if accumulated_weight == 0.0 {
+                pose.clone_into(&mut output_pose.borrow_mut());
+            } else {
+                output_pose
+                    .borrow_mut()
+                    .interpolate_with(&pose, weight / (accumulated_weight + weight));
+            }
+            accumulated_weight += weight;
+        }
+    }
+}
+
+/// A point on a two-dimensional blend space plane. It has a position on the plane and a pose source that provides the
+/// pose for the point.
+#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+pub struct BlendSpacePoint2D {
+    /// Position of the point on the blend space plane.
+    pub position: Vector2<f32>,
+
+    /// A source of animation pose.
+    #[reflect(hidden)]
+    pub pose_source: Handle<PoseNode>,
+}
+
+impl BlendSpacePoint2D {
+    /// Creates new blend space point with given position and pose source.
+    pub fn new(position: Vector2<f32>, pose_source: Handle<PoseNode>) -> Self {
+        Self {
+            position,
+            pose_source,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Triangulation {
+    positions: Vec<Vector2<f32>>,
+    triangles: Vec<[usize; 3]>,
+}
+
+fn circumcircle_contains(
+    a: Vector2<f32>,
+    b: Vector2<f32>,
+    c: Vector2<f32>,
+    point: Vector2<f32>,
+) -> bool {
+    let d = 2.0 * (a.x * (b.y - c.y) + b.x * (c.y - a.y) + c.x * (a.y - b.y));
+    if d.abs() <= f32::EPSILON {
+        // Degenerate triangle, its circumcircle is infinitely large.
+        return true;
+    }
+
+    let a_sqr = a.norm_squared();
+    let b_sqr = b.norm_squared();
+    let c_sqr = c.norm_squared();
+    let center = Vector2::new(
+        (a_sqr * (b.y - c.y) + b_sqr * (c.y - a.y) + c_sqr * (a.y - b.y)) / d,
+        (a_sqr * (c.x - b.x) + b_sqr * (a.x - c.x) + c_sqr * (b.x - a.x)) / d,
+    );
+
+    (point - center).norm_squared() < (a - center).norm_squared()
+}
+
+/// Performs Delaunay triangulation of a set of points using Bowyer-Watson algorithm. Collinear and duplicate points
+/// do not produce any triangles. It has O(n^2) complexity, which is fine for blend spaces, since they usually have
+/// just a few points.
+fn triangulate(positions: &[Vector2<f32>]) -> Vec<[usize; 3]> {
+    if positions.len() < 3 {
+        return Vec::new();
+    }
+
+    let mut min = positions[0];
+    let mut max = positions[0];
+    for position in positions.iter() {
+        min = min.inf(position);
+        max = max.sup(position);
+    }
+    let size = (max - min).amax().max(1.0);
+    let center = (min + max).scale(0.5);
+
+    // Vertices of a "super" triangle that contains every point are placed at the end of the set.
+    let mut vertices = positions.to_vec();
+    let super_triangle = [vertices.len(), vertices.len() + 1, vertices.len() + 2];
+    vertices.push(Vector2::new(center.x - 20.0 * size, center.y - size));
+    vertices.push(Vector2::new(center.x, center.y + 20.0 * size));
+    vertices.push(Vector2::new(center.x + 20.0 * size, center.y - size));
+
+    let mut triangles = vec![super_triangle];
+    for (index, &point) in positions.iter().enumerate() {
+        if positions[..index]
+            .iter()
+            .any(|other| (other - point).norm_squared() <= f32::EPSILON)
+        {
+            continue;
+        }
+
+        let (bad, good): (Vec<[usize; 3]>, Vec<[usize; 3]>) =
+            triangles.into_iter().partition(|&[a, b, c]| {
+                circumcircle_contains(vertices[a], vertices[b], vertices[c], point)
+            });
+        triangles = good;
+
+        // Edges of the polygonal hole that is left after removal of bad triangles.
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for &[a, b, c] in bad.iter() {
+            for (u, v) in [(a, b), (b, c), (c, a)] {
+                if let Some(shared) = edges
+                    .iter()
+                    .position(|&(eu, ev)| (eu == v && ev == u) || (eu == u && ev == v))
+                {
+                    edges.remove(shared);
+                } else {
+                    edges.push((u, v));
+                }
+            }
+        }
+
+        for (u, v) in edges {
+            triangles.push([u, v, index]);
+        }
+    }
+
+    triangles.retain(|triangle| {
+        let [a, b, c] = *triangle;
+        let area = (vertices[b] - vertices[a]).perp(&(vertices[c] - vertices[a]));
+        triangle.iter().all(|i| !super_triangle.contains(i)) && area.abs() > f32::EPSILON
+    });
+    triangles
+}
+
+/// Two-dimensional blend space. It takes a set of poses placed on a plane and blends them using a pair of `Weight`
+/// parameters as coordinates of a sampling point. The points are triangulated (using Delaunay triangulation) and the
+/// poses of the triangle that contains the sampling point are blended using barycentric coordinates of the point. If the
+/// sampling point lies outside of the triangulated area, it is clamped to the closest edge. The typical use case is
+/// directional locomotion: place forward, backward, left and right strafing animations at (0, 1), (0, -1), (-1, 0) and
+/// (1, 0) respectively, an idle animation at (0, 0) and set the parameters to the local velocity of a character.
+///
+/// Triangulation is cached and rebuilt automatically when positions of the points change.
+#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+pub struct BlendSpace2D {
+    /// Base node.
+    pub base: BasePoseNode,
+
+    /// A name of `Weight` parameter that defines X coordinate of the sampling point.
+    pub x_parameter: String,
+
+    /// A name of `Weight` parameter that defines Y coordinate of the sampling point.
+    pub y_parameter: String,
+
+    /// A set of points of the blend space. See [`BlendSpacePoint2D`] docs for more info.
+    pub points: Vec<BlendSpacePoint2D>,
+
+    #[visit(skip)]
+    #[reflect(hidden)]
+    triangulation: RefCell<Triangulation>,
+
+    /// Output pose of the node, contains final result of blending.
+    #[visit(skip)]
+    #[reflect(hidden)]
+    pub output_pose: RefCell<AnimationPose>,
+}
+
+impl Deref for BlendSpace2D {
+    type Target = BasePoseNode;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl DerefMut for BlendSpace2D {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.base
+    }
+}
+
+impl BlendSpace2D {
+    /// Creates new blend space node that uses a pair of `Weight` parameters with given names and a set of points.
+    pub fn new(x_parameter: String, y_parameter: String, points: Vec<BlendSpacePoint2D>) -> Self {
+        Self {
+            base: Default::default(),
+            x_parameter,
+            y_parameter,
+            points,
+            triangulation: Default::default(),
+            output_pose: Default::default(),
+        }
+    }
+
+    /// Returns a set of handles to children pose nodes.
+    pub fn children(&self) -> Vec<Handle<PoseNode>> {
+        self.points.iter().map(|p| p.pose_source).collect()
+    }
+
+    /// Calculates blend weights of the points (their indices and weights) for given sampling point. The sum of the
+    /// weights is always 1.0 (unless there are no points at all).
+    pub fn calculate_weights(&self, sampling_point: Vector2<f32>) -> Vec<(usize, f32)> {
+        match self.points.len() {
+            0 => return Vec::new(),
+            1 => return vec![(0, 1.0)],
+            _ => (),
+        }
+
+        let mut triangulation = self.triangulation.borrow_mut();
+        if triangulation.positions.len() != self.points.len()
+            || triangulation
+                .positions
+                .iter()
+                .zip(self.points.iter())
+                .any(|(a, b)| *a != b.position)
+        {
+            triangulation.positions = self.points.iter().map(|p| p.position).collect();
+            triangulation.triangles = triangulate(&triangulation.positions);
+        }
+
+        for &[a, b, c] in triangulation.triangles.iter() {
+            let (u, v, w) = get_barycentric_coords_2d(
+                sampling_point,
+                self.points[a].position,
+                self.points[b].position,
+                self.points[c].position,
+            );
+            if u >= 0.0 && v >= 0.0 && w >= 0.0 {
+                return vec![(a, u), (b, v), (c, w)];
+            }
+        }
+
+        // The point is outside of the triangulated area (or there are no triangles at all, when all the points are
+        // collinear) - find closest point on the edges.
+        let mut edges = Vec::new();
+        if triangulation.triangles.is_empty() {
+            for a in 0..self.points.len() {
+                for b in (a + 1)..self.points.len() {
+                    edges.push((a, b));
+                }
+            }
+        } else {
+            for &[a, b, c] in triangulation.triangles.iter() {
+                edges.extend_from_slice(&[(a, b), (b, c), (c, a)]);
+            }
+        }
+
+        let mut closest = (0, 0, 0.0, f32::MAX);
+        for (a, b) in edges {
+            let pa = self.points[a].position;
+            let pb = self.points[b].position;
+            let edge = pb - pa;
+            let length_sqr = edge.norm_squared();
+            let t = if length_sqr > f32::EPSILON {
+                ((sampling_point - pa).dot(&edge) / length_sqr).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let distance_sqr = (pa + edge.scale(t) - sampling_point).norm_squared();
+            if distance_sqr < closest.3 {
+                closest = (a, b, t, distance_sqr);
+            }
+        }
+
+        vec![(closest.0, 1.0 - closest.2), (closest.1, closest.2)]
+    }
+}
+
+impl EvaluatePose for BlendSpace2D {
+    fn eval_pose(
+        &self,
+        nodes: &Pool<PoseNode>,
+        params: &ParameterContainer,
+        animations: &AnimationContainer,
+        dt: f32,
+    ) -> Ref<AnimationPose> {
+        self.output_pose.borrow_mut().reset();
+
+        let fetch = |name: &str| {
+            if let Some(Parameter::Weight(value)) = params.get(name) {
+                *value
+            } else {
+                0.0
+            }
+        };
+        let sampling_point = Vector2::new(fetch(&self.x_parameter), fetch(&self.y_parameter));
+
+        let sources = self
+            .calculate_weights(sampling_point)
+            .into_iter()
+            .filter(|(_, weight)| *weight > 0.0)
+            .map(|(index, weight)| (self.points[index].pose_source, weight))
+            .collect::<Vec<_>>();
+
+        blend_weighted_poses(&self.output_pose, &sources, nodes, params, animations, dt);
+
+        self.output_pose.borrow()
+    }
+
+    fn pose(&self) -> Ref<AnimationPose> {
+        self.output_pose.borrow()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::machine::node::blend_space::{
+            BlendSpace1D, BlendSpace2D, BlendSpacePoint1D, BlendSpacePoint2D,
+        },
+        core::{algebra::Vector2, pool::Handle},
+    };
+
+    #[test]
+    fn test_blend_space_1d_span() {
+        // Points are intentionally unordered.
+        let blend_space = BlendSpace1D::new(
+            "Speed".to_string(),
+            [1.5, 0.0, 4.0]
+                .iter()
+                .map(|threshold| BlendSpacePoint1D::new(*threshold, Handle::NONE))
+                .collect(),
+        );
+
+        // Below the first point - clamped to the first point.
+        assert_eq!(blend_space.find_span(-1.0), Some((1, 1, 0.0)));
+
+        // Above the last point - clamped to the last point.
+        assert_eq!(blend_space.find_span(5.0), Some((2, 2, 0.0)));
+
+        // Exactly at a point.
+        assert_eq!(blend_space.find_span(1.5), Some((0, 2, 0.0)));
+        assert_eq!(blend_space.find_span(0.0), Some((1, 0, 0.0)));
+        assert_eq!(blend_space.find_span(4.0), Some((2, 2, 0.0)));
+
+        // Between two points.
+        assert_eq!(blend_space.find_span(0.75), Some((1, 0, 0.5)));
+        assert_eq!(blend_space.find_span(3.375), Some((0, 2, 0.75)));
+
+        // No points.
+        assert_eq!(
+            BlendSpace1D::new("Speed".to_string(), Vec::new()).find_span(1.0),
+            None
+        );
+    }
+
+    fn make_blend_space(positions: &[Vector2<f32>]) -> BlendSpace2D {
+        BlendSpace2D::new(
+            "X".to_string(),
+            "Y".to_string(),
+            positions
+                .iter()
+                .map(|p| BlendSpacePoint2D::new(*p, Handle::NONE))
+                .collect(),
+        )
+    }
+
+    fn weight_of(weights: &[(usize, f32)], index: usize) -> f32 {
+        weights
+            .iter()
+            .filter(|(i, _)| *i == index)
+            .map(|(_, w)| *w)
+            .sum()
+    }
+
+    #[test]
+    fn test_blend_space_2d_weights() {
+        let blend_space = make_blend_space(&[
+            Vector2::new(0.0, 0.0),
+            Vector2::new(0.0, 1.0),
+            Vector2::new(0.0, -1.0),
+            Vector2::new(-1.0, 0.0),
+            Vector2::new(1.0, 0.0),
+        ]);
+
+        // Exactly at a point.
+        let weights = blend_space.calculate_weights(Vector2::new(0.0, 1.0));
+        assert!((weight_of(&weights, 1) - 1.0).abs() < 1e-5);
+
+        // Inside the hull.
+        let weights = blend_space.calculate_weights(Vector2::new(0.25, 0.25));
+        let sum: f32 = weights.iter().map(|(_, w)| *w).sum();
+        assert!((sum - 1.0).abs() < 1e-5);
+        assert!((weight_of(&weights, 0) - 0.5).abs() < 1e-5);
+        assert!((weight_of(&weights, 1) - 0.25).abs() < 1e-5);
+        assert!((weight_of(&weights, 4) - 0.25).abs() < 1e-5);
+
+        // Outside the hull - clamped to the closest edge.
+        let weights = blend_space.calculate_weights(Vector2::new(1.0, 1.0));
+        assert!((weight_of(&weights, 1) - 0.5).abs() < 1e-5);
+        assert!((weight_of(&weights, 4) - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_blend_space_2d_collinear_points() {
+        let blend_space = make_blend_space(&[
+            Vector2::new(0.0, 0.0),
+            Vector2::new(1.0, 0.0),
+            Vector2::new(2.0, 0.0),
+        ]);
+
+        // The sampling point is projected on the line.
+        let weights = blend_space.calculate_weights(Vector2::new(1.5, 3.0));
+        let position = weights
+            .iter()
+            .fold(Vector2::default(), |acc, (index, weight)| {
+                acc + blend_space.points[*index].position.scale(*weight)
+            });
+        assert!((position - Vector2::new(1.5, 0.0)).norm() < 1e-5);
+    }
+}
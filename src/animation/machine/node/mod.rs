@@ -4,8 +4,13 @@
 use crate::{
     animation::{
         machine::{
-            node::{blend::BlendAnimations, play::PlayAnimation},
-            BlendAnimationsByIndex, BlendPose, IndexedBlendInput, ParameterContainer, State,
+            node::{
+                blend::{BlendAnimations, BlendTree},
+                blend_space::{BlendSpace1D, BlendSpace2D},
+                play::PlayAnimation,
+            },
+            BlendAnimationsByIndex, BlendPose, BlendSpacePoint1D, BlendSpacePoint2D,
+            IndexedBlendInput, ParameterContainer, State,
         },
         Animation, AnimationContainer, AnimationPose,
     },
@@ -22,6 +27,7 @@ use std::{
 };
 
 pub mod blend;
+pub mod blend_space;
 pub mod play;
 
 /// A set of common data fields that is used in every node.
@@ -46,6 +52,15 @@ pub enum PoseNode {
 
     /// See docs for [`BlendAnimationsByIndex`].
     BlendAnimationsByIndex(BlendAnimationsByIndex),
+
+    /// See docs for [`BlendSpace1D`].
+    BlendSpace1D(BlendSpace1D),
+
+    /// See docs for [`BlendSpace2D`].
+    BlendSpace2D(BlendSpace2D),
+
+    /// See docs for [`BlendTree`].
+    BlendTree(BlendTree),
 }
 
 impl Default for PoseNode {
@@ -74,6 +89,25 @@ impl PoseNode {
         Self::BlendAnimationsByIndex(BlendAnimationsByIndex::new(index_parameter, inputs))
     }
 
+    /// Creates new node that blends two closest poses placed on an axis using a value of given parameter.
+    pub fn make_blend_space_1d(parameter: String, points: Vec<BlendSpacePoint1D>) -> Self {
+        Self::BlendSpace1D(BlendSpace1D::new(parameter, points))
+    }
+
+    /// Creates new node that blends poses placed on a plane using values of given parameters as coordinates.
+    pub fn make_blend_space_2d(
+        x_parameter: String,
+        y_parameter: String,
+        points: Vec<BlendSpacePoint2D>,
+    ) -> Self {
+        Self::BlendSpace2D(BlendSpace2D::new(x_parameter, y_parameter, points))
+    }
+
+    /// Creates new node that calculates normalized weighted average of given poses.
+    pub fn make_blend_tree(poses: Vec<BlendPose>) -> Self {
+        Self::BlendTree(BlendTree::new(poses))
+    }
+
     /// Returns a set of handles to children pose nodes.
     pub fn children(&self) -> Vec<Handle<PoseNode>> {
         match self {
@@ -83,6 +117,9 @@ impl PoseNode {
             }
             Self::BlendAnimations(definition) => definition.children(),
             Self::BlendAnimationsByIndex(definition) => definition.children(),
+            Self::BlendSpace1D(definition) => definition.children(),
+            Self::BlendSpace2D(definition) => definition.children(),
+            Self::BlendTree(definition) => definition.children(),
         }
     }
 }
@@ -93,6 +130,9 @@ macro_rules! static_dispatch {
             PoseNode::PlayAnimation(v) => v.$func($($args),*),
             PoseNode::BlendAnimations(v) => v.$func($($args),*),
             PoseNode::BlendAnimationsByIndex(v) => v.$func($($args),*),
+            PoseNode::BlendSpace1D(v) => v.$func($($args),*),
+            PoseNode::BlendSpace2D(v) => v.$func($($args),*),
+            PoseNode::BlendTree(v) => v.$func($($args),*),
         }
     };
 }
@@ -4,6 +4,8 @@ use crate::core::{reflect::prelude::*, visitor::prelude::*};
 use fxhash::FxHashMap;
 use std::{
     cell::{Cell, RefCell},
+    fmt::{Debug, Display, Formatter},
+    marker::PhantomData,
     ops::{Deref, DerefMut},
 };
 use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
@@ -11,6 +13,10 @@ use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
 /// Machine parameter is a named variable of a fixed type. Machine uses various parameters for specific actions. For example
 /// Rule parameter is used to check where transition from a state to state is possible, `Weight` parameters are used to be
 /// a source real numbers that are used to calculate blend weights, etc.
+///
+/// In terms of types, there are four kinds of parameters: boolean ([`Parameter::Rule`]), floating point
+/// ([`Parameter::Weight`]), integer ([`Parameter::Index`]) and momentary boolean ([`Parameter::Trigger`]). Use type-checked
+/// methods of [`ParameterContainer`] (such as [`ParameterContainer::set_bool`]) to work with them.
 #[derive(Copy, Clone, Debug, PartialEq, Reflect, Visit, EnumVariantNames, EnumString, AsRefStr)]
 pub enum Parameter {
     /// Weight parameter is used to control blend weight in animation blending nodes.
@@ -21,6 +27,214 @@ pub enum Parameter {
 
     /// An index of a pose.
     Index(u32),
+
+    /// Trigger parameter is a momentary Rule parameter. It could be used in the same places as Rule parameter, but it is
+    /// automatically reset to `false` right after a transition consumes it. If multiple transitions (for example, in
+    /// different layers) are activated by the same trigger in a single frame, the trigger is reset only once, at the end
+    /// of the frame. Triggers are useful for one-shot actions, such as jumps or attacks.
+    Trigger(bool),
+}
+
+impl Parameter {
+    /// Returns a name of the type of the parameter.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Parameter::Weight(_) => "Weight",
+            Parameter::Rule(_) => "Rule",
+            Parameter::Index(_) => "Index",
+            Parameter::Trigger(_) => "Trigger",
+        }
+    }
+}
+
+/// An error, that may occur when working with parameters using type-checked methods of [`ParameterContainer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParameterError {
+    /// There is no parameter with the given name.
+    NotFound(String),
+
+    /// A parameter with the given name has different type.
+    TypeMismatch {
+        /// Name of the parameter.
+        name: String,
+        /// A type that was requested.
+        expected: &'static str,
+        /// Actual type of the parameter.
+        actual: &'static str,
+    },
+
+    /// A parameter handle is no longer valid, because the parameter was removed or the parameters were reordered.
+    /// See [`ParameterHandle`] docs for more info.
+    InvalidHandle,
+}
+
+impl Display for ParameterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterError::NotFound(name) => {
+                write!(f, "There is no parameter with {name} name!")
+            }
+            ParameterError::TypeMismatch {
+                name,
+                expected,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "Parameter {name} has {actual} type, but {expected} type was expected!"
+                )
+            }
+            ParameterError::InvalidHandle => {
+                write!(
+                    f,
+                    "Parameter handle is invalid, the parameter was removed or moved!"
+                )
+            }
+        }
+    }
+}
+
+/// A type of a value of a parameter, that could be used with [`ParameterHandle`]. It is implemented for `f32`
+/// ([`Parameter::Weight`]), `bool` ([`Parameter::Rule`]), `u32` ([`Parameter::Index`]) and [`Trigger`]
+/// ([`Parameter::Trigger`]).
+pub trait ParameterType {
+    /// Actual type of the value of the parameter.
+    type Value;
+
+    /// Name of the type of the parameter, the same as [`Parameter::type_name`].
+    fn type_name() -> &'static str;
+
+    /// Returns the value of the parameter, if it has this type.
+    fn get(parameter: &Parameter) -> Option<Self::Value>;
+
+    /// Sets the value of the parameter and returns `true`, if it has this type. Returns `false` otherwise.
+    fn set(parameter: &mut Parameter, value: Self::Value) -> bool;
+}
+
+macro_rules! define_parameter_type {
+    ($ty:ty, $variant:ident) => {
+        impl ParameterType for $ty {
+            type Value = $ty;
+
+            fn type_name() -> &'static str {
+                stringify!($variant)
+            }
+
+            fn get(parameter: &Parameter) -> Option<Self::Value> {
+                match parameter {
+                    Parameter::$variant(value) => Some(*value),
+                    _ => None,
+                }
+            }
+
+            fn set(parameter: &mut Parameter, value: Self::Value) -> bool {
+                match parameter {
+                    Parameter::$variant(current) => {
+                        *current = value;
+                        true
+                    }
+                    _ => false,
+                }
+            }
+        }
+    };
+}
+
+define_parameter_type!(f32, Weight);
+define_parameter_type!(bool, Rule);
+define_parameter_type!(u32, Index);
+
+/// A marker type for handles of [`Parameter::Trigger`] parameters, their values are `bool`s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Trigger;
+
+impl ParameterType for Trigger {
+    type Value = bool;
+
+    fn type_name() -> &'static str {
+        "Trigger"
+    }
+
+    fn get(parameter: &Parameter) -> Option<Self::Value> {
+        match parameter {
+            Parameter::Trigger(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn set(parameter: &mut Parameter, value: Self::Value) -> bool {
+        match parameter {
+            Parameter::Trigger(current) => {
+                *current = value;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A typed handle of a parameter of a [`ParameterContainer`]. It is resolved by the name of a parameter once (see
+/// [`ParameterContainer::handle`]) and then it could be used to get or set the value of the parameter without any
+/// name lookups, which is useful for parameters that are updated every frame. The type of the handle is checked when
+/// it is resolved, so it is impossible to set a value of a wrong type using the handle.
+///
+/// Any removal, renaming or reordering of the parameters of a container invalidates **every** handle of the container,
+/// even the handles of the parameters that were not affected by the change. Such handles must be resolved again.
+/// Adding new parameters does not invalidate existing handles. Invalid handles are detected and reported as
+/// [`ParameterError::InvalidHandle`], use [`ParameterContainer::is_valid_handle`] to check a handle explicitly.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::animation::machine::{Machine, ParameterError, ParameterHandle};
+///
+/// struct Character {
+///     speed: ParameterHandle<f32>,
+///     on_ground: ParameterHandle<bool>,
+/// }
+///
+/// impl Character {
+///     fn new(machine: &Machine) -> Result<Self, ParameterError> {
+///         Ok(Self {
+///             speed: machine.parameters().handle("Speed")?,
+///             on_ground: machine.parameters().handle("OnGround")?,
+///         })
+///     }
+///
+///     // Called every frame.
+///     fn update(&self, machine: &mut Machine, speed: f32, on_ground: bool) {
+///         let parameters = machine.parameters_mut();
+///         parameters.set_by_handle(self.speed, speed).unwrap();
+///         parameters.set_by_handle(self.on_ground, on_ground).unwrap();
+///     }
+/// }
+/// ```
+pub struct ParameterHandle<T> {
+    index: u32,
+    generation: u32,
+    phantom: PhantomData<T>,
+}
+
+impl<T> Copy for ParameterHandle<T> {}
+
+impl<T> Clone for ParameterHandle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> PartialEq for ParameterHandle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index && self.generation == other.generation
+    }
+}
+
+impl<T> Eq for ParameterHandle<T> {}
+
+impl<T> Debug for ParameterHandle<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ParameterHandle({}:{})", self.index, self.generation)
+    }
 }
 
 impl Default for Parameter {
@@ -97,6 +311,11 @@ pub struct ParameterContainer {
     #[reflect(hidden)]
     #[visit(skip)]
     lookup: RefCell<FxHashMap<String, usize>>,
+
+    // Incremented when existing parameters are removed or moved, it is used to detect invalid handles.
+    #[reflect(hidden)]
+    #[visit(skip)]
+    generation: Cell<u32>,
 }
 
 impl PartialEq for ParameterContainer {
@@ -110,6 +329,7 @@ impl Clone for ParameterContainer {
         Self {
             parameters: self.parameters.clone(),
             lookup: RefCell::new(self.lookup.borrow().clone()),
+            generation: self.generation.clone(),
         }
     }
 }
@@ -117,13 +337,22 @@ impl Clone for ParameterContainer {
 impl ParameterContainer {
     fn update_index(&self) {
         if self.parameters.dirty.get() {
-            *self.lookup.borrow_mut() = self
+            let lookup = self
                 .parameters
                 .parameters
                 .iter()
                 .enumerate()
                 .map(|(i, p)| (p.name.clone(), i))
-                .collect();
+                .collect::<FxHashMap<_, _>>();
+            let mut old_lookup = self.lookup.borrow_mut();
+            // Handles stay valid, unless some of the existing parameters was removed or moved.
+            if old_lookup
+                .iter()
+                .any(|(name, index)| lookup.get(name) != Some(index))
+            {
+                self.generation.set(self.generation.get().wrapping_add(1));
+            }
+            *old_lookup = lookup;
             self.parameters.dirty.set(false);
         }
     }
@@ -136,6 +365,18 @@ impl ParameterContainer {
         })
     }
 
+    /// Removes a parameter with the given name from the container and returns its value. Every handle of the container
+    /// becomes invalid (see [`ParameterHandle`] docs).
+    pub fn remove(&mut self, name: &str) -> Option<Parameter> {
+        let index = self.parameters.iter().position(|p| p.name == name)?;
+        Some(self.parameters.remove(index).value)
+    }
+
+    /// Returns a reference to every parameter of the container with its name.
+    pub fn definitions(&self) -> &[ParameterDefinition] {
+        &self.parameters.parameters
+    }
+
     /// Tries to borrow a parameter by its name. The method has O(1) complexity.
     pub fn get(&self, name: &str) -> Option<&Parameter> {
         self.update_index();
@@ -153,4 +394,239 @@ impl ParameterContainer {
             .get(name)
             .and_then(|i| self.parameters.parameters.get_mut(*i).map(|d| &mut d.value))
     }
+
+    fn get_checked(&self, name: &str) -> Result<&Parameter, ParameterError> {
+        self.get(name)
+            .ok_or_else(|| ParameterError::NotFound(name.to_owned()))
+    }
+
+    fn type_mismatch(name: &str, expected: &'static str, actual: &Parameter) -> ParameterError {
+        ParameterError::TypeMismatch {
+            name: name.to_owned(),
+            expected,
+            actual: actual.type_name(),
+        }
+    }
+
+    /// Sets a new value of an existing Rule parameter. Fails if there is no such parameter, or it has different type.
+    pub fn set_bool(&mut self, name: &str, value: bool) -> Result<(), ParameterError> {
+        match self.get_mut(name) {
+            Some(Parameter::Rule(current)) => {
+                *current = value;
+                Ok(())
+            }
+            Some(other) => Err(Self::type_mismatch(name, "Rule", other)),
+            None => Err(ParameterError::NotFound(name.to_owned())),
+        }
+    }
+
+    /// Returns a value of a Rule parameter. Fails if there is no such parameter, or it has different type.
+    pub fn get_bool(&self, name: &str) -> Result<bool, ParameterError> {
+        match self.get_checked(name)? {
+            Parameter::Rule(value) => Ok(*value),
+            other => Err(Self::type_mismatch(name, "Rule", other)),
+        }
+    }
+
+    /// Sets a new value of an existing Weight parameter. Fails if there is no such parameter, or it has different type.
+    pub fn set_float(&mut self, name: &str, value: f32) -> Result<(), ParameterError> {
+        match self.get_mut(name) {
+            Some(Parameter::Weight(current)) => {
+                *current = value;
+                Ok(())
+            }
+            Some(other) => Err(Self::type_mismatch(name, "Weight", other)),
+            None => Err(ParameterError::NotFound(name.to_owned())),
+        }
+    }
+
+    /// Returns a value of a Weight parameter. Fails if there is no such parameter, or it has different type.
+    pub fn get_float(&self, name: &str) -> Result<f32, ParameterError> {
+        match self.get_checked(name)? {
+            Parameter::Weight(value) => Ok(*value),
+            other => Err(Self::type_mismatch(name, "Weight", other)),
+        }
+    }
+
+    /// Sets a new value of an existing Index parameter. Fails if there is no such parameter, or it has different type.
+    pub fn set_int(&mut self, name: &str, value: u32) -> Result<(), ParameterError> {
+        match self.get_mut(name) {
+            Some(Parameter::Index(current)) => {
+                *current = value;
+                Ok(())
+            }
+            Some(other) => Err(Self::type_mismatch(name, "Index", other)),
+            None => Err(ParameterError::NotFound(name.to_owned())),
+        }
+    }
+
+    /// Returns a value of an Index parameter. Fails if there is no such parameter, or it has different type.
+    pub fn get_int(&self, name: &str) -> Result<u32, ParameterError> {
+        match self.get_checked(name)? {
+            Parameter::Index(value) => Ok(*value),
+            other => Err(Self::type_mismatch(name, "Index", other)),
+        }
+    }
+
+    /// Sets an existing Trigger parameter. The trigger will stay set until a transition consumes it. Fails if there is
+    /// no such parameter, or it has different type.
+    pub fn set_trigger(&mut self, name: &str) -> Result<(), ParameterError> {
+        match self.get_mut(name) {
+            Some(Parameter::Trigger(current)) => {
+                *current = true;
+                Ok(())
+            }
+            Some(other) => Err(Self::type_mismatch(name, "Trigger", other)),
+            None => Err(ParameterError::NotFound(name.to_owned())),
+        }
+    }
+
+    /// Resets an existing Trigger parameter, so it won't activate any transitions. Fails if there is no such parameter,
+    /// or it has different type.
+    pub fn reset_trigger(&mut self, name: &str) -> Result<(), ParameterError> {
+        match self.get_mut(name) {
+            Some(Parameter::Trigger(current)) => {
+                *current = false;
+                Ok(())
+            }
+            Some(other) => Err(Self::type_mismatch(name, "Trigger", other)),
+            None => Err(ParameterError::NotFound(name.to_owned())),
+        }
+    }
+
+    /// Returns `true` if a Trigger parameter is set. Fails if there is no such parameter, or it has different type.
+    pub fn is_trigger_set(&self, name: &str) -> Result<bool, ParameterError> {
+        match self.get_checked(name)? {
+            Parameter::Trigger(value) => Ok(*value),
+            other => Err(Self::type_mismatch(name, "Trigger", other)),
+        }
+    }
+
+    /// Resolves a typed handle of a parameter with the given name. Fails if there is no such parameter, or it has
+    /// different type. See [`ParameterHandle`] docs for more info.
+    pub fn handle<T: ParameterType>(
+        &self,
+        name: &str,
+    ) -> Result<ParameterHandle<T>, ParameterError> {
+        self.update_index();
+        let index = self
+            .lookup
+            .borrow()
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ParameterError::NotFound(name.to_owned()))?;
+        let parameter = &self.parameters.parameters[index].value;
+        if T::get(parameter).is_none() {
+            return Err(Self::type_mismatch(name, T::type_name(), parameter));
+        }
+        Ok(ParameterHandle {
+            index: index as u32,
+            generation: self.generation.get(),
+            phantom: PhantomData,
+        })
+    }
+
+    /// Returns `true` if the given handle points to an existing parameter, `false` - otherwise.
+    pub fn is_valid_handle<T>(&self, handle: ParameterHandle<T>) -> bool {
+        self.update_index();
+        handle.generation == self.generation.get()
+            && (handle.index as usize) < self.parameters.parameters.len()
+    }
+
+    fn definition_by_handle<T>(
+        &self,
+        handle: ParameterHandle<T>,
+    ) -> Result<&ParameterDefinition, ParameterError> {
+        if self.is_valid_handle(handle) {
+            Ok(&self.parameters.parameters[handle.index as usize])
+        } else {
+            Err(ParameterError::InvalidHandle)
+        }
+    }
+
+    /// Returns a value of a parameter using its handle. The method has O(1) complexity and it does no name lookups.
+    /// Fails if the handle is invalid, or the type of the parameter was changed.
+    pub fn get_by_handle<T: ParameterType>(
+        &self,
+        handle: ParameterHandle<T>,
+    ) -> Result<T::Value, ParameterError> {
+        let definition = self.definition_by_handle(handle)?;
+        T::get(&definition.value)
+            .ok_or_else(|| Self::type_mismatch(&definition.name, T::type_name(), &definition.value))
+    }
+
+    /// Sets a new value of a parameter using its handle. The method has O(1) complexity and it does no name lookups.
+    /// Fails if the handle is invalid, or the type of the parameter was changed.
+    pub fn set_by_handle<T: ParameterType>(
+        &mut self,
+        handle: ParameterHandle<T>,
+        value: T::Value,
+    ) -> Result<(), ParameterError> {
+        self.definition_by_handle(handle)?;
+        let definition = &mut self.parameters.parameters[handle.index as usize];
+        if T::set(&mut definition.value, value) {
+            Ok(())
+        } else {
+            Err(Self::type_mismatch(
+                &definition.name,
+                T::type_name(),
+                &definition.value,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::animation::machine::parameter::{
+        Parameter, ParameterContainer, ParameterError, ParameterHandle, Trigger,
+    };
+
+    #[test]
+    fn test_parameter_handles() {
+        let mut parameters = ParameterContainer::default();
+        parameters.add("Speed", Parameter::Weight(0.0));
+        parameters.add("Jump", Parameter::Trigger(false));
+
+        let speed: ParameterHandle<f32> = parameters.handle("Speed").unwrap();
+        let jump: ParameterHandle<Trigger> = parameters.handle("Jump").unwrap();
+        assert!(matches!(
+            parameters.handle::<bool>("Speed"),
+            Err(ParameterError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            parameters.handle::<f32>("Foo"),
+            Err(ParameterError::NotFound(_))
+        ));
+
+        parameters.set_by_handle(speed, 2.0).unwrap();
+        parameters.set_by_handle(jump, true).unwrap();
+        assert_eq!(parameters.get_float("Speed"), Ok(2.0));
+        assert_eq!(parameters.get_by_handle(jump), Ok(true));
+
+        // New parameters do not invalidate existing handles.
+        parameters.add("OnGround", Parameter::Rule(true));
+        assert!(parameters.is_valid_handle(speed));
+        assert_eq!(parameters.get_by_handle(speed), Ok(2.0));
+
+        // Removed parameters do.
+        assert_eq!(parameters.remove("Speed"), Some(Parameter::Weight(2.0)));
+        assert!(!parameters.is_valid_handle(speed));
+        assert_eq!(
+            parameters.set_by_handle(speed, 1.0),
+            Err(ParameterError::InvalidHandle)
+        );
+
+        // Removal of a parameter invalidates the handles of the parameters before it too.
+        let jump: ParameterHandle<Trigger> = parameters.handle("Jump").unwrap();
+        assert!(parameters.is_valid_handle(jump));
+        assert!(parameters.remove("OnGround").is_some());
+        assert!(!parameters.is_valid_handle(jump));
+        assert_eq!(
+            parameters.get_by_handle(jump),
+            Err(ParameterError::InvalidHandle)
+        );
+        let jump: ParameterHandle<Trigger> = parameters.handle("Jump").unwrap();
+        assert_eq!(parameters.get_by_handle(jump), Ok(true));
+    }
 }
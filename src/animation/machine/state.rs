@@ -2,8 +2,8 @@
 
 use crate::{
     animation::{
-        machine::{EvaluatePose, ParameterContainer, PoseNode},
-        AnimationContainer, AnimationPose,
+        machine::{EvaluatePose, Parameter, ParameterContainer, PoseNode},
+        Animation, AnimationContainer, AnimationPose,
     },
     core::{
         algebra::Vector2,
@@ -17,7 +17,7 @@ use std::cell::Ref;
 
 /// State is a final "container" for animation pose. It has backing pose node which provides a set of values.
 /// States can be connected with each other using _transitions_, states with transitions form a state graph.
-#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+#[derive(Debug, Visit, Clone, Reflect, PartialEq)]
 pub struct State {
     /// Position of state on the canvas. It is editor-specific data.
     pub position: Vector2<f32>,
@@ -28,6 +28,36 @@ pub struct State {
     /// Root node of the state that provides the state with animation data.
     #[reflect(read_only)]
     pub root: Handle<PoseNode>,
+
+    /// Speed multiplier of the state. While the state contributes to the pose of its layer (it is active, or it is
+    /// the source or the destination of the active transition), every animation played by the state is advanced
+    /// `speed` times faster (see [`super::Machine::apply_state_speeds`]). It also scales the time delta that is passed
+    /// to the pose nodes of the state, which affects every time-dependent pose node (for example, blending in
+    /// [`super::BlendAnimationsByIndex`]). Negative values are treated as zero (the state is paused).
+    #[reflect(description = "Speed multiplier of the state. Negative values are treated as zero.")]
+    #[visit(optional)] // Backward compatibility
+    pub speed: f32,
+
+    /// An optional name of a `Weight` parameter that is used as an additional speed multiplier. It could be used to
+    /// tie the speed of the state to some gameplay stat (attack speed, for example). Empty string means no parameter.
+    #[reflect(
+        description = "An optional name of a Weight parameter that is used as an additional \
+        speed multiplier. Keep it empty to use just the speed of the state."
+    )]
+    #[visit(optional)] // Backward compatibility
+    pub speed_parameter: String,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            position: Default::default(),
+            name: Default::default(),
+            root: Default::default(),
+            speed: 1.0,
+            speed_parameter: Default::default(),
+        }
+    }
 }
 
 impl NameProvider for State {
@@ -43,6 +73,7 @@ impl State {
             position: Default::default(),
             name: name.to_owned(),
             root,
+            ..Default::default()
         }
     }
 
@@ -51,6 +82,33 @@ impl State {
         nodes.try_borrow(self.root).map(|root| root.pose())
     }
 
+    /// Returns a list of handles of animations that are played by pose nodes of the state.
+    pub fn animations(&self, nodes: &Pool<PoseNode>) -> Vec<Handle<Animation>> {
+        let mut animations = Vec::new();
+        let mut stack = vec![self.root];
+        while let Some(handle) = stack.pop() {
+            if let Some(node) = nodes.try_borrow(handle) {
+                if let PoseNode::PlayAnimation(play_animation) = node {
+                    animations.push(play_animation.animation);
+                }
+                stack.extend(node.children());
+            }
+        }
+        animations
+    }
+
+    /// Calculates actual speed multiplier of the state, using its speed and the value of the speed parameter (if any).
+    /// The result is never negative.
+    pub fn effective_speed(&self, params: &ParameterContainer) -> f32 {
+        let mut speed = self.speed;
+        if !self.speed_parameter.is_empty() {
+            if let Some(Parameter::Weight(multiplier)) = params.get(&self.speed_parameter) {
+                speed *= *multiplier;
+            }
+        }
+        speed.max(0.0)
+    }
+
     pub(super) fn update(
         &mut self,
         nodes: &Pool<PoseNode>,
@@ -59,7 +117,7 @@ impl State {
         dt: f32,
     ) {
         if let Some(root) = nodes.try_borrow(self.root) {
-            root.eval_pose(nodes, params, animations, dt);
+            root.eval_pose(nodes, params, animations, dt * self.effective_speed(params));
         }
     }
 }
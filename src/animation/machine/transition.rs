@@ -2,9 +2,121 @@
 
 use crate::{
     animation::machine::State,
-    core::{pool::Handle, reflect::prelude::*, visitor::prelude::*},
+    core::{curve::Curve, pool::Handle, reflect::prelude::*, visitor::prelude::*},
     utils::NameProvider,
 };
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
+
+/// Defines a condition that must be met to activate a transition.
+#[derive(Clone, Debug, PartialEq, Visit, Reflect, AsRefStr, EnumString, EnumVariantNames)]
+pub enum TransitionCondition {
+    /// The transition is activated when a Rule or Trigger parameter (see [`Transition::rule`]) is set. The value of the
+    /// parameter is inverted if [`Transition::is_invert_rule`] is `true`. Trigger parameters are reset after the
+    /// transition is activated (see [`crate::animation::machine::Parameter::Trigger`] for more info).
+    Rule,
+
+    /// The transition is activated when every animation of the source state is within `remaining` seconds of its end.
+    /// It is useful to automatically leave a state when its one-shot animation (an attack, for example) is finished,
+    /// without setting a parameter from game code.
+    AnimationEnded {
+        /// Amount of time (in seconds) before the end of the animations, at which the transition is activated. Zero
+        /// means that the animations must be fully finished.
+        remaining: f32,
+    },
+
+    /// The transition is activated when a Weight or Index parameter (see [`Transition::rule`]) satisfies the
+    /// comparison with the given value. The result of the comparison is inverted if [`Transition::is_invert_rule`] is
+    /// `true`.
+    Compare {
+        /// A comparison operator.
+        comparison: ParameterComparison,
+        /// A value to compare the parameter with.
+        value: f32,
+    },
+}
+
+impl Default for TransitionCondition {
+    fn default() -> Self {
+        Self::Rule
+    }
+}
+
+/// Defines which transitions can interrupt an active transition. An interrupting transition must have strictly higher
+/// priority (see [`Transition::set_priority`]) than the active one and its condition must be met. When a transition is
+/// interrupted, the current (intermediate) blended pose is captured and used as the source pose of the interrupting
+/// transition, so there are no sudden jumps - the new transition blends from exactly what is on the screen.
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Visit, Reflect, AsRefStr, EnumString, EnumVariantNames,
+)]
+pub enum InterruptionSource {
+    /// The transition cannot be interrupted.
+    None,
+    /// The transition can be interrupted by transitions that start from the same source state.
+    Source,
+    /// The transition can be interrupted by transitions that start from its destination state.
+    Destination,
+    /// The transition can be interrupted by transitions that start either from its source or destination state.
+    Any,
+}
+
+impl Default for InterruptionSource {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// A comparison operator, that is used to compare numeric parameters in [`TransitionCondition::Compare`].
+#[derive(
+    Copy, Clone, Debug, PartialEq, Eq, Visit, Reflect, AsRefStr, EnumString, EnumVariantNames,
+)]
+pub enum ParameterComparison {
+    /// `parameter < value`
+    Less,
+    /// `parameter <= value`
+    LessOrEqual,
+    /// `parameter > value`
+    Greater,
+    /// `parameter >= value`
+    GreaterOrEqual,
+    /// `parameter == value`
+    Equal,
+    /// `parameter != value`
+    NotEqual,
+}
+
+impl Default for ParameterComparison {
+    fn default() -> Self {
+        Self::Greater
+    }
+}
+
+impl ParameterComparison {
+    /// Compares two values using the operator.
+    #[inline]
+    pub fn compare(self, parameter: f32, value: f32) -> bool {
+        match self {
+            ParameterComparison::Less => parameter < value,
+            ParameterComparison::LessOrEqual => parameter <= value,
+            ParameterComparison::Greater => parameter > value,
+            ParameterComparison::GreaterOrEqual => parameter >= value,
+            ParameterComparison::Equal => parameter == value,
+            ParameterComparison::NotEqual => parameter != value,
+        }
+    }
+}
+
+/// A named marker on the normalized time of a transition (in `[0; 1]` range), the transition emits
+/// [`crate::animation::machine::Event::TransitionMarker`] event when its normalized time crosses the marker. See
+/// [`Transition::add_marker`] docs for more info.
+#[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
+pub struct TransitionMarker {
+    /// Name of the marker, it is passed to the event.
+    pub name: String,
+
+    /// Normalized time of the marker in `[0; 1]` range, where zero is the beginning of the transition and one is the
+    /// end.
+    pub time: f32,
+}
 
 /// Transition is a connection between two states with a rule that defines possibility of actual transition with blending.
 #[derive(Default, Debug, Visit, Clone, Reflect, PartialEq)]
@@ -26,9 +138,10 @@ pub struct Transition {
     #[reflect(read_only)]
     pub(crate) dest: Handle<State>,
 
-    /// Identifier of Rule parameter which defines is transition should be activated or not.
+    /// Identifier of Rule (or Trigger) parameter which defines is transition should be activated or not. It is also
+    /// used as a name of a numeric parameter for [`TransitionCondition::Compare`].
     #[reflect(
-        description = "Name of the Rule parameter which defines whether transition \
+        description = "Name of the Rule (or Trigger) parameter which defines whether transition \
         should be activated or not"
     )]
     pub(crate) rule: String,
@@ -43,6 +156,50 @@ pub struct Transition {
     )]
     pub(crate) invert_rule: bool,
 
+    /// A condition that must be met to activate the transition.
+    #[reflect(description = "A condition that must be met to activate the transition.")]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) condition: TransitionCondition,
+
+    /// An optional curve that maps normalized transition time to blend factor. `None` means linear blending.
+    #[reflect(hidden)]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) blend_curve: Option<Curve>,
+
+    /// Priority of the transition. If multiple transitions could be activated at the same time, the one with the
+    /// highest priority is used. It is also used to decide whether the transition can interrupt other transitions.
+    #[reflect(
+        description = "Priority of the transition. If multiple transitions could be activated \
+        at the same time, the one with the highest priority is used."
+    )]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) priority: i32,
+
+    /// Defines which transitions can interrupt this transition.
+    #[reflect(
+        description = "Defines which transitions (with higher priority) can interrupt this transition."
+    )]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) interruption_source: InterruptionSource,
+
+    /// If set, the transition could be activated from any state of the layer and its source state is ignored. See
+    /// [`Transition::new_any_state`] docs for more info.
+    #[reflect(read_only)]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) from_any_state: bool,
+
+    /// If set, "any state" transition could be activated while its destination state is active.
+    #[reflect(
+        description = "If set, \"any state\" transition could be activated while its destination state is active."
+    )]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) allow_self_transition: bool,
+
+    /// A set of markers on the normalized time of the transition, sorted by time.
+    #[reflect(hidden)]
+    #[visit(optional)] // Backward compatibility
+    pub(crate) markers: Vec<TransitionMarker>,
+
     /// 0 - evaluates `src` pose, 1 - `dest`, 0..1 - blends `src` and `dest`
     pub(crate) blend_factor: f32,
 }
@@ -71,10 +228,79 @@ impl Transition {
             dest,
             rule: rule.to_owned(),
             invert_rule: false,
+            condition: Default::default(),
+            blend_curve: None,
+            priority: 0,
+            interruption_source: Default::default(),
+            from_any_state: false,
+            allow_self_transition: false,
+            markers: Default::default(),
             blend_factor: 0.0,
         }
     }
 
+    /// Creates a new named "any state" transition, that could be activated from any state of a layer, regardless of
+    /// the currently active state. It is useful for transitions that must be possible from everywhere - for example,
+    /// a transition to a death state - without wiring a transition from every state. Any state transitions compete
+    /// with the regular transitions of the active state using their priorities (see [`Self::set_priority`]) and
+    /// they could interrupt active transitions, that allow interruption (see [`Self::set_interruption_source`]).
+    ///
+    /// By default, the transition cannot be activated while its destination state is active, see
+    /// [`Self::set_allow_self_transition`] to change that.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::machine::{MachineLayer, PoseNode, State, Transition},
+    ///     core::pool::Handle,
+    /// };
+    ///
+    /// fn add_death(layer: &mut MachineLayer, die_animation: Handle<PoseNode>) {
+    ///     let die = layer.add_state(State::new("Die", die_animation));
+    ///     let mut transition = Transition::new_any_state("Any->Die", die, 0.2, "Die");
+    ///     transition.set_priority(100);
+    ///     layer.add_transition(transition);
+    /// }
+    /// ```
+    pub fn new_any_state(name: &str, dest: Handle<State>, time: f32, rule: &str) -> Transition {
+        Self {
+            from_any_state: true,
+            ..Self::new(name, Handle::NONE, dest, time, rule)
+        }
+    }
+
+    /// Returns `true` if the transition could be activated from any state, `false` - otherwise. See
+    /// [`Self::new_any_state`] docs for more info.
+    #[inline]
+    pub fn is_from_any_state(&self) -> bool {
+        self.from_any_state
+    }
+
+    /// Defines whether "any state" transition could be activated while its destination state is active. When the
+    /// self transition is activated, the destination state blends with itself. Has no effect on regular transitions.
+    #[inline]
+    pub fn set_allow_self_transition(&mut self, allow: bool) {
+        self.allow_self_transition = allow;
+    }
+
+    /// Returns `true` if "any state" transition could be activated while its destination state is active. See
+    /// [`Self::set_allow_self_transition`] docs for more info.
+    #[inline]
+    pub fn is_self_transition_allowed(&self) -> bool {
+        self.allow_self_transition
+    }
+
+    /// Returns `true` if the transition could be used to leave the given state.
+    #[inline]
+    pub(super) fn can_leave(&self, state: Handle<State>) -> bool {
+        if self.from_any_state {
+            self.allow_self_transition || self.dest != state
+        } else {
+            self.source == state && self.dest != state
+        }
+    }
+
     /// Returns a reference to the name of the transition.
     #[inline]
     pub fn name(&self) -> &str {
@@ -87,7 +313,7 @@ impl Transition {
         self.transition_time
     }
 
-    /// Returns a handle to source state.
+    /// Returns a handle to source state. "Any state" transitions (see [`Self::new_any_state`]) have no source state.
     #[inline]
     pub fn source(&self) -> Handle<State> {
         self.source
@@ -132,16 +358,183 @@ impl Transition {
         self.invert_rule
     }
 
+    /// Sets a new condition of the transition. See [`TransitionCondition`] docs for more info.
+    #[inline]
+    pub fn set_condition(&mut self, condition: TransitionCondition) {
+        self.condition = condition;
+    }
+
+    /// Returns current condition of the transition.
+    #[inline]
+    pub fn condition(&self) -> &TransitionCondition {
+        &self.condition
+    }
+
+    /// Sets a curve that maps normalized transition time (in `[0; 1]` range) to blend factor between source and
+    /// destination states. It could be used to make transitions with easing (ease-in, ease-out, etc.). The value of the
+    /// curve is clamped to `[0; 1]` range. `None` means linear blending.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::machine::Transition,
+    ///     core::curve::{Curve, CurveKey, CurveKeyKind},
+    /// };
+    ///
+    /// fn make_ease_in_out(transition: &mut Transition) {
+    ///     transition.set_blend_curve(Some(Curve::from(vec![
+    ///         CurveKey::new(0.0, 0.0, CurveKeyKind::new_cubic(0.0, 0.0)),
+    ///         CurveKey::new(1.0, 1.0, CurveKeyKind::new_cubic(0.0, 0.0)),
+    ///     ])));
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_blend_curve(&mut self, curve: Option<Curve>) {
+        self.blend_curve = curve;
+    }
+
+    /// Returns a reference to the blend curve of the transition (if any).
+    #[inline]
+    pub fn blend_curve(&self) -> Option<&Curve> {
+        self.blend_curve.as_ref()
+    }
+
+    /// Sets new priority of the transition. If multiple transitions from the same state could be activated at the
+    /// same time, the one with the highest priority is used (if priorities are equal, the transition that was added
+    /// first is used). Priority also defines whether the transition can interrupt other active transitions, see
+    /// [`InterruptionSource`] docs for more info.
+    #[inline]
+    pub fn set_priority(&mut self, priority: i32) {
+        self.priority = priority;
+    }
+
+    /// Returns current priority of the transition.
+    #[inline]
+    pub fn priority(&self) -> i32 {
+        self.priority
+    }
+
+    /// Defines which transitions can interrupt this transition, while it is active. See [`InterruptionSource`] docs
+    /// for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::machine::{InterruptionSource, Transition};
+    ///
+    /// // A hit reaction must interrupt an attack at any moment, even if the attack is still blending in.
+    /// fn setup_combat(idle_to_attack: &mut Transition, attack_to_hit: &mut Transition) {
+    ///     idle_to_attack.set_interruption_source(InterruptionSource::Destination);
+    ///     attack_to_hit.set_priority(10);
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_interruption_source(&mut self, interruption_source: InterruptionSource) {
+        self.interruption_source = interruption_source;
+    }
+
+    /// Returns current interruption source of the transition.
+    #[inline]
+    pub fn interruption_source(&self) -> InterruptionSource {
+        self.interruption_source
+    }
+
+    /// Adds a new marker on the normalized time of the transition (it is clamped to `[0; 1]` range). Every marker
+    /// emits [`crate::animation::machine::Event::TransitionMarker`] event exactly once per activation of the
+    /// transition, when the normalized time (not the blend factor) of the transition crosses the marker. The events do
+    /// not depend on the frame rate: if a single update crosses multiple markers, each of them is emitted in the
+    /// order of their times. A marker at zero is emitted on the first update, a marker at one - right before the
+    /// transition is done. Markers, that were not reached by an interrupted transition (see
+    /// [`InterruptionSource`]), are not emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::machine::{Event, MachineLayer, Transition};
+    ///
+    /// fn add_weapon_swap(holster: &mut Transition) {
+    ///     holster.add_marker("SwapWeapon", 0.5);
+    /// }
+    ///
+    /// fn handle_events(layer: &mut MachineLayer) {
+    ///     while let Some(event) = layer.pop_event() {
+    ///         if let Event::TransitionMarker { name, .. } = event {
+    ///             if name == "SwapWeapon" {
+    ///                 // Swap the weapon model here.
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn add_marker(&mut self, name: &str, time: f32) {
+        let time = time.clamp(0.0, 1.0);
+        let index = self.markers.partition_point(|marker| marker.time <= time);
+        self.markers.insert(
+            index,
+            TransitionMarker {
+                name: name.to_owned(),
+                time,
+            },
+        );
+    }
+
+    /// Removes every marker with the given name.
+    #[inline]
+    pub fn remove_marker(&mut self, name: &str) {
+        self.markers.retain(|marker| marker.name != name);
+    }
+
+    /// Returns a slice with the markers of the transition, sorted by time.
+    #[inline]
+    pub fn markers(&self) -> &[TransitionMarker] {
+        &self.markers
+    }
+
+    /// Returns normalized time of the transition, transitions with zero duration are finished instantly.
+    fn normalized_time(&self, elapsed_time: f32) -> f32 {
+        if self.transition_time > 0.0 {
+            elapsed_time / self.transition_time
+        } else {
+            1.0
+        }
+    }
+
     pub(super) fn reset(&mut self) {
         self.elapsed_time = 0.0;
         self.blend_factor = 0.0;
     }
 
-    pub(super) fn update(&mut self, dt: f32) {
+    pub(super) fn update<F>(&mut self, dt: f32, mut on_marker: F)
+    where
+        F: FnMut(&TransitionMarker),
+    {
+        let prev = if self.elapsed_time > 0.0 {
+            self.normalized_time(self.elapsed_time)
+        } else {
+            0.0
+        };
+
         self.elapsed_time += dt;
         if self.elapsed_time > self.transition_time {
             self.elapsed_time = self.transition_time;
         }
-        self.blend_factor = self.elapsed_time / self.transition_time;
+
+        let new = self.normalized_time(self.elapsed_time);
+        if new > prev {
+            for marker in self.markers.iter() {
+                if marker.time <= new && (marker.time > prev || (marker.time <= 0.0 && prev <= 0.0))
+                {
+                    on_marker(marker);
+                }
+            }
+        }
+
+        self.blend_factor = if let Some(curve) = self.blend_curve.as_ref() {
+            curve.value_at(new).clamp(0.0, 1.0)
+        } else {
+            new
+        };
     }
 }
@@ -0,0 +1,305 @@
+//! Animation mask is a sort of whitelist that restricts an animation to a subset of nodes. See [`AnimationMask`] docs
+//! for more info.
+
+use crate::{
+    core::{math::lerpf, pool::Handle, reflect::prelude::*, visitor::prelude::*},
+    scene::{graph::Graph, node::Node},
+};
+
+/// Animation mask is a sort of whitelist that restricts an animation to a subset of nodes. Its main use case is layered
+/// animation, for example when you need to play "aim" animation only for upper body of a character, while its legs are
+/// animated by "run" animation. Tracks of an animation that animate nodes outside of its mask do not contribute to the
+/// output pose of the animation at all. The mask holds handles of nodes that **will** be animated.
+///
+/// Nodes of the mask could optionally have weights in `[0; 1]` range, that scale contribution of the tracks to the
+/// output pose. Weighted masks produce smooth partial-body blends instead of a hard boundary, see
+/// [`Self::from_hierarchy_with_falloff`] for more info.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     animation::{AnimationMask, Animation},
+///     core::pool::Handle,
+///     scene::{graph::Graph, node::Node},
+/// };
+///
+/// fn restrict_to_upper_body(spine: Handle<Node>, aim_animation: &mut Animation, graph: &Graph) {
+///     aim_animation.set_mask(Some(AnimationMask::from_hierarchy(graph, spine)));
+/// }
+/// ```
+#[derive(Default, Debug, Visit, Reflect, Clone, PartialEq)]
+pub struct AnimationMask {
+    included_nodes: Vec<Handle<Node>>,
+    // Either empty (every node has weight 1.0) or has a weight for every included node.
+    #[visit(optional)] // Backward compatibility
+    weights: Vec<f32>,
+}
+
+fn sort_key(handle: &Handle<Node>) -> (u32, u32) {
+    (handle.index(), handle.generation())
+}
+
+impl From<Vec<Handle<Node>>> for AnimationMask {
+    fn from(mut included_nodes: Vec<Handle<Node>>) -> Self {
+        included_nodes.sort_by_key(sort_key);
+        included_nodes.dedup();
+        Self {
+            included_nodes,
+            weights: Default::default(),
+        }
+    }
+}
+
+impl AnimationMask {
+    /// Creates an animation mask for every descendant node starting from specified `root` (included). It could
+    /// be useful if you have an entire node hierarchy (for example, upper part of a body) that needs to be animated.
+    pub fn from_hierarchy(graph: &Graph, root: Handle<Node>) -> Self {
+        Self::from(graph.traverse_handle_iter(root).collect::<Vec<_>>())
+    }
+
+    /// Creates a weighted animation mask from the given pairs of node handles and their weights. Weights are clamped
+    /// to `[0; 1]` range, if there are duplicate handles, the first one is used.
+    pub fn from_weights(mut nodes: Vec<(Handle<Node>, f32)>) -> Self {
+        nodes.sort_by_key(|(handle, _)| sort_key(handle));
+        nodes.dedup_by_key(|(handle, _)| *handle);
+        Self {
+            included_nodes: nodes.iter().map(|(handle, _)| *handle).collect(),
+            weights: nodes
+                .iter()
+                .map(|(_, weight)| weight.clamp(0.0, 1.0))
+                .collect(),
+        }
+    }
+
+    /// Creates a weighted animation mask for every descendant node starting from specified `root` (included). Weights
+    /// of the nodes smoothly change with the depth of the nodes in the hierarchy: the `root` has `root_weight`, the
+    /// deepest nodes have `leaf_weight` and the weights of the nodes in between are interpolated linearly. For example,
+    /// an upper-body mask, that starts at the spine with weight 1.0 and fades out to 0.25 at the fingers, makes the
+    /// arms follow the torso more loosely than a binary mask would. Use `root_weight` less than `leaf_weight` for the
+    /// opposite effect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{Animation, AnimationMask},
+    ///     core::pool::Handle,
+    ///     scene::{graph::Graph, node::Node},
+    /// };
+    ///
+    /// fn blend_upper_body(spine: Handle<Node>, aim_animation: &mut Animation, graph: &Graph) {
+    ///     aim_animation.set_mask(Some(AnimationMask::from_hierarchy_with_falloff(
+    ///         graph, spine, 1.0, 0.25,
+    ///     )));
+    /// }
+    /// ```
+    pub fn from_hierarchy_with_falloff(
+        graph: &Graph,
+        root: Handle<Node>,
+        root_weight: f32,
+        leaf_weight: f32,
+    ) -> Self {
+        let mut depths = Vec::new();
+        let mut stack = vec![(root, 0usize)];
+        while let Some((handle, depth)) = stack.pop() {
+            if let Some(node) = graph.try_get(handle) {
+                depths.push((handle, depth));
+                stack.extend(node.children().iter().map(|child| (*child, depth + 1)));
+            }
+        }
+
+        let max_depth = depths
+            .iter()
+            .map(|(_, depth)| *depth)
+            .max()
+            .unwrap_or_default();
+        Self::from_weights(
+            depths
+                .into_iter()
+                .map(|(handle, depth)| {
+                    let t = if max_depth == 0 {
+                        0.0
+                    } else {
+                        depth as f32 / max_depth as f32
+                    };
+                    (handle, lerpf(root_weight, leaf_weight, t))
+                })
+                .collect(),
+        )
+    }
+
+    /// Creates a new mask by replacing every node handle of the mask using the given function, weights are preserved.
+    /// Nodes for which the function returns `None` are excluded from the new mask. It is useful to transfer the mask to
+    /// another hierarchy (for example, to an instance of a model).
+    pub fn remap<F>(&self, mut remap: F) -> Self
+    where
+        F: FnMut(Handle<Node>) -> Option<Handle<Node>>,
+    {
+        Self::from_weights(
+            self.weighted_nodes()
+                .filter_map(|(handle, weight)| remap(handle).map(|handle| (handle, weight)))
+                .collect(),
+        )
+        .simplified()
+    }
+
+    // Drops the weights if every node have weight 1.0, so unweighted masks stay unweighted.
+    fn simplified(mut self) -> Self {
+        if self.weights.iter().all(|weight| *weight == 1.0) {
+            self.weights.clear();
+        }
+        self
+    }
+
+    /// Adds a node handle to the mask, duplicates are ignored.
+    ///
+    /// # Performance
+    ///
+    /// The method has O(log(n)) complexity, which means it is very fast for most use cases.
+    #[inline]
+    pub fn add(&mut self, node: Handle<Node>) {
+        if let Err(index) = self
+            .included_nodes
+            .binary_search_by_key(&sort_key(&node), sort_key)
+        {
+            self.included_nodes.insert(index, node);
+            if !self.weights.is_empty() {
+                self.weights.insert(index, 1.0);
+            }
+        }
+    }
+
+    /// Removes a given node handle from the mask (if any).
+    ///
+    /// # Performance
+    ///
+    /// The method has O(log(n)) complexity, which means it is very fast for most use cases.
+    #[inline]
+    pub fn remove(&mut self, node: Handle<Node>) {
+        if let Ok(index) = self
+            .included_nodes
+            .binary_search_by_key(&sort_key(&node), sort_key)
+        {
+            self.included_nodes.remove(index);
+            if !self.weights.is_empty() {
+                self.weights.remove(index);
+            }
+        }
+    }
+
+    /// Checks if the mask contains a given node handle or not, only nodes in the mask will be animated.
+    ///
+    /// # Performance
+    ///
+    /// The method has O(log(n)) complexity, which means it is very fast for most use cases.
+    #[inline]
+    pub fn contains(&self, node: Handle<Node>) -> bool {
+        self.included_nodes
+            .binary_search_by_key(&sort_key(&node), sort_key)
+            .is_ok()
+    }
+
+    /// Sets the weight of a given node in the mask, the weight is clamped to `[0; 1]` range. The node is added to the
+    /// mask, if it is not in the mask already.
+    ///
+    /// # Performance
+    ///
+    /// The method has O(log(n)) complexity, unless it is the first weight that differs from 1.0.
+    pub fn set_weight(&mut self, node: Handle<Node>, weight: f32) {
+        self.add(node);
+        if let Ok(index) = self
+            .included_nodes
+            .binary_search_by_key(&sort_key(&node), sort_key)
+        {
+            if self.weights.is_empty() {
+                self.weights = vec![1.0; self.included_nodes.len()];
+            }
+            self.weights[index] = weight.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Returns the weight of a given node in the mask. Nodes that are not in the mask have zero weight, nodes of
+    /// unweighted masks have weight 1.0.
+    ///
+    /// # Performance
+    ///
+    /// The method has O(log(n)) complexity, which means it is very fast for most use cases.
+    #[inline]
+    pub fn weight(&self, node: Handle<Node>) -> f32 {
+        match self
+            .included_nodes
+            .binary_search_by_key(&sort_key(&node), sort_key)
+        {
+            Ok(index) => self.weights.get(index).cloned().unwrap_or(1.0),
+            Err(_) => 0.0,
+        }
+    }
+
+    /// Returns `true` if the nodes of the mask have individual weights, `false` - otherwise.
+    #[inline]
+    pub fn is_weighted(&self) -> bool {
+        !self.weights.is_empty()
+    }
+
+    /// Returns an iterator over the nodes of the mask and their weights.
+    pub fn weighted_nodes(&self) -> impl Iterator<Item = (Handle<Node>, f32)> + '_ {
+        self.included_nodes
+            .iter()
+            .enumerate()
+            .map(move |(index, handle)| (*handle, self.weights.get(index).cloned().unwrap_or(1.0)))
+    }
+
+    /// Return a reference to inner container. There's only non-mutable version because inner container must always
+    /// be sorted.
+    #[inline]
+    pub fn inner(&self) -> &Vec<Handle<Node>> {
+        &self.included_nodes
+    }
+
+    /// Converts the mask into inner container.
+    #[inline]
+    pub fn into_inner(self) -> Vec<Handle<Node>> {
+        self.included_nodes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::AnimationMask,
+        core::pool::Handle,
+        scene::{base::BaseBuilder, graph::Graph, pivot::PivotBuilder},
+    };
+
+    #[test]
+    fn test_mask_falloff() {
+        let mut graph = Graph::new();
+        let hand = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let arm = PivotBuilder::new(BaseBuilder::new().with_children(&[hand])).build(&mut graph);
+        let spine = PivotBuilder::new(BaseBuilder::new().with_children(&[arm])).build(&mut graph);
+
+        let mask = AnimationMask::from_hierarchy_with_falloff(&graph, spine, 1.0, 0.0);
+        assert!(mask.is_weighted());
+        assert_eq!(mask.weight(spine), 1.0);
+        assert_eq!(mask.weight(arm), 0.5);
+        assert_eq!(mask.weight(hand), 0.0);
+        assert_eq!(mask.weight(graph.get_root()), 0.0);
+
+        let mut mask = mask.remap(|handle| if handle == hand { None } else { Some(handle) });
+        assert_eq!(mask.inner().len(), 2);
+        assert_eq!(mask.weight(arm), 0.5);
+
+        mask.add(hand);
+        assert_eq!(mask.weight(hand), 1.0);
+        mask.remove(arm);
+        assert_eq!(mask.weight(arm), 0.0);
+        assert_eq!(mask.weighted_nodes().count(), 2);
+
+        // Unweighted masks stay unweighted.
+        let mask = AnimationMask::from(vec![spine, arm]).remap(Some);
+        assert!(!mask.is_weighted());
+        assert_eq!(mask.weight(arm), 1.0);
+        assert_eq!(mask.weight(Handle::NONE), 0.0);
+    }
+}
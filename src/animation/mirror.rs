@@ -0,0 +1,237 @@
+//! Animation mirroring allows you to flip an animation across a plane and to swap left/right bone pairs. See
+//! [`Animation::mirrored`] docs for more info.
+
+use crate::{
+    animation::{container::TrackValueKind, value::ValueBinding, Animation, AnimationMask},
+    core::{pool::Handle, reflect::prelude::*, visitor::prelude::*},
+    scene::node::Node,
+};
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
+
+/// An axis of a coordinate system. In context of mirroring, the axis defines the normal of the mirror plane, for
+/// example [`Axis::X`] defines `YZ` plane, that swaps left and right sides of a typical humanoid character.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum Axis {
+    /// X axis.
+    X,
+    /// Y axis.
+    Y,
+    /// Z axis.
+    Z,
+}
+
+impl Default for Axis {
+    fn default() -> Self {
+        Self::X
+    }
+}
+
+impl Axis {
+    /// Returns an index of the axis (`X = 0`, `Y = 1`, `Z = 2`).
+    pub fn index(self) -> usize {
+        match self {
+            Axis::X => 0,
+            Axis::Y => 1,
+            Axis::Z => 2,
+        }
+    }
+}
+
+impl Animation {
+    /// Creates a copy of the animation, that is mirrored across the plane with the normal defined by the given `axis`.
+    /// Tracks of every pair of bones in `bone_pairs` are swapped (tracks of the first bone will animate the second one
+    /// and vice versa), it allows you to author a "turn left" animation and get "turn right" for free.
+    ///
+    /// # Mirroring details
+    ///
+    /// - Position tracks - the component along the mirror axis is negated.
+    /// - Rotation tracks - a rotation is mirrored by negating the quaternion components that are perpendicular to the
+    /// mirror axis (for example, `(w, x, y, z)` becomes `(w, x, -y, -z)` for [`Axis::X`]). Since rotation tracks store
+    /// Euler angles, it is done by negating the angles around the two other axes, which is exact for any rotation
+    /// order.
+    /// - Scale and property tracks are left as is.
+    ///
+    /// Animation mask and root motion settings (if any) take bone pairs into account as well. Keep in mind, that the
+    /// method expects the local coordinate systems of paired bones to be mirror images of each other, which is usually
+    /// the case for symmetric skeletons.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{mirror::Axis, Animation},
+    ///     core::pool::Handle,
+    ///     scene::node::Node,
+    /// };
+    ///
+    /// fn make_turn_right(
+    ///     turn_left: &Animation,
+    ///     left_leg: Handle<Node>,
+    ///     right_leg: Handle<Node>,
+    ///     left_arm: Handle<Node>,
+    ///     right_arm: Handle<Node>,
+    /// ) -> Animation {
+    ///     let mut turn_right =
+    ///         turn_left.mirrored(Axis::X, &[(left_leg, right_leg), (left_arm, right_arm)]);
+    ///     turn_right.set_name("TurnRight");
+    ///     turn_right
+    /// }
+    /// ```
+    pub fn mirrored(&self, axis: Axis, bone_pairs: &[(Handle<Node>, Handle<Node>)]) -> Animation {
+        let swap = |handle: Handle<Node>| {
+            bone_pairs
+                .iter()
+                .find_map(|&(a, b)| {
+                    if a == handle {
+                        Some(b)
+                    } else if b == handle {
+                        Some(a)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or(handle)
+        };
+
+        let mut mirrored = self.clone();
+
+        for track in mirrored.tracks.iter_mut() {
+            track.set_target(swap(track.target()));
+
+            let negated_axes = match (track.binding(), track.data_container().value_kind()) {
+                (ValueBinding::Position, TrackValueKind::Vector3) => {
+                    [axis == Axis::X, axis == Axis::Y, axis == Axis::Z]
+                }
+                (ValueBinding::Rotation, TrackValueKind::UnitQuaternion) => {
+                    [axis != Axis::X, axis != Axis::Y, axis != Axis::Z]
+                }
+                _ => continue,
+            };
+
+            for (curve, negate) in track
+                .data_container_mut()
+                .curves_mut()
+                .iter_mut()
+                .zip(negated_axes)
+            {
+                if negate {
+                    *curve = curve.scaled(-1.0);
+                }
+            }
+        }
+
+        if let Some(mask) = self.mask.as_ref() {
+            mirrored.mask = Some(AnimationMask::from(
+                mask.inner()
+                    .iter()
+                    .map(|handle| swap(*handle))
+                    .collect::<Vec<_>>(),
+            ));
+        }
+
+        if let Some(settings) = mirrored.root_motion_settings.as_mut() {
+            settings.node = swap(settings.node);
+        }
+
+        mirrored
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{mirror::Axis, track::Track, value::TrackValue, Animation},
+        core::{
+            algebra::{Quaternion, UnitQuaternion},
+            curve::{Curve, CurveKey, CurveKeyKind},
+            pool::Handle,
+        },
+        scene::node::Node,
+    };
+
+    fn constant_curve(value: f32) -> Curve {
+        Curve::from(vec![CurveKey::new(0.0, value, CurveKeyKind::Linear)])
+    }
+
+    #[test]
+    fn test_mirrored() {
+        let left = Handle::<Node>::new(1, 1);
+        let right = Handle::<Node>::new(2, 1);
+
+        let mut position = Track::new_position();
+        position.set_target(left);
+        for (curve, value) in position
+            .data_container_mut()
+            .curves_mut()
+            .iter_mut()
+            .zip([1.0, 2.0, 3.0])
+        {
+            *curve = constant_curve(value);
+        }
+
+        let mut rotation = Track::new_rotation();
+        rotation.set_target(right);
+        for (curve, value) in rotation
+            .data_container_mut()
+            .curves_mut()
+            .iter_mut()
+            .zip([0.3, 0.5, 0.7])
+        {
+            *curve = constant_curve(value);
+        }
+
+        let mut animation = Animation::default();
+        animation.add_track(position);
+        animation.add_track(rotation);
+
+        for axis in [Axis::X, Axis::Y, Axis::Z] {
+            let mirrored = animation.mirrored(axis, &[(left, right)]);
+
+            let tracks = mirrored.tracks();
+            assert_eq!(tracks[0].target(), right);
+            assert_eq!(tracks[1].target(), left);
+
+            let mut expected_position = [1.0, 2.0, 3.0];
+            expected_position[axis.index()] *= -1.0;
+            match tracks[0].data_container().fetch(0.0) {
+                Some(TrackValue::Vector3(v)) => assert_eq!(v.as_slice(), &expected_position),
+                _ => panic!("Position track must produce a vector!"),
+            }
+
+            let original = match animation.tracks()[1].data_container().fetch(0.0) {
+                Some(TrackValue::UnitQuaternion(q)) => q,
+                _ => panic!("Rotation track must produce a quaternion!"),
+            };
+            let mut components = [original.i, original.j, original.k];
+            for (index, component) in components.iter_mut().enumerate() {
+                if index != axis.index() {
+                    *component = -*component;
+                }
+            }
+            let expected = UnitQuaternion::from_quaternion(Quaternion::new(
+                original.w,
+                components[0],
+                components[1],
+                components[2],
+            ));
+            match tracks[1].data_container().fetch(0.0) {
+                Some(TrackValue::UnitQuaternion(q)) => assert!(q.angle_to(&expected) < 1.0e-4),
+                _ => panic!("Rotation track must produce a quaternion!"),
+            }
+        }
+    }
+}
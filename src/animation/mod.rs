@@ -4,35 +4,59 @@
 #![warn(missing_docs)]
 
 use crate::{
-    animation::track::Track,
+    animation::{
+        track::Track,
+        value::{BoundValue, TrackValue, ValueBinding},
+    },
     core::{
+        algebra::{UnitQuaternion, Vector3},
+        curve::{Curve, CurveKey, CurveKeyKind},
         math::wrapf,
         pool::{Handle, Pool, Ticket},
+        rand::Rng,
         reflect::prelude::*,
         uuid::Uuid,
-        visitor::{Visit, VisitResult, Visitor},
+        visitor::{Visit, VisitError, VisitResult, Visitor},
     },
     scene::{
         graph::{Graph, NodePool},
         node::Node,
     },
-    utils::{self, NameProvider},
+    utils::{self, log::Log, NameProvider},
 };
+use fxhash::FxHashMap;
+use serde::{Deserialize, Serialize};
 use std::{
-    collections::VecDeque,
+    cmp::Ordering,
+    collections::{HashMap, VecDeque},
     fmt::Debug,
+    hash::BuildHasher,
     ops::{Index, IndexMut, Range},
 };
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
+
+pub use event_track::{EventKey, EventTrack, TrackEvent};
+pub use mask::AnimationMask;
+pub use pose::{AnimationPose, NodePose, RootMotion};
 
-pub use pose::{AnimationPose, NodePose};
+use pose::{NodeAccess, WeightedPoseAccumulator};
 pub use signal::{AnimationEvent, AnimationSignal};
 
 pub mod container;
+pub mod definition;
+pub mod event_track;
+pub mod headless;
 pub mod machine;
+pub mod mask;
+pub mod mirror;
 pub mod pose;
+pub mod quantization;
+pub mod recorder;
+pub mod retarget;
 pub mod signal;
 pub mod spritesheet;
 pub mod track;
+pub mod validation;
 pub mod value;
 
 /// # Overview
@@ -84,7 +108,7 @@ pub mod value;
 ///
 /// While key frames on the curves can be located at arbitrary position in time, animations usually plays a specific time slice.
 /// By default, each animation will play on a given time slice infinitely - it is called _animation looping_, it works in both
-/// playback directions.
+/// playback directions. The behaviour at the ends of the time slice is defined by [`LoopMode`], see its docs for more info.
 ///
 /// # Speed
 ///
@@ -96,6 +120,12 @@ pub mod value;
 /// Sometimes there's a need to disable/enable an animation or check if it is enabled or not, you can do this by using the pair
 /// of respective methods - [`Animation::set_enabled`] and [`Animation::is_enabled`].
 ///
+/// # Masking
+///
+/// An animation could be restricted to a subset of nodes using [`AnimationMask`]. Tracks of the animation that animate nodes
+/// outside of the mask won't contribute to the output pose. It is useful for layered animations, for example when you need to
+/// play "aim" animation only for upper body of a character. See [`Animation::set_mask`] docs for more info.
+///
 /// # Signals
 ///
 /// Signal is a named marker on specific time position on the animation timeline. Signal will emit an event if the animation playback
@@ -103,7 +133,8 @@ pub mod value;
 /// attach some specific actions to a position in time. For example, you can have a walking animation and you want to emit sounds
 /// when character's feet touch ground. In this case you need to add a few signals at times when each foot touches the ground.
 /// After that all you need to do is to fetch animation events one-by-one and emit respective sounds. See [`AnimationSignal`] docs
-/// for more info and examples.
+/// for more info and examples. Events are kept in the queue of the animation until they're extracted (see
+/// [`Animation::pop_event`]), so the queue must be processed (or cleared) regularly.
 ///
 /// # Examples
 ///
@@ -173,27 +204,779 @@ pub mod value;
 /// The code above creates a simple animation that moves a node along X axis in various ways. The usage of the animation
 /// is only for the sake of completeness of the example. In the real games you need to add the animation to an animation
 /// player scene node and it will do the job for you.
-#[derive(Debug, Reflect, Visit, PartialEq)]
+#[derive(Debug, Reflect, PartialEq)]
 pub struct Animation {
-    #[visit(optional)]
     name: String,
     tracks: Vec<Track>,
     time_position: f32,
-    #[visit(optional)]
     time_slice: Range<f32>,
     speed: f32,
-    looped: bool,
+    loop_mode: LoopMode,
+    direction: PlaybackDirection,
     enabled: bool,
+    #[reflect(hidden)]
+    paused: bool,
+    weight: f32,
     signals: Vec<AnimationSignal>,
+    event_tracks: Vec<EventTrack>,
+    mask: Option<AnimationMask>,
+    blend_mode: AnimationBlendMode,
+    additive_base_time: Option<f32>,
+    #[reflect(hidden)]
+    additive_reference_pose: Option<AnimationPose>,
+    root_motion_settings: Option<RootMotionSettings>,
+    sync_group: Option<SyncGroupId>,
+    hold_on_end: bool,
+    auto_apply: bool,
+    time_offset: f32,
+    loop_range: Option<Range<f32>>,
+
+    #[reflect(hidden)]
+    ping_pong_reversed: bool,
+
+    #[reflect(hidden)]
+    exiting_loop: bool,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    speed_scale: f32,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    root_motion: Option<Vector3<f32>>,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    root_motion_rotation: Option<UnitQuaternion<f32>>,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    ended: bool,
 
     // Non-serialized
     #[reflect(hidden)]
-    #[visit(skip)]
     pose: AnimationPose,
+    // Non-serialized. Time position at which the pose was calculated, `None` if the pose must be recalculated.
+    #[reflect(hidden)]
+    pose_time: Option<f32>,
     // Non-serialized
     #[reflect(hidden)]
-    #[visit(skip)]
     events: VecDeque<AnimationEvent>,
+    // Non-serialized
+    #[reflect(hidden)]
+    track_events: VecDeque<TrackEvent>,
+}
+
+/// Defines how an animation behaves when its playback position reaches either end of its time slice.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum LoopMode {
+    /// The animation is played only once and stops at the end of its time slice (or at the beginning, if it is
+    /// played in reverse).
+    Once,
+
+    /// The animation wraps its playback position to the opposite end of its time slice and continues playing
+    /// infinitely. This is the default mode.
+    Loop,
+
+    /// The animation reverses its playback direction each time it reaches an end of its time slice, so it
+    /// oscillates back and forth infinitely (also known as "yo-yo" looping).
+    PingPong,
+}
+
+impl Default for LoopMode {
+    fn default() -> Self {
+        Self::Loop
+    }
+}
+
+/// Defines the direction in which the playback position of an animation advances. See [`Animation::set_direction`]
+/// docs for more info.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum PlaybackDirection {
+    /// The playback position advances from the start of the time slice to its end. This is the default direction.
+    Forward,
+
+    /// The playback position advances from the end of the time slice to its start.
+    Backward,
+}
+
+impl Default for PlaybackDirection {
+    fn default() -> Self {
+        Self::Forward
+    }
+}
+
+/// Defines how the pose of an animation is combined with the current state of animated nodes.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum AnimationBlendMode {
+    /// The pose of the animation replaces current values of animated properties. This is the default mode.
+    Override,
+
+    /// The pose of the animation is calculated relative to a reference pose (see [`Animation::set_additive_base_time`])
+    /// and then added on top of current values of animated properties: positions and scales are added componentwise,
+    /// rotations are multiplied. It is useful to layer subtle motions (like breathing or recoil) on top of a base
+    /// animation. Keep in mind that the deltas are accumulated each frame, so additive animations should be used
+    /// together with some other (base) animation that sets the same properties each frame.
+    Additive,
+}
+
+impl Default for AnimationBlendMode {
+    fn default() -> Self {
+        Self::Override
+    }
+}
+
+/// Minimal playback state of an animation, that is enough to restore the animation to exact point of its playback.
+/// It does not contain any animation data (tracks, signals, etc.), so it is very cheap to store and to send over the
+/// network. See [`Animation::to_state`] docs for more info.
+#[derive(Copy, Clone, Debug, PartialEq, Visit, Serialize, Deserialize)]
+pub struct PlaybackState {
+    /// Playback position of the animation (in seconds).
+    pub time_position: f32,
+    /// Playback direction of the animation.
+    pub direction: PlaybackDirection,
+    /// Current direction of ping-pong playback, `true` means that the animation moves in the opposite direction.
+    pub ping_pong_reversed: bool,
+    /// `true` if the animation leaves its inner loop range, see [`Animation::exit_loop`].
+    #[visit(optional)] // Backward compatibility
+    pub exiting_loop: bool,
+    /// Weight of the animation.
+    pub weight: f32,
+    /// Enabled flag of the animation.
+    pub enabled: bool,
+    /// Paused flag of the animation.
+    pub paused: bool,
+    /// Ended flag of non-looping animations.
+    pub ended: bool,
+    /// Root motion that accumulated since the last [`Animation::pop_root_motion`] call.
+    pub root_motion: Option<Vector3<f32>>,
+    /// Root motion rotation that accumulated since the last [`Animation::pop_root_motion_rotation`] call.
+    #[visit(optional)] // Backward compatibility
+    pub root_motion_rotation: Option<UnitQuaternion<f32>>,
+}
+
+impl Default for PlaybackState {
+    fn default() -> Self {
+        Self {
+            time_position: 0.0,
+            direction: Default::default(),
+            ping_pong_reversed: false,
+            exiting_loop: false,
+            weight: 1.0,
+            enabled: true,
+            paused: false,
+            ended: false,
+            root_motion: None,
+            root_motion_rotation: None,
+        }
+    }
+}
+
+/// Defines which part of the rotation of the root node should be extracted as root motion. See [`RootMotionSettings`]
+/// docs for more info.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum RootMotionRotation {
+    /// Rotation is not extracted.
+    None,
+
+    /// Only the rotation around vertical (Y) axis is extracted. It is the most common mode for turn animations, since
+    /// character controllers usually rotate only around vertical axis.
+    Yaw,
+
+    /// Full rotation is extracted, the root node keeps its rotation from the start of the time slice of the animation.
+    Full,
+}
+
+impl Default for RootMotionRotation {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Root motion settings defines which node of an animation is the root node and which components of its movement should
+/// be extracted from the animation. Extracted movement is accumulated on each update of the animation and can be fetched
+/// using [`Animation::pop_root_motion`], while respective components of the position of the root node in the output pose
+/// of the animation are set to zero. This way the animation will move the character "in place", and the extracted
+/// movement can be used to move a character controller (for example, a capsule rigid body) instead. It is very useful for
+/// locomotion animations, because it eliminates "foot sliding" effect.
+///
+/// Rotation of the root node can be extracted too (see [`RootMotionRotation`]), it is accumulated separately and can be
+/// fetched using [`Animation::pop_root_motion_rotation`]. The extracted part of the rotation (relative to the rotation
+/// at the start of the time slice) is removed from the output pose, so the character won't rotate twice when the
+/// extracted rotation is applied to a character controller. It is useful for turn-in-place animations.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     animation::{Animation, RootMotionSettings},
+///     core::{algebra::Vector3, pool::Handle},
+///     scene::node::Node,
+/// };
+///
+/// fn setup_root_motion(animation: &mut Animation, hips: Handle<Node>) {
+///     animation.set_root_motion_settings(Some(RootMotionSettings {
+///         node: hips,
+///         ..Default::default()
+///     }));
+/// }
+///
+/// fn move_character(animation: &mut Animation, character_position: &mut Vector3<f32>) {
+///     if let Some(motion) = animation.pop_root_motion() {
+///         *character_position += motion;
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Visit, Reflect)]
+pub struct RootMotionSettings {
+    /// A handle of the root node of an animation (usually it is a hips bone of a character).
+    pub node: Handle<Node>,
+    /// Defines whether the movement along X axis should be extracted or not.
+    pub extract_x: bool,
+    /// Defines whether the movement along Y axis should be extracted or not.
+    pub extract_y: bool,
+    /// Defines whether the movement along Z axis should be extracted or not.
+    pub extract_z: bool,
+    /// Defines which part of the rotation should be extracted.
+    #[visit(optional)] // Backward compatibility
+    pub extract_rotation: RootMotionRotation,
+}
+
+impl Default for RootMotionSettings {
+    fn default() -> Self {
+        // Horizontal movement only.
+        Self {
+            node: Default::default(),
+            extract_x: true,
+            extract_y: false,
+            extract_z: true,
+            extract_rotation: RootMotionRotation::None,
+        }
+    }
+}
+
+impl RootMotionSettings {
+    fn extract(&self, mut position: Vector3<f32>) -> Vector3<f32> {
+        if !self.extract_x {
+            position.x = 0.0;
+        }
+        if !self.extract_y {
+            position.y = 0.0;
+        }
+        if !self.extract_z {
+            position.z = 0.0;
+        }
+        position
+    }
+
+    fn remove_extracted(&self, position: &mut Vector3<f32>) {
+        if self.extract_x {
+            position.x = 0.0;
+        }
+        if self.extract_y {
+            position.y = 0.0;
+        }
+        if self.extract_z {
+            position.z = 0.0;
+        }
+    }
+
+    /// Calculates the rotation that should be extracted when the root node rotates from `from` to `to`.
+    fn extract_rotation(
+        &self,
+        from: &UnitQuaternion<f32>,
+        to: &UnitQuaternion<f32>,
+    ) -> UnitQuaternion<f32> {
+        match self.extract_rotation {
+            RootMotionRotation::None => UnitQuaternion::identity(),
+            RootMotionRotation::Yaw => {
+                UnitQuaternion::from_axis_angle(&Vector3::y_axis(), yaw_delta(from, to))
+            }
+            RootMotionRotation::Full => from.inverse() * to,
+        }
+    }
+
+    /// Removes extracted part of the rotation (relative to the `reference` rotation) from the given rotation.
+    fn remove_extracted_rotation(
+        &self,
+        rotation: &mut UnitQuaternion<f32>,
+        reference: &UnitQuaternion<f32>,
+    ) {
+        match self.extract_rotation {
+            RootMotionRotation::None => (),
+            RootMotionRotation::Yaw => {
+                *rotation = UnitQuaternion::from_axis_angle(
+                    &Vector3::y_axis(),
+                    -yaw_delta(reference, rotation),
+                ) * *rotation;
+            }
+            RootMotionRotation::Full => *rotation = *reference,
+        }
+    }
+}
+
+fn fetch_rotation(track: &Track, time: f32) -> Option<UnitQuaternion<f32>> {
+    track.fetch(time).and_then(|bound_value| {
+        if let TrackValue::UnitQuaternion(rotation) = bound_value.value {
+            Some(rotation)
+        } else {
+            None
+        }
+    })
+}
+
+/// Copies keys of the curve that lie within the given range and bakes the values of the curve at the edges of the
+/// range into new keys (unless there are keys already). Keys are shifted, so the range starts at zero. Baked keys use
+/// the kind of the key that defines the span they're located in, or the `kind_override` (if any).
+fn slice_curve(curve: &Curve, range: &Range<f32>, kind_override: Option<&CurveKeyKind>) -> Curve {
+    let keys = curve.keys();
+
+    let bake = |time: f32| {
+        let kind = kind_override.cloned().unwrap_or_else(|| {
+            keys.iter()
+                .rev()
+                .find(|key| key.location() <= time)
+                .or_else(|| keys.first())
+                .map(|key| key.kind.clone())
+                .unwrap_or(CurveKeyKind::Linear)
+        });
+        let value = match kind_override {
+            Some(kind) => curve.value_at_with_kind(time, kind),
+            None => curve.value_at(time),
+        };
+        CurveKey::new(time - range.start, value, kind)
+    };
+
+    let mut new_keys = Vec::new();
+    if !keys.iter().any(|key| key.location() == range.start) {
+        new_keys.push(bake(range.start));
+    }
+    for key in keys
+        .iter()
+        .filter(|key| key.location() >= range.start && key.location() <= range.end)
+    {
+        new_keys.push(CurveKey::new(
+            key.location() - range.start,
+            key.value,
+            key.kind.clone(),
+        ));
+    }
+    if !keys.iter().any(|key| key.location() == range.end) {
+        new_keys.push(bake(range.end));
+    }
+
+    Curve::from(new_keys)
+}
+
+/// Mirrors the given curve within the given range, so the value of the new curve at `t` is equal to the value of the
+/// source curve at `range.start + range.end - t`. A kind of a key defines the span that starts at the key, so the kinds
+/// are moved to the other ends of their spans and tangents of cubic keys are mirrored. If the value or the tangent of
+/// a span cannot be represented by a single key (for example, a step of a constant key), the key is split into two
+/// keys at the same location. Automatic tangents of Catmull-Rom keys next to such keys may change slightly.
+fn reverse_curve(curve: &Curve, range: &Range<f32>, kind_override: Option<&CurveKeyKind>) -> Curve {
+    let keys = curve.keys();
+    let kind_of = |key: &CurveKey| kind_override.cloned().unwrap_or_else(|| key.kind.clone());
+
+    let mut new_keys = Vec::with_capacity(keys.len());
+    for (index, key) in keys.iter().enumerate().rev() {
+        let location = range.start + range.end - key.location();
+        let kind = kind_of(key);
+
+        let (left_tangent, right_tangent) = match kind {
+            CurveKeyKind::Cubic {
+                left_tangent,
+                right_tangent,
+            } => (left_tangent, right_tangent),
+            _ => (0.0, 0.0),
+        };
+
+        // The span that ends at the key in the new curve is the span that started at the key in the source curve.
+        let arrival_tangent = match kind {
+            CurveKeyKind::Cubic { .. } if index + 1 < keys.len() => Some(-right_tangent),
+            _ => None,
+        };
+
+        // The span that starts at the key in the new curve is the span that ended at the key in the source curve,
+        // it was defined by the previous key.
+        let departure = index.checked_sub(1).map(|prev_index| {
+            let prev = &keys[prev_index];
+            match kind_of(prev) {
+                CurveKeyKind::Constant => (prev.value, CurveKeyKind::Constant),
+                CurveKeyKind::Cubic { .. } => (
+                    key.value,
+                    CurveKeyKind::Cubic {
+                        left_tangent: arrival_tangent.unwrap_or_default(),
+                        right_tangent: -left_tangent,
+                    },
+                ),
+                prev_kind => (key.value, prev_kind),
+            }
+        });
+
+        let mirrored_kind = match kind {
+            CurveKeyKind::Cubic { .. } => CurveKeyKind::Cubic {
+                left_tangent: -right_tangent,
+                right_tangent: -left_tangent,
+            },
+            kind => kind,
+        };
+
+        match departure {
+            Some((value, departure_kind))
+                if value == key.value
+                    && (arrival_tangent.is_none()
+                        || matches!(departure_kind, CurveKeyKind::Cubic { .. })) =>
+            {
+                new_keys.push(CurveKey::new(location, value, departure_kind));
+            }
+            departure => {
+                new_keys.push(CurveKey::new(location, key.value, mirrored_kind));
+                if let Some((value, departure_kind)) = departure {
+                    new_keys.push(CurveKey::new(location, value, departure_kind));
+                }
+            }
+        }
+    }
+
+    let mut reversed = Curve::from(new_keys);
+    reversed.set_id(curve.id());
+    reversed.set_name(curve.name());
+    reversed.set_extrapolation(curve.post_extrapolation(), curve.pre_extrapolation());
+    reversed
+}
+
+/// Returns the angle of the rotation around Y axis (twist) of the given rotation.
+fn yaw(rotation: &UnitQuaternion<f32>) -> f32 {
+    2.0 * rotation.j.atan2(rotation.w)
+}
+
+/// Returns the shortest signed difference between yaw angles of the given rotations, it is always in `[-pi; pi]`
+/// range, so there are no sudden jumps when the yaw angle wraps around.
+fn yaw_delta(from: &UnitQuaternion<f32>, to: &UnitQuaternion<f32>) -> f32 {
+    wrapf(
+        yaw(to) - yaw(from),
+        -std::f32::consts::PI,
+        std::f32::consts::PI,
+    )
+}
+
+/// Aligns the phase of the given animation with the phase of the leader of its sync group (if any) and returns time
+/// delta for the animation, that makes it advance its phase at the same rate as the leader.
+fn synchronize(
+    handle: Handle<Animation>,
+    animation: &mut Animation,
+    leaders: &FxHashMap<SyncGroupId, SyncGroupLeader>,
+    dt: f32,
+) -> f32 {
+    let leader = if let Some(leader) = animation
+        .sync_group
+        .and_then(|sync_group| leaders.get(&sync_group))
+    {
+        leader
+    } else {
+        return dt;
+    };
+
+    let speed = animation.playback_speed();
+    if leader.handle == handle || animation.paused || speed == 0.0 {
+        return dt;
+    }
+
+    animation.set_normalized_time(leader.phase);
+    dt * leader.phase_speed * animation.length() / speed
+}
+
+/// Walks the playback path that starts at `position` and has the given signed length (`delta`) and calls the given
+/// closure for every part of the path with `(from, to, forward, inclusive)` arguments. The path is split into multiple
+/// parts if it goes beyond the time slice - looping animations continue from the opposite end of the time slice,
+/// ping-pong animations continue in opposite direction. Inner loop range (if any) takes precedence - the path continues
+/// from the opposite end of the range, when it reaches an end of the range from the inside (or from the intro part).
+/// Every part includes its beginning and excludes its end, so consecutive parts (and consecutive updates) never share a
+/// point of time, unless `inclusive` is `true`. It is set for parts that end exactly at a bound of the time slice,
+/// where looping animations wrap around and non-looping animations stop. Ping-pong animations do not need it, the part
+/// after a bounce begins at the bound. Returns the end of the path and the direction of playback at the end.
+fn walk_playback_path<F>(
+    time_slice: &Range<f32>,
+    loop_mode: LoopMode,
+    inner_loop: Option<&Range<f32>>,
+    mut position: f32,
+    mut delta: f32,
+    mut func: F,
+) -> (f32, bool)
+where
+    F: FnMut(f32, f32, bool, bool),
+{
+    // Prevents infinite loops on huge time deltas.
+    const MAX_PASSES: usize = 32;
+
+    let start = time_slice.start;
+    let end = time_slice.end;
+    let mut forward = delta >= 0.0;
+
+    for _ in 0..MAX_PASSES {
+        let target = position + delta;
+
+        if let Some(inner_loop) = inner_loop {
+            let (crosses_loop, bound, restart) = if forward {
+                (
+                    position <= inner_loop.end && target > inner_loop.end,
+                    inner_loop.end,
+                    inner_loop.start,
+                )
+            } else {
+                (
+                    position >= inner_loop.start && target < inner_loop.start,
+                    inner_loop.start,
+                    inner_loop.end,
+                )
+            };
+
+            if crosses_loop {
+                func(position, bound, forward, position != bound);
+                delta -= bound - position;
+                position = restart;
+                continue;
+            }
+        }
+
+        let bound = if forward { end } else { start };
+        let crosses_bound = if forward {
+            target > end
+        } else {
+            target < start
+        };
+
+        let inclusive = loop_mode != LoopMode::PingPong && position != bound;
+
+        if !crosses_bound || loop_mode == LoopMode::Once || end - start <= 0.0 {
+            let target = target.clamp(start, end);
+            func(position, target, forward, inclusive && target == bound);
+            position = target;
+            break;
+        }
+
+        func(position, bound, forward, inclusive);
+        delta -= bound - position;
+
+        match loop_mode {
+            LoopMode::Loop => {
+                position = if forward { start } else { end };
+            }
+            LoopMode::PingPong => {
+                position = bound;
+                forward = !forward;
+                delta = -delta;
+            }
+            LoopMode::Once => unreachable!(),
+        }
+    }
+
+    (position, forward)
+}
+
+/// Emits events for every enabled signal in the `[from, to)` range (or `(to, from]` range when playing backwards),
+/// `inclusive` adds `to` to the range. Events are emitted in the order of playback, which means that the signals are
+/// sorted by their time in descending order when playing backwards.
+fn fire_signals_in_range(
+    signals: &[AnimationSignal],
+    events: &mut VecDeque<AnimationEvent>,
+    from: f32,
+    to: f32,
+    forward: bool,
+    inclusive: bool,
+) {
+    let mut crossed = signals
+        .iter()
+        .filter(|s| {
+            let reaches_end = s.time == to && inclusive;
+            s.enabled
+                && (forward && from <= s.time && (s.time < to || reaches_end)
+                    || !forward && s.time <= from && (to < s.time || reaches_end))
+        })
+        .collect::<Vec<_>>();
+
+    crossed.sort_by(|a, b| {
+        let ordering = a.time.partial_cmp(&b.time).unwrap_or(Ordering::Equal);
+        if forward {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+
+    events.extend(crossed.into_iter().map(|signal| AnimationEvent {
+        signal_id: signal.id,
+        name: signal.name.clone(),
+    }));
+}
+
+/// Current version of serialized [`Animation`]s. Older data is upgraded on load, so it is written using the current
+/// version when saved again. Version history:
+///
+/// - `0` - any data that was saved before the version was introduced. Some fields may be missing, they're loaded
+/// with these defaults:
+///   - `Name` - empty name.
+///   - `TimeSlice` - fitted to the range of the keys of the tracks (see [`Animation::fit_time_slice_to_tracks`]),
+///   legacy animations were played over the whole range of their tracks.
+///   - `Weight` - `1.0`.
+///   - `LoopMode` - derived from the legacy `Looped` flag: [`LoopMode::Loop`] if it is set, [`LoopMode::Once`] -
+///   otherwise.
+///   - `PingPongReversed`, `Direction`, `Paused` - forward playback, not paused.
+///   - `EventTracks` - no event tracks.
+///   - `Mask` - no mask.
+///   - `BlendMode` - [`AnimationBlendMode::Override`].
+///   - `AdditiveBaseTime` - `0.0`.
+///   - `RootMotionSettings` - no root motion.
+///   - `SyncGroup` - no sync group.
+/// - `1` - explicit `Version` field. Every field listed above is always written.
+/// - `2` - `AdditiveReferencePose` field, older data has no reference pose (the additive base time is used).
+/// - `3` - `HoldOnEnd` field, older data holds the pose of ended animations (`true`).
+/// - `4` - `AutoApply` field, older animations are not applied automatically (`false`).
+/// - `5` - `TimeOffset` field, older animations have no time offset (`0.0`).
+/// - `6` - `LoopRange` and `ExitingLoop` fields, older animations have no loop range (`None`) and they're not exiting
+/// it (`false`).
+///
+/// When a new field is added, the version must be increased, the default of the field for older versions must be
+/// added to the list above and the upgrade (if the default needs to be calculated) must be added to
+/// [`Animation::migrate`].
+const ANIMATION_VERSION: u32 = 6;
+
+/// Flags of the fields that were loaded, they're used to upgrade data of older versions.
+struct LoadedFields {
+    time_slice: bool,
+}
+
+impl Animation {
+    /// Upgrades loaded data of the given version to the current version (see [`ANIMATION_VERSION`]).
+    fn migrate(&mut self, version: u32, loaded: LoadedFields) {
+        if version < 1 && !loaded.time_slice {
+            self.fit_time_slice_to_tracks();
+        }
+    }
+}
+
+impl Visit for Animation {
+    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+        let mut region = visitor.enter_region(name)?;
+
+        let mut version = ANIMATION_VERSION;
+        if version.visit("Version", &mut region).is_err() && region.is_reading() {
+            version = 0;
+        }
+        if version > ANIMATION_VERSION {
+            return Err(VisitError::User(format!(
+                "Unsupported version {version} of animation data! Maximum supported version is {ANIMATION_VERSION}."
+            )));
+        }
+
+        let _ = self.name.visit("Name", &mut region); // Backward compatibility
+        self.tracks.visit("Tracks", &mut region)?;
+        self.time_position.visit("TimePosition", &mut region)?;
+        let time_slice_loaded = self.time_slice.visit("TimeSlice", &mut region).is_ok(); // Backward compatibility
+        self.speed.visit("Speed", &mut region)?;
+        self.enabled.visit("Enabled", &mut region)?;
+        self.signals.visit("Signals", &mut region)?;
+        let _ = self.weight.visit("Weight", &mut region); // Backward compatibility
+        let _ = self.event_tracks.visit("EventTracks", &mut region); // Backward compatibility
+
+        // Older versions stored a simple looping flag instead of the loop mode. It is still written
+        // to keep the data readable by older versions.
+        let mut looped = self.loop_mode != LoopMode::Once;
+        looped.visit("Looped", &mut region)?;
+        if self.loop_mode.visit("LoopMode", &mut region).is_err() && region.is_reading() {
+            self.loop_mode = if looped {
+                LoopMode::Loop
+            } else {
+                LoopMode::Once
+            };
+        }
+        let _ = self
+            .ping_pong_reversed
+            .visit("PingPongReversed", &mut region); // Backward compatibility
+        let _ = self.direction.visit("Direction", &mut region); // Backward compatibility
+        let _ = self.paused.visit("Paused", &mut region); // Backward compatibility
+        let _ = self.mask.visit("Mask", &mut region); // Backward compatibility
+        let _ = self.blend_mode.visit("BlendMode", &mut region); // Backward compatibility
+        let _ = self
+            .additive_base_time
+            .visit("AdditiveBaseTime", &mut region); // Backward compatibility
+        let _ = self
+            .additive_reference_pose
+            .visit("AdditiveReferencePose", &mut region); // Backward compatibility
+        let _ = self
+            .root_motion_settings
+            .visit("RootMotionSettings", &mut region); // Backward compatibility
+        let _ = self.sync_group.visit("SyncGroup", &mut region); // Backward compatibility
+        let _ = self.hold_on_end.visit("HoldOnEnd", &mut region); // Backward compatibility
+        let _ = self.auto_apply.visit("AutoApply", &mut region); // Backward compatibility
+        let _ = self.time_offset.visit("TimeOffset", &mut region); // Backward compatibility
+        let _ = self.loop_range.visit("LoopRange", &mut region); // Backward compatibility
+        let _ = self.exiting_loop.visit("ExitingLoop", &mut region); // Backward compatibility
+
+        if region.is_reading() {
+            self.migrate(
+                version,
+                LoadedFields {
+                    time_slice: time_slice_loaded,
+                },
+            );
+            self.pose_time = None;
+        }
+
+        Ok(())
+    }
 }
 
 impl NameProvider for Animation {
@@ -209,11 +992,33 @@ impl Clone for Animation {
             tracks: self.tracks.clone(),
             speed: self.speed,
             time_position: self.time_position,
-            looped: self.looped,
+            loop_mode: self.loop_mode,
+            direction: self.direction,
+            ping_pong_reversed: self.ping_pong_reversed,
             enabled: self.enabled,
+            paused: self.paused,
+            weight: self.weight,
             pose: Default::default(),
+            pose_time: None,
             signals: self.signals.clone(),
+            event_tracks: self.event_tracks.clone(),
+            mask: self.mask.clone(),
+            blend_mode: self.blend_mode,
+            additive_base_time: self.additive_base_time,
+            additive_reference_pose: self.additive_reference_pose.clone(),
+            root_motion_settings: self.root_motion_settings.clone(),
+            sync_group: self.sync_group,
+            hold_on_end: self.hold_on_end,
+            auto_apply: self.auto_apply,
+            time_offset: self.time_offset,
+            loop_range: self.loop_range.clone(),
+            exiting_loop: self.exiting_loop,
+            speed_scale: self.speed_scale,
+            root_motion: None,
+            root_motion_rotation: None,
+            ended: self.ended,
             events: Default::default(),
+            track_events: Default::default(),
             time_slice: self.time_slice.clone(),
         }
     }
@@ -231,23 +1036,40 @@ impl Animation {
     }
 
     /// Adds new track to the animation. Animation can have unlimited number of tracks, each track is responsible
-    /// for animation of a single scene node.
+    /// for animation of a single scene node. A warning is logged, if the track has degenerate scale keys (see
+    /// [`Track::has_degenerate_scale`]).
     pub fn add_track(&mut self, track: Track) {
+        self.warn_degenerate_scale(&track);
+        self.invalidate_pose();
         self.tracks.push(track);
     }
 
     /// Removes a track at given index.
     pub fn remove_track(&mut self, index: usize) -> Track {
+        self.invalidate_pose();
         self.tracks.remove(index)
     }
 
     /// Inserts a track at given index.
     pub fn insert_track(&mut self, index: usize, track: Track) {
+        self.warn_degenerate_scale(&track);
+        self.invalidate_pose();
         self.tracks.insert(index, track)
     }
 
+    fn warn_degenerate_scale(&self, track: &Track) {
+        if track.has_degenerate_scale() {
+            Log::warn(format!(
+                "Scale track {} of animation {} has zero or negative keys, it will produce degenerate transforms!",
+                track.id(),
+                self.name
+            ));
+        }
+    }
+
     /// Removes last track from the list of tracks of the animation.
     pub fn pop_track(&mut self) -> Option<Track> {
+        self.invalidate_pose();
         self.tracks.pop()
     }
 
@@ -256,6 +1078,7 @@ impl Animation {
     /// in case if you formed animation from code using just curves and don't know the actual length of the
     /// animation.  
     pub fn fit_length_to_content(&mut self) {
+        self.invalidate_pose();
         self.time_slice.start = 0.0;
         for track in self.tracks.iter_mut() {
             if track.time_length() > self.time_slice.end {
@@ -276,493 +1099,4071 @@ impl Animation {
     /// the actual time position will be wrapped to fit the time slice. For example, if you have an animation that has
     /// `0.0..5.0s` time slice and you trying to set `7.5s` position, the actual time position will be `2.5s` (it
     /// wraps the input value on the given time slice).
-    /// - If the animation is **not** looping and the new time position is outside of the time slice of the animation,
-    /// then the actual time position will be clamped to the time clice of the animation.
+    /// - If the animation is **not** looping (or uses [`LoopMode::PingPong`]) and the new time position is outside of
+    /// the time slice of the animation, then the actual time position will be clamped to the time clice of the animation.
     pub fn set_time_position(&mut self, time: f32) -> &mut Self {
-        if self.looped {
-            self.time_position = wrapf(time, self.time_slice.start, self.time_slice.end);
-        } else {
-            self.time_position = time.clamp(self.time_slice.start, self.time_slice.end);
+        self.ended = false;
+
+        match self.loop_mode {
+            LoopMode::Loop => {
+                self.time_position = wrapf(time, self.time_slice.start, self.time_slice.end);
+            }
+            LoopMode::Once | LoopMode::PingPong => {
+                self.time_position = time.clamp(self.time_slice.start, self.time_slice.end);
+            }
         }
 
         self
     }
 
-    /// Sets new time slice of the animation in seconds. It defines a time interval in which the animation will
-    /// be played. Current playback position will be clamped (or wrapped if the animation is looping) to fit to new
-    /// bounds.
-    pub fn set_time_slice(&mut self, time_slice: Range<f32>) {
-        assert!(time_slice.start <= time_slice.end);
+    /// Advances (if `n` is positive) or rewinds (if `n` is negative) the animation by exactly `n` frames at the given
+    /// frame rate, regardless of playback speed, direction and pause state. Current playback position is snapped to
+    /// the nearest frame first, so repeated stepping never accumulates an error. Looping animations wrap around their
+    /// time slice, every other animation is clamped to it (the same as [`Self::set_time_position`]). Signals and
+    /// event tracks that are crossed are fired, the pose of the animation is updated at the new position, no root
+    /// motion is accumulated. It is useful for frame-by-frame navigation in tools, that are independent of real time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn next_frame(animation: &mut Animation) -> i32 {
+    ///     animation.step_frames(1, 30.0);
+    ///     animation.frame_index(30.0)
+    /// }
+    /// ```
+    pub fn step_frames(&mut self, n: i32, fps: f32) -> &mut Self {
+        if fps <= 0.0 || fps.is_nan() {
+            Log::err(format!(
+                "Unable to step animation {}, frame rate must be positive (got {fps})!",
+                self.name
+            ));
+            return self;
+        }
 
-        self.time_slice = time_slice;
+        let position = self.time_position;
+        let target = self.time_slice.start + (self.frame_index(fps) + n) as f32 / fps;
+        let target = if self.loop_mode == LoopMode::Loop {
+            target
+        } else {
+            target.clamp(self.time_slice.start, self.time_slice.end)
+        };
 
-        // Ensure time position is in given time slice.
-        self.set_time_position(self.time_position);
-    }
+        self.fire_signals(position, target - position);
+        if self.active_loop_range().is_some() {
+            self.advance_time_position(position, target - position);
+        } else {
+            self.set_time_position(target);
+        }
+        self.update_pose();
 
-    /// Returns current time slice of the animation.
-    pub fn time_slice(&self) -> Range<f32> {
-        self.time_slice.clone()
+        self
     }
 
-    /// Rewinds the animation to the beginning.
-    pub fn rewind(&mut self) -> &mut Self {
-        self.set_time_position(self.time_slice.start)
+    /// Returns an index of the frame (relative to the start of the time slice) that is the nearest to current
+    /// playback position, using the given frame rate. See [`Self::step_frames`] for more info.
+    pub fn frame_index(&self, fps: f32) -> i32 {
+        ((self.time_position - self.time_slice.start) * fps).round() as i32
     }
 
-    /// Returns length of the animation in seconds.
-    pub fn length(&self) -> f32 {
-        self.time_slice.end - self.time_slice.start
-    }
+    /// Moves the playback position in ping-pong manner - the position is reflected from the ends of the time slice
+    /// and each reflection flips the playback direction. The position at the exact end of the time slice is treated
+    /// as a reflection, so the end keys are evaluated only once per pass.
+    fn ping_pong_time_position(&mut self, time: f32) {
+        let length = self.length();
+        if length <= 0.0 {
+            self.time_position = self.time_slice.start;
+            return;
+        }
 
-    /// Performs a single update tick and calculates an output pose. This method is low level, you should not use it
-    /// in normal circumstances - the engine will call it for you.
-    pub fn tick(&mut self, dt: f32) {
-        self.update_pose();
+        let passes = (time - self.time_slice.start) / length;
+        let pass = passes.floor();
+        let fraction = passes - pass;
 
-        let current_time_position = self.time_position();
-        let new_time_position = current_time_position + dt * self.speed();
+        if (pass as i64).rem_euclid(2) == 1 {
+            self.time_position = self.time_slice.end - fraction * length;
+            self.ping_pong_reversed = !self.ping_pong_reversed;
+        } else {
+            self.time_position = self.time_slice.start + fraction * length;
+        }
+    }
 
-        for signal in self.signals.iter_mut().filter(|s| s.enabled) {
-            if self.speed >= 0.0
-                && (current_time_position < signal.time && new_time_position >= signal.time)
-                || self.speed < 0.0
-                    && (current_time_position > signal.time && new_time_position <= signal.time)
-            {
-                // TODO: Make this configurable.
-                if self.events.len() < 32 {
-                    self.events.push_back(AnimationEvent {
-                        signal_id: signal.id,
-                        name: signal.name.clone(),
-                    });
-                }
+    /// Moves the playback position along the playback path, that starts at `position` and has the given signed length
+    /// (`delta`), taking the loop mode and the inner loop range into account.
+    fn advance_time_position(&mut self, position: f32, delta: f32) {
+        if let Some(inner_loop) = self.active_loop_range() {
+            let (position, forward) = walk_playback_path(
+                &self.time_slice,
+                self.loop_mode,
+                Some(&inner_loop),
+                position,
+                delta,
+                |_, _, _, _| {},
+            );
+            if forward != (delta >= 0.0) {
+                self.ping_pong_reversed = !self.ping_pong_reversed;
             }
+            self.set_time_position(position);
+        } else if self.loop_mode == LoopMode::PingPong {
+            self.ping_pong_time_position(position + delta);
+        } else {
+            self.set_time_position(position + delta);
         }
-
-        self.set_time_position(new_time_position);
     }
 
-    /// Extracts a first event from the events queue of the animation.
-    pub fn pop_event(&mut self) -> Option<AnimationEvent> {
-        self.events.pop_front()
+    /// Returns the inner loop range clamped to the time slice, if the playhead must be kept within it.
+    fn active_loop_range(&self) -> Option<Range<f32>> {
+        if self.exiting_loop {
+            return None;
+        }
+        let range = self.loop_range.as_ref()?;
+        let start = range.start.max(self.time_slice.start);
+        let end = range.end.min(self.time_slice.end);
+        if start < end {
+            Some(start..end)
+        } else {
+            None
+        }
     }
 
-    /// Returns a reference to inner events queue. It is useful when you need to iterate over the events, but
-    /// don't extract them from the queue.
-    pub fn events_ref(&self) -> &VecDeque<AnimationEvent> {
-        &self.events
+    /// Returns actual playback speed of the animation, it takes playback direction, current ping-pong direction and
+    /// speed scale into account.
+    fn playback_speed(&self) -> f32 {
+        let speed = match self.direction {
+            PlaybackDirection::Forward => self.speed,
+            PlaybackDirection::Backward => -self.speed,
+        } * self.speed_scale;
+        if self.ping_pong_reversed {
+            -speed
+        } else {
+            speed
+        }
     }
 
-    /// Return a mutable reference to inner events queue. Provides you a full controls over animation events,
-    /// you can even manually inject events in the queue.
-    pub fn events_mut(&mut self) -> &mut VecDeque<AnimationEvent> {
-        &mut self.events
+    /// Sets new time slice of the animation in seconds. It defines a time interval in which the animation will
+    /// be played. Current playback position will be clamped to fit to new bounds. The start of the time slice must
+    /// be strictly less than its end, otherwise the new time slice will be ignored and an error will be logged.
+    ///
+    /// Keys of the tracks that lie outside of the time slice are kept intact, but they're never reached during
+    /// playback. However, they still affect the values inside the time slice, since the curves are interpolated
+    /// between the closest keys on both sides of sampling position. If there are no keys on one of the sides
+    /// (for example, the slice starts before the first key), the value of the closest key is used. Use
+    /// [`Self::fit_time_slice_to_tracks`] to make the time slice match the actual range of the keys.
+    pub fn set_time_slice(&mut self, time_slice: Range<f32>) {
+        if time_slice.start.is_nan()
+            || time_slice.end.is_nan()
+            || time_slice.start >= time_slice.end
+        {
+            Log::err(format!(
+                "Unable to set time slice {:?} for animation {}, the start must be less than the end!",
+                time_slice, self.name
+            ));
+            return;
+        }
+
+        // Ensure time position is in given time slice.
+        let time_position = self.time_position.clamp(time_slice.start, time_slice.end);
+
+        self.time_slice = time_slice;
+        self.invalidate_pose();
+
+        self.set_time_position(time_position);
     }
 
-    /// Takes the events queue and returns it to the caller, leaving the internal queue empty.
-    pub fn take_events(&mut self) -> VecDeque<AnimationEvent> {
-        std::mem::take(&mut self.events)
+    /// Sets the time slice of the animation to the range of the keys of all its tracks (from the left-most key to
+    /// the right-most one). Current playback position will be clamped to fit to new bounds. Does nothing if the
+    /// tracks have no keys at all or if every key is located at the same time position.
+    pub fn fit_time_slice_to_tracks(&mut self) {
+        let mut range: Option<Range<f32>> = None;
+        for track in self.tracks.iter() {
+            for curve in track.data_container().curves_ref() {
+                if let (Some(first), Some(last)) = (curve.keys().first(), curve.keys().last()) {
+                    let range = range.get_or_insert(first.location()..last.location());
+                    range.start = range.start.min(first.location());
+                    range.end = range.end.max(last.location());
+                }
+            }
+        }
+
+        if let Some(range) = range {
+            if range.start < range.end {
+                self.set_time_slice(range);
+            }
+        }
     }
 
-    /// Returns current time position of the animation. The time position is guaranteed to be in the range of
-    /// current time slice of the animation.
-    pub fn time_position(&self) -> f32 {
-        self.time_position
+    /// Extracts a sub-range of the animation into a new animation (a "take"). It is useful to split a single long
+    /// timeline (for example, an imported one) into separate clips, such as idle, walk and jump. Keys of the tracks that
+    /// lie within the given range are copied, values of the curves at the edges of the range are baked into new keys, so
+    /// the new animation looks exactly the same as the range of the source animation. Every key, signal and event
+    /// key is shifted, so that the time slice of the new animation starts at zero. Playback state of the new animation
+    /// is reset (except weight, enabled flag and playback direction), every other setting is copied as is.
+    ///
+    /// Tracks that have no keys within the range are either dropped (if `keep_empty_tracks` is `false`) or kept without
+    /// any keys. Returns a copy of the animation with no changes if the range is empty or invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, AnimationContainer};
+    ///
+    /// fn split_timeline(timeline: &Animation, container: &mut AnimationContainer) {
+    ///     for (name, range) in [("Idle", 0.0..2.0), ("Walk", 2.0..3.0), ("Jump", 3.0..3.8)] {
+    ///         let mut clip = timeline.slice(range, false);
+    ///         clip.set_name(name);
+    ///         container.add(clip);
+    ///     }
+    /// }
+    /// ```
+    pub fn slice(&self, range: Range<f32>, keep_empty_tracks: bool) -> Animation {
+        let mut slice = self.clone();
+
+        if range.start.is_nan() || range.end.is_nan() || range.start >= range.end {
+            Log::err(format!(
+                "Unable to slice animation {} using range {:?}, the start must be less than the end!",
+                self.name, range
+            ));
+            return slice;
+        }
+
+        let in_range = |time: f32| time >= range.start && time <= range.end;
+
+        let mut tracks = Vec::with_capacity(self.tracks.len());
+        for track in self.tracks.iter() {
+            let has_keys = track
+                .data_container()
+                .curves_ref()
+                .iter()
+                .any(|curve| curve.keys().iter().any(|key| in_range(key.location())));
+
+            if !has_keys && !keep_empty_tracks {
+                continue;
+            }
+
+            let mut track = track.clone();
+            let kind_override = track.interpolation().key_kind();
+            for curve in track.data_container_mut().curves_mut() {
+                *curve = if has_keys {
+                    slice_curve(curve, &range, kind_override.as_ref())
+                } else {
+                    Curve::default()
+                };
+            }
+            tracks.push(track);
+        }
+        slice.tracks = tracks;
+
+        slice.signals = self
+            .signals
+            .iter()
+            .filter(|signal| in_range(signal.time))
+            .map(|signal| AnimationSignal {
+                time: signal.time - range.start,
+                ..signal.clone()
+            })
+            .collect();
+
+        for event_track in slice.event_tracks.iter_mut() {
+            let keys = event_track.keys().to_vec();
+            event_track.clear();
+            for key in keys.into_iter().filter(|key| in_range(key.time)) {
+                event_track.add_key(EventKey {
+                    time: key.time - range.start,
+                    ..key
+                });
+            }
+        }
+
+        slice.from_state(&PlaybackState {
+            time_position: 0.0,
+            ping_pong_reversed: false,
+            ended: false,
+            root_motion: None,
+            root_motion_rotation: None,
+            ..self.to_state()
+        });
+        slice.time_slice = 0.0..(range.end - range.start);
+        slice.loop_range = self
+            .loop_range
+            .as_ref()
+            .map(|loop_range| (loop_range.start - range.start)..(loop_range.end - range.start));
+        slice.rewind();
+
+        slice
     }
 
-    /// Sets new speed multiplier for the animation. By default it is set to 1.0. Negative values can be used
-    /// to play the animation in reverse.
-    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
-        self.speed = speed;
+    /// Creates a new animation, that plays the animation backwards. Unlike reverse playback (see
+    /// [`Self::set_direction`]), the curves are actually mirrored within the time slice (a key at time `t` is moved to
+    /// `start + end - t`), so the new animation is a regular clip that could be played forward, blended or edited
+    /// further. Interpolation of every span is preserved - tangents of cubic keys are mirrored, steps of constant keys
+    /// happen at mirrored times (such keys are split into two keys at the same time, which may slightly change
+    /// automatic tangents of neighboring Catmull-Rom keys). Signals, event keys and the time of the additive base pose
+    /// are mirrored as well, every other setting is copied as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, AnimationContainer};
+    ///
+    /// fn add_sit_down_clip(stand_up: &Animation, container: &mut AnimationContainer) {
+    ///     let mut sit_down = stand_up.reversed();
+    ///     sit_down.set_name("SitDown");
+    ///     container.add(sit_down);
+    /// }
+    /// ```
+    pub fn reversed(&self) -> Animation {
+        let mut reversed = self.clone();
+        let range = self.time_slice.clone();
+        let mirror = |time: f32| range.start + range.end - time;
+
+        for track in reversed.tracks.iter_mut() {
+            let kind_override = track.interpolation().key_kind();
+            for curve in track.data_container_mut().curves_mut() {
+                *curve = reverse_curve(curve, &range, kind_override.as_ref());
+            }
+        }
+
+        for signal in reversed.signals.iter_mut() {
+            signal.time = mirror(signal.time);
+        }
+
+        for event_track in reversed.event_tracks.iter_mut() {
+            let keys = event_track.keys().to_vec();
+            event_track.clear();
+            for key in keys {
+                event_track.add_key(EventKey {
+                    time: mirror(key.time),
+                    ..key
+                });
+            }
+        }
+
+        reversed.additive_base_time = self.additive_base_time.map(mirror);
+        reversed.loop_range = self
+            .loop_range
+            .as_ref()
+            .map(|loop_range| mirror(loop_range.end)..mirror(loop_range.start));
+        reversed.time_position = mirror(self.time_position);
+        reversed.invalidate_pose();
+
+        reversed
+    }
+
+    /// Returns current time slice of the animation.
+    pub fn time_slice(&self) -> Range<f32> {
+        self.time_slice.clone()
+    }
+
+    /// Rewinds the animation to the beginning. Animations played in reverse (see [`Self::set_direction`] and
+    /// [`Self::set_speed`]) begin at the end of their time slice. The beginning is shifted by the time offset of the
+    /// animation, see [`Self::set_time_offset`].
+    pub fn rewind(&mut self) -> &mut Self {
+        self.ping_pong_reversed = false;
+        self.exiting_loop = false;
+        if self.playback_speed() < 0.0 {
+            self.set_time_position(self.time_slice.end - self.time_offset)
+        } else {
+            self.set_time_position(self.time_slice.start + self.time_offset)
+        }
+    }
+
+    /// Sets the time offset (phase) of the animation in seconds. The animation begins playback at the offset from
+    /// the start of its time slice (or before the end of the time slice for animations played in reverse), looping
+    /// animations wrap the offset around the time slice. Current playback position is shifted by the difference of the
+    /// offsets, so the animation behaves as if it was started with the new offset. Identical characters, that play
+    /// the same animation with different offsets, do not move in lockstep. The offset is serialized, so it stays the
+    /// same for every instance across saves. See [`AnimationContainer::randomize_time_offsets`] to desynchronize a
+    /// whole container.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn setup_idle(npc_index: usize, idle: &mut Animation) {
+    ///     // Spread the phases of the NPCs evenly over the animation.
+    ///     let phase = (npc_index % 8) as f32 / 8.0;
+    ///     idle.set_time_offset(phase * idle.length());
+    /// }
+    /// ```
+    pub fn set_time_offset(&mut self, offset: f32) -> &mut Self {
+        let delta = offset - self.time_offset;
+        self.time_offset = offset;
+        let position = if self.playback_speed() < 0.0 {
+            self.time_position - delta
+        } else {
+            self.time_position + delta
+        };
+        self.set_time_position(position)
+    }
+
+    /// Returns current time offset of the animation. See [`Self::set_time_offset`] docs for more info.
+    pub fn time_offset(&self) -> f32 {
+        self.time_offset
+    }
+
+    /// Sets an inner loop range of the animation, `None` removes the range. It allows a single clip to have "intro",
+    /// "loop" and "outro" sections: the playhead plays the intro (the part before the range) once, then it cycles
+    /// within the range (regardless of the loop mode) until [`Self::exit_loop`] is called, after which it continues to
+    /// the end of the time slice, where the loop mode of the animation is applied as usual. Transitions between the
+    /// sections are seamless, the playhead is wrapped around exactly like in looping animations, signals and root motion
+    /// follow it. The range is clamped to the time slice, the start of the range must be less than its end. Reverse
+    /// playback cycles within the range in the opposite direction. The range is serialized, rewinding the animation
+    /// (see [`Self::rewind`]) enters the loop again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn setup_jump(jump: &mut Animation) {
+    ///     // Take-off is 0.0..0.4, hovering in the air is 0.4..0.9, landing is 0.9..1.3.
+    ///     jump.set_loop_range(Some(0.4..0.9));
+    /// }
+    ///
+    /// fn on_landed(jump: &mut Animation) {
+    ///     jump.exit_loop();
+    /// }
+    /// ```
+    pub fn set_loop_range(&mut self, range: Option<Range<f32>>) -> &mut Self {
+        if let Some(range) = range.as_ref() {
+            if range.start.is_nan() || range.end.is_nan() || range.start >= range.end {
+                Log::err(format!(
+                    "Unable to set loop range {:?} for animation {}, the start must be less than the end!",
+                    range, self.name
+                ));
+                return self;
+            }
+        }
+
+        self.loop_range = range;
+        self.exiting_loop = false;
         self
     }
 
-    /// Returns speed multiplier of the animation.
-    pub fn speed(&self) -> f32 {
-        self.speed
+    /// Returns current inner loop range of the animation. See [`Self::set_loop_range`] docs for more info.
+    pub fn loop_range(&self) -> Option<Range<f32>> {
+        self.loop_range.clone()
     }
 
-    /// Enables or disables looping of the animation.
-    pub fn set_loop(&mut self, state: bool) -> &mut Self {
-        self.looped = state;
+    /// Makes the playhead leave the inner loop range, when it reaches the end of the range next time (or the start of
+    /// the range for reverse playback), so the rest of the animation is played. See [`Self::set_loop_range`] docs for
+    /// more info.
+    pub fn exit_loop(&mut self) -> &mut Self {
+        self.exiting_loop = true;
         self
     }
 
-    /// Returns `true` if the animation is looping, `false` - otherwise.
-    pub fn is_loop(&self) -> bool {
-        self.looped
+    /// Returns `true` if the playhead leaves (or has left) the inner loop range, `false` - otherwise.
+    pub fn is_exiting_loop(&self) -> bool {
+        self.exiting_loop
     }
 
-    /// Returns `true` if the animation was played until the end of current time slice of the animation, `false` -
-    /// otherwise. Looping animations will always return `false`.
-    pub fn has_ended(&self) -> bool {
-        !self.looped && (self.time_position - self.time_slice.end).abs() <= f32::EPSILON
+    /// Returns length of the animation in seconds.
+    pub fn length(&self) -> f32 {
+        self.time_slice.end - self.time_slice.start
     }
 
-    /// Enables or disables the animation, disabled animations does not updated and their output pose will remain
-    /// the same. By default every animation is enabled.
-    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
-        self.enabled = enabled;
-        self
+    /// Performs a single update tick and calculates an output pose. This method is low level, you should not use it
+    /// in normal circumstances - the engine will call it for you.
+    pub fn tick(&mut self, dt: f32) {
+        // Fading of tracks does not depend on playback speed, so it takes the same time even for paused animations.
+        for track in self.tracks.iter_mut() {
+            if track.is_fading() {
+                track.update_fade(dt);
+                self.pose_time = None;
+            }
+        }
+
+        if self.has_ended() && !self.hold_on_end {
+            // Finished animation releases animated properties.
+            self.pose.reset();
+            self.pose_time = None;
+        } else if self.pose_time == Some(self.time_position) {
+            // Nothing has changed since the pose was calculated (for example, the animation is paused), except root
+            // motion, which must not be reported twice.
+            self.pose.set_root_motion(None);
+        } else {
+            self.update_pose();
+        }
+
+        if self.paused {
+            return;
+        }
+
+        let speed = self.playback_speed();
+        let current_time_position = self.time_position();
+
+        self.fire_signals(current_time_position, dt * speed);
+        self.accumulate_root_motion(current_time_position, dt * speed);
+        self.advance_time_position(current_time_position, dt * speed);
+
+        if self.loop_mode == LoopMode::Once {
+            let end = if speed < 0.0 {
+                self.time_slice.start
+            } else {
+                self.time_slice.end
+            };
+
+            let was_ended = self.ended;
+            self.ended = (self.time_position - end).abs() <= f32::EPSILON;
+
+            // The pose is calculated before advancing the time position, make sure that the final frame of the
+            // animation is applied at least once before the pose is released.
+            if self.ended && !was_ended && !self.hold_on_end {
+                self.update_pose();
+            }
+        }
     }
 
-    /// Returns `true` if the animation is enabled, `false` - otherwise.
-    pub fn is_enabled(&self) -> bool {
-        self.enabled
+    /// Emits events for every signal on the playback path that starts at `position` and has the given signed length
+    /// (`delta`). Every signal is fired exactly once per crossing, no matter how long the path is - multiple signals
+    /// could be fired in a single update, if the time delta is large (for example, on low frame rate or after a
+    /// hitch), even if the path goes beyond the time slice of the animation. The same applies to the keys of event
+    /// tracks.
+    fn fire_signals(&mut self, position: f32, delta: f32) {
+        let inner_loop = self.active_loop_range();
+        let signals = &self.signals;
+        let events = &mut self.events;
+        let event_tracks = &self.event_tracks;
+        let track_events = &mut self.track_events;
+        walk_playback_path(
+            &self.time_slice,
+            self.loop_mode,
+            inner_loop.as_ref(),
+            position,
+            delta,
+            |from, to, forward, inclusive| {
+                fire_signals_in_range(signals, events, from, to, forward, inclusive);
+                for event_track in event_tracks.iter() {
+                    event_track.fire_events(from, to, forward, inclusive, track_events);
+                }
+            },
+        );
     }
 
-    /// Returns a mutable reference to the track container.
-    pub fn tracks_mut(&mut self) -> &mut [Track] {
-        &mut self.tracks
+    /// Accumulates the movement of the root node on the playback path that starts at `position` and has the given signed
+    /// length (`delta`). Wrapping of looping animations is taken into account - the movement is accumulated for every part
+    /// of the path separately, so there's no huge "backward" jump on a loop. The same applies to the rotation of the
+    /// root node.
+    fn accumulate_root_motion(&mut self, position: f32, delta: f32) {
+        let settings = if let Some(settings) = self.root_motion_settings.as_ref() {
+            settings
+        } else {
+            return;
+        };
+
+        let mut frame_motion = RootMotion::default();
+        let inner_loop = self.active_loop_range();
+
+        if let Some(track) = self.root_motion_track(&ValueBinding::Position) {
+            let fetch = |time: f32| {
+                track.fetch(time).and_then(|bound_value| {
+                    if let TrackValue::Vector3(position) = bound_value.value {
+                        Some(position)
+                    } else {
+                        None
+                    }
+                })
+            };
+
+            let mut motion = Vector3::default();
+            walk_playback_path(
+                &self.time_slice,
+                self.loop_mode,
+                inner_loop.as_ref(),
+                position,
+                delta,
+                |from, to, _, _| {
+                    if let (Some(from), Some(to)) = (fetch(from), fetch(to)) {
+                        motion += to - from;
+                    }
+                },
+            );
+
+            frame_motion.delta_position = settings.extract(motion);
+            *self.root_motion.get_or_insert_with(Default::default) += frame_motion.delta_position;
+        }
+
+        if settings.extract_rotation != RootMotionRotation::None {
+            if let Some(track) = self.root_motion_track(&ValueBinding::Rotation) {
+                let fetch = |time: f32| fetch_rotation(track, time);
+
+                let mut rotation = UnitQuaternion::identity();
+                walk_playback_path(
+                    &self.time_slice,
+                    self.loop_mode,
+                    inner_loop.as_ref(),
+                    position,
+                    delta,
+                    |from, to, _, _| {
+                        if let (Some(from), Some(to)) = (fetch(from), fetch(to)) {
+                            rotation *= settings.extract_rotation(&from, &to);
+                        }
+                    },
+                );
+
+                frame_motion.delta_rotation = rotation;
+                let accumulated = self
+                    .root_motion_rotation
+                    .get_or_insert_with(UnitQuaternion::identity);
+                *accumulated *= rotation;
+            }
+        }
+
+        // The motion of this update is also stored in the output pose, so it could be blended together with the
+        // pose (for example, by animation blending state machines).
+        self.pose.set_root_motion(Some(frame_motion));
     }
 
-    /// Adds a new animation signal to the animation. See [`AnimationSignal`] docs for more info and examples.
-    pub fn add_signal(&mut self, signal: AnimationSignal) -> &mut Self {
-        self.signals.push(signal);
+    /// Returns an enabled track of the root node (see [`RootMotionSettings`]) with the given binding.
+    fn root_motion_track(&self, binding: &ValueBinding) -> Option<&Track> {
+        let settings = self.root_motion_settings.as_ref()?;
+        let any_solo = self.has_solo_tracks();
+        self.tracks.iter().find(|track| {
+            track.is_audible(any_solo)
+                && track.target() == settings.node
+                && track.binding() == binding
+        })
+    }
+
+    /// Returns `true` if at least one track of the animation is soloed (see [`Track::set_solo`]).
+    pub fn has_solo_tracks(&self) -> bool {
+        self.tracks.iter().any(|track| track.is_solo())
+    }
+
+    /// Extracts a first event from the events queue of the animation. The queue is not limited in size, events stay in it
+    /// until they're extracted, taken (see [`Self::take_events`]) or cleared.
+    pub fn pop_event(&mut self) -> Option<AnimationEvent> {
+        self.events.pop_front()
+    }
+
+    /// Returns a reference to inner events queue. It is useful when you need to iterate over the events, but
+    /// don't extract them from the queue.
+    pub fn events_ref(&self) -> &VecDeque<AnimationEvent> {
+        &self.events
+    }
+
+    /// Return a mutable reference to inner events queue. Provides you a full controls over animation events,
+    /// you can even manually inject events in the queue.
+    pub fn events_mut(&mut self) -> &mut VecDeque<AnimationEvent> {
+        &mut self.events
+    }
+
+    /// Takes the events queue and returns it to the caller, leaving the internal queue empty.
+    pub fn take_events(&mut self) -> VecDeque<AnimationEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Adds a new event track to the animation. See [`EventTrack`] docs for more info.
+    pub fn add_event_track(&mut self, event_track: EventTrack) -> &mut Self {
+        self.event_tracks.push(event_track);
         self
     }
 
-    /// Removes last animation signal from the container of the animation.
-    pub fn pop_signal(&mut self) -> Option<AnimationSignal> {
-        self.signals.pop()
+    /// Removes an event track at given index.
+    pub fn remove_event_track(&mut self, index: usize) -> EventTrack {
+        self.event_tracks.remove(index)
     }
 
-    /// Inserts a new animation signal at given position.
-    pub fn insert_signal(&mut self, index: usize, signal: AnimationSignal) {
-        self.signals.insert(index, signal)
+    /// Returns a reference to the event tracks of the animation.
+    pub fn event_tracks(&self) -> &[EventTrack] {
+        &self.event_tracks
     }
 
-    /// Removes an animation signal at given index.
-    pub fn remove_signal(&mut self, index: usize) -> AnimationSignal {
-        self.signals.remove(index)
+    /// Returns a mutable reference to the event tracks of the animation.
+    pub fn event_tracks_mut(&mut self) -> &mut [EventTrack] {
+        &mut self.event_tracks
     }
 
-    /// Returns a reference to the animation signals container.
-    pub fn signals(&self) -> &[AnimationSignal] {
-        &self.signals
+    /// Tries to find an event track by its name.
+    pub fn find_event_track_by_name_ref<S: AsRef<str>>(&self, name: S) -> Option<&EventTrack> {
+        self.event_tracks
+            .iter()
+            .find(|event_track| event_track.name() == name.as_ref())
     }
 
-    /// Returns a mutable reference to the inner animation signals container, allowing you to modify the signals.
-    pub fn signals_mut(&mut self) -> &mut [AnimationSignal] {
-        &mut self.signals
+    /// Extracts a first event from the queue of events emitted by the event tracks of the animation.
+    pub fn pop_track_event(&mut self) -> Option<TrackEvent> {
+        self.track_events.pop_front()
     }
 
-    /// Removes all tracks from the animation for which the given `filter` closure returns `false`. Could be useful
-    /// to remove undesired animation tracks.
-    pub fn retain_tracks<F>(&mut self, filter: F)
-    where
-        F: FnMut(&Track) -> bool,
-    {
-        self.tracks.retain(filter)
+    /// Returns a reference to the queue of events emitted by the event tracks of the animation.
+    pub fn track_events_ref(&self) -> &VecDeque<TrackEvent> {
+        &self.track_events
     }
 
-    /// Enables or disables animation tracks for nodes in hierarchy starting from given root. Could be useful to enable
-    /// or disable animation for skeleton parts, i.e. you don't want legs to be animated and you know that legs starts
-    /// from torso bone, then you could do this.
+    /// Takes the queue of events emitted by the event tracks and returns it to the caller, leaving the internal queue
+    /// empty.
+    pub fn take_track_events(&mut self) -> VecDeque<TrackEvent> {
+        std::mem::take(&mut self.track_events)
+    }
+
+    /// Returns current time position of the animation. The time position is guaranteed to be in the range of
+    /// current time slice of the animation.
+    pub fn time_position(&self) -> f32 {
+        self.time_position
+    }
+
+    /// Returns current playback position of the animation, normalized to `0..1` range across its time slice. `0.0`
+    /// is the start of the time slice and `1.0` is its end, animations with an empty time slice always return `0.0`.
+    /// Playback position of looping animations wraps, so normalized time goes from `0.0` to `1.0` on each loop.
     ///
-    /// ```
-    /// use fyrox::scene::node::Node;
+    /// # Example
+    ///
+    /// ```rust
     /// use fyrox::animation::Animation;
-    /// use fyrox::core::pool::Handle;
-    /// use fyrox::scene::graph::Graph;
     ///
-    /// fn disable_legs(torso_bone: Handle<Node>, aim_animation: &mut Animation, graph: &Graph) {
-    ///     aim_animation.set_tracks_enabled_from(torso_bone, false, graph)
+    /// fn progress_bar_text(animation: &Animation) -> String {
+    ///     format!("{:.0}%", animation.normalized_time() * 100.0)
     /// }
     /// ```
-    ///
-    /// After this legs won't be animated and animation could be blended together with run animation so it will produce
-    /// new animation - run and aim.
-    pub fn set_tracks_enabled_from(&mut self, handle: Handle<Node>, enabled: bool, graph: &Graph) {
-        let mut stack = vec![handle];
-        while let Some(node) = stack.pop() {
-            for track in self.tracks.iter_mut() {
-                if track.target() == node {
-                    track.set_enabled(enabled);
-                }
-            }
-            for child in graph[node].children() {
-                stack.push(*child);
-            }
+    pub fn normalized_time(&self) -> f32 {
+        let length = self.length();
+        if length > 0.0 {
+            ((self.time_position - self.time_slice.start) / length).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Sets new playback position of the animation using normalized time (see [`Self::normalized_time`]). It is a
+    /// shortcut for [`Self::set_time_position`], so the same rules of wrapping/clamping apply. It could be used to
+    /// scrub the animation using a slider in `0..1` range.
+    pub fn set_normalized_time(&mut self, normalized_time: f32) -> &mut Self {
+        self.set_time_position(self.time_slice.start + normalized_time * self.length())
+    }
+
+    /// Returns the amount of time (in seconds of the time line of the animation, regardless of its speed) that is
+    /// left until the end of non-looping animations. Playback direction is taken into account, the end of animations
+    /// played in reverse is the start of their time slice. Looping animations never end, so the method returns
+    /// `None` for them.
+    pub fn time_remaining(&self) -> Option<f32> {
+        if self.loop_mode == LoopMode::Once {
+            Some(if self.playback_speed() < 0.0 {
+                self.time_position - self.time_slice.start
+            } else {
+                self.time_slice.end - self.time_position
+            })
+        } else {
+            None
         }
     }
 
-    /// Tries to find all tracks that refer to a given node and enables or disables them.
-    pub fn set_node_track_enabled(&mut self, handle: Handle<Node>, enabled: bool) {
-        for track in self.tracks.iter_mut() {
-            if track.target() == handle {
-                track.set_enabled(enabled);
-            }
+    /// Moves the playback position of the animation to the given time (clamped to the time slice of the animation) and
+    /// immediately calculates the output pose at the new position, without advancing the animation by a time delta.
+    /// Unlike [`Self::set_time_position`], the method emits events for every enabled signal between the old and the new
+    /// positions. It is useful for timeline scrubbing in tools, replays and networked synchronization of animations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{animation::Animation, scene::graph::Graph};
+    ///
+    /// fn scrub(animation: &mut Animation, time: f32, graph: &mut Graph) {
+    ///     animation.set_time(time);
+    ///     animation.pose().apply(graph);
+    /// }
+    /// ```
+    pub fn set_time(&mut self, time: f32) -> &mut Self {
+        let time = time.clamp(self.time_slice.start, self.time_slice.end);
+        let forward = time >= self.time_position;
+        let bound = if forward {
+            self.time_slice.end
+        } else {
+            self.time_slice.start
+        };
+
+        fire_signals_in_range(
+            &self.signals,
+            &mut self.events,
+            self.time_position,
+            time,
+            forward,
+            time == bound && time != self.time_position,
+        );
+
+        self.time_position = time;
+        self.ended = false;
+        self.update_pose();
+
+        self
+    }
+
+    /// Returns current time position of the animation. It is a shortcut for [`Self::time_position`].
+    pub fn time(&self) -> f32 {
+        self.time_position
+    }
+
+    /// Sets new speed multiplier for the animation. By default it is set to 1.0. Negative values can be used
+    /// to play the animation in reverse. The multiplier scales the time delta passed to [`Self::tick`], so each
+    /// animation in a container can be played at its own rate. Looping animations with negative speed wrap
+    /// at the beginning of their time slice.
+    pub fn set_speed(&mut self, speed: f32) -> &mut Self {
+        if self.speed != speed {
+            self.speed = speed;
+            self.invalidate_pose();
+        }
+        self
+    }
+
+    /// Returns speed multiplier of the animation.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Sets new runtime speed scale of the animation. It is an additional multiplier of the speed (see
+    /// [`Self::set_speed`]), that is not serialized and is intended to be used by animation blending state machines
+    /// to apply speed multipliers of their states (see [`crate::animation::machine::State::speed`]). Negative values
+    /// are clamped to zero. Default value is 1.0.
+    pub fn set_speed_scale(&mut self, speed_scale: f32) -> &mut Self {
+        let speed_scale = speed_scale.max(0.0);
+        if self.speed_scale != speed_scale {
+            self.speed_scale = speed_scale;
+            self.invalidate_pose();
+        }
+        self
+    }
+
+    /// Returns current runtime speed scale of the animation.
+    pub fn speed_scale(&self) -> f32 {
+        self.speed_scale
+    }
+
+    /// Sets new playback direction of the animation. It flips the direction in which the playback position
+    /// advances without the need to use negative speed (see [`Self::set_speed`]), both settings are combined, so
+    /// backward direction with speed 2.0 plays the animation in reverse twice as fast (and backward direction with
+    /// negative speed plays the animation forward). When played backwards, looping animations wrap from the start of
+    /// their time slice to its end, signals are fired in reverse order and the "end" of non-looping animations is the
+    /// start of the time slice (see [`Self::has_ended`]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, PlaybackDirection};
+    ///
+    /// fn rewind_effect(animation: &mut Animation) {
+    ///     animation
+    ///         .set_direction(PlaybackDirection::Backward)
+    ///         .set_speed(2.0);
+    /// }
+    /// ```
+    pub fn set_direction(&mut self, direction: PlaybackDirection) -> &mut Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns current playback direction of the animation.
+    pub fn direction(&self) -> PlaybackDirection {
+        self.direction
+    }
+
+    /// Enables or disables looping of the animation. It is a shortcut for [`Self::set_loop_mode`] with either
+    /// [`LoopMode::Loop`] or [`LoopMode::Once`].
+    pub fn set_loop(&mut self, state: bool) -> &mut Self {
+        self.set_loop_mode(if state {
+            LoopMode::Loop
+        } else {
+            LoopMode::Once
+        })
+    }
+
+    /// Returns `true` if the animation is looping (in either [`LoopMode::Loop`] or [`LoopMode::PingPong`] modes),
+    /// `false` - otherwise.
+    pub fn is_loop(&self) -> bool {
+        self.loop_mode != LoopMode::Once
+    }
+
+    /// Sets new loop mode of the animation. See [`LoopMode`] docs for more info.
+    pub fn set_loop_mode(&mut self, loop_mode: LoopMode) -> &mut Self {
+        self.loop_mode = loop_mode;
+        self.ping_pong_reversed = false;
+        self
+    }
+
+    /// Returns current loop mode of the animation.
+    pub fn loop_mode(&self) -> LoopMode {
+        self.loop_mode
+    }
+
+    /// Returns `true` if the animation was played until the end of current time slice of the animation, `false` -
+    /// otherwise. Animations with negative speed are played in reverse, so their "end" is the beginning of the time
+    /// slice. Looping animations will always return `false`. The flag is latched when a non-looping animation reaches
+    /// its end during an update and it is reset when the animation is re-enabled or its time position is changed (for
+    /// example, by [`Self::rewind`]). It could be used to chain animations:
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{Animation, AnimationContainer},
+    ///     core::pool::Handle,
+    /// };
+    ///
+    /// fn play_after(
+    ///     animations: &mut AnimationContainer,
+    ///     first: Handle<Animation>,
+    ///     second: Handle<Animation>,
+    /// ) {
+    ///     if animations[first].has_ended() {
+    ///         animations[first].set_enabled(false);
+    ///         animations[second].rewind().set_enabled(true);
+    ///     }
+    /// }
+    /// ```
+    pub fn has_ended(&self) -> bool {
+        self.loop_mode == LoopMode::Once && self.ended
+    }
+
+    /// Enables or disables the animation, disabled animations does not updated and their output pose will remain
+    /// the same. By default every animation is enabled.
+    pub fn set_enabled(&mut self, enabled: bool) -> &mut Self {
+        if enabled && !self.enabled {
+            self.ended = false;
+        }
+        self.enabled = enabled;
+        self
+    }
+
+    /// Returns `true` if the animation is enabled, `false` - otherwise.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Captures current playback state of the animation. The state could be restored later using [`Self::from_state`].
+    /// Update of animations is fully deterministic - the same state, the same animation data and the same sequence of
+    /// time deltas always produce bit-identical results (poses, events, root motion). It makes the pair of methods
+    /// suitable for rollback and lockstep networking: save the state of each animation every tick and restore it
+    /// instead of re-simulating the animation from the beginning.
+    ///
+    /// Keep in mind, that the state of cross-fades of [`AnimationContainer`] is not included in the state.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, PlaybackState};
+    ///
+    /// fn rollback(animation: &mut Animation, history: &[PlaybackState], ticks_back: usize) {
+    ///     if let Some(state) = history.iter().rev().nth(ticks_back) {
+    ///         animation.from_state(state);
+    ///     }
+    /// }
+    /// ```
+    pub fn to_state(&self) -> PlaybackState {
+        PlaybackState {
+            time_position: self.time_position,
+            direction: self.direction,
+            ping_pong_reversed: self.ping_pong_reversed,
+            exiting_loop: self.exiting_loop,
+            weight: self.weight,
+            enabled: self.enabled,
+            paused: self.paused,
+            ended: self.ended,
+            root_motion: self.root_motion,
+            root_motion_rotation: self.root_motion_rotation,
+        }
+    }
+
+    /// Restores playback state of the animation, that was previously captured by [`Self::to_state`]. Pending events
+    /// of the animation are discarded, since they belong to the "future" of the restored state. The output pose of
+    /// the animation will be updated on the next update of the animation.
+    pub fn from_state(&mut self, state: &PlaybackState) -> &mut Self {
+        self.time_position = state.time_position;
+        self.direction = state.direction;
+        self.ping_pong_reversed = state.ping_pong_reversed;
+        self.exiting_loop = state.exiting_loop;
+        self.weight = state.weight;
+        self.enabled = state.enabled;
+        self.paused = state.paused;
+        self.ended = state.ended;
+        self.root_motion = state.root_motion;
+        self.root_motion_rotation = state.root_motion_rotation;
+        self.events.clear();
+        self.track_events.clear();
+        self
+    }
+
+    /// Pauses the animation. Unlike disabled animations (see [`Self::set_enabled`]), paused animations are still
+    /// updated and their output pose is still applied, but their playback position does not advance, no signals are
+    /// fired and no root motion is accumulated. Use [`Self::resume`] to continue playback from the exact time position
+    /// at which the animation was paused. Pausing and resuming does not change the enabled state of the animation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn toggle_pause(animation: &mut Animation) {
+    ///     if animation.is_paused() {
+    ///         animation.resume();
+    ///     } else {
+    ///         animation.pause();
+    ///     }
+    /// }
+    /// ```
+    pub fn pause(&mut self) -> &mut Self {
+        self.paused = true;
+        self
+    }
+
+    /// Resumes playback of a paused animation. See [`Self::pause`] docs for more info.
+    pub fn resume(&mut self) -> &mut Self {
+        self.paused = false;
+        self
+    }
+
+    /// Returns `true` if the animation is paused, `false` - otherwise. See [`Self::pause`] docs for more info.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Sets new weight of the animation, negative values are clamped to zero. The weight is used by
+    /// [`AnimationContainer`] to blend poses of multiple enabled animations that animate the same properties: the
+    /// contributions are averaged using the weights and if the total weight of a property is less than one, the result is
+    /// blended with the current value of the property. It is a lightweight alternative to animation blending state
+    /// machine (see [`machine::Machine`]) for simple cases. Default weight is 1.0.
+    pub fn set_weight(&mut self, weight: f32) -> &mut Self {
+        self.weight = weight.max(0.0);
+        self
+    }
+
+    /// Returns current weight of the animation.
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Returns a mutable reference to the track container.
+    pub fn tracks_mut(&mut self) -> &mut [Track] {
+        self.invalidate_pose();
+        &mut self.tracks
+    }
+
+    /// Creates a new instance of the animation. The instance shares curve data of the tracks with the animation (see
+    /// [`Track`] docs), while it has its own playback state (time position, speed, weight, etc.) which is reset to the
+    /// beginning of the animation. It allows you to keep a single "clip" (for example, from an animation player of a
+    /// model resource) and to play it on any number of characters without duplicating its curves in memory. Targets of
+    /// the tracks of the instance could be changed freely (see [`Self::clone_with_remap`]), only changes of the curves (via
+    /// [`Track::data_container_mut`]) make a copy of the data of the respective track.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, AnimationContainer};
+    ///
+    /// fn add_instances(clip: &Animation, players: &mut [AnimationContainer]) {
+    ///     for animations in players {
+    ///         let mut instance = clip.instantiate();
+    ///         instance.set_speed(1.5);
+    ///         assert!(instance.shares_track_data_with(clip));
+    ///         animations.add(instance);
+    ///     }
+    /// }
+    /// ```
+    pub fn instantiate(&self) -> Animation {
+        let mut instance = self.clone();
+        instance.ended = false;
+        instance.rewind();
+        instance
+    }
+
+    /// Returns `true` if every track of the animation shares its curve data with the respective track of the given
+    /// animation (see [`Self::instantiate`]), `false` - otherwise.
+    pub fn shares_track_data_with(&self, other: &Animation) -> bool {
+        self.tracks.len() == other.tracks.len()
+            && self
+                .tracks
+                .iter()
+                .zip(other.tracks.iter())
+                .all(|(a, b)| a.shares_data_with(b))
+    }
+
+    /// Adds a new animation signal to the animation. See [`AnimationSignal`] docs for more info and examples.
+    pub fn add_signal(&mut self, signal: AnimationSignal) -> &mut Self {
+        self.signals.push(signal);
+        self
+    }
+
+    /// Removes last animation signal from the container of the animation.
+    pub fn pop_signal(&mut self) -> Option<AnimationSignal> {
+        self.signals.pop()
+    }
+
+    /// Inserts a new animation signal at given position.
+    pub fn insert_signal(&mut self, index: usize, signal: AnimationSignal) {
+        self.signals.insert(index, signal)
+    }
+
+    /// Removes an animation signal at given index.
+    pub fn remove_signal(&mut self, index: usize) -> AnimationSignal {
+        self.signals.remove(index)
+    }
+
+    /// Returns a reference to the animation signals container.
+    pub fn signals(&self) -> &[AnimationSignal] {
+        &self.signals
+    }
+
+    /// Returns a mutable reference to the inner animation signals container, allowing you to modify the signals.
+    pub fn signals_mut(&mut self) -> &mut [AnimationSignal] {
+        &mut self.signals
+    }
+
+    /// Creates a copy of the animation, whose tracks point at other nodes. Target of each track is remapped using the
+    /// given `map` (from old handles to new ones), the same applies to the animation mask and root motion settings.
+    /// Targets that have no mapping are either kept as is, or dropped (if `drop_unmapped` is `true`) - in this case
+    /// respective tracks and mask entries are removed and root motion node is set to [`Handle::NONE`].
+    ///
+    /// The method is useful when a hierarchy of nodes is copied together with an animation player - the map produced
+    /// by [`Graph::copy_node`] (or [`Graph::copy_node_inplace`]) could be used to make copied animations drive copied
+    /// nodes. Use [`crate::animation::retarget::retarget`] if you need to map nodes by names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::Animation,
+    ///     core::pool::Handle,
+    ///     scene::{graph::Graph, node::Node},
+    /// };
+    ///
+    /// fn copy_with_animation(
+    ///     graph: &mut Graph,
+    ///     root: Handle<Node>,
+    ///     animation: &Animation,
+    /// ) -> (Handle<Node>, Animation) {
+    ///     let (copy, map) = graph.copy_node_inplace(root, &mut |_, _| true);
+    ///     (copy, animation.clone_with_remap(map.inner(), true))
+    /// }
+    /// ```
+    pub fn clone_with_remap<S: BuildHasher>(
+        &self,
+        map: &HashMap<Handle<Node>, Handle<Node>, S>,
+        drop_unmapped: bool,
+    ) -> Animation {
+        let remap = |handle: Handle<Node>| match map.get(&handle) {
+            Some(new_handle) => Some(*new_handle),
+            None if drop_unmapped => None,
+            None => Some(handle),
+        };
+
+        let mut copy = self.clone();
+
+        copy.tracks.retain(|track| remap(track.target()).is_some());
+        for track in copy.tracks.iter_mut() {
+            if let Some(target) = remap(track.target()) {
+                track.set_target(target);
+            }
+        }
+
+        if let Some(mask) = self.mask.as_ref() {
+            copy.mask = Some(mask.remap(&remap));
+        }
+
+        if let Some(settings) = copy.root_motion_settings.as_mut() {
+            settings.node = remap(settings.node).unwrap_or_default();
+        }
+
+        copy
+    }
+
+    /// Removes all tracks from the animation for which the given `filter` closure returns `false`. Could be useful
+    /// to remove undesired animation tracks.
+    pub fn retain_tracks<F>(&mut self, filter: F)
+    where
+        F: FnMut(&Track) -> bool,
+    {
+        self.invalidate_pose();
+        self.tracks.retain(filter)
+    }
+
+    /// Enables or disables animation tracks for nodes in hierarchy starting from given root. Could be useful to enable
+    /// or disable animation for skeleton parts, i.e. you don't want legs to be animated and you know that legs starts
+    /// from torso bone, then you could do this.
+    ///
+    /// ```
+    /// use fyrox::scene::node::Node;
+    /// use fyrox::animation::Animation;
+    /// use fyrox::core::pool::Handle;
+    /// use fyrox::scene::graph::Graph;
+    ///
+    /// fn disable_legs(torso_bone: Handle<Node>, aim_animation: &mut Animation, graph: &Graph) {
+    ///     aim_animation.set_tracks_enabled_from(torso_bone, false, graph)
+    /// }
+    /// ```
+    ///
+    /// After this legs won't be animated and animation could be blended together with run animation so it will produce
+    /// new animation - run and aim.
+    pub fn set_tracks_enabled_from(&mut self, handle: Handle<Node>, enabled: bool, graph: &Graph) {
+        self.invalidate_pose();
+        let mut stack = vec![handle];
+        while let Some(node) = stack.pop() {
+            for track in self.tracks.iter_mut() {
+                if track.target() == node {
+                    track.set_enabled(enabled);
+                }
+            }
+            for child in graph[node].children() {
+                stack.push(*child);
+            }
+        }
+    }
+
+    /// Tries to find all tracks that refer to a given node and enables or disables them.
+    pub fn set_node_track_enabled(&mut self, handle: Handle<Node>, enabled: bool) {
+        self.invalidate_pose();
+        for track in self.tracks.iter_mut() {
+            if track.target() == handle {
+                track.set_enabled(enabled);
+            }
+        }
+    }
+
+    /// Returns an iterator that yields a number of references to tracks that refer to a given node.
+    pub fn tracks_of(&self, handle: Handle<Node>) -> impl Iterator<Item = &Track> {
+        self.tracks
+            .iter()
+            .filter(move |track| track.target() == handle)
+    }
+
+    /// Returns an iterator that yields a number of references to tracks that refer to a given node.
+    pub fn tracks_of_mut(&mut self, handle: Handle<Node>) -> impl Iterator<Item = &mut Track> {
+        self.invalidate_pose();
+        self.tracks
+            .iter_mut()
+            .filter(move |track| track.target() == handle)
+    }
+
+    /// Returns names of every track group of the animation, in order of first appearance. See [`Track::set_group`]
+    /// for more info.
+    pub fn track_groups(&self) -> Vec<&str> {
+        let mut groups = Vec::new();
+        for group in self.tracks.iter().filter_map(|track| track.group()) {
+            if !groups.contains(&group) {
+                groups.push(group);
+            }
+        }
+        groups
+    }
+
+    /// Returns an iterator that yields references to the tracks of the given group.
+    pub fn tracks_of_group<'a>(&'a self, group: &'a str) -> impl Iterator<Item = &'a Track> {
+        self.tracks
+            .iter()
+            .filter(move |track| track.is_in_group(group))
+    }
+
+    /// Returns an iterator that yields mutable references to the tracks of the given group.
+    pub fn tracks_of_group_mut<'a>(
+        &'a mut self,
+        group: &'a str,
+    ) -> impl Iterator<Item = &'a mut Track> {
+        self.invalidate_pose();
+        self.tracks
+            .iter_mut()
+            .filter(move |track| track.is_in_group(group))
+    }
+
+    /// Enables or disables every track of the given group. See [`Track::set_enabled`] for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn disable_facial_animation(animation: &mut Animation) {
+    ///     animation.set_group_enabled("Face", false);
+    /// }
+    /// ```
+    pub fn set_group_enabled(&mut self, group: &str, enabled: bool) {
+        for track in self.tracks_of_group_mut(group) {
+            track.set_enabled(enabled);
+        }
+    }
+
+    /// Mutes or unmutes every track of the given group. See [`Track::set_mute`] for more info.
+    pub fn set_group_mute(&mut self, group: &str, muted: bool) {
+        for track in self.tracks_of_group_mut(group) {
+            track.set_mute(muted);
+        }
+    }
+
+    /// Sets or clears the solo flag of every track of the given group. See [`Track::set_solo`] for more info.
+    pub fn set_group_solo(&mut self, group: &str, solo: bool) {
+        for track in self.tracks_of_group_mut(group) {
+            track.set_solo(solo);
+        }
+    }
+
+    /// Tries to find a layer by its name. Returns index of the signal and its reference.
+    #[inline]
+    pub fn find_signal_by_name_ref<S: AsRef<str>>(
+        &self,
+        name: S,
+    ) -> Option<(usize, &AnimationSignal)> {
+        utils::find_by_name_ref(self.signals.iter().enumerate(), name)
+    }
+
+    /// Tries to find a signal by its name. Returns index of the signal and its reference.
+    #[inline]
+    pub fn find_signal_by_name_mut<S: AsRef<str>>(
+        &mut self,
+        name: S,
+    ) -> Option<(usize, &mut AnimationSignal)> {
+        utils::find_by_name_mut(self.signals.iter_mut().enumerate(), name)
+    }
+
+    /// Returns `true` if there's a signal with given name and id.
+    #[inline]
+    pub fn has_signal<S: AsRef<str>>(&self, name: S, id: Uuid) -> bool {
+        self.find_signal_by_name_ref(name)
+            .map_or(false, |(_, s)| s.id == id)
+    }
+
+    /// Removes all tracks from the animation.
+    pub fn remove_tracks(&mut self) {
+        self.invalidate_pose();
+        self.tracks.clear();
+    }
+
+    /// Sets new animation mask and returns the previous one. Only tracks that animate nodes from the mask will
+    /// contribute to the output pose, `None` means that every track of the animation is used. See [`AnimationMask`]
+    /// docs for more info.
+    pub fn set_mask(&mut self, mask: Option<AnimationMask>) -> Option<AnimationMask> {
+        self.invalidate_pose();
+        std::mem::replace(&mut self.mask, mask)
+    }
+
+    /// Returns a reference to current animation mask (if any).
+    pub fn mask(&self) -> Option<&AnimationMask> {
+        self.mask.as_ref()
+    }
+
+    /// Sets new blend mode of the animation. See [`AnimationBlendMode`] docs for more info.
+    pub fn set_blend_mode(&mut self, blend_mode: AnimationBlendMode) -> &mut Self {
+        self.blend_mode = blend_mode;
+        self.invalidate_pose();
+        self
+    }
+
+    /// Returns current blend mode of the animation.
+    pub fn blend_mode(&self) -> AnimationBlendMode {
+        self.blend_mode
+    }
+
+    /// Sets a new sync group of the animation. Animations of the same sync group in a container share normalized
+    /// playback phase (in `0..1` range) instead of absolute time. The animation with the highest weight in a group
+    /// (taking current cross-fade into account) is the leader of the group, its phase is used to drive other animations
+    /// of the group - their playback speed is scaled, so they complete a cycle together with the leader. It is useful to
+    /// keep locomotion animations (walk, run, etc.) of different length phase-aligned, so foot contacts will match when
+    /// the animations are blended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// const LOCOMOTION: u32 = 0;
+    ///
+    /// fn sync_locomotion(walk: &mut Animation, run: &mut Animation) {
+    ///     walk.set_sync_group(Some(LOCOMOTION));
+    ///     run.set_sync_group(Some(LOCOMOTION));
+    /// }
+    /// ```
+    pub fn set_sync_group(&mut self, sync_group: Option<SyncGroupId>) -> &mut Self {
+        self.sync_group = sync_group;
+        self
+    }
+
+    /// Returns current sync group of the animation (if any). See [`Self::set_sync_group`] docs for more info.
+    pub fn sync_group(&self) -> Option<SyncGroupId> {
+        self.sync_group
+    }
+
+    /// Defines whether a finished animation (see [`Self::has_ended`]) keeps applying the pose of its final frame or
+    /// not. When enabled (default), the animated properties stay at their final values until the animation is
+    /// disabled, rewound or played again - it is the expected behavior of one-shot animations like opening of a door.
+    /// When disabled, the output pose of the animation becomes empty right after the final frame was applied, so the
+    /// animated properties are released and could be controlled by other animations or by game code. Has no effect
+    /// on looping animations, since they never end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, LoopMode};
+    ///
+    /// fn setup_hit_reaction(animation: &mut Animation) {
+    ///     animation.set_loop_mode(LoopMode::Once).set_hold_on_end(false);
+    /// }
+    /// ```
+    pub fn set_hold_on_end(&mut self, hold: bool) -> &mut Self {
+        self.hold_on_end = hold;
+        self.invalidate_pose();
+        self
+    }
+
+    /// Returns `true` if the animation keeps applying its final pose after it has ended, `false` - otherwise. See
+    /// [`Self::set_hold_on_end`] docs for more info.
+    pub fn is_hold_on_end(&self) -> bool {
+        self.hold_on_end
+    }
+
+    /// Forces the animation player to apply the pose of the animation, even if automatic pose applying of the player is
+    /// disabled (see [`crate::scene::animation::AnimationPlayer::set_auto_apply`]). By default, it is `false` and the
+    /// poses of animations are applied only if the player applies them automatically. Keep in mind, that animation
+    /// blending state machines disable automatic pose applying of their animation players, so it allows you to have
+    /// animations that are applied directly (for example, facial animations) together with animations that are consumed
+    /// by a state machine (for example, body animations) in the same animation player. Opted-in animations are blended
+    /// together exactly the same way as in automatic pose applying - using their weights and masks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{Animation, AnimationContainer},
+    ///     core::pool::Handle,
+    /// };
+    ///
+    /// fn setup_face(animations: &mut AnimationContainer, blink: Handle<Animation>) {
+    ///     animations[blink].set_auto_apply(true);
+    /// }
+    /// ```
+    pub fn set_auto_apply(&mut self, auto_apply: bool) -> &mut Self {
+        self.auto_apply = auto_apply;
+        self
+    }
+
+    /// Returns `true` if the pose of the animation is always applied by its animation player, `false` - otherwise. See
+    /// [`Self::set_auto_apply`] docs for more info.
+    pub fn is_auto_apply(&self) -> bool {
+        self.auto_apply
+    }
+
+    /// Sets a time position (in seconds) of the reference pose for [`AnimationBlendMode::Additive`] blend mode. The
+    /// output pose of an additive animation is a difference between the pose at current time position and the pose at
+    /// the reference time position. By default the reference pose is the first frame of the time slice.
+    pub fn set_additive_base_time(&mut self, time: f32) -> &mut Self {
+        self.additive_base_time = Some(time);
+        self.invalidate_pose();
+        self
+    }
+
+    /// Returns a time position of the reference pose for [`AnimationBlendMode::Additive`] blend mode.
+    pub fn additive_base_time(&self) -> f32 {
+        self.additive_base_time.unwrap_or(self.time_slice.start)
+    }
+
+    /// Sets a fixed reference pose (usually, the bind pose of a skeleton) for [`AnimationBlendMode::Additive`] blend
+    /// mode. If set, the output pose of an additive animation is a difference between the pose at current time
+    /// position and the reference pose, instead of the pose of the animation at its additive base time (see
+    /// [`Self::set_additive_base_time`]). Values, that have no pair in the reference pose, are still calculated
+    /// relative to the additive base time. Deltas are then composed over the poses of other animations by the
+    /// animation container. `None` means that the additive base time is used.
+    ///
+    /// The reference pose is not saved to RON strings (see [`Self::save_to_ron`]), because it depends on the scene. Use
+    /// [`Self::capture_reference_pose`] to make a reference pose from the current state of scene nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{Animation, AnimationBlendMode},
+    ///     scene::graph::Graph,
+    /// };
+    ///
+    /// // Must be called while the skeleton is in its bind pose (for example, right after it was instantiated).
+    /// fn make_additive(lean: &mut Animation, graph: &Graph) {
+    ///     let bind_pose = lean.capture_reference_pose(graph);
+    ///     lean.set_blend_mode(AnimationBlendMode::Additive)
+    ///         .set_additive_reference_pose(Some(bind_pose));
+    /// }
+    /// ```
+    pub fn set_additive_reference_pose(&mut self, pose: Option<AnimationPose>) -> &mut Self {
+        self.additive_reference_pose = pose;
+        self.invalidate_pose();
+        self
+    }
+
+    /// Returns a reference to the reference pose for [`AnimationBlendMode::Additive`] blend mode (if any).
+    pub fn additive_reference_pose(&self) -> Option<&AnimationPose> {
+        self.additive_reference_pose.as_ref()
+    }
+
+    /// Captures current local transforms (only the parts, that are animated by the tracks of the animation) of the
+    /// nodes animated by the animation. Nodes that do not exist in the graph are ignored. The pose could then be used
+    /// as a reference pose for additive blending, see [`Self::set_additive_reference_pose`] docs for more info.
+    pub fn capture_reference_pose(&self, graph: &Graph) -> AnimationPose {
+        let mut pose = AnimationPose::default();
+        for track in self.tracks.iter() {
+            let node = if let Some(node) = graph.try_get(track.target()) {
+                node
+            } else {
+                continue;
+            };
+
+            let transform = node.local_transform();
+            let value = match track.binding() {
+                ValueBinding::Position => TrackValue::Vector3(**transform.position()),
+                ValueBinding::Scale => TrackValue::Vector3(**transform.scale()),
+                ValueBinding::Rotation => TrackValue::UnitQuaternion(**transform.rotation()),
+                ValueBinding::Property { .. } | ValueBinding::BlendShape { .. } => continue,
+            };
+
+            pose.add_to_node_pose(
+                track.target(),
+                BoundValue {
+                    binding: track.binding().clone(),
+                    value,
+                    ..Default::default()
+                },
+            );
+        }
+        pose
+    }
+
+    /// Returns a reference value for additive blending of the given value of the track.
+    fn additive_reference(
+        &self,
+        track: &Track,
+        bound_value: &BoundValue,
+        additive_base_time: f32,
+    ) -> Option<TrackValue> {
+        self.additive_reference_pose
+            .as_ref()
+            .and_then(|pose| pose.poses().get(&track.target()))
+            .and_then(|node_pose| {
+                node_pose
+                    .values
+                    .values
+                    .iter()
+                    .find(|value| value.binding == bound_value.binding)
+            })
+            .map(|reference| reference.value.clone())
+            .or_else(|| {
+                track
+                    .fetch(additive_base_time)
+                    .map(|reference| reference.value)
+            })
+    }
+
+    /// Sets new root motion settings of the animation. `None` disables root motion extraction. See [`RootMotionSettings`]
+    /// docs for more info.
+    pub fn set_root_motion_settings(&mut self, settings: Option<RootMotionSettings>) -> &mut Self {
+        self.root_motion_settings = settings;
+        self.root_motion = None;
+        self.root_motion_rotation = None;
+        self.invalidate_pose();
+        self
+    }
+
+    /// Returns a reference to current root motion settings (if any).
+    pub fn root_motion_settings(&self) -> Option<&RootMotionSettings> {
+        self.root_motion_settings.as_ref()
+    }
+
+    /// Takes the movement of the root node accumulated since the last call of the method. Returns `None` if root motion
+    /// extraction is disabled or there was no update of the animation since the last call. See [`RootMotionSettings`] docs
+    /// for more info.
+    pub fn pop_root_motion(&mut self) -> Option<Vector3<f32>> {
+        self.root_motion.take()
+    }
+
+    /// Takes the rotation of the root node accumulated since the last call of the method. Returns `None` if root motion
+    /// rotation extraction is disabled (see [`RootMotionSettings::extract_rotation`]) or there was no update of the
+    /// animation since the last call.
+    pub fn pop_root_motion_rotation(&mut self) -> Option<UnitQuaternion<f32>> {
+        self.root_motion_rotation.take()
+    }
+
+    /// Forces the animation to recalculate its output pose on next update. The pose is cached and it is recalculated
+    /// only if the time position of the animation has changed or the animation was modified using its methods, which
+    /// saves a lot of time for paused or frozen animations. Mutable borrowing of the animation from its container (see
+    /// [`AnimationContainer::get_mut`]) invalidates the pose too, so the edits of the editor are always visible. Call
+    /// this method if the animation was modified in some other way (for example, using reflection).
+    pub fn invalidate_pose(&mut self) {
+        self.pose_time = None;
+    }
+
+    fn update_pose(&mut self) {
+        self.pose_time = Some(self.time_position);
+        self.pose.reset();
+        let additive_base_time = self.additive_base_time();
+        let any_solo = self.has_solo_tracks();
+        for track in self.tracks.iter() {
+            let mask_weight = self
+                .mask
+                .as_ref()
+                .map_or(1.0, |mask| mask.weight(track.target()));
+            if track.is_audible(any_solo) && mask_weight > 0.0 {
+                if let Some(mut bound_value) = track.fetch(self.time_position) {
+                    bound_value.weight *= mask_weight;
+
+                    if self.blend_mode == AnimationBlendMode::Additive
+                        && !matches!(
+                            bound_value.binding,
+                            ValueBinding::Property { .. } | ValueBinding::BlendShape { .. }
+                        )
+                    {
+                        if let Some(delta) = self
+                            .additive_reference(track, &bound_value, additive_base_time)
+                            .and_then(|reference| bound_value.value.relative_to(&reference))
+                        {
+                            bound_value.value = delta;
+                        }
+                    }
+
+                    self.pose.add_to_node_pose(track.target(), bound_value);
+                }
+            }
+        }
+
+        // Extracted movement of the root node must not be applied to the node itself.
+        if let Some(settings) = self.root_motion_settings.as_ref() {
+            let reference_rotation = if settings.extract_rotation == RootMotionRotation::None {
+                None
+            } else {
+                self.root_motion_track(&ValueBinding::Rotation)
+                    .and_then(|track| fetch_rotation(track, self.time_slice.start))
+            };
+
+            if let Some(node_pose) = self.pose.poses_mut().get_mut(&settings.node) {
+                for bound_value in node_pose.values.values.iter_mut() {
+                    match (&bound_value.binding, &mut bound_value.value) {
+                        (ValueBinding::Position, TrackValue::Vector3(position)) => {
+                            settings.remove_extracted(position);
+                        }
+                        (ValueBinding::Rotation, TrackValue::UnitQuaternion(rotation)) => {
+                            if let Some(reference) = reference_rotation.as_ref() {
+                                settings.remove_extracted_rotation(rotation, reference);
+                            }
+                        }
+                        _ => (),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns current pose of the animation (a final result that can be applied to a scene graph).
+    pub fn pose(&self) -> &AnimationPose {
+        &self.pose
+    }
+
+    /// Captures current pose of the animation. The snapshot is decoupled from the animation, so it can be stored and
+    /// applied to a graph later (using [`AnimationPose::apply`]) without advancing any animation. It is useful for
+    /// "freezing" an animated character (hitstop), for seeding a ragdoll, etc. The snapshot is serializable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{Animation, AnimationPose},
+    ///     scene::{animation::AnimationPlayer, graph::Graph},
+    /// };
+    ///
+    /// fn freeze(animation: &Animation, animation_player: &mut AnimationPlayer) -> AnimationPose {
+    ///     // Prevent the player from overwriting the frozen pose.
+    ///     animation_player.set_auto_apply(false);
+    ///     animation.snapshot_pose()
+    /// }
+    ///
+    /// fn hold(frozen_pose: &AnimationPose, graph: &mut Graph) {
+    ///     frozen_pose.apply(graph);
+    /// }
+    /// ```
+    pub fn snapshot_pose(&self) -> AnimationPose {
+        self.pose.clone()
+    }
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            tracks: Vec::new(),
+            speed: 1.0,
+            time_position: 0.0,
+            enabled: true,
+            paused: false,
+            weight: 1.0,
+            loop_mode: Default::default(),
+            direction: Default::default(),
+            ping_pong_reversed: false,
+            pose: Default::default(),
+            pose_time: None,
+            signals: Default::default(),
+            event_tracks: Default::default(),
+            mask: None,
+            blend_mode: Default::default(),
+            additive_base_time: None,
+            additive_reference_pose: None,
+            root_motion_settings: None,
+            sync_group: None,
+            hold_on_end: true,
+            auto_apply: false,
+            time_offset: 0.0,
+            loop_range: None,
+            exiting_loop: false,
+            speed_scale: 1.0,
+            root_motion: None,
+            root_motion_rotation: None,
+            ended: false,
+            events: Default::default(),
+            track_events: Default::default(),
+            time_slice: Default::default(),
+        }
+    }
+}
+
+/// An identifier of a sync group of animations. See [`Animation::set_sync_group`] docs for more info.
+pub type SyncGroupId = u32;
+
+/// A leader of a sync group, its phase drives every other animation of the group.
+struct SyncGroupLeader {
+    handle: Handle<Animation>,
+    weight: f32,
+    phase: f32,
+    phase_speed: f32,
+}
+
+/// An active cross-fade between two animations of a container. See [`AnimationContainer::fade_to`] docs for more info.
+#[derive(Debug, Clone, PartialEq)]
+struct CrossFade {
+    source: Handle<Animation>,
+    target: Handle<Animation>,
+    duration: f32,
+    elapsed: f32,
+}
+
+impl CrossFade {
+    fn weight(&self) -> f32 {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).min(1.0)
+        }
+    }
+}
+
+/// Minimal amount of animations in a container to update them in parallel (with `parallel_animation` feature). Smaller
+/// containers are updated serially, because the overhead of parallel update would be bigger than the gain.
+pub const PARALLEL_UPDATE_THRESHOLD: usize = 16;
+
+/// Animations, that were borrowed mutably from outside of the container, could be modified in any way. The cached poses
+/// of such animations must be recalculated.
+fn invalidated(animation: &mut Animation) -> &mut Animation {
+    animation.invalidate_pose();
+    animation
+}
+
+/// A container for animations. It is a tiny wrapper around [`Pool`], you should never create the container yourself,
+/// it is managed by the engine.
+#[derive(Debug, Clone, Reflect, PartialEq)]
+pub struct AnimationContainer {
+    pool: Pool<Animation>,
+
+    #[reflect(hidden)]
+    cross_fade: Option<CrossFade>,
+}
+
+impl Default for AnimationContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnimationContainer {
+    /// Creates an empty animation container.
+    pub fn new() -> Self {
+        Self {
+            pool: Pool::new(),
+            cross_fade: None,
+        }
+    }
+
+    /// Returns a total amount of animations in the container.
+    #[inline]
+    pub fn alive_count(&self) -> u32 {
+        self.pool.alive_count()
+    }
+
+    /// Returns a total amount of animations in the container. It is the same as [`Self::alive_count`], but returns
+    /// `usize`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.pool.alive_count() as usize
+    }
+
+    /// Returns `true` if there are no animations in the container, `false` - otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.pool.alive_count() == 0
+    }
+
+    /// Returns an iterator yielding a references to animations in the container. Free slots of the inner pool are
+    /// skipped, so the iterator yields only "alive" animations.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Animation> {
+        self.pool.iter()
+    }
+
+    /// Returns an iterator yielding a pair (handle, reference) to animations in the container.
+    #[inline]
+    pub fn pair_iter(&self) -> impl Iterator<Item = (Handle<Animation>, &Animation)> {
+        self.pool.pair_iter()
+    }
+
+    /// Returns an iterator yielding a pair (handle, mutable reference) to animations in the container.
+    #[inline]
+    pub fn pair_iter_mut(&mut self) -> impl Iterator<Item = (Handle<Animation>, &mut Animation)> {
+        self.pool
+            .pair_iter_mut()
+            .map(|(handle, animation)| (handle, invalidated(animation)))
+    }
+
+    /// Returns an iterator yielding a mutable references to animations in the container. It could be used to modify
+    /// every animation in the container at once, for example to pause everything during a cutscene:
+    ///
+    /// ```rust
+    /// use fyrox::scene::animation::AnimationPlayer;
+    ///
+    /// fn pause_all(animation_player: &mut AnimationPlayer) {
+    ///     for animation in animation_player.animations_mut().iter_mut() {
+    ///         animation.set_enabled(false);
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Animation> {
+        self.pool.iter_mut().map(invalidated)
+    }
+
+    /// Adds a new animation to the container and returns its handle.
+    #[inline]
+    pub fn add(&mut self, animation: Animation) -> Handle<Animation> {
+        self.pool.spawn(animation)
+    }
+
+    /// Adds a new animation to the container and returns its handle together with a reference to the added animation,
+    /// so it could be configured right away without a second lookup.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{Animation, AnimationContainer, AnimationSignal},
+    ///     core::pool::Handle,
+    /// };
+    ///
+    /// fn add_footsteps(container: &mut AnimationContainer, walk: Animation) -> Handle<Animation> {
+    ///     let (handle, animation) = container.add_and_get(walk);
+    ///     animation.set_speed(1.5);
+    ///     animation.add_signal(AnimationSignal::new(Default::default(), "Footstep", 0.5));
+    ///     handle
+    /// }
+    /// ```
+    #[inline]
+    pub fn add_and_get(&mut self, animation: Animation) -> (Handle<Animation>, &mut Animation) {
+        let handle = self.pool.spawn(animation);
+        (handle, &mut self.pool[handle])
+    }
+
+    /// Moves every animation from the `other` container to this one and returns a mapping from the handles of the
+    /// animations in the `other` container to their new handles in this container. Names of the moved animations are
+    /// prefixed with `name_prefix` (use an empty string to keep the names intact), it could be used to avoid name
+    /// collisions when assembling animation libraries from multiple packs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// fn add_sword_pack(animations: &mut AnimationContainer, sword_pack: AnimationContainer) {
+    ///     let mapping = animations.import(sword_pack, "Sword/");
+    ///     assert!(mapping.values().all(|handle| animations.try_get(*handle).is_some()));
+    /// }
+    /// ```
+    pub fn import(
+        &mut self,
+        mut other: AnimationContainer,
+        name_prefix: &str,
+    ) -> FxHashMap<Handle<Animation>, Handle<Animation>> {
+        let handles = other
+            .pool
+            .pair_iter()
+            .map(|(handle, _)| handle)
+            .collect::<Vec<_>>();
+
+        let mut mapping = FxHashMap::default();
+        for old_handle in handles {
+            if let Some(mut animation) = other.pool.try_free(old_handle) {
+                if !name_prefix.is_empty() {
+                    animation.name = format!("{}{}", name_prefix, animation.name);
+                }
+                mapping.insert(old_handle, self.pool.spawn(animation));
+            }
+        }
+        mapping
+    }
+
+    /// Tries to remove an animation from the container by its handle.
+    #[inline]
+    pub fn remove(&mut self, handle: Handle<Animation>) -> Option<Animation> {
+        self.pool.try_free(handle)
+    }
+
+    /// Extracts animation from container and reserves its handle. It is used to temporarily take
+    /// ownership over animation, and then put animation back using given ticket.
+    pub fn take_reserve(&mut self, handle: Handle<Animation>) -> (Ticket<Animation>, Animation) {
+        self.pool.take_reserve(handle)
+    }
+
+    /// Puts animation back by given ticket.
+    pub fn put_back(
+        &mut self,
+        ticket: Ticket<Animation>,
+        animation: Animation,
+    ) -> Handle<Animation> {
+        self.pool.put_back(ticket, animation)
+    }
+
+    /// Makes animation handle vacant again.
+    pub fn forget_ticket(&mut self, ticket: Ticket<Animation>) {
+        self.pool.forget_ticket(ticket)
+    }
+
+    /// Removes all animations and frees the memory occupied by the inner pool. Any active cross-fade is cancelled, so
+    /// a new set of animations starts clean. Every handle produced by the container before clearing becomes invalid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.pool = Pool::new();
+        self.cross_fade = None;
+    }
+
+    /// Tries to borrow a reference to an animation in the container. Panics if the handle is invalid.
+    #[inline]
+    pub fn get(&self, handle: Handle<Animation>) -> &Animation {
+        self.pool.borrow(handle)
+    }
+
+    /// Tries to borrow a mutable reference to an animation in the container. Panics if the handle is invalid. The
+    /// cached pose of the animation is invalidated, see [`Animation::invalidate_pose`] docs for more info.
+    #[inline]
+    pub fn get_mut(&mut self, handle: Handle<Animation>) -> &mut Animation {
+        invalidated(self.pool.borrow_mut(handle))
+    }
+
+    /// Tries to borrow a reference to an animation in the container.
+    #[inline]
+    pub fn try_get(&self, handle: Handle<Animation>) -> Option<&Animation> {
+        self.pool.try_borrow(handle)
+    }
+
+    /// Tries to borrow a mutable reference to an animation in the container. The cached pose of the animation is
+    /// invalidated, see [`Animation::invalidate_pose`] docs for more info.
+    #[inline]
+    pub fn try_get_mut(&mut self, handle: Handle<Animation>) -> Option<&mut Animation> {
+        self.pool.try_borrow_mut(handle).map(invalidated)
+    }
+
+    /// Tries to find an animation by its name in the container. Names of animations are not guaranteed to be unique,
+    /// the method returns the first animation with the given name. It is a shortcut for [`Self::find_by_name_ref`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// fn is_jumping(animations: &AnimationContainer) -> bool {
+    ///     animations
+    ///         .find_by_name("Jump")
+    ///         .map_or(false, |(_, jump)| jump.is_enabled())
+    /// }
+    /// ```
+    #[inline]
+    pub fn find_by_name<S: AsRef<str>>(&self, name: S) -> Option<(Handle<Animation>, &Animation)> {
+        self.find_by_name_ref(name)
+    }
+
+    /// Tries to find an animation by its name in the container. Names of animations are not guaranteed to be unique,
+    /// the method returns the first animation with the given name.
+    #[inline]
+    pub fn find_by_name_ref<S: AsRef<str>>(
+        &self,
+        name: S,
+    ) -> Option<(Handle<Animation>, &Animation)> {
+        utils::find_by_name_ref(self.pool.pair_iter(), name)
+    }
+
+    /// Tries to find an animation by its name in the container and returns its handle and a mutable reference to it.
+    /// Names of animations are not guaranteed to be unique, the method returns the first animation with the given name.
+    #[inline]
+    pub fn find_by_name_mut<S: AsRef<str>>(
+        &mut self,
+        name: S,
+    ) -> Option<(Handle<Animation>, &mut Animation)> {
+        utils::find_by_name_mut(self.pool.pair_iter_mut(), name)
+    }
+
+    /// Removes every animation from the container that does not satisfy a particular condition represented by the given
+    /// closue.
+    #[inline]
+    pub fn retain<P>(&mut self, pred: P)
+    where
+        P: FnMut(&Animation) -> bool,
+    {
+        self.pool.retain(pred)
+    }
+
+    /// Tries to remove the first animation with the given name from the container and returns it. The pool slot of the
+    /// animation is freed, so it could be reused by subsequent [`Self::add`] calls.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// fn remove_debug_animation(animations: &mut AnimationContainer) {
+    ///     if let Some(animation) = animations.remove_by_name("Debug") {
+    ///         println!("{} was removed!", animation.name());
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    pub fn remove_by_name<S: AsRef<str>>(&mut self, name: S) -> Option<Animation> {
+        let handle = self.find_by_name_ref(name).map(|(handle, _)| handle)?;
+        self.remove(handle)
+    }
+
+    /// Removes every animation from the container whose name does not satisfy a particular condition represented by
+    /// the given closure.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// fn strip_debug_animations(animations: &mut AnimationContainer) {
+    ///     animations.retain_by_name(|name| !name.starts_with("Debug"));
+    /// }
+    /// ```
+    #[inline]
+    pub fn retain_by_name<P>(&mut self, mut pred: P)
+    where
+        P: FnMut(&str) -> bool,
+    {
+        self.pool.retain(|animation| pred(animation.name()))
+    }
+
+    /// Starts smooth transition (cross-fade) from currently playing animation to the given one. The source animation
+    /// is either the target of a cross-fade that is currently in progress or the first enabled animation in the
+    /// container (other than the target). The target animation will be enabled immediately, and its pose will be
+    /// blended with the pose of the source animation (positions and scales are interpolated linearly, rotations -
+    /// spherically) with the weight that goes from 0 to 1 during the given amount of time (in seconds). When the
+    /// cross-fade is done, the source animation will be disabled automatically. If there's a cross-fade in progress,
+    /// its source animation will be disabled immediately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// fn start_running(animations: &mut AnimationContainer) {
+    ///     let run = animations.find_by_name_ref("Run").map(|(handle, _)| handle);
+    ///     if let Some(run) = run {
+    ///         animations.fade_to(run, 0.25);
+    ///     }
+    /// }
+    /// ```
+    pub fn fade_to(&mut self, target: Handle<Animation>, duration: f32) {
+        if !self.pool.is_valid_handle(target) {
+            return;
+        }
+
+        let source = if let Some(cross_fade) = self.cross_fade.take() {
+            if cross_fade.target == target {
+                // Already fading to the target.
+                self.cross_fade = Some(cross_fade);
+                return;
+            }
+
+            if let Some(prev_source) = self.pool.try_borrow_mut(cross_fade.source) {
+                prev_source.set_enabled(false);
+            }
+
+            cross_fade.target
+        } else {
+            self.pool
+                .pair_iter()
+                .find_map(|(handle, animation)| {
+                    if handle != target && animation.is_enabled() {
+                        Some(handle)
+                    } else {
+                        None
+                    }
+                })
+                .unwrap_or_default()
+        };
+
+        self.pool[target].set_enabled(true);
+
+        self.cross_fade = Some(CrossFade {
+            source,
+            target,
+            duration,
+            elapsed: 0.0,
+        });
+    }
+
+    /// Returns normalized progress (in `0..1` range) of current cross-fade between animations, or `None` if there's no
+    /// cross-fade in progress. See [`Self::fade_to`] docs for more info.
+    pub fn cross_fade_progress(&self) -> Option<f32> {
+        self.cross_fade
+            .as_ref()
+            .map(|cross_fade| cross_fade.weight())
+    }
+
+    /// Updates all animations in the container and applies their poses to respective nodes. Returns `true` if at least
+    /// one pose was applied, `false` - otherwise. This method is intended to be used only by the internals of the
+    /// engine!
+    pub fn update_animations(&mut self, nodes: &mut NodePool, apply: bool, dt: f32) -> bool {
+        self.update_and_apply(nodes, apply, dt)
+    }
+
+    // Works with any storage of nodes, so the container could be updated in place in a graph as well.
+    fn update_and_apply<N: NodeAccess>(&mut self, nodes: &mut N, apply: bool, dt: f32) -> bool {
+        self.tick_animations(dt);
+
+        // Animations that opted in for automatic applying (see `Animation::set_auto_apply`) are applied anyway.
+        let override_applied = self.apply_override_poses(nodes, apply);
+
+        let cross_fade_applied = self.update_cross_fade(nodes, apply, dt);
+
+        let additive_applied = self.apply_additive_poses(nodes, apply);
+
+        override_applied || cross_fade_applied || additive_applied
+    }
+
+    /// Applies current output poses of every enabled animation in the container to respective nodes of the given graph,
+    /// without advancing the animations. The poses are combined exactly the same way as in automatic pose applying
+    /// (see [`crate::scene::animation::AnimationPlayer::set_auto_apply`]): weights and masks of animations as well as
+    /// current cross-fade are taken into account, additive animations are applied last.
+    pub fn apply_poses(&self, graph: &mut Graph) {
+        self.apply_override_poses(graph, true);
+        self.apply_cross_fade_pose(graph);
+        self.apply_additive_poses(graph, true);
+    }
+
+    /// Applies the poses of override animations, `apply_all` defines whether the poses of every enabled animation
+    /// must be applied or only the ones that opted in for automatic applying. Returns `true` if any pose was applied.
+    fn apply_override_poses<N: NodeAccess>(&self, nodes: &mut N, apply_all: bool) -> bool {
+        let cross_fade = self.cross_fade.as_ref();
+        let mut contributors = self
+            .pool
+            .pair_iter()
+            .filter(|(handle, anim)| {
+                // Poses of cross-fading animations are applied after blending.
+                let is_cross_fading = cross_fade.map_or(false, |cross_fade| {
+                    cross_fade.source == *handle || cross_fade.target == *handle
+                });
+
+                anim.enabled
+                    && (apply_all || anim.auto_apply)
+                    && anim.blend_mode == AnimationBlendMode::Override
+                    && !is_cross_fading
+            })
+            .map(|(_, anim)| anim);
+
+        if let Some(first) = contributors.next() {
+            match contributors.next() {
+                // Fast path for the most common case - a single animation with default weight.
+                None if first.weight == 1.0 => first.pose.apply_internal(nodes),
+                second => {
+                    let mut accumulator = WeightedPoseAccumulator::default();
+                    for animation in std::iter::once(first).chain(second).chain(contributors) {
+                        accumulator.add(&animation.pose, animation.weight);
+                    }
+                    accumulator.apply_internal(nodes);
+                }
+            }
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Additive animations are applied last, on top of the poses of other animations. See
+    /// [`Self::apply_override_poses`] for the meaning of `apply_all`.
+    fn apply_additive_poses<N: NodeAccess>(&self, nodes: &mut N, apply_all: bool) -> bool {
+        let mut applied = false;
+        for animation in self.pool.iter().filter(|anim| {
+            anim.enabled
+                && (apply_all || anim.auto_apply)
+                && anim.blend_mode == AnimationBlendMode::Additive
+        }) {
+            animation.pose.apply_additive_internal(nodes);
+            applied = true;
+        }
+        applied
+    }
+
+    fn apply_cross_fade_pose<N: NodeAccess>(&self, nodes: &mut N) {
+        if let Some(cross_fade) = self.cross_fade.as_ref() {
+            let mut pose = AnimationPose::default();
+            if let Some(source) = self.pool.try_borrow(cross_fade.source) {
+                source.pose.clone_into(&mut pose);
+            }
+            if let Some(target) = self.pool.try_borrow(cross_fade.target) {
+                pose.interpolate_with(&target.pose, cross_fade.weight());
+            }
+            pose.apply_internal(nodes);
+        }
+    }
+
+    /// Advances every enabled animation and calculates their output poses. Animations do not access the graph during
+    /// the update, so with `parallel_animation` feature large containers (see [`PARALLEL_UPDATE_THRESHOLD`]) are updated
+    /// in parallel. The poses are then applied to the graph serially.
+    fn tick_animations(&mut self, dt: f32) {
+        let leaders = self.sync_group_leaders();
+
+        #[cfg(feature = "parallel_animation")]
+        if self.pool.alive_count() as usize >= PARALLEL_UPDATE_THRESHOLD {
+            use rayon::prelude::*;
+
+            let mut animations = self
+                .pool
+                .pair_iter_mut()
+                .filter(|(_, anim)| anim.enabled)
+                .collect::<Vec<_>>();
+            animations.par_iter_mut().for_each(|(handle, animation)| {
+                let dt = synchronize(*handle, animation, &leaders, dt);
+                animation.tick(dt)
+            });
+
+            return;
+        }
+
+        for (handle, animation) in self.pool.pair_iter_mut().filter(|(_, anim)| anim.enabled) {
+            let dt = synchronize(handle, animation, &leaders, dt);
+            animation.tick(dt);
+        }
+    }
+
+    /// Finds a leader for every sync group in the container. See [`Animation::set_sync_group`] docs for more info.
+    fn sync_group_leaders(&self) -> FxHashMap<SyncGroupId, SyncGroupLeader> {
+        let mut leaders = FxHashMap::<SyncGroupId, SyncGroupLeader>::default();
+        for (handle, animation) in self.pool.pair_iter() {
+            let sync_group = if let Some(sync_group) = animation.sync_group {
+                sync_group
+            } else {
+                continue;
+            };
+
+            let length = animation.length();
+            if !animation.enabled || animation.paused || length <= 0.0 {
+                continue;
+            }
+
+            let weight = animation.weight
+                * self.cross_fade.as_ref().map_or(1.0, |cross_fade| {
+                    if cross_fade.source == handle {
+                        1.0 - cross_fade.weight()
+                    } else if cross_fade.target == handle {
+                        cross_fade.weight()
+                    } else {
+                        1.0
+                    }
+                });
+
+            let candidate = SyncGroupLeader {
+                handle,
+                weight,
+                phase: animation.normalized_time(),
+                phase_speed: animation.playback_speed() / length,
+            };
+
+            match leaders.get_mut(&sync_group) {
+                Some(leader) => {
+                    if candidate.weight > leader.weight {
+                        *leader = candidate;
+                    }
+                }
+                None => {
+                    leaders.insert(sync_group, candidate);
+                }
+            }
+        }
+        leaders
+    }
+
+    fn update_cross_fade<N: NodeAccess>(&mut self, nodes: &mut N, apply: bool, dt: f32) -> bool {
+        let (source, target) = if let Some(cross_fade) = self.cross_fade.as_mut() {
+            cross_fade.elapsed += dt;
+            (cross_fade.source, cross_fade.target)
+        } else {
+            return false;
+        };
+
+        let is_auto_applied = |handle: Handle<Animation>| {
+            self.pool
+                .try_borrow(handle)
+                .map_or(false, |animation| animation.auto_apply)
+        };
+
+        let applied = apply || is_auto_applied(source) || is_auto_applied(target);
+        if applied {
+            self.apply_cross_fade_pose(nodes);
+        }
+
+        self.finish_cross_fade();
+
+        applied
+    }
+
+    /// Finishes current cross-fade (if it is complete) and disables its source animation.
+    fn finish_cross_fade(&mut self) {
+        if self
+            .cross_fade
+            .as_ref()
+            .map_or(false, |cross_fade| cross_fade.weight() >= 1.0)
+        {
+            if let Some(cross_fade) = self.cross_fade.take() {
+                if let Some(source) = self.pool.try_borrow_mut(cross_fade.source) {
+                    source.set_enabled(false);
+                }
+            }
+        }
+    }
+
+    /// Removes queued animation events from every animation in the container.
+    ///
+    /// # Potential use cases
+    ///
+    /// Sometimes there is a need to use animation events only from one frame, in this case you should clear events each frame.
+    /// This situation might come up when you have multiple animations with signals, but at each frame not every event gets
+    /// processed. This might result in unwanted side effects, like multiple attack events may result in huge damage in a single
+    /// frame.
+    pub fn clear_animation_events(&mut self) {
+        for animation in self.pool.iter_mut() {
+            animation.events.clear();
+            animation.track_events.clear();
+        }
+    }
+
+    /// Takes events from the event queues of every animation in the container and returns them paired with the
+    /// handles of the animations, that produced the events. The queues are emptied right away, even if the returned
+    /// iterator is not consumed completely. Events of a single animation are returned in the order in which they were
+    /// produced, the order of animations is unspecified. Only the events of signals are returned, events of event tracks
+    /// are kept in their own queues, use [`Self::drain_track_events`] to take them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::AnimationContainer;
+    ///
+    /// fn process_events(animations: &mut AnimationContainer) {
+    ///     for (animation, event) in animations.drain_events() {
+    ///         println!("{} produced {} event", animation, event.name);
+    ///     }
+    /// }
+    /// ```
+    pub fn drain_events(&mut self) -> impl Iterator<Item = (Handle<Animation>, AnimationEvent)> {
+        let mut events = Vec::new();
+        for (handle, animation) in self.pool.pair_iter_mut() {
+            events.extend(animation.events.drain(..).map(|event| (handle, event)));
+        }
+        events.into_iter()
+    }
+
+    /// Takes events from the queues of events of event tracks (see [`EventTrack`]) of every animation in the container
+    /// and returns them paired with the handles of the animations, that produced the events. It works the same as
+    /// [`Self::drain_events`], see its docs for more info.
+    pub fn drain_track_events(&mut self) -> impl Iterator<Item = (Handle<Animation>, TrackEvent)> {
+        let mut events = Vec::new();
+        for (handle, animation) in self.pool.pair_iter_mut() {
+            events.extend(
+                animation
+                    .track_events
+                    .drain(..)
+                    .map(|event| (handle, event)),
+            );
+        }
+        events.into_iter()
+    }
+
+    /// Enables or disables every animation in the container. It is useful to pause every animation of a character
+    /// during a cutscene, for example. See [`Animation::set_enabled`] for more info.
+    #[inline]
+    pub fn set_enabled_all(&mut self, enabled: bool) {
+        for animation in self.pool.iter_mut() {
+            animation.set_enabled(enabled);
+        }
+    }
+
+    /// Enables every animation in the container.
+    #[inline]
+    pub fn enable_all(&mut self) {
+        self.set_enabled_all(true)
+    }
+
+    /// Disables every animation in the container.
+    #[inline]
+    pub fn disable_all(&mut self) {
+        self.set_enabled_all(false)
+    }
+
+    /// Rewinds every animation in the container to the beginning of its time slice (or to the end for animations
+    /// played in reverse). See [`Animation::rewind`] for more info.
+    #[inline]
+    pub fn reset_all(&mut self) {
+        for animation in self.pool.iter_mut() {
+            animation.rewind();
+        }
+    }
+
+    /// Sets a random time offset in `[0; length)` range to every animation in the container, using the given random
+    /// number generator. Pass a seeded generator to get the same offsets every time. Offsets are generated once and
+    /// serialized with the animations, so they stay stable. See [`Animation::set_time_offset`] for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{rand::thread_rng, scene::animation::AnimationPlayer};
+    ///
+    /// fn desynchronize_crowd(npc_players: &mut [&mut AnimationPlayer]) {
+    ///     let mut rng = thread_rng();
+    ///     for player in npc_players {
+    ///         player.animations_mut().randomize_time_offsets(&mut rng);
+    ///     }
+    /// }
+    /// ```
+    pub fn randomize_time_offsets<R: Rng>(&mut self, rng: &mut R) {
+        for animation in self.pool.iter_mut() {
+            let length = animation.length();
+            let offset = if length > 0.0 {
+                rng.gen_range(0.0..length)
+            } else {
+                0.0
+            };
+            animation.set_time_offset(offset);
+        }
+    }
+
+    /// Enables or disables every track of the given group in every animation of the container. See
+    /// [`Animation::set_group_enabled`] for more info.
+    pub fn set_group_enabled(&mut self, group: &str, enabled: bool) {
+        for animation in self.pool.iter_mut() {
+            animation.set_group_enabled(group, enabled);
+        }
+    }
+
+    /// Mutes or unmutes every track of the given group in every animation of the container. See
+    /// [`Animation::set_group_mute`] for more info.
+    pub fn set_group_mute(&mut self, group: &str, muted: bool) {
+        for animation in self.pool.iter_mut() {
+            animation.set_group_mute(group, muted);
+        }
+    }
+
+    /// Sets or clears the solo flag of every track of the given group in every animation of the container. See
+    /// [`Animation::set_group_solo`] for more info.
+    pub fn set_group_solo(&mut self, group: &str, solo: bool) {
+        for animation in self.pool.iter_mut() {
+            animation.set_group_solo(group, solo);
+        }
+    }
+}
+
+/// Current version of serialized [`AnimationContainer`]s, see [`ANIMATION_VERSION`] for more info about versioning.
+const ANIMATION_CONTAINER_VERSION: u32 = 1;
+
+impl Visit for AnimationContainer {
+    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+        if visitor.is_reading() && self.pool.get_capacity() != 0 {
+            panic!("Animation pool must be empty on load!");
+        }
+
+        let mut region = visitor.enter_region(name)?;
+
+        // There were no changes of the layout of the container so far, the version is reserved for future changes.
+        // Version `0` means data saved before the version was introduced.
+        let mut version = ANIMATION_CONTAINER_VERSION;
+        if version.visit("Version", &mut region).is_err() && region.is_reading() {
+            version = 0;
+        }
+        if version > ANIMATION_CONTAINER_VERSION {
+            return Err(VisitError::User(format!(
+                "Unsupported version {version} of animation container data! Maximum supported version is \
+                {ANIMATION_CONTAINER_VERSION}."
+            )));
+        }
+
+        self.pool.visit("Pool", &mut region)?;
+
+        Ok(())
+    }
+}
+
+impl Index<Handle<Animation>> for AnimationContainer {
+    type Output = Animation;
+
+    fn index(&self, index: Handle<Animation>) -> &Self::Output {
+        &self.pool[index]
+    }
+}
+
+impl IndexMut<Handle<Animation>> for AnimationContainer {
+    fn index_mut(&mut self, index: Handle<Animation>) -> &mut Self::Output {
+        invalidated(&mut self.pool[index])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{
+            container::{TrackDataContainer, TrackValueKind},
+            pose::WeightCheck,
+            track::Track,
+            value::{
+                BoundValue, ComponentMask, ScaleBlending, TrackValue, TransformSpace, ValueBinding,
+                ValueType,
+            },
+            Animation, AnimationBlendMode, AnimationContainer, AnimationPose, AnimationSignal,
+            EventKey, EventTrack, LoopMode, PlaybackDirection, RootMotionRotation,
+            RootMotionSettings,
+        },
+        core::{
+            algebra::{UnitQuaternion, Vector3},
+            curve::{Curve, CurveKey, CurveKeyKind},
+            pool::Handle,
+            rand::{rngs::StdRng, SeedableRng},
+            reflect::ResolvePath,
+            visitor::prelude::*,
+        },
+        scene::{
+            base::BaseBuilder,
+            camera::{Camera, CameraBuilder, Projection},
+            graph::Graph,
+            mesh::{BlendShape, Mesh, MeshBuilder},
+            node::Node,
+            pivot::PivotBuilder,
+        },
+    };
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_playback_state_restore() {
+        let mut track = Track::new_position();
+        track.set_target(Handle::new(1, 1));
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 3.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(2.0, -1.0, CurveKeyKind::Linear),
+        ]);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Signal", 1.5));
+        animation.set_time_slice(0.0..2.0);
+
+        let dts = [0.016, 0.033, 0.1, 0.017, 0.6, 0.25, 0.016];
+
+        for _ in 0..10 {
+            animation.tick(0.07);
+        }
+        let state = animation.to_state();
+
+        let mut reference = animation.clone();
+        for dt in dts {
+            reference.tick(dt);
+        }
+
+        // Simulate a misprediction, then rollback and re-simulate.
+        for _ in 0..5 {
+            animation.tick(0.2);
+        }
+        animation.from_state(&state);
+        for dt in dts {
+            animation.tick(dt);
+        }
+
+        assert_eq!(animation.to_state(), reference.to_state());
+        assert_eq!(animation.pose(), reference.pose());
+        assert_eq!(animation.events_ref(), reference.events_ref());
+    }
+
+    #[test]
+    fn test_root_motion_yaw() {
+        let hips = Handle::new(1, 1);
+
+        let mut track = Track::new_rotation();
+        track.set_target(hips);
+        // Constant tilt around X axis, yaw goes from 0 to 90 degrees.
+        track.data_container_mut().curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(0.0, 0.3, CurveKeyKind::Linear)]);
+        track.data_container_mut().curves_mut()[1] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, FRAC_PI_2, CurveKeyKind::Linear),
+        ]);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+        animation.set_root_motion_settings(Some(RootMotionSettings {
+            node: hips,
+            extract_rotation: RootMotionRotation::Yaw,
+            ..Default::default()
+        }));
+
+        // 1.5 loops of the animation.
+        for _ in 0..15 {
+            animation.tick(0.1);
+        }
+        let (axis, angle) = animation
+            .pop_root_motion_rotation()
+            .unwrap()
+            .axis_angle()
+            .unwrap();
+        assert!((axis.y.abs() - 1.0).abs() < 1.0e-4);
+        assert!((angle * axis.y - 1.5 * FRAC_PI_2).abs() < 1.0e-4);
+        assert!(animation.pop_root_motion_rotation().is_none());
+
+        // The yaw must be removed from the pose, but the tilt must be kept.
+        animation.tick(0.0);
+        let node_pose = animation.pose().poses().get(&hips).unwrap();
+        match node_pose.values.values[0].value {
+            TrackValue::UnitQuaternion(rotation) => {
+                let expected = UnitQuaternion::from_euler_angles(0.3, 0.0, 0.0);
+                assert!(rotation.angle_to(&expected) < 1.0e-4);
+            }
+            _ => panic!("Rotation track must produce a quaternion!"),
+        }
+    }
+
+    #[test]
+    fn test_sync_groups() {
+        let mut walk = Animation::default();
+        walk.set_time_slice(0.0..1.0);
+        walk.set_sync_group(Some(0));
+
+        let mut run = Animation::default();
+        run.set_time_slice(0.0..0.6);
+        run.set_time_position(0.3)
+            .set_weight(0.5)
+            .set_sync_group(Some(0));
+
+        let mut container = AnimationContainer::new();
+        let walk = container.add(walk);
+        let run = container.add(run);
+
+        let mut graph = Graph::new();
+        for _ in 0..7 {
+            container.update_and_apply(&mut graph, false, 0.13);
+            assert!(
+                (container[walk].normalized_time() - container[run].normalized_time()).abs()
+                    < 1.0e-4
+            );
+        }
+
+        // The run animation becomes the leader of the group.
+        container[run].set_weight(1.0);
+        container[walk].set_weight(0.2);
+        for _ in 0..7 {
+            container.update_and_apply(&mut graph, false, 0.13);
+            assert!(
+                (container[walk].normalized_time() - container[run].normalized_time()).abs()
+                    < 1.0e-4
+            );
+        }
+        // The leader plays at its own pace: 0.91 * 0.6 + 7 * 0.13 = 1.456 seconds, that is two full cycles + 0.256.
+        assert!((container[run].time_position() - 0.256).abs() < 1.0e-3);
+    }
+
+    #[test]
+    fn test_blend_shape_binding() {
+        let mut graph = Graph::new();
+        let mesh = MeshBuilder::new(BaseBuilder::new())
+            .with_blend_shapes(vec![
+                BlendShape::new("Smile", 0.0),
+                BlendShape::new("Blink", 0.0),
+            ])
+            .build(&mut graph);
+
+        let mut track = Track::new(
+            TrackDataContainer::new(TrackValueKind::Real),
+            ValueBinding::blend_shape("Smile"),
+        );
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 2.0, CurveKeyKind::Linear),
+        ]);
+        track.set_target(mesh);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+
+        let weight = |graph: &Graph, name: &str| {
+            graph[mesh]
+                .cast::<Mesh>()
+                .unwrap()
+                .blend_shape_weight(name)
+                .unwrap()
+        };
+
+        animation.set_time_position(0.25);
+        animation.tick(0.0);
+        animation.pose().apply(&mut graph);
+        assert_eq!(weight(&graph, "Smile"), 0.5);
+        assert_eq!(weight(&graph, "Blink"), 0.0);
+
+        // The weight is clamped.
+        animation.set_time_position(0.75);
+        animation.tick(0.0);
+        animation.pose().apply(&mut graph);
+        assert_eq!(weight(&graph, "Smile"), 1.0);
+
+        // Partial weight of the value blends with the current weight of the blend shape.
+        let mut value = animation.tracks()[0].fetch(0.25).unwrap();
+        value.weight = 0.5;
+        value.apply(&mut graph[mesh]);
+        assert_eq!(weight(&graph, "Smile"), 0.75);
+        assert!(!value.error_flag.is_raised());
+
+        // Unknown blend shapes and non-mesh nodes are reported and left untouched.
+        let pivot = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        for (node, name) in [(mesh, "Frown"), (pivot, "Smile")] {
+            let mut track = Track::new(
+                TrackDataContainer::new(TrackValueKind::Real),
+                ValueBinding::blend_shape(name),
+            );
+            track.set_target(node);
+            let value = track.fetch(0.0).unwrap();
+            value.apply(&mut graph[node]);
+            assert!(value.error_flag.is_raised());
+        }
+        assert_eq!(weight(&graph, "Smile"), 0.75);
+        assert_eq!(weight(&graph, "Blink"), 0.0);
+    }
+
+    #[test]
+    fn test_animation_weights() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let make_animation = |x: f32, weight: f32| {
+            let mut track = Track::new_position();
+            track.set_target(node);
+            track.data_container_mut().curves_mut()[0] =
+                Curve::from(vec![CurveKey::new(0.0, x, CurveKeyKind::Constant)]);
+            let mut animation = Animation::default();
+            animation.add_track(track);
+            animation.set_time_slice(0.0..1.0);
+            animation.set_weight(weight);
+            animation
+        };
+
+        let mut container = AnimationContainer::new();
+        let walk = container.add(make_animation(1.0, 1.0));
+        let run = container.add(make_animation(3.0, 3.0));
+        // Zero weight means no contribution at all.
+        container.add(make_animation(100.0, 0.0));
+
+        // Weighted average of the poses.
+        container.update_and_apply(&mut graph, true, 0.1);
+        assert_eq!(graph[node].local_transform().position().x, 2.5);
+
+        // Total weight below one blends the result with the current value of the property.
+        container[walk].set_weight(0.25);
+        container[run].set_weight(0.25);
+        container.update_and_apply(&mut graph, true, 0.1);
+        assert_eq!(graph[node].local_transform().position().x, 2.25);
+    }
+
+    #[test]
+    fn test_binding_error_reported_once() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let binding = ValueBinding::Property {
+            name: "foo.bar".to_owned(),
+            value_type: ValueType::F32,
+        };
+        let mut track = Track::new(
+            TrackDataContainer::new(TrackValueKind::Real),
+            binding.clone(),
+        );
+        track.data_container_mut().curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(0.0, 1.0, CurveKeyKind::Constant)]);
+        track.set_target(node);
+
+        let value = track.fetch(0.0).unwrap();
+        assert!(!value.error_flag.is_raised());
+        value.apply(&mut graph[node]);
+        assert!(value.error_flag.is_raised());
+
+        // Every value of the track shares the flag, so the error is not reported every frame.
+        assert!(track.fetch(0.5).unwrap().error_flag.is_raised());
+
+        // A track that was loaded separately reports its own errors.
+        let mut loaded = Track::default();
+        let mut visitor = Visitor::new();
+        track.clone().visit("Track", &mut visitor).unwrap();
+        let mut data = Vec::new();
+        visitor.save_binary_to_memory(&mut data).unwrap();
+        let mut visitor = Visitor::load_from_memory(data).unwrap();
+        loaded.visit("Track", &mut visitor).unwrap();
+        assert!(!loaded.fetch(0.0).unwrap().error_flag.is_raised());
+
+        // Changing the binding resets the flag.
+        track.set_binding(binding);
+        assert!(!track.fetch(0.0).unwrap().error_flag.is_raised());
+    }
+
+    #[test]
+    fn test_component_mask() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        graph[node]
+            .local_transform_mut()
+            .set_position(Vector3::new(5.0, 0.0, 7.0));
+
+        let mut track = Track::new_position();
+        track.set_target(node);
+        track.set_components(ComponentMask::new(false, true, false));
+        for curve in track.data_container_mut().curves_mut() {
+            *curve = Curve::from(vec![CurveKey::new(0.0, 2.0, CurveKeyKind::Constant)]);
+        }
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+
+        let mut container = AnimationContainer::new();
+        container.add(animation);
+        container.update_and_apply(&mut graph, true, 0.1);
+
+        assert_eq!(
+            **graph[node].local_transform().position(),
+            Vector3::new(5.0, 2.0, 7.0)
+        );
+    }
+
+    #[test]
+    fn test_slice() {
+        let mut track = Track::new_position();
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 2.0, CurveKeyKind::Linear),
+            CurveKey::new(2.0, 0.0, CurveKeyKind::Linear),
+        ]);
+
+        let mut empty_track = Track::new_position();
+        empty_track.data_container_mut().curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(3.0, 1.0, CurveKeyKind::Linear)]);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.add_track(empty_track);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Inside", 1.0));
+        animation.add_signal(AnimationSignal::new(Default::default(), "Outside", 2.5));
+        animation.set_time_slice(0.0..3.0);
+
+        let slice = animation.slice(0.5..1.5, false);
+        assert_eq!(slice.time_slice(), 0.0..1.0);
+        assert_eq!(slice.time_position(), 0.0);
+        assert_eq!(slice.tracks().len(), 1);
+        assert_eq!(slice.signals().len(), 1);
+        assert_eq!(slice.signals()[0].time, 0.5);
+
+        let curve = &slice.tracks()[0].data_container().curves_ref()[0];
+        let keys = curve
+            .keys()
+            .iter()
+            .map(|key| (key.location(), key.value))
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec![(0.0, 1.0), (0.5, 2.0), (1.0, 1.0)]);
+
+        let slice = animation.slice(0.5..1.5, true);
+        assert_eq!(slice.tracks().len(), 2);
+        assert!(slice.tracks()[1].data_container().curves_ref()[0]
+            .keys()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_multiplicative_scale_blending() {
+        let scale = |value: f32| BoundValue {
+            binding: ValueBinding::Scale,
+            value: TrackValue::Vector3(Vector3::repeat(value)),
+            components: Default::default(),
+            scale_blending: ScaleBlending::Multiplicative,
+            weight: 1.0,
+            space: Default::default(),
+            error_flag: Default::default(),
+        };
+
+        let mut blended = scale(0.1).weighted_clone(0.5);
+        blended.blend_with(&scale(10.0), 0.5);
+        match blended.value {
+            TrackValue::Vector3(v) => assert!((v - Vector3::repeat(1.0)).norm() < 1.0e-4),
+            _ => panic!("Scale must be a vector!"),
+        }
+
+        let interpolated = scale(0.1).interpolate(&scale(10.0), 0.5).unwrap();
+        assert_eq!(interpolated.value, blended.value);
+
+        // Linear blending with the total weight less than one produces degenerate scale.
+        let mut linear = scale(0.1);
+        linear.scale_blending = ScaleBlending::Linear;
+        match linear.weighted_clone(0.0).value {
+            TrackValue::Vector3(v) => assert_eq!(v, Vector3::zeros()),
+            _ => panic!("Scale must be a vector!"),
+        }
+    }
+
+    #[test]
+    fn test_legacy_data_migration() {
+        // Layout of animations before the version was introduced.
+        struct LegacyAnimation {
+            tracks: Vec<Track>,
+            looped: bool,
+        }
+
+        impl Visit for LegacyAnimation {
+            fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+                let mut region = visitor.enter_region(name)?;
+                self.tracks.visit("Tracks", &mut region)?;
+                0.0f32.visit("TimePosition", &mut region)?;
+                1.0f32.visit("Speed", &mut region)?;
+                true.visit("Enabled", &mut region)?;
+                Vec::<AnimationSignal>::new().visit("Signals", &mut region)?;
+                self.looped.visit("Looped", &mut region)
+            }
+        }
+
+        let mut track = Track::new_position();
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(2.0, 1.0, CurveKeyKind::Linear),
+        ]);
+
+        for (looped, loop_mode) in [(true, LoopMode::Loop), (false, LoopMode::Once)] {
+            let mut legacy = LegacyAnimation {
+                tracks: vec![track.clone()],
+                looped,
+            };
+            let mut visitor = Visitor::new();
+            legacy.visit("Animation", &mut visitor).unwrap();
+            let mut data = Vec::new();
+            visitor.save_binary_to_memory(&mut data).unwrap();
+
+            let mut visitor = Visitor::load_from_memory(data).unwrap();
+            let mut animation = Animation::default();
+            animation.visit("Animation", &mut visitor).unwrap();
+
+            assert_eq!(animation.time_slice(), 0.0..2.0);
+            assert_eq!(animation.loop_mode(), loop_mode);
+            assert_eq!(animation.weight(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_ping_pong() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        animation.set_loop_mode(LoopMode::PingPong);
+        for (name, time) in [("Start", 0.0), ("Middle", 0.5), ("End", 1.0)] {
+            animation.add_signal(AnimationSignal::new(Default::default(), name, time));
+        }
+
+        let mut positions = Vec::new();
+        let mut names = Vec::new();
+        for _ in 0..9 {
+            animation.tick(0.25);
+            positions.push(animation.time_position());
+            while let Some(event) = animation.pop_event() {
+                names.push(event.name);
+            }
+        }
+
+        // The playhead is reflected at both ends, each end is reached exactly once per pass.
+        assert_eq!(
+            positions,
+            vec![0.25, 0.5, 0.75, 1.0, 0.75, 0.5, 0.25, 0.0, 0.25]
+        );
+        // Signals at the turnaround points are not fired twice.
+        assert_eq!(names, vec!["Start", "Middle", "End", "Middle", "Start"]);
+
+        // Ping-pong mode survives serialization, the legacy looping flag is written as well.
+        let mut visitor = Visitor::new();
+        animation.visit("Animation", &mut visitor).unwrap();
+        let mut data = Vec::new();
+        visitor.save_binary_to_memory(&mut data).unwrap();
+        let mut visitor = Visitor::load_from_memory(data).unwrap();
+        let mut loaded = Animation::default();
+        loaded.visit("Animation", &mut visitor).unwrap();
+        assert_eq!(loaded.loop_mode(), LoopMode::PingPong);
+        loaded.tick(0.25);
+        assert_eq!(loaded.time_position(), 0.5);
+    }
+
+    #[test]
+    fn test_versioned_data_loading() {
+        // Layout of animations of older versions, only the fields that existed in the given version are written.
+        struct VersionedAnimation {
+            version: u32,
+        }
+
+        impl Visit for VersionedAnimation {
+            fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+                let mut region = visitor.enter_region(name)?;
+                self.version.visit("Version", &mut region)?;
+                Vec::<Track>::new().visit("Tracks", &mut region)?;
+                0.0f32.visit("TimePosition", &mut region)?;
+                (0.0f32..1.0).visit("TimeSlice", &mut region)?;
+                1.0f32.visit("Speed", &mut region)?;
+                true.visit("Enabled", &mut region)?;
+                Vec::<AnimationSignal>::new().visit("Signals", &mut region)?;
+                false.visit("Looped", &mut region)?;
+                LoopMode::Once.visit("LoopMode", &mut region)?;
+                if self.version >= 3 {
+                    false.visit("HoldOnEnd", &mut region)?;
+                }
+                if self.version >= 4 {
+                    true.visit("AutoApply", &mut region)?;
+                }
+                if self.version >= 5 {
+                    0.5f32.visit("TimeOffset", &mut region)?;
+                }
+                if self.version >= 6 {
+                    Some(0.25f32..0.75).visit("LoopRange", &mut region)?;
+                    true.visit("ExitingLoop", &mut region)?;
+                }
+                Ok(())
+            }
+        }
+
+        let load = |version: u32| {
+            let mut visitor = Visitor::new();
+            VersionedAnimation { version }
+                .visit("Animation", &mut visitor)
+                .unwrap();
+            let mut data = Vec::new();
+            visitor.save_binary_to_memory(&mut data).unwrap();
+
+            let mut visitor = Visitor::load_from_memory(data).unwrap();
+            let mut animation = Animation::default();
+            animation.visit("Animation", &mut visitor).unwrap();
+            animation
+        };
+
+        // Version 3 - HoldOnEnd.
+        assert!(load(2).is_hold_on_end());
+        assert!(!load(3).is_hold_on_end());
+
+        // Version 4 - AutoApply.
+        assert!(!load(3).is_auto_apply());
+        assert!(load(4).is_auto_apply());
+
+        // Version 5 - TimeOffset.
+        assert_eq!(load(4).time_offset(), 0.0);
+        assert_eq!(load(5).time_offset(), 0.5);
+
+        // Version 6 - LoopRange and ExitingLoop.
+        assert_eq!(load(5).loop_range(), None);
+        assert!(!load(5).is_exiting_loop());
+        assert_eq!(load(6).loop_range(), Some(0.25..0.75));
+        assert!(load(6).is_exiting_loop());
+    }
+
+    #[test]
+    fn test_mute_and_solo() {
+        let make_track = |target: Handle<Node>| {
+            let mut track = Track::new_position();
+            track.set_target(target);
+            track
+        };
+
+        let a = Handle::new(1, 1);
+        let b = Handle::new(2, 1);
+        let c = Handle::new(3, 1);
+
+        let mut animation = Animation::default();
+        animation.add_track(make_track(a));
+        animation.add_track(make_track(b));
+        animation.add_track(make_track(c));
+
+        let animated_nodes = |animation: &mut Animation| {
+            animation.tick(0.0);
+            let mut nodes = animation.pose().poses().keys().cloned().collect::<Vec<_>>();
+            nodes.sort_by_key(|handle| handle.index());
+            nodes
+        };
+
+        assert_eq!(animated_nodes(&mut animation), vec![a, b, c]);
+
+        animation.tracks_mut()[0].set_mute(true);
+        assert_eq!(animated_nodes(&mut animation), vec![b, c]);
+
+        animation.tracks_mut()[2].set_solo(true);
+        assert_eq!(animated_nodes(&mut animation), vec![c]);
+
+        // Muted tracks stay silent, even if they're soloed.
+        animation.tracks_mut()[0].set_solo(true);
+        assert_eq!(animated_nodes(&mut animation), vec![c]);
+    }
+
+    #[test]
+    fn test_track_groups() {
+        let make_track = |target: Handle<Node>, group: Option<&str>| {
+            let mut track = Track::new_position();
+            track.set_target(target);
+            track.set_group(group.map(ToOwned::to_owned));
+            track
+        };
+
+        let a = Handle::new(1, 1);
+        let b = Handle::new(2, 1);
+        let c = Handle::new(3, 1);
+
+        let mut animation = Animation::default();
+        animation.add_track(make_track(a, Some("Arms")));
+        animation.add_track(make_track(b, Some("Legs")));
+        animation.add_track(make_track(c, Some("Arms")));
+        assert_eq!(animation.track_groups(), vec!["Arms", "Legs"]);
+        assert_eq!(animation.tracks_of_group("Arms").count(), 2);
+
+        let animated_nodes = |animation: &mut Animation| {
+            animation.tick(0.0);
+            let mut nodes = animation.pose().poses().keys().cloned().collect::<Vec<_>>();
+            nodes.sort_by_key(|handle| handle.index());
+            nodes
+        };
+
+        animation.set_group_mute("Arms", true);
+        assert_eq!(animated_nodes(&mut animation), vec![b]);
+
+        animation.set_group_mute("Arms", false);
+        animation.set_group_solo("Arms", true);
+        assert_eq!(animated_nodes(&mut animation), vec![a, c]);
+
+        animation.set_group_solo("Arms", false);
+        let mut container = AnimationContainer::new();
+        let handle = container.add(animation);
+        container.set_group_enabled("Legs", false);
+        assert!(!container[handle].tracks()[1].is_enabled());
+        assert!(container[handle].tracks()[0].is_enabled());
+    }
+
+    #[test]
+    fn test_track_fade() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut track = Track::new_position();
+        track.set_target(node);
+        for (curve, value) in track
+            .data_container_mut()
+            .curves_mut()
+            .iter_mut()
+            .zip([10.0, 0.0, 0.0])
+        {
+            *curve = Curve::from(vec![CurveKey::new(0.0, value, CurveKeyKind::Constant)]);
+        }
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.tracks_mut()[0].fade(0.0, 1.0);
+        assert!(animation.tracks()[0].is_fading());
+
+        animation.tick(0.5);
+        assert_eq!(animation.tracks()[0].weight(), 0.5);
+        animation.pose().apply(&mut graph);
+        assert_eq!(
+            **graph[node].local_transform().position(),
+            Vector3::new(5.0, 0.0, 0.0)
+        );
+
+        // Fully faded track does not animate anything.
+        animation.tick(0.5);
+        assert!(!animation.tracks()[0].is_fading());
+        assert!(animation.pose().poses().is_empty());
+
+        animation.tracks_mut()[0].fade(1.0, 0.0);
+        animation.tick(0.0);
+        animation.pose().apply(&mut graph);
+        assert_eq!(
+            **graph[node].local_transform().position(),
+            Vector3::new(10.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_additive_reference_pose() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        graph[node]
+            .local_transform_mut()
+            .set_position(Vector3::new(1.0, 2.0, 3.0));
+
+        let mut track = Track::new_position();
+        track.set_target(node);
+        for curve in track.data_container_mut().curves_mut() {
+            *curve = Curve::from(vec![CurveKey::new(0.0, 5.0, CurveKeyKind::Constant)]);
+        }
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_blend_mode(AnimationBlendMode::Additive);
+
+        // Relative to the base time the delta is zero.
+        animation.tick(0.0);
+        assert_eq!(
+            animation.pose().poses()[&node].values.values[0].value,
+            TrackValue::Vector3(Vector3::zeros())
+        );
+
+        let bind_pose = animation.capture_reference_pose(&graph);
+        animation.set_additive_reference_pose(Some(bind_pose));
+        animation.tick(0.0);
+        assert_eq!(
+            animation.pose().poses()[&node].values.values[0].value,
+            TrackValue::Vector3(Vector3::new(4.0, 3.0, 2.0))
+        );
+    }
+
+    #[test]
+    fn test_signals_on_large_time_delta() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        for (name, time) in [
+            ("Start", 0.0),
+            ("A", 0.3),
+            ("B", 0.31),
+            ("C", 0.32),
+            ("End", 1.0),
+        ] {
+            animation.add_signal(AnimationSignal::new(Default::default(), name, time));
+        }
+
+        let fired = |animation: &mut Animation, dt: f32| {
+            animation.tick(dt);
+            let mut names = Vec::new();
+            while let Some(event) = animation.pop_event() {
+                names.push(event.name);
+            }
+            names
+        };
+
+        // A single hitch skips over every signal and wraps around.
+        assert_eq!(
+            fired(&mut animation, 1.5),
+            vec!["Start", "A", "B", "C", "End", "Start", "A", "B", "C"]
+        );
+        // Small steps do not fire anything twice.
+        assert_eq!(fired(&mut animation, 0.01), Vec::<String>::new());
+
+        // Reverse playback fires the signals in reverse order.
+        animation.set_speed(-1.0);
+        assert_eq!(
+            fired(&mut animation, 0.6),
+            vec!["C", "B", "A", "Start", "End"]
+        );
+
+        // Non-looping animations fire the signal at the end exactly once.
+        animation.set_speed(1.0);
+        animation.set_loop_mode(LoopMode::Once);
+        animation.set_time_position(0.5);
+        assert_eq!(fired(&mut animation, 2.0), vec!["End"]);
+        assert_eq!(fired(&mut animation, 0.1), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_many_signals_in_single_update() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        animation.set_loop_mode(LoopMode::Once);
+        let mut event_track = EventTrack::new("Steps");
+        for i in 0..100 {
+            let time = i as f32 * 0.01;
+            let name = format!("Signal{}", i);
+            animation.add_signal(AnimationSignal::new(Default::default(), &name, time));
+            event_track.add_key(EventKey::new(time, &name, ""));
+        }
+        animation.add_event_track(event_track);
+
+        // Every crossed signal is queued, no matter how many of them were crossed.
+        animation.tick(2.0);
+        let names = animation
+            .take_events()
+            .into_iter()
+            .map(|event| event.name)
+            .collect::<Vec<_>>();
+        let expected = (0..100).map(|i| format!("Signal{}", i)).collect::<Vec<_>>();
+        assert_eq!(names, expected);
+
+        let names = animation
+            .take_track_events()
+            .into_iter()
+            .map(|event| event.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, expected);
+    }
+
+    #[test]
+    fn test_signal_wrap_around() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Early", 0.0625));
+        animation.add_signal(AnimationSignal::new(Default::default(), "Late", 0.9375));
+
+        let fired = |animation: &mut Animation, dt: f32| {
+            animation.tick(dt);
+            let mut names = Vec::new();
+            while let Some(event) = animation.pop_event() {
+                names.push(event.name);
+            }
+            names
+        };
+
+        // Signals on both sides of the loop seam are fired in playback order.
+        animation.set_time_position(0.875);
+        assert_eq!(fired(&mut animation, 0.25), vec!["Late", "Early"]);
+        assert_eq!(animation.time_position(), 0.125);
+        assert_eq!(fired(&mut animation, 0.25), Vec::<String>::new());
+
+        // The same when playing backwards.
+        animation.set_speed(-1.0);
+        animation.set_time_position(0.125);
+        assert_eq!(fired(&mut animation, 0.25), vec!["Early", "Late"]);
+        assert_eq!(animation.time_position(), 0.875);
+
+        // A signal that is crossed before and after a reflection is fired twice.
+        animation.set_speed(1.0);
+        animation.set_loop_mode(LoopMode::PingPong);
+        animation.set_time_position(0.875);
+        assert_eq!(fired(&mut animation, 0.25), vec!["Late", "Late"]);
+        assert_eq!(animation.time_position(), 0.875);
+    }
+
+    #[test]
+    fn test_drain_events() {
+        let mut container = AnimationContainer::new();
+        let mut handles = Vec::new();
+        for name in ["Walk", "Run"] {
+            let mut animation = Animation::default();
+            animation.set_time_slice(0.0..1.0);
+            animation.add_signal(AnimationSignal::new(Default::default(), name, 0.1));
+            animation.add_signal(AnimationSignal::new(Default::default(), "Step", 0.2));
+            let mut event_track = EventTrack::new("Sounds");
+            event_track.add_key(EventKey::new(0.3, "Footstep", name));
+            animation.add_event_track(event_track);
+            handles.push(container.add(animation));
+        }
+
+        for animation in container.iter_mut() {
+            animation.tick(0.5);
+        }
+
+        let events = container.drain_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 4);
+        for (handle, name) in handles.iter().zip(["Walk", "Run"]) {
+            let names = events
+                .iter()
+                .filter(|(animation, _)| animation == handle)
+                .map(|(_, event)| event.name.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec![name, "Step"]);
+        }
+
+        assert_eq!(container.drain_events().count(), 0);
+        assert!(container
+            .iter()
+            .all(|animation| animation.events_ref().is_empty()));
+
+        // Events of event tracks are left intact and drained separately.
+        let events = container.drain_track_events().collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        for (handle, name) in handles.iter().zip(["Walk", "Run"]) {
+            let payloads = events
+                .iter()
+                .filter(|(animation, _)| animation == handle)
+                .map(|(_, event)| event.payload.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(payloads, vec![name]);
         }
-    }
 
-    /// Returns an iterator that yields a number of references to tracks that refer to a given node.
-    pub fn tracks_of(&self, handle: Handle<Node>) -> impl Iterator<Item = &Track> {
-        self.tracks
+        assert_eq!(container.drain_track_events().count(), 0);
+        assert!(container
             .iter()
-            .filter(move |track| track.target() == handle)
+            .all(|animation| animation.track_events_ref().is_empty()));
     }
 
-    /// Returns an iterator that yields a number of references to tracks that refer to a given node.
-    pub fn tracks_of_mut(&mut self, handle: Handle<Node>) -> impl Iterator<Item = &mut Track> {
-        self.tracks
-            .iter_mut()
-            .filter(move |track| track.target() == handle)
-    }
+    #[test]
+    fn test_playback_direction() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        animation.set_loop_mode(LoopMode::Once);
+        animation.set_direction(PlaybackDirection::Backward);
 
-    /// Tries to find a layer by its name. Returns index of the signal and its reference.
-    #[inline]
-    pub fn find_signal_by_name_ref<S: AsRef<str>>(
-        &self,
-        name: S,
-    ) -> Option<(usize, &AnimationSignal)> {
-        utils::find_by_name_ref(self.signals.iter().enumerate(), name)
-    }
+        // Backward playback starts at the end of the time slice.
+        animation.rewind();
+        assert_eq!(animation.time_position(), 1.0);
+        animation.tick(0.25);
+        assert_eq!(animation.time_position(), 0.75);
 
-    /// Tries to find a signal by its name. Returns index of the signal and its reference.
-    #[inline]
-    pub fn find_signal_by_name_mut<S: AsRef<str>>(
-        &mut self,
-        name: S,
-    ) -> Option<(usize, &mut AnimationSignal)> {
-        utils::find_by_name_mut(self.signals.iter_mut().enumerate(), name)
-    }
+        // Negative speed in backward direction plays the animation forward.
+        animation.set_speed(-1.0);
+        animation.tick(0.25);
+        assert_eq!(animation.time_position(), 1.0);
 
-    /// Returns `true` if there's a signal with given name and id.
-    #[inline]
-    pub fn has_signal<S: AsRef<str>>(&self, name: S, id: Uuid) -> bool {
-        self.find_signal_by_name_ref(name)
-            .map_or(false, |(_, s)| s.id == id)
+        // Backward playback ends at the start of the time slice.
+        animation.set_speed(1.0);
+        animation.tick(0.5);
+        assert!(!animation.has_ended());
+        animation.tick(0.75);
+        assert_eq!(animation.time_position(), 0.0);
+        assert!(animation.has_ended());
+
+        let mut visitor = Visitor::new();
+        animation.visit("Animation", &mut visitor).unwrap();
+        let mut data = Vec::new();
+        visitor.save_binary_to_memory(&mut data).unwrap();
+        let mut visitor = Visitor::load_from_memory(data).unwrap();
+        let mut loaded = Animation::default();
+        loaded.visit("Animation", &mut visitor).unwrap();
+        assert_eq!(loaded.direction(), PlaybackDirection::Backward);
     }
 
-    /// Removes all tracks from the animation.
-    pub fn remove_tracks(&mut self) {
-        self.tracks.clear();
+    #[test]
+    fn test_reversed() {
+        let mut track = Track::new_position();
+        track.set_target(Handle::new(1, 1));
+        let curves = track.data_container_mut().curves_mut();
+        curves[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(0.5, 2.0, CurveKeyKind::new_cubic(0.3, -0.7)),
+            CurveKey::new(1.0, -1.0, CurveKeyKind::Constant),
+            CurveKey::new(1.5, 5.0, CurveKeyKind::new_cubic(0.1, 0.4)),
+            CurveKey::new(2.0, 1.0, CurveKeyKind::Linear),
+        ]);
+        curves[1] = Curve::from(vec![
+            CurveKey::new(0.0, 1.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(0.7, 3.0, CurveKeyKind::CatmullRom),
+            CurveKey::new(1.2, -2.0, CurveKeyKind::Linear),
+            CurveKey::new(2.0, 0.0, CurveKeyKind::Constant),
+        ]);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Signal", 0.5));
+        animation.set_time_slice(0.0..2.0);
+
+        let sample = |animation: &Animation, time: f32| match animation.tracks()[0]
+            .data_container()
+            .fetch(time)
+        {
+            Some(TrackValue::Vector3(v)) => v,
+            _ => panic!("Position track must produce a vector!"),
+        };
+
+        let reversed = animation.reversed();
+        assert_eq!(reversed.signals()[0].time, 1.5);
+
+        // Steps of constant keys are sampled between the keys, values exactly at the steps may differ after two
+        // reversals.
+        let round_trip = reversed.reversed();
+        for i in 0..40 {
+            let time = i as f32 * 0.05 + 0.013;
+            let expected = sample(&animation, time);
+            assert!((sample(&reversed, 2.0 - time) - expected).norm() < 1.0e-4);
+            assert!((sample(&round_trip, time) - expected).norm() < 1.0e-4);
+        }
+        assert_eq!(round_trip.signals()[0].time, 0.5);
     }
 
-    fn update_pose(&mut self) {
-        self.pose.reset();
-        for track in self.tracks.iter() {
-            if track.is_enabled() {
-                if let Some(bound_value) = track.fetch(self.time_position) {
-                    self.pose.add_to_node_pose(track.target(), bound_value);
+    #[test]
+    fn test_loop_range() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..3.0);
+        animation.set_loop_mode(LoopMode::Once);
+        animation.set_loop_range(Some(1.0..2.0));
+        animation.add_signal(AnimationSignal::new(Default::default(), "Loop", 1.25));
+        animation.add_signal(AnimationSignal::new(Default::default(), "Outro", 2.5));
+
+        let count_events = |animation: &mut Animation, name: &str| {
+            let mut count = 0;
+            while let Some(event) = animation.pop_event() {
+                if event.name == name {
+                    count += 1;
                 }
             }
-        }
-    }
+            count
+        };
 
-    /// Returns current pose of the animation (a final result that can be applied to a scene graph).
-    pub fn pose(&self) -> &AnimationPose {
-        &self.pose
+        // The intro is played once, then the playhead cycles within the loop range.
+        animation.tick(0.5);
+        animation.tick(1.0);
+        assert!((animation.time_position() - 1.5).abs() < 1.0e-5);
+        assert_eq!(count_events(&mut animation, "Loop"), 1);
+
+        animation.tick(2.0);
+        assert!((animation.time_position() - 1.5).abs() < 1.0e-5);
+        assert_eq!(count_events(&mut animation, "Loop"), 2);
+
+        // Exit continues seamlessly to the end of the time slice.
+        animation.exit_loop();
+        animation.tick(1.0);
+        assert!((animation.time_position() - 2.5).abs() < 1.0e-5);
+        assert_eq!(count_events(&mut animation, "Outro"), 1);
+
+        animation.tick(1.0);
+        assert!(animation.has_ended());
+
+        // Rewinding enters the loop again.
+        animation.rewind();
+        assert!(!animation.is_exiting_loop());
+        animation.tick(2.5);
+        assert!((animation.time_position() - 1.5).abs() < 1.0e-5);
     }
-}
 
-impl Default for Animation {
-    fn default() -> Self {
-        Self {
-            name: Default::default(),
-            tracks: Vec::new(),
-            speed: 1.0,
-            time_position: 0.0,
-            enabled: true,
-            looped: true,
-            pose: Default::default(),
-            signals: Default::default(),
-            events: Default::default(),
-            time_slice: Default::default(),
+    #[test]
+    fn test_step_frames() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..1.0);
+        animation.add_signal(AnimationSignal::new(Default::default(), "Signal", 0.5));
+
+        let fps = 10.0;
+        for frame in 1..=7 {
+            animation.step_frames(1, fps);
+            assert_eq!(animation.frame_index(fps), frame);
         }
-    }
-}
+        assert!(animation.pop_event().is_some());
+        assert!(animation.pop_event().is_none());
 
-/// A container for animations. It is a tiny wrapper around [`Pool`], you should never create the container yourself,
-/// it is managed by the engine.
-#[derive(Debug, Clone, Reflect, PartialEq)]
-pub struct AnimationContainer {
-    pool: Pool<Animation>,
-}
+        // Non-looping animations are clamped.
+        animation.set_loop_mode(LoopMode::Once);
+        animation.step_frames(5, fps);
+        assert_eq!(animation.frame_index(fps), 10);
+        animation.step_frames(-15, fps);
+        assert_eq!(animation.frame_index(fps), 0);
 
-impl Default for AnimationContainer {
-    fn default() -> Self {
-        Self::new()
+        // Looping animations wrap around.
+        animation.set_loop_mode(LoopMode::Loop);
+        animation.step_frames(-2, fps);
+        assert_eq!(animation.frame_index(fps), 8);
     }
-}
 
-impl AnimationContainer {
-    /// Creates an empty animation container.
-    pub fn new() -> Self {
-        Self { pool: Pool::new() }
-    }
+    #[test]
+    fn test_binding_presets() {
+        let mut graph = Graph::new();
+        let camera = CameraBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let pivot = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
 
-    /// Returns a total amount of animations in the container.
-    #[inline]
-    pub fn alive_count(&self) -> u32 {
-        self.pool.alive_count()
-    }
+        let mut animation = Animation::default();
+        for target in [camera, pivot] {
+            let mut track = Track::new(
+                TrackDataContainer::new(TrackValueKind::Real),
+                ValueBinding::camera_fov(),
+            );
+            track.data_container_mut().curves_mut()[0] =
+                Curve::from(vec![CurveKey::new(0.0, 0.5, CurveKeyKind::Constant)]);
+            track.set_target(target);
+            animation.add_track(track);
+        }
 
-    /// Returns an iterator yielding a references to animations in the container.
-    #[inline]
-    pub fn iter(&self) -> impl Iterator<Item = &Animation> {
-        self.pool.iter()
-    }
+        let mut container = AnimationContainer::new();
+        container.add(animation);
+        container.update_and_apply(&mut graph, true, 0.1);
 
-    /// Returns an iterator yielding a pair (handle, reference) to animations in the container.
-    #[inline]
-    pub fn pair_iter(&self) -> impl Iterator<Item = (Handle<Animation>, &Animation)> {
-        self.pool.pair_iter()
-    }
+        match graph[camera].cast::<Camera>().unwrap().projection() {
+            Projection::Perspective(perspective) => assert_eq!(perspective.fov, 0.5),
+            Projection::Orthographic(_) => panic!("Camera must use perspective projection!"),
+        }
 
-    /// Returns an iterator yielding a pair (handle, reference) to animations in the container.
-    #[inline]
-    pub fn pair_iter_mut(&mut self) -> impl Iterator<Item = (Handle<Animation>, &mut Animation)> {
-        self.pool.pair_iter_mut()
+        // The binding is not supported by pivots.
+        let issues = container.validate(&graph);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].target, pivot);
     }
 
-    /// Returns an iterator yielding a references to animations in the container.
-    #[inline]
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut Animation> {
-        self.pool.iter_mut()
-    }
+    #[test]
+    fn test_world_space_tracks() {
+        let mut graph = Graph::new();
+        let child = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let parent = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        graph.link_nodes(child, parent);
+        graph[parent]
+            .local_transform_mut()
+            .set_position(Vector3::new(10.0, 0.0, 0.0))
+            .set_rotation(UnitQuaternion::from_axis_angle(
+                &Vector3::y_axis(),
+                FRAC_PI_2,
+            ))
+            .set_scale(Vector3::repeat(2.0));
+        graph.update_hierarchical_data();
 
-    /// Adds a new animation to the container and returns its handle.
-    #[inline]
-    pub fn add(&mut self, animation: Animation) -> Handle<Animation> {
-        self.pool.spawn(animation)
-    }
+        let mut animation = Animation::default();
+        for (mut track, value) in [
+            (Track::new_position(), Vector3::new(10.0, 0.0, -4.0)),
+            (Track::new_rotation(), Vector3::new(0.0, FRAC_PI_2, 0.0)),
+            (Track::new_scale(), Vector3::new(4.0, 2.0, 2.0)),
+        ] {
+            for (curve, component) in track
+                .data_container_mut()
+                .curves_mut()
+                .iter_mut()
+                .zip(value.iter())
+            {
+                *curve = Curve::from(vec![CurveKey::new(0.0, *component, CurveKeyKind::Constant)]);
+            }
+            track.set_target(child);
+            track.set_space(TransformSpace::World);
+            animation.add_track(track);
+        }
 
-    /// Tries to remove an animation from the container by its handle.
-    #[inline]
-    pub fn remove(&mut self, handle: Handle<Animation>) -> Option<Animation> {
-        self.pool.try_free(handle)
-    }
+        let mut container = AnimationContainer::new();
+        container.add(animation);
+        container.update_and_apply(&mut graph, true, 0.1);
 
-    /// Extracts animation from container and reserves its handle. It is used to temporarily take
-    /// ownership over animation, and then put animation back using given ticket.
-    pub fn take_reserve(&mut self, handle: Handle<Animation>) -> (Ticket<Animation>, Animation) {
-        self.pool.take_reserve(handle)
-    }
+        let transform = graph[child].local_transform();
+        assert!((**transform.position() - Vector3::new(2.0, 0.0, 0.0)).norm() < 1.0e-4);
+        assert!(transform.rotation().angle() < 1.0e-4);
+        assert!((**transform.scale() - Vector3::new(2.0, 1.0, 1.0)).norm() < 1.0e-4);
 
-    /// Puts animation back by given ticket.
-    pub fn put_back(
-        &mut self,
-        ticket: Ticket<Animation>,
-        animation: Animation,
-    ) -> Handle<Animation> {
-        self.pool.put_back(ticket, animation)
+        graph.update_hierarchical_data();
+        assert!((graph[child].global_position() - Vector3::new(10.0, 0.0, -4.0)).norm() < 1.0e-4);
     }
 
-    /// Makes animation handle vacant again.
-    pub fn forget_ticket(&mut self, ticket: Ticket<Animation>) {
-        self.pool.forget_ticket(ticket)
-    }
+    #[test]
+    fn test_pose_weight_check() {
+        let node = Handle::new(1, 1);
+        let mut animation_pose = AnimationPose::default();
+        animation_pose.add_to_node_pose(
+            node,
+            BoundValue {
+                binding: ValueBinding::Position,
+                value: TrackValue::Vector3(Vector3::new(2.0, 0.0, 0.0)),
+                components: Default::default(),
+                scale_blending: Default::default(),
+                weight: 1.0,
+                space: Default::default(),
+                error_flag: Default::default(),
+            },
+        );
+        assert_eq!(animation_pose.accumulated_weight(node), Some(1.0));
 
-    /// Removes all animations.
-    #[inline]
-    pub fn clear(&mut self) {
-        self.pool.clear()
-    }
+        // Misconfigured weights, that sum up to 1.5.
+        let mut pose = AnimationPose::default();
+        pose.blend_with(&animation_pose, 1.0);
+        pose.blend_with(&animation_pose, 0.5);
+        assert_eq!(pose.accumulated_weight(node), Some(1.5));
+        assert_eq!(pose.accumulated_weight(Handle::new(2, 1)), None);
+        assert_eq!(pose.accumulated_weights().count(), 1);
 
-    /// Tries to borrow a reference to an animation in the container. Panics if the handle is invalid.
-    #[inline]
-    pub fn get(&self, handle: Handle<Animation>) -> &Animation {
-        self.pool.borrow(handle)
-    }
+        pose.check_weights(WeightCheck::Warn);
+        assert_eq!(pose.accumulated_weight(node), Some(1.5));
 
-    /// Tries to borrow a mutable reference to an animation in the container. Panics if the handle is invalid.
-    #[inline]
-    pub fn get_mut(&mut self, handle: Handle<Animation>) -> &mut Animation {
-        self.pool.borrow_mut(handle)
+        pose.check_weights(WeightCheck::Normalize);
+        assert_eq!(pose.accumulated_weight(node), Some(1.0));
+        match pose.poses()[&node].values.values[0].value {
+            TrackValue::Vector3(v) => assert!((v - Vector3::new(2.0, 0.0, 0.0)).norm() < 1.0e-4),
+            _ => panic!("Position must be a vector!"),
+        }
     }
 
-    /// Tries to borrow a reference to an animation in the container.
-    #[inline]
-    pub fn try_get(&self, handle: Handle<Animation>) -> Option<&Animation> {
-        self.pool.try_borrow(handle)
+    #[test]
+    fn test_hold_on_end() {
+        let node = Handle::new(1, 1);
+        let mut track = Track::new_position();
+        track.set_target(node);
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::Linear),
+        ]);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+        animation.set_loop_mode(LoopMode::Once);
+        assert!(animation.is_hold_on_end());
+
+        let is_final_pose = |animation: &Animation| match animation
+            .pose()
+            .poses()
+            .get(&node)
+            .map(|pose| &pose.values.values[0].value)
+        {
+            Some(TrackValue::Vector3(v)) => (v.x - 1.0).abs() < 1.0e-4,
+            _ => false,
+        };
+
+        for _ in 0..15 {
+            animation.tick(0.1);
+        }
+        assert!(animation.has_ended());
+        assert!(is_final_pose(&animation));
+
+        let mut released = animation.clone();
+        released.set_hold_on_end(false);
+        released.tick(0.1);
+        assert!(released.pose().poses().is_empty());
+
+        // The final frame must be applied once before the pose is released.
+        released.rewind();
+        while !released.has_ended() {
+            released.tick(0.1);
+        }
+        assert!(is_final_pose(&released));
+        released.tick(0.1);
+        assert!(released.pose().poses().is_empty());
     }
 
-    /// Tries to borrow a mutable reference to an animation in the container.
-    #[inline]
-    pub fn try_get_mut(&mut self, handle: Handle<Animation>) -> Option<&mut Animation> {
-        self.pool.try_borrow_mut(handle)
+    #[test]
+    fn test_animation_auto_apply() {
+        let mut graph = Graph::new();
+        let face = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let body = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let make_animation = |node: Handle<Node>| {
+            let mut track = Track::new_position();
+            track.set_target(node);
+            track.data_container_mut().curves_mut()[0] =
+                Curve::from(vec![CurveKey::new(0.0, 1.0, CurveKeyKind::Linear)]);
+            let mut animation = Animation::default();
+            animation.add_track(track);
+            animation.set_time_slice(0.0..1.0);
+            animation
+        };
+
+        let mut container = AnimationContainer::new();
+        let blink = container.add(make_animation(face));
+        container.add(make_animation(body));
+        container[blink].set_auto_apply(true);
+
+        // Automatic applying is disabled (for example, by a state machine), only opted in animations are applied.
+        container.update_and_apply(&mut graph, false, 0.1);
+        assert_eq!(graph[face].local_transform().position().x, 1.0);
+        assert_eq!(graph[body].local_transform().position().x, 0.0);
+
+        container.update_and_apply(&mut graph, true, 0.1);
+        assert_eq!(graph[body].local_transform().position().x, 1.0);
     }
 
-    /// Tries to find an animation by its name in the container.
-    #[inline]
-    pub fn find_by_name_ref<S: AsRef<str>>(
-        &self,
-        name: S,
-    ) -> Option<(Handle<Animation>, &Animation)> {
-        utils::find_by_name_ref(self.pool.pair_iter(), name)
+    #[test]
+    fn test_cross_fade() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let make_animation = |x: f32, enabled: bool| {
+            let mut track = Track::new_position();
+            track.set_target(node);
+            track.data_container_mut().curves_mut()[0] =
+                Curve::from(vec![CurveKey::new(0.0, x, CurveKeyKind::Constant)]);
+            let mut animation = Animation::default();
+            animation.add_track(track);
+            animation.set_time_slice(0.0..1.0);
+            animation.set_enabled(enabled);
+            animation
+        };
+
+        let mut container = AnimationContainer::new();
+        let walk = container.add(make_animation(1.0, true));
+        let run = container.add(make_animation(3.0, false));
+        let idle = container.add(make_animation(5.0, false));
+        let position = |graph: &Graph| graph[node].local_transform().position().x;
+
+        container.fade_to(run, 0.5);
+        assert!(container[run].is_enabled());
+        container.update_and_apply(&mut graph, true, 0.25);
+        assert_eq!(container.cross_fade_progress(), Some(0.5));
+        assert_eq!(position(&graph), 2.0);
+
+        // The source is disabled when the cross-fade is done.
+        container.update_and_apply(&mut graph, true, 0.25);
+        assert_eq!(container.cross_fade_progress(), None);
+        assert_eq!(position(&graph), 3.0);
+        assert!(!container[walk].is_enabled());
+
+        // Fading to the same animation again does not restart the cross-fade.
+        container.fade_to(walk, 1.0);
+        container.update_and_apply(&mut graph, true, 0.25);
+        container.fade_to(walk, 1.0);
+        assert_eq!(container.cross_fade_progress(), Some(0.25));
+        assert_eq!(position(&graph), 2.5);
+
+        // Interrupted cross-fade continues from its target, its source is disabled immediately.
+        container.fade_to(idle, 1.0);
+        assert!(!container[run].is_enabled());
+        container.update_and_apply(&mut graph, true, 0.25);
+        assert_eq!(position(&graph), 2.0);
     }
 
-    /// Tries to find an animation by its name in the container.
-    #[inline]
-    pub fn find_by_name_mut<S: AsRef<str>>(
-        &mut self,
-        name: S,
-    ) -> Option<(Handle<Animation>, &mut Animation)> {
-        utils::find_by_name_mut(self.pool.pair_iter_mut(), name)
+    #[test]
+    fn test_animation_instancing() {
+        let mut track = Track::new_position();
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::Linear),
+        ]);
+        let mut clip = Animation::default();
+        clip.add_track(track);
+        clip.set_time_slice(0.0..1.0);
+        clip.set_time_position(0.5);
+
+        let mut instance = clip.instantiate();
+        assert_eq!(instance.time_position(), 0.0);
+        assert!(instance.shares_track_data_with(&clip));
+
+        // Retargeting keeps the data shared.
+        instance.tracks_mut()[0].set_target(Handle::new(1, 1));
+        assert!(instance.shares_track_data_with(&clip));
+
+        // Modification of the curves makes a copy.
+        instance.tracks_mut()[0].data_container_mut().curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(0.0, 2.0, CurveKeyKind::Linear)]);
+        assert!(!instance.shares_track_data_with(&clip));
+        assert_eq!(
+            clip.tracks()[0].data_container().curves_ref()[0]
+                .keys()
+                .len(),
+            2
+        );
+
+        // Shared data is saved inline and loaded back.
+        let other = clip.instantiate();
+        let mut visitor = Visitor::new();
+        clip.visit("Animation", &mut visitor).unwrap();
+        let data = visitor.save_binary_to_vec().unwrap();
+        let mut visitor = Visitor::load_from_memory(data).unwrap();
+        let mut loaded = Animation::default();
+        loaded.visit("Animation", &mut visitor).unwrap();
+        assert_eq!(loaded.tracks(), other.tracks());
+        assert!(other.shares_track_data_with(&clip));
     }
 
-    /// Removes every animation from the container that does not satisfy a particular condition represented by the given
-    /// closue.
-    #[inline]
-    pub fn retain<P>(&mut self, pred: P)
-    where
-        P: FnMut(&Animation) -> bool,
-    {
-        self.pool.retain(pred)
+    #[test]
+    fn test_rotation_shortest_path() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        // Keys straddle 180 degrees boundary, the rotation must not go the long way through zero angle.
+        let mut track = Track::new_rotation();
+        track.set_target(node);
+        for (location, angle) in [(0.0, 170.0f32), (1.0, -170.0)] {
+            assert!(track.data_container_mut().add_rotation_key(
+                location,
+                &UnitQuaternion::from_axis_angle(&Vector3::z_axis(), angle.to_radians()),
+            ));
+        }
+        let keys = track.data_container().curves_ref()[2].keys();
+        assert!((keys[1].value - 190.0f32.to_radians()).abs() < 1.0e-5);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+        animation.set_time_position(0.5).pause();
+        animation.tick(0.0);
+        animation.pose().apply(&mut graph);
+
+        let rotation = **graph[node].local_transform().rotation();
+        assert!((rotation.angle() - 180.0f32.to_radians()).abs() < 1.0e-3);
+        let axis = rotation * Vector3::x();
+        assert!((axis + Vector3::x()).norm() < 1.0e-3);
     }
 
-    /// Updates all animations in the container and applies their poses to respective nodes. This method is intended to
-    /// be used only by the internals of the engine!
-    pub fn update_animations(&mut self, nodes: &mut NodePool, apply: bool, dt: f32) {
-        for animation in self.pool.iter_mut().filter(|anim| anim.enabled) {
-            animation.tick(dt);
-            if apply {
-                animation.pose.apply_internal(nodes);
-            }
+    #[test]
+    fn test_container_bulk_control() {
+        let mut container = AnimationContainer::new();
+        for _ in 0..2 {
+            let mut animation = Animation::default();
+            animation.set_time_slice(0.0..1.0);
+            container.add(animation);
         }
+
+        container.update_and_apply(&mut Graph::new(), false, 0.5);
+        container.disable_all();
+        assert!(container.iter().all(|animation| !animation.is_enabled()));
+
+        container.enable_all();
+        assert!(container.iter().all(|animation| animation.is_enabled()));
+
+        container.reset_all();
+        assert!(container
+            .iter()
+            .all(|animation| animation.time_position() == 0.0));
     }
 
-    /// Removes queued animation events from every animation in the container.
-    ///
-    /// # Potential use cases
-    ///
-    /// Sometimes there is a need to use animation events only from one frame, in this case you should clear events each frame.
-    /// This situation might come up when you have multiple animations with signals, but at each frame not every event gets
-    /// processed. This might result in unwanted side effects, like multiple attack events may result in huge damage in a single
-    /// frame.
-    pub fn clear_animation_events(&mut self) {
-        for animation in self.pool.iter_mut() {
-            animation.events.clear();
+    #[test]
+    fn test_time_offset() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..2.0);
+        animation.set_loop_mode(LoopMode::Loop);
+
+        animation.set_time_offset(0.5);
+        assert_eq!(animation.time_position(), 0.5);
+        animation.tick(0.25);
+        animation.rewind();
+        assert_eq!(animation.time_position(), 0.5);
+
+        // Looping animations wrap the offset around.
+        animation.set_time_offset(2.5);
+        assert_eq!(animation.time_offset(), 2.5);
+        assert_eq!(animation.time_position(), 0.5);
+
+        let mut container = AnimationContainer::new();
+        for _ in 0..4 {
+            container.add(animation.clone());
         }
+        container.randomize_time_offsets(&mut StdRng::seed_from_u64(123));
+        let offsets = container
+            .iter()
+            .map(|animation| animation.time_offset())
+            .collect::<Vec<_>>();
+        assert!(offsets.iter().all(|offset| (0.0..2.0).contains(offset)));
+
+        // The same seed gives the same offsets.
+        container.randomize_time_offsets(&mut StdRng::seed_from_u64(123));
+        assert!(container
+            .iter()
+            .zip(offsets)
+            .all(|(animation, offset)| animation.time_offset() == offset));
     }
-}
 
-impl Visit for AnimationContainer {
-    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
-        if visitor.is_reading() && self.pool.get_capacity() != 0 {
-            panic!("Animation pool must be empty on load!");
+    #[test]
+    fn test_pose_cache() {
+        let node = Handle::new(1, 1);
+        let mut track = Track::new_position();
+        track.set_target(node);
+        track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 10.0, CurveKeyKind::Linear),
+        ]);
+
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+        animation.set_time_position(0.5).pause();
+
+        let position_x =
+            |animation: &Animation| match animation.pose().poses()[&node].values.values[0].value {
+                TrackValue::Vector3(v) => v.x,
+                _ => unreachable!(),
+            };
+
+        animation.tick(0.1);
+        assert_eq!(position_x(&animation), 5.0);
+
+        // Paused animation keeps its pose.
+        animation.tick(0.1);
+        assert_eq!(position_x(&animation), 5.0);
+
+        // Modification of the keys invalidates the cached pose.
+        animation.tracks_mut()[0].data_container_mut().curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 20.0, CurveKeyKind::Linear),
+        ]);
+        animation.tick(0.1);
+        assert_eq!(position_x(&animation), 10.0);
+
+        // So does the change of time position.
+        animation.set_time_position(0.25);
+        animation.tick(0.1);
+        assert_eq!(position_x(&animation), 5.0);
+
+        // Disabling tracks of a node.
+        animation.set_node_track_enabled(node, false);
+        animation.tick(0.1);
+        assert!(animation.pose().poses().is_empty());
+        animation.set_node_track_enabled(node, true);
+        animation.tick(0.1);
+        assert_eq!(position_x(&animation), 5.0);
+
+        // Modification of tracks of a node.
+        for track in animation.tracks_of_mut(node) {
+            track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+                CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+                CurveKey::new(1.0, 40.0, CurveKeyKind::Linear),
+            ]);
         }
+        animation.tick(0.1);
+        assert_eq!(position_x(&animation), 10.0);
 
-        let mut region = visitor.enter_region(name)?;
+        // Speed changes.
+        animation.tick(0.1);
+        animation.set_speed(2.0);
+        assert!(animation.pose_time.is_none());
 
-        self.pool.visit("Pool", &mut region)?;
+        // Edits of an animation, that was borrowed from a container (for example, by the editor or using
+        // reflection), are visible at the same time position.
+        let mut graph = Graph::new();
+        let mut container = AnimationContainer::new();
+        let animation = container.add(animation);
+        container.update_and_apply(&mut graph, false, 0.1);
+        *container[animation]
+            .get_resolve_path_mut::<bool>("tracks[0].enabled")
+            .unwrap() = false;
+        container.update_and_apply(&mut graph, false, 0.1);
+        assert!(container[animation].pose().poses().is_empty());
 
-        Ok(())
+        if let Some(animation) = container.try_get_mut(animation) {
+            *animation
+                .get_resolve_path_mut::<bool>("tracks[0].enabled")
+                .unwrap() = true;
+        }
+        container.update_and_apply(&mut graph, false, 0.1);
+        assert_eq!(position_x(&container[animation]), 10.0);
+        assert_eq!(container[animation].time_position(), 0.25);
     }
-}
 
-impl Index<Handle<Animation>> for AnimationContainer {
-    type Output = Animation;
+    #[test]
+    fn test_discrete_track_values() {
+        let fetch = |container: &TrackDataContainer, time: f32| match container.fetch(time) {
+            Some(TrackValue::Real(value)) => value,
+            _ => unreachable!(),
+        };
 
-    fn index(&self, index: Handle<Animation>) -> &Self::Output {
-        &self.pool[index]
-    }
-}
+        // Linear keys are used intentionally here to check quantization of smeared values.
+        let mut container = TrackDataContainer::new(TrackValueKind::Bool);
+        container.curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 1.0, CurveKeyKind::Linear),
+        ]);
+        assert_eq!(fetch(&container, 0.0), 0.0);
+        assert_eq!(fetch(&container, 0.49), 0.0);
+        assert_eq!(fetch(&container, 0.5), 1.0);
+        assert_eq!(fetch(&container, 1.0), 1.0);
 
-impl IndexMut<Handle<Animation>> for AnimationContainer {
-    fn index_mut(&mut self, index: Handle<Animation>) -> &mut Self::Output {
-        &mut self.pool[index]
+        let mut container = TrackDataContainer::new(TrackValueKind::Integer);
+        container.curves_mut()[0] = Curve::from(vec![
+            CurveKey::new(0.0, 2.0, CurveKeyKind::Linear),
+            CurveKey::new(1.0, 3.0, CurveKeyKind::Linear),
+        ]);
+        assert_eq!(fetch(&container, 0.4), 2.0);
+        assert_eq!(fetch(&container, 0.6), 3.0);
+        container.curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(0.0, -2.6, CurveKeyKind::Constant)]);
+        assert_eq!(fetch(&container, 0.0), -3.0);
+
+        // Keys added without an explicit kind are stepped for discrete values.
+        for (kind, expected) in [
+            (TrackValueKind::Bool, CurveKeyKind::Constant),
+            (TrackValueKind::Integer, CurveKeyKind::Constant),
+            (TrackValueKind::Real, CurveKeyKind::Linear),
+        ] {
+            let mut container = TrackDataContainer::new(kind);
+            assert!(container.add_key(0, 0.0, 0.0));
+            assert!(container.add_key(0, 1.0, 5.0));
+            assert!(!container.add_key(1, 0.0, 0.0));
+            assert!(container.curves_ref()[0]
+                .keys()
+                .iter()
+                .all(|key| key.kind == expected));
+        }
+
+        let mut container = TrackDataContainer::new(TrackValueKind::Integer);
+        container.add_key(0, 0.0, 0.0);
+        container.add_key(0, 1.0, 5.0);
+        assert_eq!(fetch(&container, 0.9), 0.0);
+        assert_eq!(fetch(&container, 1.0), 5.0);
     }
 }
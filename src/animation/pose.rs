@@ -1,17 +1,28 @@
 //! Pose is a set of property values of a node ([`NodePose`]) or a set of nodes ([`AnimationPose`]).
 
 use crate::{
-    animation::{value::BoundValue, value::BoundValueCollection},
-    core::pool::Handle,
-    scene::{graph::Graph, graph::NodePool, node::Node},
+    animation::{
+        value::BoundValue,
+        value::{BindingErrorFlag, BoundValueCollection, TrackValue, TransformSpace, ValueBinding},
+    },
+    core::{
+        algebra::{Matrix4, UnitQuaternion, Vector3},
+        math::quat_nlerp_shortest,
+        pool::Handle,
+        reflect::prelude::*,
+        visitor::prelude::*,
+    },
+    scene::{graph::Graph, graph::NodePool, mesh::Mesh, node::Node},
     utils::log::{Log, MessageKind},
 };
 use fxhash::FxHashMap;
-use std::collections::hash_map::Entry;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::hash_map::Entry};
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
 
 /// A "captured" state of properties of some animated scene node. The pose can be considered as container of values of some
 /// properties.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Visit)]
 pub struct NodePose {
     /// A handle of an animated node.
     pub node: Handle<Node>,
@@ -45,10 +56,102 @@ impl NodePose {
     }
 }
 
-/// Animations pose is a set of node poses. See [`NodePose`] docs for more info.
-#[derive(Default, Debug, Clone, PartialEq)]
+/// Root motion of a single update of an animation, or a blend of root motions of multiple animations. See
+/// [`super::RootMotionSettings`] docs for more info.
+#[derive(Clone, Debug, PartialEq, Visit)]
+pub struct RootMotion {
+    /// Movement of the root node.
+    pub delta_position: Vector3<f32>,
+
+    /// Rotation of the root node.
+    pub delta_rotation: UnitQuaternion<f32>,
+}
+
+impl Default for RootMotion {
+    fn default() -> Self {
+        Self {
+            delta_position: Default::default(),
+            delta_rotation: UnitQuaternion::identity(),
+        }
+    }
+}
+
+impl RootMotion {
+    /// Accumulates weighted root motion. See [`AnimationPose::blend_with`] docs for more info.
+    pub fn blend_with(&mut self, other: &RootMotion, weight: f32) {
+        self.delta_position += other.delta_position.scale(weight);
+        self.delta_rotation *=
+            quat_nlerp_shortest(&UnitQuaternion::identity(), &other.delta_rotation, weight);
+        self.delta_rotation.renormalize();
+    }
+
+    /// Interpolates root motion towards another. See [`AnimationPose::interpolate_with`] docs for more info.
+    pub fn interpolate_with(&mut self, other: &RootMotion, t: f32) {
+        self.delta_position = self.delta_position.lerp(&other.delta_position, t);
+        self.delta_rotation = quat_nlerp_shortest(&self.delta_rotation, &other.delta_rotation, t);
+    }
+}
+
+/// Maximum accumulated weight of a node pose, that is still considered normal. Small excess is possible due to
+/// floating-point errors of weight calculations. See [`WeightCheck`] docs for more info.
+pub const MAX_ACCUMULATED_WEIGHT: f32 = 1.01;
+
+/// Defines what to do with node poses, whose accumulated weight exceeds [`MAX_ACCUMULATED_WEIGHT`]. Numbers and
+/// vectors are accumulated when poses are blended (see [`AnimationPose::blend_with`]), which means that if the weights
+/// of the blended poses sum up to more than 1.0, the resulting values will be "overshot" - for example, blending two
+/// equal positions with weights of 1.0 each gives a doubled position. It is usually caused by misconfigured blend
+/// weights of a state machine, and it is hard to spot.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum WeightCheck {
+    /// Accumulated weights are not checked.
+    None,
+
+    /// A warning is written to the log for a node pose with excessive accumulated weight. The warning is written only once
+    /// per pose, since poses are checked every frame. The pose itself is left untouched.
+    Warn,
+
+    /// Values of node poses with excessive accumulated weight are divided by the weight, so the result is a weighted
+    /// average of the blended poses. Rotations are left as is, since they're interpolated, not accumulated.
+    Normalize,
+}
+
+impl Default for WeightCheck {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Animations pose is a set of node poses. See [`NodePose`] docs for more info. The pose is serializable, so it could be
+/// saved and applied later (see [`super::Animation::snapshot_pose`] docs for more info).
+///
+/// The pose also tracks accumulated weight of every node pose, that was blended into it using [`Self::blend_with`].
+/// The weights could be used to detect misconfigured blending, see [`Self::accumulated_weight`] and
+/// [`Self::check_weights`] for more info.
+#[derive(Default, Debug, Clone, PartialEq, Visit)]
 pub struct AnimationPose {
     poses: FxHashMap<Handle<Node>, NodePose>,
+    #[visit(optional)] // Backward compatibility
+    root_motion: Option<RootMotion>,
+    // Node poses, that are not in the map, have the weight of 1.0.
+    #[visit(skip)]
+    weights: FxHashMap<Handle<Node>, f32>,
+    // Poses are checked every frame, so an excessive weight is reported only once per pose. The flag survives
+    // resets of the pose.
+    #[visit(skip)]
+    weight_warning_flag: BindingErrorFlag,
 }
 
 impl AnimationPose {
@@ -58,20 +161,84 @@ impl AnimationPose {
         for (handle, local_pose) in self.poses.iter() {
             dest.poses.insert(*handle, local_pose.clone());
         }
+        dest.root_motion = self.root_motion.clone();
+        dest.weights = self.weights.clone();
+    }
+
+    fn weight_of(&self, node: &Handle<Node>) -> f32 {
+        self.weights.get(node).cloned().unwrap_or(1.0)
     }
 
     /// Blends current animation pose with another using a weight coefficient. Missing node poses (from either animation poses)
     /// will become a weighted copies of a respective node pose.
+    ///
+    /// This is exactly the same blending that is used internally by the animation blending state machine (see
+    /// [`super::machine::BlendAnimations`]): numbers and vectors are accumulated (`current + other * weight`), while
+    /// rotations are interpolated along the shortest path (`nlerp(current, other, weight)`). It means that to get a weighted average of a few
+    /// poses, you should start from an empty pose and blend every pose into it with the weights that sum up to 1.0. Use
+    /// [`Self::interpolate_with`] if you need to interpolate between two poses. Root motion (see [`Self::root_motion`])
+    /// is blended the same way, using the same weight.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{Animation, AnimationPose};
+    ///
+    /// fn mix(walk: &Animation, aim: &Animation, aim_weight: f32) -> AnimationPose {
+    ///     let mut pose = AnimationPose::default();
+    ///     pose.blend_with(walk.pose(), 1.0 - aim_weight);
+    ///     pose.blend_with(aim.pose(), aim_weight);
+    ///     pose
+    /// }
+    /// ```
     pub fn blend_with(&mut self, other: &AnimationPose, weight: f32) {
         for (handle, other_pose) in other.poses.iter() {
+            let other_weight = other.weight_of(handle) * weight;
             if let Some(current_pose) = self.poses.get_mut(handle) {
                 current_pose.blend_with(other_pose, weight);
+                let current_weight = self.weight_of(handle);
+                self.weights.insert(*handle, current_weight + other_weight);
             } else {
                 // There are no corresponding local pose, do fake blend between identity
                 // pose and other.
                 self.add_node_pose(other_pose.weighted_clone(weight));
+                self.weights.insert(*handle, other_weight);
             }
         }
+
+        if let Some(other_root_motion) = other.root_motion.as_ref() {
+            self.root_motion
+                .get_or_insert_with(Default::default)
+                .blend_with(other_root_motion, weight);
+        }
+    }
+
+    /// Interpolates current animation pose towards another using an interpolation coefficient. Unlike [`Self::blend_with`],
+    /// node poses (and their values) that present only in one of the poses are not weighted - the values from the current
+    /// pose are left untouched and missing values are copied from the other pose. It is used to perform smooth cross-fade
+    /// between two animations that animate partially-overlapping sets of properties. Root motion is interpolated too,
+    /// missing root motion is treated as no motion, so the motion of a pose fades in (or out) smoothly.
+    pub fn interpolate_with(&mut self, other: &AnimationPose, t: f32) {
+        for (handle, other_pose) in other.poses.iter() {
+            let other_weight = other.weight_of(handle);
+            if let Some(current_pose) = self.poses.get_mut(handle) {
+                current_pose.values.interpolate_with(&other_pose.values, t);
+                let current_weight = self.weight_of(handle);
+                self.weights.insert(
+                    *handle,
+                    current_weight + (other_weight - current_weight) * t,
+                );
+            } else {
+                self.add_node_pose(other_pose.clone());
+                self.weights.insert(*handle, other_weight);
+            }
+        }
+
+        if self.root_motion.is_some() || other.root_motion.is_some() {
+            self.root_motion
+                .get_or_insert_with(Default::default)
+                .interpolate_with(&other.root_motion.clone().unwrap_or_default(), t);
+        }
     }
 
     fn add_node_pose(&mut self, local_pose: NodePose) {
@@ -97,6 +264,127 @@ impl AnimationPose {
     /// Clears the pose.
     pub fn reset(&mut self) {
         self.poses.clear();
+        self.root_motion = None;
+        self.weights.clear();
+    }
+
+    /// Replaces the pose with a weighted average of the given poses. Weights are normalized (so they sum up to 1.0),
+    /// negative weights are treated as zero. Unlike [`Self::blend_with`], the average is calculated per-track: every
+    /// value is averaged among the poses that have it, so a value that is present only in some of the poses is not
+    /// weakened by the poses that do not animate it. Rotations are averaged along the shortest path. Root motions are
+    /// blended using the normalized weights. The pose stays empty if the total weight is zero.
+    pub fn set_weighted_average(&mut self, poses: &[(&AnimationPose, f32)]) {
+        self.reset();
+
+        let total_weight = poses.iter().map(|(_, weight)| weight.max(0.0)).sum::<f32>();
+        if total_weight <= 0.0 {
+            return;
+        }
+
+        let mut accumulator = WeightedPoseAccumulator::default();
+        for (pose, weight) in poses {
+            let weight = weight.max(0.0) / total_weight;
+            accumulator.add(pose, weight);
+
+            if let Some(root_motion) = pose.root_motion.as_ref().filter(|_| weight > 0.0) {
+                self.root_motion
+                    .get_or_insert_with(Default::default)
+                    .blend_with(root_motion, weight);
+            }
+        }
+
+        for (node, values) in accumulator.values {
+            for (mut bound_value, weight) in values {
+                if !matches!(bound_value.value, TrackValue::UnitQuaternion(_)) {
+                    bound_value.value = bound_value.value.weighted_clone(1.0 / weight);
+                }
+                self.add_to_node_pose(node, bound_value);
+            }
+        }
+    }
+
+    /// Returns accumulated weight of the pose of the given node, or `None` if there is no pose for the node. The weight
+    /// is a sum of weights of every pose that was blended into the current one using [`Self::blend_with`] (interpolation
+    /// interpolates the weights). Poses of animations have the weight of 1.0. Normally the weight should not exceed
+    /// 1.0, see [`WeightCheck`] docs for more info.
+    pub fn accumulated_weight(&self, node: Handle<Node>) -> Option<f32> {
+        if self.poses.contains_key(&node) {
+            Some(self.weight_of(&node))
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over accumulated weights of every node pose. It is useful for debugging of blending, see
+    /// [`Self::accumulated_weight`] docs for more info.
+    pub fn accumulated_weights(&self) -> impl Iterator<Item = (Handle<Node>, f32)> + '_ {
+        self.poses
+            .keys()
+            .map(move |handle| (*handle, self.weight_of(handle)))
+    }
+
+    /// Checks accumulated weights of every node pose and either reports or normalizes node poses whose accumulated
+    /// weight exceeds [`MAX_ACCUMULATED_WEIGHT`]. Normalized node poses have the weight of 1.0 after the call. See
+    /// [`WeightCheck`] docs for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{pose::WeightCheck, Animation, AnimationPose};
+    ///
+    /// fn mix(walk: &Animation, run: &Animation, walk_weight: f32, run_weight: f32) -> AnimationPose {
+    ///     let mut pose = AnimationPose::default();
+    ///     pose.blend_with(walk.pose(), walk_weight);
+    ///     pose.blend_with(run.pose(), run_weight);
+    ///     // Weights might sum up to more than 1.0, normalize the pose to get a weighted average.
+    ///     pose.check_weights(WeightCheck::Normalize);
+    ///     pose
+    /// }
+    /// ```
+    pub fn check_weights(&mut self, check: WeightCheck) {
+        if check == WeightCheck::None {
+            return;
+        }
+
+        for (handle, weight) in self.weights.iter_mut() {
+            if *weight <= MAX_ACCUMULATED_WEIGHT {
+                continue;
+            }
+
+            match check {
+                WeightCheck::Warn => {
+                    if self.weight_warning_flag.raise() {
+                        Log::warn(format!(
+                            "Accumulated weight of the pose of node {handle} exceeds 1.0 ({weight}), check blend weights!"
+                        ))
+                    }
+                }
+                WeightCheck::Normalize => {
+                    if let Some(node_pose) = self.poses.get_mut(handle) {
+                        for bound_value in node_pose.values.values.iter_mut() {
+                            if !matches!(bound_value.value, TrackValue::UnitQuaternion(_)) {
+                                *bound_value = bound_value.weighted_clone(1.0 / *weight);
+                            }
+                        }
+                    }
+                    *weight = 1.0;
+                }
+                WeightCheck::None => (),
+            }
+        }
+    }
+
+    /// Sets new root motion of the pose.
+    pub fn set_root_motion(&mut self, root_motion: Option<RootMotion>) {
+        self.root_motion = root_motion;
+    }
+
+    /// Returns root motion of the pose (if any). For poses of animations it is the motion of the root node of the last
+    /// update of the animation (see [`super::RootMotionSettings`]). For poses of animation blending state machines it is
+    /// the blended motion of every animation that contributes to the pose - the motions are blended using the same
+    /// weights as the poses themselves, so there are no sudden jumps during transitions between states.
+    pub fn root_motion(&self) -> Option<&RootMotion> {
+        self.root_motion.as_ref()
     }
 
     /// Returns a reference to inner node pose map.
@@ -109,27 +397,44 @@ impl AnimationPose {
         &mut self.poses
     }
 
-    pub(crate) fn apply_internal(&self, nodes: &mut NodePool) {
+    pub(crate) fn apply_internal<N: NodeAccess>(&self, nodes: &mut N) {
         for (node, local_pose) in self.poses.iter() {
             if node.is_none() {
                 Log::writeln(MessageKind::Error, "Invalid node handle found for animation pose, most likely it means that animation retargeting failed!");
-            } else if let Some(node) = nodes.try_borrow_mut(*node) {
-                local_pose.values.apply(node);
+            } else {
+                let values = local_space_values(&local_pose.values, || {
+                    parent_transform(nodes.node(*node), |parent| nodes.node(parent))
+                });
+                if let Some(node) = nodes.node_mut(*node) {
+                    values.apply(node);
+                }
             }
         }
     }
 
-    /// Tries to set each value to the each property from the animation pose to respective scene nodes.
+    /// Tries to set each value to the each property from the animation pose to respective scene nodes. Values in
+    /// world space (see [`TransformSpace`]) are converted to the local space of their nodes using current global
+    /// transforms of the parents of the nodes.
     pub fn apply(&self, graph: &mut Graph) {
+        self.apply_internal(graph)
+    }
+
+    pub(crate) fn apply_additive_internal<N: NodeAccess>(&self, nodes: &mut N) {
         for (node, local_pose) in self.poses.iter() {
             if node.is_none() {
                 Log::writeln(MessageKind::Error, "Invalid node handle found for animation pose, most likely it means that animation retargeting failed!");
-            } else if let Some(node) = graph.try_get_mut(*node) {
-                local_pose.values.apply(node);
+            } else if let Some(node) = nodes.node_mut(*node) {
+                local_pose.values.apply_additive(node);
             }
         }
     }
 
+    /// Tries to add each value from the animation pose to each respective property of scene nodes. Values of the pose are
+    /// treated as deltas, see [`BoundValueCollection::apply_additive`] docs for more info.
+    pub fn apply_additive(&self, graph: &mut Graph) {
+        self.apply_additive_internal(graph)
+    }
+
     /// Calls given callback function for each node and allows you to apply pose with your own
     /// rules. This could be useful if you need to ignore transform some part of pose for a node.
     pub fn apply_with<C>(&self, graph: &mut Graph, mut callback: C)
@@ -145,3 +450,152 @@ impl AnimationPose {
         }
     }
 }
+
+/// Accumulates weighted contributions of multiple animation poses and applies the result to scene nodes. Values with the
+/// same binding are averaged using their weights, if the total weight of a value is less than one, the value is blended
+/// with the current value of the respective property (only for position, rotation and scale bindings).
+#[derive(Default, Debug)]
+pub(crate) struct WeightedPoseAccumulator {
+    values: FxHashMap<Handle<Node>, Vec<(BoundValue, f32)>>,
+}
+
+impl WeightedPoseAccumulator {
+    pub(crate) fn add(&mut self, pose: &AnimationPose, weight: f32) {
+        if weight <= 0.0 {
+            return;
+        }
+
+        for (node, node_pose) in pose.poses.iter() {
+            let values = self.values.entry(*node).or_default();
+            for bound_value in node_pose.values.values.iter() {
+                // Faded values contribute less to the result.
+                let weight = weight * bound_value.weight;
+                if weight <= 0.0 {
+                    continue;
+                }
+
+                if let Some((accumulated, total_weight)) = values
+                    .iter_mut()
+                    .find(|(value, _)| value.binding == bound_value.binding)
+                {
+                    *total_weight += weight;
+                    if let (TrackValue::UnitQuaternion(a), TrackValue::UnitQuaternion(b)) =
+                        (&mut accumulated.value, &bound_value.value)
+                    {
+                        // Running weighted average of rotations.
+                        *a = quat_nlerp_shortest(a, b, weight / *total_weight);
+                    } else {
+                        accumulated.blend_with(bound_value, weight);
+                    }
+                } else {
+                    values.push((bound_value.weighted_clone(weight), weight));
+                }
+            }
+        }
+    }
+
+    pub(crate) fn apply_internal<N: NodeAccess>(&self, nodes: &mut N) {
+        for (node, values) in self.values.iter() {
+            let parent = if values
+                .iter()
+                .any(|(value, _)| value.space == TransformSpace::World)
+            {
+                parent_transform(nodes.node(*node), |parent| nodes.node(parent))
+            } else {
+                Matrix4::identity()
+            };
+
+            if let Some(node_ref) = nodes.node_mut(*node) {
+                for (accumulated, total_weight) in values.iter() {
+                    let mut bound_value = accumulated.to_local_space(&parent);
+                    // The weights of the values are already accounted in the total weight.
+                    bound_value.weight = 1.0;
+
+                    if !matches!(bound_value.value, TrackValue::UnitQuaternion(_)) {
+                        bound_value.value = bound_value.value.weighted_clone(1.0 / *total_weight);
+                    }
+
+                    if *total_weight < 1.0 {
+                        if let Some(blended) = current_value(node_ref, &bound_value.binding)
+                            .and_then(|current| {
+                                current.interpolate(&bound_value.value, *total_weight)
+                            })
+                        {
+                            bound_value.value = blended;
+                        }
+                    }
+
+                    bound_value.apply(node_ref);
+                }
+            }
+        }
+    }
+}
+
+/// Provides access to scene nodes, to which poses are applied. It allows to use the same code to apply poses to a
+/// graph and to a bare pool of nodes (which is used during the update of the graph).
+pub(crate) trait NodeAccess {
+    fn node(&self, handle: Handle<Node>) -> Option<&Node>;
+
+    fn node_mut(&mut self, handle: Handle<Node>) -> Option<&mut Node>;
+}
+
+impl NodeAccess for NodePool {
+    fn node(&self, handle: Handle<Node>) -> Option<&Node> {
+        self.try_borrow(handle)
+    }
+
+    fn node_mut(&mut self, handle: Handle<Node>) -> Option<&mut Node> {
+        self.try_borrow_mut(handle)
+    }
+}
+
+impl NodeAccess for Graph {
+    fn node(&self, handle: Handle<Node>) -> Option<&Node> {
+        self.try_get(handle)
+    }
+
+    fn node_mut(&mut self, handle: Handle<Node>) -> Option<&mut Node> {
+        self.try_get_mut(handle)
+    }
+}
+
+/// Returns global transform of the parent of the given node, or identity matrix if there's no parent.
+fn parent_transform<'a, F>(node: Option<&'a Node>, get: F) -> Matrix4<f32>
+where
+    F: FnOnce(Handle<Node>) -> Option<&'a Node>,
+{
+    node.and_then(|node| get(node.parent()))
+        .map(|parent| parent.global_transform())
+        .unwrap_or_else(Matrix4::identity)
+}
+
+/// Converts world-space values of the collection to local space, the collection is copied only if there's at least
+/// one value in world space.
+fn local_space_values<F>(
+    values: &BoundValueCollection,
+    parent_transform: F,
+) -> Cow<BoundValueCollection>
+where
+    F: FnOnce() -> Matrix4<f32>,
+{
+    if values.has_world_space_values() {
+        Cow::Owned(values.to_local_space(&parent_transform()))
+    } else {
+        Cow::Borrowed(values)
+    }
+}
+
+fn current_value(node: &Node, binding: &ValueBinding) -> Option<TrackValue> {
+    let transform = node.local_transform();
+    match binding {
+        ValueBinding::Position => Some(TrackValue::Vector3(**transform.position())),
+        ValueBinding::Scale => Some(TrackValue::Vector3(**transform.scale())),
+        ValueBinding::Rotation => Some(TrackValue::UnitQuaternion(**transform.rotation())),
+        ValueBinding::Property { .. } => None,
+        ValueBinding::BlendShape { name } => node
+            .cast::<Mesh>()
+            .and_then(|mesh| mesh.blend_shape_weight(name))
+            .map(TrackValue::Real),
+    }
+}
@@ -0,0 +1,668 @@
+//! Quantization of animation tracks reduces the size of serialized animations (for example, long skeletal animations
+//! in scene files). See [`QuantizedTrackData`] docs for more info.
+
+use crate::{
+    animation::container::{TrackDataContainer, TrackValueKind},
+    core::{
+        algebra::{Quaternion, UnitQuaternion, Vector3},
+        curve::{Curve, CurveKey, CurveKeyKind, Extrapolation},
+        uuid::Uuid,
+        visitor::{prelude::*, PodVecView},
+    },
+};
+use std::f32::consts::{FRAC_1_SQRT_2, PI, SQRT_2};
+
+/// Maximum amount of bits per value of fixed-point curves.
+const MAX_VALUE_BITS: u32 = 24;
+
+/// Amount of bits per component of smallest-three quaternions.
+const MIN_ROTATION_BITS: u32 = 6;
+const MAX_ROTATION_BITS: u32 = 20;
+
+/// Writes values of arbitrary bit width one after another.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bit: usize,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, bits: u32) {
+        for i in 0..bits {
+            if self.bit % 8 == 0 {
+                self.bytes.push(0);
+            }
+            if (value >> i) & 1 != 0 {
+                *self.bytes.last_mut().unwrap() |= 1 << (self.bit % 8);
+            }
+            self.bit += 1;
+        }
+    }
+}
+
+/// Reads values written by [`BitWriter`], missing bits are read as zeros.
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    bit: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, bit: 0 }
+    }
+
+    fn read(&mut self, bits: u32) -> u32 {
+        let mut value = 0;
+        for i in 0..bits {
+            let byte = self.bytes.get(self.bit / 8).cloned().unwrap_or_default();
+            if (byte >> (self.bit % 8)) & 1 != 0 {
+                value |= 1 << i;
+            }
+            self.bit += 1;
+        }
+        value
+    }
+}
+
+/// Returns the amount of bits required to store every integer in `[0; max]` range.
+fn bits_for(max: u32) -> u32 {
+    32 - max.leading_zeros()
+}
+
+fn mask(bits: u32) -> u32 {
+    ((1u64 << bits) - 1) as u32
+}
+
+/// Everything of a curve except its keys.
+#[derive(Default, Debug, Clone, PartialEq, Visit)]
+struct CurveHeader {
+    id: Uuid,
+    name: String,
+    pre_extrapolation: Extrapolation,
+    post_extrapolation: Extrapolation,
+}
+
+impl CurveHeader {
+    fn new(curve: &Curve) -> Self {
+        Self {
+            id: curve.id(),
+            name: curve.name().to_owned(),
+            pre_extrapolation: curve.pre_extrapolation(),
+            post_extrapolation: curve.post_extrapolation(),
+        }
+    }
+
+    fn make_curve(&self, keys: Vec<CurveKey>) -> Curve {
+        let mut curve = Curve::from(keys);
+        curve.set_id(self.id);
+        curve.set_name(&self.name);
+        curve.set_extrapolation(self.pre_extrapolation, self.post_extrapolation);
+        curve
+    }
+}
+
+/// Kinds of the keys of a curve, tangents of cubic keys are stored as is.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct KeyKinds {
+    kinds: Vec<u8>,
+    tangents: Vec<f32>,
+}
+
+impl KeyKinds {
+    fn new(curve: &Curve) -> Self {
+        let mut key_kinds = Self::default();
+        for key in curve.keys() {
+            let kind = match key.kind {
+                CurveKeyKind::Constant => 0,
+                CurveKeyKind::Linear => 1,
+                CurveKeyKind::Cubic {
+                    left_tangent,
+                    right_tangent,
+                } => {
+                    key_kinds.tangents.push(left_tangent);
+                    key_kinds.tangents.push(right_tangent);
+                    2
+                }
+                CurveKeyKind::CatmullRom => 3,
+                CurveKeyKind::Smooth => 4,
+            };
+            key_kinds.kinds.push(kind);
+        }
+        key_kinds
+    }
+
+    fn decode(&self) -> Vec<CurveKeyKind> {
+        let mut tangents = self.tangents.iter().cloned();
+        self.kinds
+            .iter()
+            .map(|kind| match kind {
+                0 => CurveKeyKind::Constant,
+                2 => CurveKeyKind::Cubic {
+                    left_tangent: tangents.next().unwrap_or_default(),
+                    right_tangent: tangents.next().unwrap_or_default(),
+                },
+                3 => CurveKeyKind::CatmullRom,
+                4 => CurveKeyKind::Smooth,
+                _ => CurveKeyKind::Linear,
+            })
+            .collect()
+    }
+}
+
+impl Visit for KeyKinds {
+    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+        let mut region = visitor.enter_region(name)?;
+
+        PodVecView::from_pod_vec(&mut self.kinds).visit("Kinds", &mut region)?;
+        PodVecView::from_pod_vec(&mut self.tangents).visit("Tangents", &mut region)?;
+
+        Ok(())
+    }
+}
+
+/// A curve with values stored as fixed-point numbers within the range of the values of the curve.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct QuantizedCurve {
+    header: CurveHeader,
+    locations: Vec<f32>,
+    kinds: KeyKinds,
+    min: f32,
+    step: f32,
+    bits: u32,
+    values: Vec<u8>,
+}
+
+impl QuantizedCurve {
+    fn new(curve: &Curve, max_error: f32) -> Self {
+        let keys = curve.keys();
+
+        let (min, max) = keys.iter().fold((f32::MAX, f32::MIN), |(min, max), key| {
+            (min.min(key.value), max.max(key.value))
+        });
+        let (min, range) = if keys.is_empty() {
+            (0.0, 0.0)
+        } else {
+            (min, max - min)
+        };
+
+        // Rounding to the nearest step gives at most half of the step error.
+        let max_steps = mask(MAX_VALUE_BITS) as f32;
+        let step = (2.0 * max_error).max(range / max_steps);
+        let bits = if step > 0.0 {
+            bits_for((range / step).ceil().min(max_steps) as u32)
+        } else {
+            0
+        };
+
+        let mut writer = BitWriter::default();
+        for key in keys {
+            let value = if step > 0.0 {
+                ((key.value - min) / step).round() as u32
+            } else {
+                0
+            };
+            writer.write(value.min(mask(bits)), bits);
+        }
+
+        Self {
+            header: CurveHeader::new(curve),
+            locations: keys.iter().map(|key| key.location()).collect(),
+            kinds: KeyKinds::new(curve),
+            min,
+            step,
+            bits,
+            values: writer.bytes,
+        }
+    }
+
+    fn decode(&self) -> Curve {
+        let mut reader = BitReader::new(&self.values);
+        let keys = self
+            .locations
+            .iter()
+            .zip(self.kinds.decode())
+            .map(|(location, kind)| {
+                let value = self.min + reader.read(self.bits) as f32 * self.step;
+                CurveKey::new(*location, value, kind)
+            })
+            .collect();
+        self.header.make_curve(keys)
+    }
+}
+
+impl Visit for QuantizedCurve {
+    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+        let mut region = visitor.enter_region(name)?;
+
+        self.header.visit("Header", &mut region)?;
+        PodVecView::from_pod_vec(&mut self.locations).visit("Locations", &mut region)?;
+        self.kinds.visit("Kinds", &mut region)?;
+        self.min.visit("Min", &mut region)?;
+        self.step.visit("Step", &mut region)?;
+        self.bits.visit("Bits", &mut region)?;
+        PodVecView::from_pod_vec(&mut self.values).visit("Values", &mut region)?;
+
+        Ok(())
+    }
+}
+
+/// Packs the quaternion using "smallest three" compression - the largest component is dropped (it is restored from
+/// the other three, because the quaternion is unit) and the rest are stored as fixed-point numbers in
+/// `[-1/sqrt(2); 1/sqrt(2)]` range.
+fn pack_quaternion(writer: &mut BitWriter, rotation: &UnitQuaternion<f32>, bits: u32) {
+    let coords = rotation.coords;
+    let largest = (1..4).fold(0, |largest, i| {
+        if coords[i].abs() > coords[largest].abs() {
+            i
+        } else {
+            largest
+        }
+    });
+    // `q` and `-q` represent the same rotation, so the largest component is always positive.
+    let sign = if coords[largest] < 0.0 { -1.0 } else { 1.0 };
+
+    writer.write(largest as u32, 2);
+    let max = mask(bits) as f32;
+    for i in (0..4).filter(|i| *i != largest) {
+        let normalized = (coords[i] * sign / FRAC_1_SQRT_2 + 1.0) * 0.5;
+        writer.write((normalized * max).round().clamp(0.0, max) as u32, bits);
+    }
+}
+
+fn unpack_quaternion(reader: &mut BitReader, bits: u32) -> UnitQuaternion<f32> {
+    let largest = reader.read(2) as usize;
+    let max = mask(bits) as f32;
+    let mut coords = [0.0; 4];
+    let mut sum = 0.0;
+    for (i, coord) in coords.iter_mut().enumerate() {
+        if i != largest {
+            *coord = (reader.read(bits) as f32 / max * 2.0 - 1.0) * FRAC_1_SQRT_2;
+            sum += *coord * *coord;
+        }
+    }
+    coords[largest] = (1.0f32 - sum).max(0.0).sqrt();
+    UnitQuaternion::new_normalize(Quaternion::new(coords[3], coords[0], coords[1], coords[2]))
+}
+
+/// Returns the angle, that is equivalent to the given one, but is closest to the reference angle.
+fn unwrap_angle(angle: f32, reference: f32) -> f32 {
+    angle + ((reference - angle) / (2.0 * PI)).round() * 2.0 * PI
+}
+
+/// Returns Euler angles of the rotation, that are closest to the given ones. Rotation tracks store Euler angles and
+/// they must stay continuous after decompression, so every set of equivalent angles is considered.
+fn closest_euler_angles(rotation: &UnitQuaternion<f32>, reference: &Vector3<f32>) -> Vector3<f32> {
+    let (roll, pitch, yaw) = rotation.euler_angles();
+    [
+        Vector3::new(roll, pitch, yaw),
+        Vector3::new(roll + PI, PI - pitch, yaw + PI),
+    ]
+    .iter()
+    .map(|angles| {
+        Vector3::new(
+            unwrap_angle(angles.x, reference.x),
+            unwrap_angle(angles.y, reference.y),
+            unwrap_angle(angles.z, reference.z),
+        )
+    })
+    .min_by(|a, b| {
+        (a - reference)
+            .norm_squared()
+            .partial_cmp(&(b - reference).norm_squared())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })
+    .unwrap_or(*reference)
+}
+
+/// Rotation curves (Euler angles) with shared key locations, that are stored as smallest-three quaternions.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct QuantizedRotation {
+    headers: Vec<CurveHeader>,
+    kinds: Vec<KeyKinds>,
+    locations: Vec<f32>,
+    first: Vector3<f32>,
+    bits: u32,
+    rotations: Vec<u8>,
+}
+
+impl QuantizedRotation {
+    /// Tries to quantize the curves with angular error no more than the given one. Returns `None` if the curves do
+    /// not share key locations or if the Euler angles cannot be restored with the required precision.
+    fn new(curves: &[Curve], max_error: f32) -> Option<Self> {
+        let first_keys = curves.first()?.keys();
+        if curves.len() != 3
+            || first_keys.is_empty()
+            || !curves.iter().all(|curve| {
+                curve.keys().len() == first_keys.len()
+                    && curve
+                        .keys()
+                        .iter()
+                        .zip(first_keys)
+                        .all(|(a, b)| a.location() == b.location())
+            })
+        {
+            return None;
+        }
+
+        let angles = (0..first_keys.len())
+            .map(|i| {
+                Vector3::new(
+                    curves[0].keys()[i].value,
+                    curves[1].keys()[i].value,
+                    curves[2].keys()[i].value,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Angular error is roughly four times larger than the error of the components in the worst case.
+        let bits = if max_error > 0.0 {
+            bits_for((4.0 * SQRT_2 / max_error).min(u32::MAX as f32) as u32)
+        } else {
+            MAX_ROTATION_BITS
+        }
+        .clamp(MIN_ROTATION_BITS, MAX_ROTATION_BITS);
+
+        let mut writer = BitWriter::default();
+        for angles in angles.iter() {
+            pack_quaternion(
+                &mut writer,
+                &UnitQuaternion::from_euler_angles(angles.x, angles.y, angles.z),
+                bits,
+            );
+        }
+
+        let quantized = Self {
+            headers: curves.iter().map(CurveHeader::new).collect(),
+            kinds: curves.iter().map(KeyKinds::new).collect(),
+            locations: first_keys.iter().map(|key| key.location()).collect(),
+            first: angles[0],
+            bits,
+            rotations: writer.bytes,
+        };
+
+        // Decompressed angles could differ from the source ones a lot (for example, near gimbal lock or if there's a
+        // jump between adjacent keys), so the result must be checked.
+        if quantized
+            .decode_angles()
+            .iter()
+            .zip(angles.iter())
+            .all(|(decoded, source)| (decoded - source).amax() <= max_error)
+        {
+            Some(quantized)
+        } else {
+            None
+        }
+    }
+
+    fn decode_angles(&self) -> Vec<Vector3<f32>> {
+        let mut reader = BitReader::new(&self.rotations);
+        let mut angles = Vec::with_capacity(self.locations.len());
+        let mut prev = self.first;
+        for _ in 0..self.locations.len() {
+            let rotation = unpack_quaternion(&mut reader, self.bits);
+            prev = closest_euler_angles(&rotation, &prev);
+            angles.push(prev);
+        }
+        angles
+    }
+
+    fn decode(&self) -> Vec<Curve> {
+        let angles = self.decode_angles();
+        self.headers
+            .iter()
+            .zip(self.kinds.iter())
+            .enumerate()
+            .map(|(component, (header, kinds))| {
+                let keys = self
+                    .locations
+                    .iter()
+                    .zip(angles.iter())
+                    .zip(kinds.decode())
+                    .map(|((location, angles), kind)| {
+                        CurveKey::new(*location, angles[component], kind)
+                    })
+                    .collect();
+                header.make_curve(keys)
+            })
+            .collect()
+    }
+}
+
+impl Visit for QuantizedRotation {
+    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+        let mut region = visitor.enter_region(name)?;
+
+        self.headers.visit("Headers", &mut region)?;
+        self.kinds.visit("Kinds", &mut region)?;
+        PodVecView::from_pod_vec(&mut self.locations).visit("Locations", &mut region)?;
+        self.first.visit("First", &mut region)?;
+        self.bits.visit("Bits", &mut region)?;
+        PodVecView::from_pod_vec(&mut self.rotations).visit("Rotations", &mut region)?;
+
+        Ok(())
+    }
+}
+
+/// Compact (lossy) representation of the curves of a track, that is used to save quantized tracks (see
+/// [`crate::animation::track::Track::set_quantization`]). It stores key values with the precision, that is just
+/// enough to keep the error below the given maximum:
+///
+/// - Rotations are stored as "smallest three" quaternions - the largest component of a unit quaternion is dropped
+/// and the rest are stored as fixed-point numbers, the amount of bits per component depends on the maximum error
+/// (angular, in radians). Rotation tracks store Euler angles, so the quaternions are converted back to continuous
+/// Euler angles on load. If the curves of the track do not share key locations, or the angles cannot be restored
+/// within the error (which could happen near gimbal lock), the angles are stored as any other values.
+/// - Any other values (positions, scales, numeric properties) are stored as fixed-point numbers within the bounding
+/// range of the values of each curve, the amount of bits per value depends on the maximum error and on the range.
+///
+/// Key locations and tangents of cubic keys are stored as is, ids of the keys are not preserved. The error is
+/// guaranteed for the values of the keys, interpolated values between the keys could have slightly larger error
+/// for cubic and automatic tangents.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct QuantizedTrackData {
+    kind: TrackValueKind,
+    rotation: Option<QuantizedRotation>,
+    curves: Vec<QuantizedCurve>,
+}
+
+impl QuantizedTrackData {
+    /// Quantizes the curves of the given container with the given maximum error. Negative errors are treated as
+    /// zero, which means the highest possible precision.
+    pub fn new(container: &TrackDataContainer, max_error: f32) -> Self {
+        let max_error = max_error.max(0.0);
+        let curves = container.curves_ref();
+
+        let rotation = if container.value_kind() == TrackValueKind::UnitQuaternion {
+            QuantizedRotation::new(curves, max_error)
+        } else {
+            None
+        };
+
+        Self {
+            kind: container.value_kind(),
+            curves: if rotation.is_some() {
+                Default::default()
+            } else {
+                curves
+                    .iter()
+                    .map(|curve| QuantizedCurve::new(curve, max_error))
+                    .collect()
+            },
+            rotation,
+        }
+    }
+
+    /// Restores the curves of the track.
+    pub fn decode(&self) -> TrackDataContainer {
+        let curves = if let Some(rotation) = self.rotation.as_ref() {
+            rotation.decode()
+        } else {
+            self.curves.iter().map(QuantizedCurve::decode).collect()
+        };
+
+        let mut container = TrackDataContainer::default();
+        container.set_value_kind(self.kind);
+        for curve in curves {
+            container.add_curve(curve);
+        }
+        container
+    }
+}
+
+impl Visit for QuantizedTrackData {
+    fn visit(&mut self, name: &str, visitor: &mut Visitor) -> VisitResult {
+        let mut region = visitor.enter_region(name)?;
+
+        self.kind.visit("Kind", &mut region)?;
+        self.rotation.visit("Rotation", &mut region)?;
+        self.curves.visit("Curves", &mut region)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{quantization::QuantizedTrackData, track::Track, value::TrackValue},
+        core::{
+            curve::{Curve, CurveKey, CurveKeyKind},
+            visitor::prelude::*,
+        },
+    };
+    use std::f32::consts::FRAC_PI_2;
+
+    fn make_curve(func: fn(f32) -> f32) -> Curve {
+        Curve::from(
+            (0..=300)
+                .map(|i| {
+                    let time = i as f32 / 30.0;
+                    CurveKey::new(time, func(time), CurveKeyKind::Linear)
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn make_track(mut track: Track, funcs: [fn(f32) -> f32; 3]) -> Track {
+        for (curve, func) in track
+            .data_container_mut()
+            .curves_mut()
+            .iter_mut()
+            .zip(funcs.iter())
+        {
+            *curve = make_curve(*func);
+        }
+        track.set_quantization(Some(1.0e-3));
+        track
+    }
+
+    fn make_tracks() -> Vec<Track> {
+        vec![
+            make_track(
+                Track::new_position(),
+                [|t| 10.0 * t.sin(), |t| t * t, |_| -3.0],
+            ),
+            // Angles go beyond `[-pi; pi]` range.
+            make_track(
+                Track::new_rotation(),
+                [|t| 0.7 * t, |t| 0.3 * t.cos(), |t| 0.1 * t],
+            ),
+            make_track(
+                Track::new_scale(),
+                [|t| 1.0 + 0.5 * t.sin(), |_| 1.0, |_| 1.0],
+            ),
+        ]
+    }
+
+    fn save(tracks: &mut Vec<Track>) -> Vec<u8> {
+        let mut visitor = Visitor::new();
+        tracks.visit("Tracks", &mut visitor).unwrap();
+        visitor.save_binary_to_vec().unwrap()
+    }
+
+    #[test]
+    fn test_quantization_round_trip() {
+        let max_error = 1.0e-3;
+        let mut tracks = make_tracks();
+        let source = tracks.clone();
+
+        // Rotation curves must be compressed as quaternions.
+        assert!(
+            QuantizedTrackData::new(tracks[1].data_container(), max_error)
+                .rotation
+                .is_some()
+        );
+
+        let quantized_data = save(&mut tracks);
+        // Runtime curves are not affected by saving.
+        assert_eq!(tracks, source);
+
+        let mut visitor = Visitor::load_from_memory(quantized_data.clone()).unwrap();
+        let mut loaded = Vec::<Track>::new();
+        loaded.visit("Tracks", &mut visitor).unwrap();
+        assert_eq!(loaded.len(), source.len());
+
+        for (loaded, source) in loaded.iter().zip(source.iter()) {
+            assert_eq!(loaded.quantization(), Some(max_error));
+            for (loaded, source) in loaded
+                .data_container()
+                .curves_ref()
+                .iter()
+                .zip(source.data_container().curves_ref())
+            {
+                assert_eq!(loaded.id(), source.id());
+                assert_eq!(loaded.keys().len(), source.keys().len());
+                for (a, b) in loaded.keys().iter().zip(source.keys()) {
+                    assert_eq!(a.location(), b.location());
+                    assert!((a.value - b.value).abs() <= max_error * 1.01);
+                }
+            }
+
+            for i in 0..1000 {
+                let time = i as f32 / 100.0 + 0.0013;
+                match (
+                    loaded.data_container().fetch(time),
+                    source.data_container().fetch(time),
+                ) {
+                    (Some(TrackValue::Vector3(a)), Some(TrackValue::Vector3(b))) => {
+                        assert!((a - b).amax() <= max_error * 1.01)
+                    }
+                    (Some(TrackValue::UnitQuaternion(a)), Some(TrackValue::UnitQuaternion(b))) => {
+                        assert!(a.angle_to(&b) <= 3.1 * max_error)
+                    }
+                    _ => panic!("Tracks must produce values of the same kind!"),
+                }
+            }
+        }
+
+        // Quantized tracks are much smaller than the source ones.
+        let mut uncompressed = source;
+        for track in uncompressed.iter_mut() {
+            track.set_quantization(None);
+        }
+        assert!(quantized_data.len() * 4 < save(&mut uncompressed).len());
+
+        // Angles near gimbal lock could be stored as regular values, the error is still within the tolerance.
+        let rotation = make_track(
+            Track::new_rotation(),
+            [|t| 0.2 * t, |t| FRAC_PI_2 + 0.01 * t.sin(), |t| -0.3 * t],
+        );
+        let decoded = QuantizedTrackData::new(rotation.data_container(), max_error).decode();
+        for (a, b) in decoded.curves_ref()[1]
+            .keys()
+            .iter()
+            .zip(rotation.data_container().curves_ref()[1].keys())
+        {
+            assert!((a.value - b.value).abs() <= max_error * 1.01);
+        }
+        assert!(decoded
+            .fetch(1.0)
+            .zip(rotation.data_container().fetch(1.0))
+            .map_or(false, |values| match values {
+                (TrackValue::UnitQuaternion(a), TrackValue::UnitQuaternion(b)) => {
+                    a.angle_to(&b) <= 3.1 * max_error
+                }
+                _ => false,
+            }));
+    }
+}
@@ -0,0 +1,202 @@
+//! Animation recorder captures transforms of scene nodes over time and bakes them into an animation. See
+//! [`AnimationRecorder`] docs for more info.
+
+use crate::{
+    animation::{track::Track, Animation},
+    core::pool::Handle,
+    scene::{graph::Graph, node::Node},
+};
+
+struct RecordedNode {
+    node: Handle<Node>,
+    position: Track,
+    rotation: Track,
+    scale: Track,
+}
+
+impl RecordedNode {
+    fn new(node: Handle<Node>) -> Self {
+        let make_track = |mut track: Track| {
+            track.set_target(node);
+            track
+        };
+
+        Self {
+            node,
+            position: make_track(Track::new_position()),
+            rotation: make_track(Track::new_rotation()),
+            scale: make_track(Track::new_scale()),
+        }
+    }
+
+    fn capture(&mut self, graph: &Graph, time: f32) {
+        let transform = if let Some(node) = graph.try_get(self.node) {
+            node.local_transform()
+        } else {
+            return;
+        };
+
+        // Rotation keys are unwrapped, so there are no sudden jumps of 360 degrees between adjacent keys.
+        self.rotation
+            .data_container_mut()
+            .add_rotation_key(time, transform.rotation());
+
+        for (track, value) in [
+            (&mut self.position, **transform.position()),
+            (&mut self.scale, **transform.scale()),
+        ] {
+            let container = track.data_container_mut();
+            for (index, component) in value.iter().enumerate() {
+                container.add_key(index, time, *component);
+            }
+        }
+    }
+}
+
+/// Animation recorder captures local transforms (position, rotation and scale) of a set of scene nodes with a fixed
+/// sampling rate and bakes them into a new [`Animation`]. It is useful to record motion, that was scripted in code or
+/// simulated by physics, into a reusable clip (for example, for cinematics).
+///
+/// The recorder must be updated every frame (see [`Self::update`]) after the nodes were moved, the nodes are sampled
+/// at the very first update and then each `1.0 / sample_rate` seconds. Use [`Self::stop`] to finish recording and to get the
+/// animation. Recorded curves could be optionally simplified (see [`Self::set_simplification_tolerance`]), which
+/// removes redundant keys (for example, when a node did not move for a while).
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     animation::{recorder::AnimationRecorder, Animation},
+///     core::pool::Handle,
+///     scene::{graph::Graph, node::Node},
+/// };
+///
+/// fn start_recording(ragdoll_bones: &[Handle<Node>]) -> AnimationRecorder {
+///     let mut recorder = AnimationRecorder::new(ragdoll_bones, 30.0);
+///     recorder.set_simplification_tolerance(Some(0.001));
+///     recorder
+/// }
+///
+/// // Must be called every frame.
+/// fn record(recorder: &mut AnimationRecorder, graph: &Graph, dt: f32) {
+///     recorder.update(graph, dt);
+/// }
+///
+/// fn finish_recording(recorder: AnimationRecorder) -> Animation {
+///     let mut animation = recorder.stop();
+///     animation.set_name("Fall");
+///     animation
+/// }
+/// ```
+pub struct AnimationRecorder {
+    nodes: Vec<RecordedNode>,
+    sample_interval: f32,
+    time: f32,
+    next_sample_time: f32,
+    simplification_tolerance: Option<f32>,
+}
+
+impl AnimationRecorder {
+    /// Creates a new recorder for the given set of nodes. `sample_rate` defines how many samples are taken per
+    /// second, it is clamped to a minimum of one sample per second.
+    pub fn new(nodes: &[Handle<Node>], sample_rate: f32) -> Self {
+        Self {
+            nodes: nodes.iter().map(|node| RecordedNode::new(*node)).collect(),
+            sample_interval: 1.0 / sample_rate.max(1.0),
+            time: 0.0,
+            next_sample_time: 0.0,
+            simplification_tolerance: None,
+        }
+    }
+
+    /// Sets maximum allowed deviation of simplified curves from the recorded ones. `None` disables simplification.
+    /// Simplification is done once, when recording is stopped. See [`crate::core::curve::Curve::simplify`] docs for
+    /// more info.
+    pub fn set_simplification_tolerance(&mut self, tolerance: Option<f32>) {
+        self.simplification_tolerance = tolerance;
+    }
+
+    /// Returns current simplification tolerance.
+    pub fn simplification_tolerance(&self) -> Option<f32> {
+        self.simplification_tolerance
+    }
+
+    /// Returns the amount of time (in seconds) that was recorded so far.
+    pub fn recorded_time(&self) -> f32 {
+        self.time
+    }
+
+    /// Samples the nodes (if it is time for a new sample) and then advances recording time by the given time delta.
+    /// Nodes that do not exist in the graph are not sampled.
+    pub fn update(&mut self, graph: &Graph, dt: f32) {
+        if self.time >= self.next_sample_time {
+            for node in self.nodes.iter_mut() {
+                node.capture(graph, self.time);
+            }
+
+            while self.next_sample_time <= self.time {
+                self.next_sample_time += self.sample_interval;
+            }
+        }
+
+        self.time += dt.max(0.0);
+    }
+
+    /// Stops recording and bakes recorded samples into a new animation. Time slice of the animation is fitted to the
+    /// recorded keys.
+    pub fn stop(self) -> Animation {
+        let mut animation = Animation::default();
+
+        for node in self.nodes {
+            for mut track in [node.position, node.rotation, node.scale] {
+                if let Some(tolerance) = self.simplification_tolerance {
+                    for curve in track.data_container_mut().curves_mut() {
+                        curve.simplify(tolerance);
+                    }
+                }
+                animation.add_track(track);
+            }
+        }
+
+        animation.fit_time_slice_to_tracks();
+
+        animation
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{recorder::AnimationRecorder, value::TrackValue},
+        core::algebra::Vector3,
+        scene::{base::BaseBuilder, graph::Graph, pivot::PivotBuilder},
+    };
+
+    #[test]
+    fn test_recorder() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut recorder = AnimationRecorder::new(&[node], 10.0);
+        recorder.set_simplification_tolerance(Some(0.0001));
+
+        for i in 0..=10 {
+            graph[node]
+                .local_transform_mut()
+                .set_position(Vector3::new(i as f32, 0.0, 0.0));
+            recorder.update(&graph, 0.1);
+        }
+
+        let animation = recorder.stop();
+        assert_eq!(animation.tracks().len(), 3);
+        assert!((animation.time_slice().end - 1.0).abs() < 1.0e-4);
+
+        let position = &animation.tracks()[0];
+        // Linear motion must be simplified down to its end points.
+        assert_eq!(position.data_container().curves_ref()[0].keys().len(), 2);
+        match position.data_container().fetch(0.55) {
+            Some(TrackValue::Vector3(v)) => assert!((v.x - 5.5).abs() < 1.0e-3),
+            _ => panic!("Position track must produce a vector!"),
+        }
+    }
+}
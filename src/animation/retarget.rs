@@ -0,0 +1,212 @@
+//! Animation retargeting allows you to "transfer" an animation authored for one node hierarchy (skeleton) to another
+//! similar hierarchy. See [`retarget`] docs for more info.
+
+use crate::{
+    animation::Animation,
+    core::pool::Handle,
+    scene::{graph::Graph, node::Node},
+    utils::log::Log,
+};
+use fxhash::FxHashMap;
+
+/// Builds a mapping from names of nodes in the hierarchy of a source graph (starting from `source_root`) to handles of
+/// nodes with the same names in the hierarchy of a destination graph (starting from `dest_root`). Names that are missing
+/// in the destination hierarchy are not included in the mapping. If there are multiple nodes with the same name in the
+/// destination hierarchy, the first one (in depth-first order) is used. The mapping could then be used in [`retarget`].
+pub fn build_name_mapping(
+    source_graph: &Graph,
+    source_root: Handle<Node>,
+    dest_graph: &Graph,
+    dest_root: Handle<Node>,
+) -> FxHashMap<String, Handle<Node>> {
+    let mut dest_nodes = FxHashMap::default();
+    for handle in dest_graph.traverse_handle_iter(dest_root) {
+        dest_nodes
+            .entry(dest_graph[handle].name_owned())
+            .or_insert(handle);
+    }
+
+    let mut mapping = FxHashMap::default();
+    for handle in source_graph.traverse_handle_iter(source_root) {
+        let name = source_graph[handle].name();
+        if let Some(dest_handle) = dest_nodes.get(name) {
+            mapping.insert(name.to_owned(), *dest_handle);
+        }
+    }
+    mapping
+}
+
+/// Creates a copy of the given animation, whose tracks point at nodes of some other (destination) node hierarchy. Nodes of
+/// the destination hierarchy are defined by the `mapping` from names of the nodes in the source graph (the one the
+/// animation was authored for) to the handles of the nodes in the destination graph. Tracks whose target has no mapping
+/// are dropped with a warning. Animation mask and root motion settings (if any) are remapped too.
+///
+/// Unlike [`crate::resource::model::Model::retarget_animations`], the function works with any pair of graphs and does
+/// not require a model resource.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     animation::{retarget, Animation},
+///     core::pool::Handle,
+///     scene::{graph::Graph, node::Node},
+/// };
+///
+/// fn share_animation(
+///     animation: &Animation,
+///     source_graph: &Graph,
+///     source_root: Handle<Node>,
+///     dest_graph: &Graph,
+///     dest_root: Handle<Node>,
+/// ) -> Animation {
+///     let mapping =
+///         retarget::build_name_mapping(source_graph, source_root, dest_graph, dest_root);
+///     retarget::retarget(animation, source_graph, &mapping)
+/// }
+/// ```
+pub fn retarget(
+    animation: &Animation,
+    source_graph: &Graph,
+    mapping: &FxHashMap<String, Handle<Node>>,
+) -> Animation {
+    let remap = |handle: Handle<Node>| {
+        source_graph
+            .try_get(handle)
+            .and_then(|node| mapping.get(node.name()))
+            .cloned()
+    };
+
+    let mut retargeted = animation.clone();
+
+    retargeted.retain_tracks(|track| {
+        if remap(track.target()).is_some() {
+            true
+        } else {
+            Log::warn(format!(
+                "Track of animation {} was dropped during retargeting, because there is no mapping for node {}!",
+                animation.name(),
+                source_graph
+                    .try_get(track.target())
+                    .map_or("<Invalid>", |node| node.name())
+            ));
+            false
+        }
+    });
+
+    for track in retargeted.tracks_mut() {
+        if let Some(target) = remap(track.target()) {
+            track.set_target(target);
+        }
+    }
+
+    if let Some(mask) = animation.mask() {
+        retargeted.set_mask(Some(mask.remap(&remap)));
+    }
+
+    if let Some(settings) = animation.root_motion_settings() {
+        let mut settings = settings.clone();
+        settings.node = remap(settings.node).unwrap_or_default();
+        retargeted.set_root_motion_settings(Some(settings));
+    }
+
+    retargeted
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{
+            mask::AnimationMask,
+            retarget::{build_name_mapping, retarget},
+            track::Track,
+            Animation, RootMotionSettings,
+        },
+        core::pool::Handle,
+        scene::{base::BaseBuilder, graph::Graph, node::Node, pivot::PivotBuilder},
+    };
+
+    // Creates a chain of nodes with the given names, returns handles of the nodes in the same order.
+    fn make_chain(graph: &mut Graph, names: &[&str]) -> Vec<Handle<Node>> {
+        let mut handles: Vec<Handle<Node>> = Vec::new();
+        for name in names {
+            let handle = PivotBuilder::new(BaseBuilder::new().with_name(*name)).build(graph);
+            if let Some(parent) = handles.last() {
+                graph.link_nodes(handle, *parent);
+            }
+            handles.push(handle);
+        }
+        handles
+    }
+
+    #[test]
+    fn test_build_name_mapping() {
+        let mut source_graph = Graph::new();
+        let source = make_chain(&mut source_graph, &["Root", "Hips", "Spine", "Tail"]);
+
+        let mut dest_graph = Graph::new();
+        // Shift handles of the destination nodes, so they differ from the source ones.
+        make_chain(&mut dest_graph, &["Unrelated", "Hips"]);
+        let dest = make_chain(&mut dest_graph, &["Root", "Hips", "Spine", "Head"]);
+
+        let mapping = build_name_mapping(&source_graph, source[0], &dest_graph, dest[0]);
+
+        assert_eq!(mapping.len(), 3);
+        assert_eq!(mapping["Root"], dest[0]);
+        assert_eq!(mapping["Hips"], dest[1]);
+        assert_eq!(mapping["Spine"], dest[2]);
+        assert!(!mapping.contains_key("Tail"));
+        assert!(!mapping.contains_key("Head"));
+        assert!(!mapping.contains_key("Unrelated"));
+    }
+
+    #[test]
+    fn test_retarget() {
+        let mut source_graph = Graph::new();
+        let source = make_chain(&mut source_graph, &["Root", "Hips", "Spine", "Tail"]);
+
+        let mut dest_graph = Graph::new();
+        make_chain(&mut dest_graph, &["Unrelated"]);
+        let dest = make_chain(&mut dest_graph, &["Root", "Hips", "Spine"]);
+
+        let mut animation = Animation::default();
+        for (target, mut track) in [
+            (source[1], Track::new_position()),
+            (source[3], Track::new_rotation()),
+            (source[2], Track::new_rotation()),
+        ] {
+            track.set_target(target);
+            animation.add_track(track);
+        }
+        animation.set_mask(Some(AnimationMask::from_hierarchy(
+            &source_graph,
+            source[1],
+        )));
+        animation.set_root_motion_settings(Some(RootMotionSettings {
+            node: source[1],
+            ..Default::default()
+        }));
+
+        let mapping = build_name_mapping(&source_graph, source[0], &dest_graph, dest[0]);
+        let retargeted = retarget(&animation, &source_graph, &mapping);
+
+        // Track of the tail has no mapping and must be dropped, the rest must point at the destination nodes.
+        let targets = retargeted
+            .tracks()
+            .iter()
+            .map(|track| track.target())
+            .collect::<Vec<_>>();
+        assert_eq!(targets, vec![dest[1], dest[2]]);
+
+        let mask = retargeted.mask().unwrap();
+        assert!(mask.contains(dest[1]));
+        assert!(mask.contains(dest[2]));
+        assert_eq!(mask.weighted_nodes().count(), 2);
+
+        assert_eq!(retargeted.root_motion_settings().unwrap().node, dest[1]);
+
+        // Source animation must stay intact.
+        assert_eq!(animation.tracks().len(), 3);
+        assert_eq!(animation.tracks()[1].target(), source[3]);
+    }
+}
@@ -3,23 +3,101 @@
 use crate::{
     animation::{
         container::{TrackDataContainer, TrackValueKind},
-        value::{BoundValue, ValueBinding},
+        quantization::QuantizedTrackData,
+        value::{
+            BindingErrorFlag, BoundValue, ComponentMask, ScaleBlending, TransformSpace,
+            ValueBinding,
+        },
+    },
+    core::{
+        curve::CurveKeyKind, pool::Handle, reflect::prelude::*, uuid::Uuid, visitor::prelude::*,
     },
-    core::{pool::Handle, reflect::prelude::*, uuid::Uuid, visitor::prelude::*},
     scene::node::Node,
 };
-use std::fmt::Debug;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, sync::Arc};
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
+
+/// Interpolation override of a track. It forces every key of every curve of a track to be evaluated with a specific
+/// interpolation, regardless of the kinds of the keys (see [`CurveKeyKind`]). It is useful when a whole track should
+/// be stepped (for example, to snap switches), but the keys were authored with some other kind.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum TrackInterpolation {
+    /// Every key uses its own kind (no override).
+    PerKey,
+    /// Every key is evaluated as [`CurveKeyKind::Constant`].
+    Constant,
+    /// Every key is evaluated as [`CurveKeyKind::Linear`].
+    Linear,
+    /// Every key is evaluated as [`CurveKeyKind::CatmullRom`].
+    CatmullRom,
+}
+
+impl Default for TrackInterpolation {
+    fn default() -> Self {
+        Self::PerKey
+    }
+}
+
+impl TrackInterpolation {
+    /// Returns a key kind that overrides kinds of the keys, or `None` for [`TrackInterpolation::PerKey`].
+    pub fn key_kind(self) -> Option<CurveKeyKind> {
+        match self {
+            TrackInterpolation::PerKey => None,
+            TrackInterpolation::Constant => Some(CurveKeyKind::Constant),
+            TrackInterpolation::Linear => Some(CurveKeyKind::Linear),
+            TrackInterpolation::CatmullRom => Some(CurveKeyKind::CatmullRom),
+        }
+    }
+}
 
 /// Track is responsible in animating a property of a single scene node. The track consists up to 4 parametric curves
 /// that contains the actual property data. Parametric curves allows the engine to perform various interpolations between
 /// key values.
+///
+/// Curve data of a track is shared between clones of the track (copy-on-write), so multiple instances of the same
+/// animation (for example, on hundreds of characters with the same set of clips) do not duplicate curves in memory.
+/// The data is copied only when it is modified via [`Self::data_container_mut`].
 #[derive(Debug, Reflect, Clone, PartialEq)]
 pub struct Track {
     binding: ValueBinding,
-    frames: TrackDataContainer,
+    #[reflect(hidden)]
+    frames: Arc<TrackDataContainer>,
     enabled: bool,
     target: Handle<Node>,
     id: Uuid,
+    interpolation: TrackInterpolation,
+    components: ComponentMask,
+    scale_blending: ScaleBlending,
+    space: TransformSpace,
+    group: Option<String>,
+    quantization: Option<f32>,
+    // Debugging aids, they're not serialized.
+    muted: bool,
+    solo: bool,
+    // Runtime fading state, it is not serialized.
+    #[reflect(hidden)]
+    weight: f32,
+    #[reflect(hidden)]
+    target_weight: f32,
+    #[reflect(hidden)]
+    fade_speed: f32,
+    // Runtime diagnostic state, it is not serialized.
+    #[reflect(hidden)]
+    error_flag: BindingErrorFlag,
 }
 
 impl Visit for Track {
@@ -31,7 +109,38 @@ impl Visit for Track {
 
         let _ = self.binding.visit("Binding", &mut region); // Backward compatibility
         let _ = self.id.visit("Id", &mut region); // Backward compatibility
-        let _ = self.frames.visit("Frames", &mut region); // Backward compatibility
+
+        let _ = self.quantization.visit("Quantization", &mut region); // Backward compatibility
+
+        match self.quantization {
+            // Quantized curves are saved in compact form, runtime curves are left intact.
+            Some(max_error) if !region.is_reading() => {
+                let _ = QuantizedTrackData::new(&self.frames, max_error)
+                    .visit("QuantizedFrames", &mut region);
+            }
+            _ => {
+                // Backward compatibility. Curve data is saved inline (not as a shared reference) to keep the format
+                // intact.
+                let _ = if region.is_reading() || Arc::get_mut(&mut self.frames).is_some() {
+                    Arc::make_mut(&mut self.frames).visit("Frames", &mut region)
+                } else {
+                    TrackDataContainer::clone(&self.frames).visit("Frames", &mut region)
+                };
+
+                if region.is_reading() {
+                    let mut quantized = QuantizedTrackData::default();
+                    if quantized.visit("QuantizedFrames", &mut region).is_ok() {
+                        self.frames = Arc::new(quantized.decode());
+                    }
+                }
+            }
+        }
+
+        let _ = self.interpolation.visit("Interpolation", &mut region); // Backward compatibility
+        let _ = self.components.visit("Components", &mut region); // Backward compatibility
+        let _ = self.scale_blending.visit("ScaleBlending", &mut region); // Backward compatibility
+        let _ = self.group.visit("Group", &mut region); // Backward compatibility
+        let _ = self.space.visit("Space", &mut region); // Backward compatibility
 
         Ok(())
     }
@@ -41,10 +150,22 @@ impl Default for Track {
     fn default() -> Self {
         Self {
             binding: ValueBinding::Position,
-            frames: TrackDataContainer::default(),
+            frames: Default::default(),
             enabled: true,
             target: Default::default(),
             id: Uuid::new_v4(),
+            interpolation: Default::default(),
+            components: Default::default(),
+            scale_blending: Default::default(),
+            space: Default::default(),
+            group: None,
+            quantization: None,
+            muted: false,
+            solo: false,
+            weight: 1.0,
+            target_weight: 1.0,
+            fade_speed: 0.0,
+            error_flag: Default::default(),
         }
     }
 }
@@ -54,7 +175,7 @@ impl Track {
     /// curves to be able to produces property values.
     pub fn new(container: TrackDataContainer, binding: ValueBinding) -> Self {
         Self {
-            frames: container,
+            frames: Arc::new(container),
             binding,
             ..Default::default()
         }
@@ -63,7 +184,7 @@ impl Track {
     /// Creates a new track that is responsible in animating a position property of a scene node.
     pub fn new_position() -> Self {
         Self {
-            frames: TrackDataContainer::new(TrackValueKind::Vector3),
+            frames: Arc::new(TrackDataContainer::new(TrackValueKind::Vector3)),
             binding: ValueBinding::Position,
             ..Default::default()
         }
@@ -72,7 +193,7 @@ impl Track {
     /// Creates a new track that is responsible in animating a rotation property of a scene node.
     pub fn new_rotation() -> Self {
         Self {
-            frames: TrackDataContainer::new(TrackValueKind::UnitQuaternion),
+            frames: Arc::new(TrackDataContainer::new(TrackValueKind::UnitQuaternion)),
             binding: ValueBinding::Rotation,
             ..Default::default()
         }
@@ -81,7 +202,7 @@ impl Track {
     /// Creates a new track that is responsible in animating a scaling property of a scene node.
     pub fn new_scale() -> Self {
         Self {
-            frames: TrackDataContainer::new(TrackValueKind::Vector3),
+            frames: Arc::new(TrackDataContainer::new(TrackValueKind::Vector3)),
             binding: ValueBinding::Scale,
             ..Default::default()
         }
@@ -90,6 +211,7 @@ impl Track {
     /// Sets new track binding. See [`ValueBinding`] docs for more info.
     pub fn set_binding(&mut self, binding: ValueBinding) {
         self.binding = binding;
+        self.error_flag = Default::default();
     }
 
     /// Returns current track binding.
@@ -100,6 +222,7 @@ impl Track {
     /// Sets a handle of a node that will be animated.
     pub fn set_target(&mut self, target: Handle<Node>) {
         self.target = target;
+        self.error_flag = Default::default();
     }
 
     /// Returns a handle of a node that will be animated.
@@ -112,22 +235,120 @@ impl Track {
         &self.frames
     }
 
-    /// Returns a reference to the data container.
+    /// Returns a reference to the data container. If the data is shared with other tracks, it is copied first, so
+    /// the changes won't affect the other tracks.
     pub fn data_container_mut(&mut self) -> &mut TrackDataContainer {
-        &mut self.frames
+        Arc::make_mut(&mut self.frames)
     }
 
     /// Sets new data container and returns the previous one.
     pub fn set_data_container(&mut self, container: TrackDataContainer) -> TrackDataContainer {
-        std::mem::replace(&mut self.frames, container)
+        let previous = std::mem::replace(&mut self.frames, Arc::new(container));
+        Arc::try_unwrap(previous).unwrap_or_else(|shared| TrackDataContainer::clone(&shared))
+    }
+
+    /// Returns a shared reference to the data container. It could be used to share the same curve data between
+    /// multiple tracks, see [`Self::set_shared_data_container`].
+    pub fn shared_data_container(&self) -> &Arc<TrackDataContainer> {
+        &self.frames
+    }
+
+    /// Sets new data container, that could be shared with other tracks.
+    pub fn set_shared_data_container(&mut self, container: Arc<TrackDataContainer>) {
+        self.frames = container;
+    }
+
+    /// Returns `true` if the track shares its curve data with the given track, `false` - otherwise.
+    pub fn shares_data_with(&self, other: &Track) -> bool {
+        Arc::ptr_eq(&self.frames, &other.frames)
     }
 
     /// Tries to get a new property value at a given time position.
     pub fn fetch(&self, time: f32) -> Option<BoundValue> {
-        self.frames.fetch(time).map(|v| BoundValue {
-            binding: self.binding.clone(),
-            value: v,
-        })
+        let kind = self.interpolation.key_kind();
+        self.frames
+            .fetch_with_kind(time, kind.as_ref())
+            .map(|v| BoundValue {
+                binding: self.binding.clone(),
+                value: v,
+                components: self.components,
+                scale_blending: self.scale_blending,
+                weight: self.weight,
+                space: self.space,
+                error_flag: self.error_flag.clone(),
+            })
+    }
+
+    /// Sets new component mask of the track. It allows you to animate only some components of a transform binding
+    /// (position, rotation or scale) and leave the rest untouched, for example to animate only vertical position of a
+    /// node, while its horizontal position is controlled by gameplay code. The mask is ignored by property bindings.
+    /// See [`ComponentMask`] docs for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{track::Track, value::ComponentMask};
+    ///
+    /// fn make_bob_track() -> Track {
+    ///     let mut track = Track::new_position();
+    ///     track.set_components(ComponentMask::new(false, true, false));
+    ///     track
+    /// }
+    /// ```
+    pub fn set_components(&mut self, components: ComponentMask) {
+        self.components = components;
+    }
+
+    /// Returns current component mask of the track.
+    pub fn components(&self) -> ComponentMask {
+        self.components
+    }
+
+    /// Sets how the values of the track are blended with the values of other tracks, if the track is bound to the
+    /// scale of a node. See [`ScaleBlending`] docs for more info.
+    pub fn set_scale_blending(&mut self, scale_blending: ScaleBlending) {
+        self.scale_blending = scale_blending;
+    }
+
+    /// Returns current scale blending mode of the track.
+    pub fn scale_blending(&self) -> ScaleBlending {
+        self.scale_blending
+    }
+
+    /// Sets a coordinate space of the values of the track. It is used only by transform bindings (position, rotation,
+    /// scale), world-space values are converted to the local space of the target node, when a pose is applied. See
+    /// [`TransformSpace`] docs for more info (including the cost of the conversion).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::{track::Track, value::TransformSpace};
+    ///
+    /// fn make_hand_ik_target_track() -> Track {
+    ///     let mut track = Track::new_position();
+    ///     track.set_space(TransformSpace::World);
+    ///     track
+    /// }
+    /// ```
+    pub fn set_space(&mut self, space: TransformSpace) {
+        self.space = space;
+    }
+
+    /// Returns current coordinate space of the values of the track.
+    pub fn space(&self) -> TransformSpace {
+        self.space
+    }
+
+    /// Returns `true` if the track is bound to the scale of a node and it has at least one zero or negative key,
+    /// `false` - otherwise. Such keys produce degenerate transforms (that cannot be inverted or decomposed back to
+    /// translation, rotation and scale), which usually results in visual artifacts.
+    pub fn has_degenerate_scale(&self) -> bool {
+        self.binding == ValueBinding::Scale
+            && self
+                .frames
+                .curves_ref()
+                .iter()
+                .any(|curve| curve.keys().iter().any(|key| key.value <= 0.0))
     }
 
     /// Enables or disables the track. Disabled tracks won't animate their nodes/properties.
@@ -140,6 +361,137 @@ impl Track {
         self.enabled
     }
 
+    /// Mutes or unmutes the track. Muted tracks do not contribute to the pose of their animation. Unlike
+    /// [`Self::set_enabled`], the flag is a debugging aid (to find out which track causes a glitch, for example) and it
+    /// is not serialized.
+    pub fn set_mute(&mut self, muted: bool) {
+        self.muted = muted;
+    }
+
+    /// Returns `true` if the track is muted, `false` - otherwise.
+    pub fn is_muted(&self) -> bool {
+        self.muted
+    }
+
+    /// Sets or clears the solo flag of the track. If an animation has at least one soloed track, only soloed tracks
+    /// contribute to the pose of the animation. The flag is a debugging aid and it is not serialized.
+    pub fn set_solo(&mut self, solo: bool) {
+        self.solo = solo;
+    }
+
+    /// Returns `true` if the track is soloed, `false` - otherwise.
+    pub fn is_solo(&self) -> bool {
+        self.solo
+    }
+
+    /// Returns `true` if the track contributes to the pose of its animation, `any_solo` tells whether the animation
+    /// has soloed tracks.
+    pub(crate) fn is_audible(&self, any_solo: bool) -> bool {
+        self.enabled && !self.muted && (!any_solo || self.solo) && self.weight > 0.0
+    }
+
+    /// Smoothly changes the weight of the track to the given value in `[0; 1]` range over the given amount of time
+    /// (in seconds). The weight scales the contribution of the track to the pose of its animation: values of faded
+    /// transform tracks are mixed with current transform of the target node, deltas of additive animations are
+    /// scaled. A track with zero weight does not animate anything, as if it was disabled. Fading is useful to hand
+    /// control over a node between an animation and some procedural system (for example, inverse kinematics) without
+    /// visible pops. Zero or negative duration changes the weight immediately.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn release_arm(animation: &mut Animation, arm_track: usize) {
+    ///     animation.tracks_mut()[arm_track].fade(0.0, 0.25);
+    /// }
+    /// ```
+    pub fn fade(&mut self, target_weight: f32, duration: f32) {
+        self.target_weight = target_weight.clamp(0.0, 1.0);
+        if duration > 0.0 {
+            self.fade_speed = (self.target_weight - self.weight).abs() / duration;
+        } else {
+            self.weight = self.target_weight;
+            self.fade_speed = 0.0;
+        }
+    }
+
+    /// Sets the weight of the track immediately, stopping any active fading. See [`Self::fade`] for more info.
+    pub fn set_weight(&mut self, weight: f32) {
+        self.fade(weight, 0.0);
+    }
+
+    /// Returns current weight of the track. See [`Self::fade`] for more info.
+    pub fn weight(&self) -> f32 {
+        self.weight
+    }
+
+    /// Returns the weight, that the track is fading to. See [`Self::fade`] for more info.
+    pub fn target_weight(&self) -> f32 {
+        self.target_weight
+    }
+
+    /// Returns `true` if the track is fading in or out, `false` - otherwise.
+    pub fn is_fading(&self) -> bool {
+        self.weight != self.target_weight
+    }
+
+    /// Moves the weight of the track towards its target weight.
+    pub(crate) fn update_fade(&mut self, dt: f32) {
+        if self.is_fading() {
+            let step = self.fade_speed * dt.abs();
+            if (self.target_weight - self.weight).abs() <= step {
+                self.weight = self.target_weight;
+            } else {
+                self.weight += step.copysign(self.target_weight - self.weight);
+            }
+        }
+    }
+
+    /// Sets maximum allowed error of quantization of the curves of the track, `None` disables quantization (default).
+    /// Quantized tracks are saved in compact (lossy) form, which significantly reduces the size of long animations
+    /// (for example, baked skeletal animations) in scene files: rotations are stored as compressed quaternions and
+    /// any other values - as fixed-point numbers. Runtime curves of the track are not affected by saving, the loss of
+    /// precision appears only after loading. The error is measured in the units of the values of the track (radians for
+    /// rotations). See [`QuantizedTrackData`] docs for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::animation::Animation;
+    ///
+    /// fn compress_for_storage(animation: &mut Animation) {
+    ///     for track in animation.tracks_mut() {
+    ///         track.set_quantization(Some(0.0005));
+    ///     }
+    /// }
+    /// ```
+    pub fn set_quantization(&mut self, max_error: Option<f32>) {
+        self.quantization = max_error;
+    }
+
+    /// Returns maximum allowed error of quantization of the track (if any).
+    pub fn quantization(&self) -> Option<f32> {
+        self.quantization
+    }
+
+    /// Sets a name of a group of the track, `None` removes the track from its group. Groups are used to organize
+    /// tracks of complex animations (for example, "Left Arm" or "Face") and to enable, mute or solo many tracks at once
+    /// (see [`crate::animation::Animation::set_group_enabled`]). Groups are serialized.
+    pub fn set_group(&mut self, group: Option<String>) {
+        self.group = group;
+    }
+
+    /// Returns a name of the group of the track (if any).
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// Returns `true` if the track belongs to the group with the given name, `false` - otherwise.
+    pub fn is_in_group(&self, group: &str) -> bool {
+        self.group.as_deref() == Some(group)
+    }
+
     /// Returns length of the track in seconds.
     pub fn time_length(&self) -> f32 {
         self.frames.time_length()
@@ -149,4 +501,19 @@ impl Track {
     pub fn id(&self) -> Uuid {
         self.id
     }
+
+    /// Sets new id of the track.
+    pub fn set_id(&mut self, id: Uuid) {
+        self.id = id;
+    }
+
+    /// Sets new interpolation override of the track. See [`TrackInterpolation`] docs for more info.
+    pub fn set_interpolation(&mut self, interpolation: TrackInterpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Returns current interpolation override of the track.
+    pub fn interpolation(&self) -> TrackInterpolation {
+        self.interpolation
+    }
 }
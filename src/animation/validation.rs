@@ -0,0 +1,281 @@
+//! Validation of animation tracks allows you to find tracks that cannot animate anything, for example because their
+//! target nodes were deleted. See [`AnimationContainer::validate`] docs for more info.
+
+use crate::{
+    animation::{
+        container::TrackValueKind, track::Track, value::ValueBinding, Animation, AnimationContainer,
+    },
+    core::{
+        pool::Handle,
+        reflect::{prelude::*, ResolvePath},
+        uuid::Uuid,
+    },
+    scene::{graph::Graph, mesh::Mesh, node::Node},
+};
+use std::fmt::{Display, Formatter};
+
+/// A reason why a track cannot animate its target node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ValidationIssueKind {
+    /// Target node of the track does not exist in the graph.
+    InvalidTarget,
+
+    /// Binding of the track cannot be applied to the target node. The string contains human-readable description of
+    /// the mismatch.
+    BindingMismatch(String),
+}
+
+/// A problem with a track of an animation, found by [`AnimationContainer::validate`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationIssue {
+    /// A handle of the animation, that contains the track.
+    pub animation: Handle<Animation>,
+
+    /// An id of the track.
+    pub track: Uuid,
+
+    /// A handle of the target node of the track.
+    pub target: Handle<Node>,
+
+    /// Actual problem.
+    pub kind: ValidationIssueKind,
+}
+
+impl Display for ValidationIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.kind {
+            ValidationIssueKind::InvalidTarget => write!(
+                f,
+                "Track {} of animation {} targets node {}, that does not exist!",
+                self.track, self.animation, self.target
+            ),
+            ValidationIssueKind::BindingMismatch(ref reason) => write!(
+                f,
+                "Track {} of animation {} cannot animate node {}: {}",
+                self.track, self.animation, self.target, reason
+            ),
+        }
+    }
+}
+
+fn check_track(track: &Track, graph: &Graph) -> Option<ValidationIssueKind> {
+    let node = match graph.try_get(track.target()) {
+        Some(node) => node,
+        None => return Some(ValidationIssueKind::InvalidTarget),
+    };
+
+    let kind = track.data_container().value_kind();
+    let mismatch = match track.binding() {
+        ValueBinding::Position | ValueBinding::Scale if kind != TrackValueKind::Vector3 => {
+            Some(format!(
+                "{} binding requires Vector3 values, but the track produces {:?} values",
+                track.binding(),
+                kind
+            ))
+        }
+        ValueBinding::Rotation if kind != TrackValueKind::UnitQuaternion => Some(format!(
+            "Rotation binding requires UnitQuaternion values, but the track produces {kind:?} values"
+        )),
+        ValueBinding::BlendShape { name } => {
+            if kind != TrackValueKind::Real {
+                Some(format!(
+                    "{} binding requires Real values, but the track produces {:?} values",
+                    track.binding(),
+                    kind
+                ))
+            } else {
+                match node.cast::<Mesh>() {
+                    None => Some(format!("blend shape {name} requires a mesh node")),
+                    Some(mesh) if mesh.blend_shape_weight(name).is_none() => {
+                        Some(format!("there is no blend shape {name}"))
+                    }
+                    Some(_) => None,
+                }
+            }
+        }
+        ValueBinding::Property { name, value_type } => {
+            match node.as_reflect().resolve_path(name) {
+                Err(err) => Some(format!("there is no property {name}: {err}")),
+                Ok(property) => {
+                    let property = property
+                        .as_inheritable_variable()
+                        .map_or(property, |variable| variable.inner_value_ref());
+
+                    match track
+                        .data_container()
+                        .fetch(0.0)
+                        .and_then(|value| value.numeric_type_cast(*value_type))
+                    {
+                        None => Some(format!(
+                            "{kind:?} values of the track cannot be converted to {value_type:?}"
+                        )),
+                        Some(value) if value.as_any().type_id() != property.as_any().type_id() => {
+                            Some(format!(
+                                "property {} has type {}, but the track produces {}",
+                                name,
+                                property.type_name(),
+                                value.type_name()
+                            ))
+                        }
+                        Some(_) => None,
+                    }
+                }
+            }
+        }
+        _ => None,
+    };
+
+    mismatch.map(ValidationIssueKind::BindingMismatch)
+}
+
+impl AnimationContainer {
+    /// Checks every track of every animation in the container and reports the tracks that cannot animate anything,
+    /// either because their target nodes do not exist in the given graph, or because their bindings do not match the
+    /// target nodes (for example, a property binding with a path that does not exist or with a type that differs from
+    /// the actual type of the property). Such tracks are silently ignored during playback, which is usually a sign of
+    /// broken animations after refactoring of a scene. The method is read-only and could be used at any time, both in
+    /// the editor and at runtime. See [`Self::remove_invalid_tracks`] to fix the issues automatically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{animation::AnimationContainer, scene::graph::Graph, utils::log::Log};
+    ///
+    /// fn report_broken_tracks(animations: &AnimationContainer, graph: &Graph) {
+    ///     for issue in animations.validate(graph) {
+    ///         Log::warn(issue.to_string());
+    ///     }
+    /// }
+    /// ```
+    pub fn validate(&self, graph: &Graph) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        for (handle, animation) in self.pool.pair_iter() {
+            for track in animation.tracks() {
+                if let Some(kind) = check_track(track, graph) {
+                    issues.push(ValidationIssue {
+                        animation: handle,
+                        track: track.id(),
+                        target: track.target(),
+                        kind,
+                    });
+                }
+            }
+        }
+        issues
+    }
+
+    /// Removes every track that was reported by [`Self::validate`] and returns the list of issues of the removed tracks.
+    /// It is useful to repair animations right after a scene was loaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     core::pool::Handle,
+    ///     scene::{animation::AnimationPlayer, node::Node, Scene},
+    ///     utils::log::Log,
+    /// };
+    ///
+    /// fn repair_animations(scene: &mut Scene, animation_player: Handle<Node>) {
+    ///     let graph = &mut scene.graph;
+    ///     let mut animations = (**graph[animation_player]
+    ///         .query_component_ref::<AnimationPlayer>()
+    ///         .unwrap()
+    ///         .animations())
+    ///     .clone();
+    ///     for issue in animations.remove_invalid_tracks(graph) {
+    ///         Log::warn(format!("Removed a broken track. Reason: {issue}"));
+    ///     }
+    ///     graph[animation_player]
+    ///         .query_component_mut::<AnimationPlayer>()
+    ///         .unwrap()
+    ///         .set_animations(animations);
+    /// }
+    /// ```
+    pub fn remove_invalid_tracks(&mut self, graph: &Graph) -> Vec<ValidationIssue> {
+        let issues = self.validate(graph);
+        for issue in issues.iter() {
+            if let Some(animation) = self.pool.try_borrow_mut(issue.animation) {
+                animation.retain_tracks(|track| track.id() != issue.track);
+            }
+        }
+        issues
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{
+            container::{TrackDataContainer, TrackValueKind},
+            track::Track,
+            validation::ValidationIssueKind,
+            value::{ValueBinding, ValueType},
+            Animation, AnimationContainer,
+        },
+        core::pool::Handle,
+        scene::{base::BaseBuilder, graph::Graph, pivot::PivotBuilder},
+    };
+
+    #[test]
+    fn test_validation() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut valid = Track::new_position();
+        valid.set_target(node);
+
+        let mut dangling = Track::new_rotation();
+        dangling.set_target(Handle::new(123, 1));
+
+        let mut wrong_kind = Track::new(
+            TrackDataContainer::new(TrackValueKind::Real),
+            ValueBinding::Scale,
+        );
+        wrong_kind.set_target(node);
+
+        let mut wrong_path = Track::new(
+            TrackDataContainer::new(TrackValueKind::Real),
+            ValueBinding::Property {
+                name: "foo.bar".to_owned(),
+                value_type: ValueType::F32,
+            },
+        );
+        wrong_path.set_target(node);
+
+        let mut not_mesh = Track::new(
+            TrackDataContainer::new(TrackValueKind::Real),
+            ValueBinding::blend_shape("Smile"),
+        );
+        not_mesh.set_target(node);
+
+        let mut animation = Animation::default();
+        for track in [valid, dangling, wrong_kind, wrong_path, not_mesh] {
+            animation.add_track(track);
+        }
+
+        let mut container = AnimationContainer::new();
+        let animation = container.add(animation);
+
+        let issues = container.validate(&graph);
+        assert_eq!(issues.len(), 4);
+        assert!(issues.iter().all(|issue| issue.animation == animation));
+        assert_eq!(issues[0].kind, ValidationIssueKind::InvalidTarget);
+        assert!(matches!(
+            issues[1].kind,
+            ValidationIssueKind::BindingMismatch(_)
+        ));
+        assert!(matches!(
+            issues[2].kind,
+            ValidationIssueKind::BindingMismatch(_)
+        ));
+        assert!(matches!(
+            issues[3].kind,
+            ValidationIssueKind::BindingMismatch(_)
+        ));
+
+        assert_eq!(container.remove_invalid_tracks(&graph).len(), 4);
+        assert!(container.validate(&graph).is_empty());
+        assert_eq!(container[animation].tracks().len(), 1);
+    }
+}
@@ -3,19 +3,33 @@
 
 use crate::{
     core::{
-        algebra::{UnitQuaternion, Vector2, Vector3, Vector4},
-        math::lerpf,
+        algebra::{Matrix4, Point3, UnitQuaternion, Vector2, Vector3, Vector4},
+        color::Color,
+        math::{lerpf, quat_nlerp_shortest, quat_slerp_shortest, Matrix4Ext},
         num_traits::AsPrimitive,
         reflect::{prelude::*, SetFieldByPathError},
         visitor::prelude::*,
     },
-    scene::node::Node,
+    scene::{
+        camera::{Camera, PerspectiveProjection, Projection},
+        light::{point::PointLight, spot::SpotLight, BaseLight},
+        mesh::Mesh,
+        node::Node,
+    },
     utils::log::Log,
 };
-use std::fmt::{Debug, Display, Formatter};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
 
 /// An actual type of a property value.
-#[derive(Visit, Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Visit, Reflect, Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ValueType {
     /// `bool`
     Bool,
@@ -113,6 +127,10 @@ pub enum ValueType {
     UnitQuaternionF32,
     /// `UnitQuaternion<f64>`
     UnitQuaternionF64,
+
+    /// [`Color`] (RGBA, 8 bits per component). The value is produced from a 4-dimensional vector, whose components are
+    /// in `0..1` range (see [`crate::animation::container::TrackValueKind::Color`]).
+    Color,
 }
 
 impl Default for ValueType {
@@ -124,7 +142,7 @@ impl Default for ValueType {
 /// A real value that can be produced by an animation track. Animations always operate on real numbers (`f32`) for any kind
 /// of machine numeric types (including `bool`). This is needed to be able to blend values; final blending result is then
 /// converted to an actual machine type of a target property.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Visit)]
 pub enum TrackValue {
     /// A real number.
     Real(f32),
@@ -142,6 +160,12 @@ pub enum TrackValue {
     UnitQuaternion(UnitQuaternion<f32>),
 }
 
+impl Default for TrackValue {
+    fn default() -> Self {
+        Self::Real(0.0)
+    }
+}
+
 impl TrackValue {
     /// Clones the value and applies the given weight to it.
     pub fn weighted_clone(&self, weight: f32) -> Self {
@@ -162,21 +186,41 @@ impl TrackValue {
             (Self::Vector2(a), Self::Vector2(b)) => *a += b.scale(weight),
             (Self::Vector3(a), Self::Vector3(b)) => *a += b.scale(weight),
             (Self::Vector4(a), Self::Vector4(b)) => *a += b.scale(weight),
-            (Self::UnitQuaternion(a), Self::UnitQuaternion(b)) => *a = a.nlerp(b, weight),
+            (Self::UnitQuaternion(a), Self::UnitQuaternion(b)) => {
+                *a = quat_nlerp_shortest(a, b, weight)
+            }
             _ => (),
         }
     }
 
     /// Tries to calculate intermediate value between the current and an other using interpolation coefficient. Interpolation
-    /// will fail if the types of current and the other values don't match.
+    /// will fail if the types of current and the other values don't match. Numbers and vectors are interpolated linearly,
+    /// quaternions - spherically, along the shortest path.
     pub fn interpolate(&self, other: &Self, t: f32) -> Option<Self> {
         match (self, other) {
             (Self::Real(a), Self::Real(b)) => Some(Self::Real(lerpf(*a, *b, t))),
             (Self::Vector2(a), Self::Vector2(b)) => Some(Self::Vector2(a.lerp(b, t))),
             (Self::Vector3(a), Self::Vector3(b)) => Some(Self::Vector3(a.lerp(b, t))),
             (Self::Vector4(a), Self::Vector4(b)) => Some(Self::Vector4(a.lerp(b, t))),
+            (Self::UnitQuaternion(a), Self::UnitQuaternion(b)) => Some(Self::UnitQuaternion(
+                quat_slerp_shortest(a, b, t, f32::EPSILON),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Tries to calculate a difference between the current and a reference value. Numbers and vectors are subtracted,
+    /// quaternions produce a rotation that transforms the reference rotation into the current. The result could be used
+    /// as a delta for additive animation. The method will fail if the types of current and the reference values don't
+    /// match.
+    pub fn relative_to(&self, reference: &Self) -> Option<Self> {
+        match (self, reference) {
+            (Self::Real(a), Self::Real(b)) => Some(Self::Real(*a - *b)),
+            (Self::Vector2(a), Self::Vector2(b)) => Some(Self::Vector2(a - b)),
+            (Self::Vector3(a), Self::Vector3(b)) => Some(Self::Vector3(a - b)),
+            (Self::Vector4(a), Self::Vector4(b)) => Some(Self::Vector4(a - b)),
             (Self::UnitQuaternion(a), Self::UnitQuaternion(b)) => {
-                Some(Self::UnitQuaternion(a.nlerp(b, t)))
+                Some(Self::UnitQuaternion(b.inverse() * a))
             }
             _ => None,
         }
@@ -275,6 +319,15 @@ impl TrackValue {
                 ValueType::Vector4I16 => Some(Box::new(convert_vec4::<i16>(vec4))),
                 ValueType::Vector4U8 => Some(Box::new(convert_vec4::<u8>(vec4))),
                 ValueType::Vector4I8 => Some(Box::new(convert_vec4::<i8>(vec4))),
+                ValueType::Color => {
+                    let to_u8 = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+                    Some(Box::new(Color::from_rgba(
+                        to_u8(vec4.x),
+                        to_u8(vec4.y),
+                        to_u8(vec4.z),
+                        to_u8(vec4.w),
+                    )))
+                }
                 _ => None,
             },
             TrackValue::UnitQuaternion(quat) => match value_type {
@@ -305,6 +358,18 @@ pub enum ValueBinding {
         /// Actual property type (only numeric properties are supported).
         value_type: ValueType,
     },
+    /// A binding to a weight of a blend shape of a mesh (see [`crate::scene::mesh::BlendShape`]). Use it with tracks
+    /// with [`crate::animation::container::TrackValueKind::Real`] values, the weight is clamped to `[0; 1]` range.
+    BlendShape {
+        /// A name of the blend shape.
+        name: String,
+    },
+}
+
+impl Default for ValueBinding {
+    fn default() -> Self {
+        Self::Position
+    }
 }
 
 impl Display for ValueBinding {
@@ -314,48 +379,592 @@ impl Display for ValueBinding {
             ValueBinding::Scale => write!(f, "Scale"),
             ValueBinding::Rotation => write!(f, "Rotation"),
             ValueBinding::Property { name, .. } => write!(f, "{}", name),
+            ValueBinding::BlendShape { name } => write!(f, "Blend Shape {}", name),
+        }
+    }
+}
+
+// Presets of well-known properties of scene nodes. The presets are just property bindings with the paths built from
+// reflection constants, so any typo in the paths would be a compilation error.
+impl ValueBinding {
+    fn f32_property(name: String) -> Self {
+        Self::Property {
+            name,
+            value_type: ValueType::F32,
+        }
+    }
+
+    /// Creates a binding to field of view (in radians) of a camera with perspective projection. Use it with tracks with
+    /// [`crate::animation::container::TrackValueKind::Real`] values. The same applies to every other preset.
+    ///
+    /// Keep in mind, that presets are not checked against the type of target nodes. A binding to a property, that does
+    /// not exist in a target node (for example, the field of view of a mesh), is ignored and an error is written to the
+    /// log once. Use [`crate::animation::AnimationContainer::validate`] to find such tracks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     animation::{
+    ///         container::{TrackDataContainer, TrackValueKind},
+    ///         track::Track,
+    ///         value::ValueBinding,
+    ///         Animation,
+    ///     },
+    ///     core::{
+    ///         curve::{Curve, CurveKey, CurveKeyKind},
+    ///         pool::Handle,
+    ///     },
+    ///     scene::node::Node,
+    /// };
+    ///
+    /// fn add_zoom(animation: &mut Animation, camera: Handle<Node>) {
+    ///     let mut container = TrackDataContainer::new(TrackValueKind::Real);
+    ///     container.curves_mut()[0] = Curve::from(vec![
+    ///         CurveKey::new(0.0, 75.0f32.to_radians(), CurveKeyKind::Linear),
+    ///         CurveKey::new(2.0, 30.0f32.to_radians(), CurveKeyKind::Linear),
+    ///     ]);
+    ///
+    ///     let mut track = Track::new(container, ValueBinding::camera_fov());
+    ///     track.set_target(camera);
+    ///     animation.add_track(track);
+    /// }
+    /// ```
+    pub fn camera_fov() -> Self {
+        Self::f32_property(format!(
+            "{}.{}.{}",
+            Camera::PROJECTION,
+            Projection::PERSPECTIVE_F_0,
+            PerspectiveProjection::FOV
+        ))
+    }
+
+    /// Creates a binding to the location of the near clipping plane of a camera with perspective projection.
+    pub fn camera_z_near() -> Self {
+        Self::f32_property(format!(
+            "{}.{}.{}",
+            Camera::PROJECTION,
+            Projection::PERSPECTIVE_F_0,
+            PerspectiveProjection::Z_NEAR
+        ))
+    }
+
+    /// Creates a binding to the location of the far clipping plane of a camera with perspective projection.
+    pub fn camera_z_far() -> Self {
+        Self::f32_property(format!(
+            "{}.{}.{}",
+            Camera::PROJECTION,
+            Projection::PERSPECTIVE_F_0,
+            PerspectiveProjection::Z_FAR
+        ))
+    }
+
+    /// Creates a binding to the intensity of a light source. It works with every kind of light sources (point, spot and
+    /// directional).
+    pub fn light_intensity() -> Self {
+        Self::f32_property(format!(
+            "{}.{}",
+            PointLight::BASE_LIGHT,
+            BaseLight::INTENSITY
+        ))
+    }
+
+    /// Creates a binding to the radius of a point light.
+    pub fn point_light_radius() -> Self {
+        Self::f32_property(PointLight::RADIUS.to_owned())
+    }
+
+    /// Creates a binding to the distance of a spot light.
+    pub fn spot_light_distance() -> Self {
+        Self::f32_property(SpotLight::DISTANCE.to_owned())
+    }
+
+    /// Creates a binding to the angle (in radians) of the hotspot cone of a spot light.
+    pub fn spot_light_hotspot_cone_angle() -> Self {
+        Self::f32_property(SpotLight::HOTSPOT_CONE_ANGLE.to_owned())
+    }
+
+    /// Creates a binding to the angle delta (in radians) of the falloff cone of a spot light.
+    pub fn spot_light_falloff_angle_delta() -> Self {
+        Self::f32_property(SpotLight::FALLOFF_ANGLE_DELTA.to_owned())
+    }
+
+    /// Creates a binding to a weight of a blend shape with the given name. See [`ValueBinding::BlendShape`] for more
+    /// info.
+    pub fn blend_shape<S: AsRef<str>>(name: S) -> Self {
+        Self::BlendShape {
+            name: name.as_ref().to_owned(),
+        }
+    }
+}
+
+/// A set of flags, that defines which components of a transform value (position, rotation or scale) are applied to a
+/// node. Disabled components are left untouched, so they could be controlled by something else (for example, by
+/// gameplay code). For rotations the flags define Euler angles (roll, pitch, yaw) that will be applied.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Visit, Reflect, Serialize, Deserialize)]
+pub struct ComponentMask {
+    /// Enables the X component (or the angle around X axis for rotations).
+    pub x: bool,
+    /// Enables the Y component (or the angle around Y axis for rotations).
+    pub y: bool,
+    /// Enables the Z component (or the angle around Z axis for rotations).
+    pub z: bool,
+}
+
+impl Default for ComponentMask {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl ComponentMask {
+    /// Every component is enabled.
+    pub const ALL: Self = Self::new(true, true, true);
+
+    /// Creates a new mask.
+    pub const fn new(x: bool, y: bool, z: bool) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns `true` if every component is enabled, `false` - otherwise.
+    pub fn is_all(&self) -> bool {
+        self.x && self.y && self.z
+    }
+
+    /// Returns a mask, that has every component enabled in either of the masks.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::new(self.x || other.x, self.y || other.y, self.z || other.z)
+    }
+
+    /// Takes enabled components from `new` and disabled components from `current`.
+    pub fn select(&self, current: &Vector3<f32>, new: &Vector3<f32>) -> Vector3<f32> {
+        Vector3::new(
+            if self.x { new.x } else { current.x },
+            if self.y { new.y } else { current.y },
+            if self.z { new.z } else { current.z },
+        )
+    }
+
+    fn select_rotation(
+        &self,
+        current: &UnitQuaternion<f32>,
+        new: &UnitQuaternion<f32>,
+    ) -> UnitQuaternion<f32> {
+        if self.is_all() {
+            *new
+        } else {
+            let angles = |q: &UnitQuaternion<f32>| {
+                let (roll, pitch, yaw) = q.euler_angles();
+                Vector3::new(roll, pitch, yaw)
+            };
+            let selected = self.select(&angles(current), &angles(new));
+            UnitQuaternion::from_euler_angles(selected.x, selected.y, selected.z)
         }
     }
 }
 
+/// Defines how scale values are blended (see [`ValueBinding::Scale`]).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum ScaleBlending {
+    /// Scale values are blended as any other vectors - using weighted sum (or linear interpolation). It is cheap, but
+    /// looks wrong for scales with large ratio (for example, `0.1` and `10.0`) and produces degenerate (zero) scale if
+    /// the total weight of blended values is less than one.
+    Linear,
+    /// Scale values are blended in logarithmic space - using weighted product (`a^wa * b^wb`). It produces uniform
+    /// speed of scaling for any ratios of scales and tends to the identity scale (instead of zero) if the total weight
+    /// of blended values is less than one. Non-positive components are clamped to a tiny positive number.
+    Multiplicative,
+}
+
+impl Default for ScaleBlending {
+    fn default() -> Self {
+        Self::Linear
+    }
+}
+
+/// Defines a coordinate space of the values of transform bindings (position, rotation, scale).
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    Visit,
+    Reflect,
+    AsRefStr,
+    EnumString,
+    EnumVariantNames,
+    Serialize,
+    Deserialize,
+)]
+pub enum TransformSpace {
+    /// Values are relative to the parent of a node and they're applied to the local transform of the node as is.
+    Local,
+    /// Values are in world coordinates. They're converted to the local space of a node using the global transform of
+    /// its parent, when a pose is applied to a graph. The conversion requires the inverse of the parent transform,
+    /// which is computed once per node per pose application, so it is noticeably more expensive than local space
+    /// and should be used only when it is really needed (for example, to pin a hand to a world-space target, no matter
+    /// how the rest of the hierarchy is animated). The global transform of the parent is the one that was calculated
+    /// during the last update of the graph, so it lags one frame behind, if the parent is animated as well.
+    World,
+}
+
+impl Default for TransformSpace {
+    fn default() -> Self {
+        Self::Local
+    }
+}
+
+/// Minimal scale component, that is used by [`ScaleBlending::Multiplicative`] blending.
+const MIN_SCALE: f32 = 1.0e-6;
+
+fn scale_pow(scale: &Vector3<f32>, exponent: f32) -> Vector3<f32> {
+    scale.map(|component| component.max(MIN_SCALE).powf(exponent))
+}
+
+/// A flag, that marks a binding whose error was already reported. Animations are updated every frame and the same error
+/// (for example, a property that does not exist) would flood the log otherwise. The flag is shared between a track and
+/// the values it produces, so the error is written to the log once per track. A track gets a new flag, when its binding
+/// or its target is changed. The flag does not affect comparison of values.
+#[derive(Clone, Debug, Default)]
+pub struct BindingErrorFlag(Arc<AtomicBool>);
+
+impl PartialEq for BindingErrorFlag {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl BindingErrorFlag {
+    /// Marks the error as reported. Returns `true` if the error was not reported before.
+    pub fn raise(&self) -> bool {
+        !self.0.swap(true, Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the error was already reported, `false` - otherwise.
+    pub fn is_raised(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
 /// A value that is bound to a property.
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Visit)]
 pub struct BoundValue {
     /// A property to which the value is bound to.
     pub binding: ValueBinding,
     /// The new value for the property the binding points to.
     pub value: TrackValue,
+    /// Components of the value, that will be applied to a node. It is used only by transform bindings (position,
+    /// rotation, scale), see [`ComponentMask`] docs for more info.
+    #[visit(optional)] // Backward compatibility
+    pub components: ComponentMask,
+    /// Defines how the value is blended, if it is bound to the scale of a node. Blending mode of the current value is
+    /// used when it is blended with some other value.
+    #[visit(optional)] // Backward compatibility
+    pub scale_blending: ScaleBlending,
+    /// Weight of the value in `[0; 1]` range. Values with weight less than one are mixed with current values of
+    /// transform properties, when applied to a node. It is used to fade tracks in and out smoothly, see
+    /// [`crate::animation::track::Track::fade`] for more info.
+    #[visit(optional)] // Backward compatibility
+    pub weight: f32,
+    /// Coordinate space of the value, it is used only by transform bindings (position, rotation, scale). See
+    /// [`TransformSpace`] docs for more info.
+    #[visit(optional)] // Backward compatibility
+    pub space: TransformSpace,
+    /// A flag, that is used to report errors of the binding only once. It is shared with the track that produced
+    /// the value, see [`BindingErrorFlag`] docs for more info.
+    #[visit(skip)]
+    pub error_flag: BindingErrorFlag,
+}
+
+impl Default for BoundValue {
+    fn default() -> Self {
+        Self {
+            binding: Default::default(),
+            value: Default::default(),
+            components: Default::default(),
+            scale_blending: Default::default(),
+            weight: 1.0,
+            space: Default::default(),
+            error_flag: Default::default(),
+        }
+    }
 }
 
 impl BoundValue {
     /// Performs a weighted clone of the value. See [`TrackValue::weighted_clone`] for more info.
     pub fn weighted_clone(&self, weight: f32) -> Self {
+        let value = match (self.is_multiplicative_scale(), &self.value) {
+            (true, TrackValue::Vector3(scale)) => TrackValue::Vector3(scale_pow(scale, weight)),
+            _ => self.value.weighted_clone(weight),
+        };
+
         Self {
             binding: self.binding.clone(),
-            value: self.value.weighted_clone(weight),
+            value,
+            components: self.components,
+            scale_blending: self.scale_blending,
+            weight: self.weight,
+            space: self.space,
+            error_flag: self.error_flag.clone(),
         }
     }
 
     /// Blends the current value with an other value using the given weight. See [`TrackValue::blend_with`] for
-    /// more info.
+    /// more info. Component mask of the value becomes a union of both masks. Scale values are blended according
+    /// to [`ScaleBlending`] of the current value. The weight of the value becomes the largest of both weights.
     pub fn blend_with(&mut self, other: &Self, weight: f32) {
         assert_eq!(self.binding, other.binding);
-        self.value.blend_with(&other.value, weight);
+        let multiplicative = self.is_multiplicative_scale();
+        match (multiplicative, &mut self.value, &other.value) {
+            (true, TrackValue::Vector3(a), TrackValue::Vector3(b)) => {
+                *a = a.component_mul(&scale_pow(b, weight))
+            }
+            _ => self.value.blend_with(&other.value, weight),
+        }
+        self.components = self.components.union(&other.components);
+        self.weight = self.weight.max(other.weight);
     }
 
     /// Tries to interpolate the current value with some other using the given interpolation coefficient. See
-    /// [`TrackValue::interpolate`] for more info.
+    /// [`TrackValue::interpolate`] for more info. Scale values are interpolated according to [`ScaleBlending`] of
+    /// the current value.
     pub fn interpolate(&self, other: &Self, t: f32) -> Option<Self> {
         assert_eq!(self.binding, other.binding);
-        self.value.interpolate(&other.value, t).map(|value| Self {
+        let value = match (self.is_multiplicative_scale(), &self.value, &other.value) {
+            (true, TrackValue::Vector3(a), TrackValue::Vector3(b)) => Some(TrackValue::Vector3(
+                scale_pow(a, 1.0 - t).component_mul(&scale_pow(b, t)),
+            )),
+            _ => self.value.interpolate(&other.value, t),
+        };
+        value.map(|value| Self {
             binding: self.binding.clone(),
             value,
+            components: self.components.union(&other.components),
+            scale_blending: self.scale_blending,
+            weight: lerpf(self.weight, other.weight, t),
+            space: self.space,
+            error_flag: self.error_flag.clone(),
         })
     }
+
+    /// Converts a world-space value of a transform binding to the local space of a node with the given global
+    /// transform of its parent. Local-space values and values of property bindings are returned as is. Scale of the
+    /// parent is assumed to be free of shear (which is true for any hierarchy without non-uniform scaling of rotated
+    /// nodes).
+    pub fn to_local_space(&self, parent_transform: &Matrix4<f32>) -> Self {
+        let mut local = self.clone();
+        if self.space != TransformSpace::World {
+            return local;
+        }
+        local.space = TransformSpace::Local;
+
+        match (&self.binding, &mut local.value) {
+            (ValueBinding::Position, TrackValue::Vector3(position)) => {
+                let inverse = parent_transform
+                    .try_inverse()
+                    .unwrap_or_else(Matrix4::identity);
+                *position = inverse.transform_point(&Point3::from(*position)).coords;
+            }
+            (ValueBinding::Rotation, TrackValue::UnitQuaternion(rotation)) => {
+                let parent_rotation = UnitQuaternion::from_matrix_eps(
+                    &parent_transform.basis(),
+                    f32::EPSILON,
+                    16,
+                    UnitQuaternion::identity(),
+                );
+                *rotation = parent_rotation.inverse() * *rotation;
+            }
+            (ValueBinding::Scale, TrackValue::Vector3(scale)) => {
+                let basis = parent_transform.basis();
+                for (i, component) in scale.iter_mut().enumerate() {
+                    let parent_scale = basis.column(i).norm();
+                    if parent_scale > f32::EPSILON {
+                        *component /= parent_scale;
+                    }
+                }
+            }
+            _ => (),
+        }
+
+        local
+    }
+
+    fn weighted_target(&self, current: &Vector3<f32>, target: &Vector3<f32>) -> Vector3<f32> {
+        if self.weight < 1.0 {
+            current.lerp(target, self.weight)
+        } else {
+            *target
+        }
+    }
+
+    /// Writes an error of the binding to the log, if it was not reported yet. See [`BindingErrorFlag`] docs for more
+    /// info.
+    fn report_error<F: FnOnce() -> String>(&self, message: F) {
+        if self.error_flag.raise() {
+            Log::err(message())
+        }
+    }
+
+    fn is_multiplicative_scale(&self) -> bool {
+        self.binding == ValueBinding::Scale && self.scale_blending == ScaleBlending::Multiplicative
+    }
+
+    /// Tries to set the value to the property (by binding) of the given scene node. If the weight of the value is less
+    /// than one, transform properties and blend shape weights are interpolated from their current values towards the
+    /// value, other properties are set as is. Values with zero weight are ignored.
+    pub fn apply(&self, node_ref: &mut Node) {
+        if self.weight <= 0.0 {
+            return;
+        }
+
+        match self.binding {
+            ValueBinding::Position => {
+                if let TrackValue::Vector3(v) = self.value {
+                    let current = **node_ref.local_transform().position();
+                    let v = self.weighted_target(&current, &v);
+                    node_ref
+                        .local_transform_mut()
+                        .set_position(self.components.select(&current, &v));
+                } else {
+                    self.report_error(|| format!(
+                        "Unable to apply position of node {}, because underlying type is not Vector3!",
+                        node_ref.name()
+                    ))
+                }
+            }
+            ValueBinding::Scale => {
+                if let TrackValue::Vector3(v) = self.value {
+                    let current = **node_ref.local_transform().scale();
+                    let v = self.weighted_target(&current, &v);
+                    node_ref
+                        .local_transform_mut()
+                        .set_scale(self.components.select(&current, &v));
+                } else {
+                    self.report_error(|| format!(
+                        "Unable to apply scaling of node {}, because underlying type is not Vector3!",
+                        node_ref.name()
+                    ))
+                }
+            }
+            ValueBinding::Rotation => {
+                if let TrackValue::UnitQuaternion(v) = self.value {
+                    let current = **node_ref.local_transform().rotation();
+                    let v = if self.weight < 1.0 {
+                        quat_nlerp_shortest(&current, &v, self.weight)
+                    } else {
+                        v
+                    };
+                    node_ref
+                        .local_transform_mut()
+                        .set_rotation(self.components.select_rotation(&current, &v));
+                } else {
+                    self.report_error(|| format!(
+                        "Unable to apply rotation of node {}, because underlying type is not UnitQuaternion!",
+                        node_ref.name()
+                    ))
+                }
+            }
+            ValueBinding::Property {
+                name: ref property_name,
+                value_type,
+            } => {
+                if let Some(casted) = self.value.numeric_type_cast(value_type) {
+                    if let Err(err) = node_ref
+                        .as_reflect_mut()
+                        .set_field_by_path(property_name, casted)
+                    {
+                        match err {
+                            SetFieldByPathError::InvalidPath { reason, .. } => {
+                                self.report_error(|| {
+                                    format!(
+                                        "Failed to set property {} of node {}! Invalid path: {}",
+                                        property_name,
+                                        node_ref.name(),
+                                        reason
+                                    )
+                                });
+                            }
+                            SetFieldByPathError::InvalidValue(_) => {
+                                self.report_error(|| {
+                                    format!(
+                                        "Failed to set property {} of node {}! Types mismatch!",
+                                        property_name,
+                                        node_ref.name()
+                                    )
+                                });
+                            }
+                        }
+                    }
+                } else {
+                    self.report_error(|| {
+                        format!(
+                            "Failed to set property {} of node {}! Unable to convert {:?} to {:?}!",
+                            property_name,
+                            node_ref.name(),
+                            self.value,
+                            value_type
+                        )
+                    });
+                }
+            }
+            ValueBinding::BlendShape { ref name } => {
+                if let TrackValue::Real(weight) = self.value {
+                    let result = match node_ref.cast_mut::<Mesh>() {
+                        Some(mesh) => match mesh.blend_shape_weight(name) {
+                            Some(current) => {
+                                let weight = if self.weight < 1.0 {
+                                    lerpf(current, weight, self.weight)
+                                } else {
+                                    weight
+                                };
+                                mesh.set_blend_shape_weight(name, weight);
+                                Ok(())
+                            }
+                            None => Err("has no such blend shape"),
+                        },
+                        None => Err("is not a mesh"),
+                    };
+
+                    if let Err(reason) = result {
+                        self.report_error(|| {
+                            format!(
+                                "Failed to set weight of blend shape {} of node {}! The node {}!",
+                                name,
+                                node_ref.name(),
+                                reason
+                            )
+                        });
+                    }
+                } else {
+                    self.report_error(|| {
+                        format!(
+                            "Unable to apply weight of blend shape {} of node {}, because underlying type is not Real!",
+                            name,
+                            node_ref.name()
+                        )
+                    });
+                }
+            }
+        }
+    }
 }
 
 /// A collection of values that are bounds to some properties.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Visit)]
 pub struct BoundValueCollection {
     /// Actual values collection.
     pub values: Vec<BoundValue>,
@@ -397,59 +1006,86 @@ impl BoundValueCollection {
         Self { values: new_values }
     }
 
+    /// Interpolates each value of the current collection towards a respective (by binding) value in the other collection
+    /// in place. Values that have no pair in the other collection are left untouched, values of the other collection that
+    /// have no pair in the current collection are copied as is. See [`TrackValue::interpolate`] docs for more info.
+    pub fn interpolate_with(&mut self, other: &Self, t: f32) {
+        for other_value in other.values.iter() {
+            if let Some(value) = self
+                .values
+                .iter_mut()
+                .find(|v| v.binding == other_value.binding)
+            {
+                if let Some(interpolated) = value.interpolate(other_value, t) {
+                    *value = interpolated;
+                }
+            } else {
+                self.values.push(other_value.clone());
+            }
+        }
+    }
+
+    /// Returns `true` if the collection has at least one value in world space, `false` - otherwise.
+    pub fn has_world_space_values(&self) -> bool {
+        self.values
+            .iter()
+            .any(|value| value.space == TransformSpace::World)
+    }
+
+    /// Converts every world-space value of the collection to the local space of a node with the given global
+    /// transform of its parent. See [`BoundValue::to_local_space`] for more info.
+    pub fn to_local_space(&self, parent_transform: &Matrix4<f32>) -> Self {
+        Self {
+            values: self
+                .values
+                .iter()
+                .map(|value| value.to_local_space(parent_transform))
+                .collect(),
+        }
+    }
+
     /// Tries to set each value from the collection to the respective property (by binding) of the given scene node.
+    /// Values in world space are applied as if they were in local space, use [`Self::to_local_space`] to convert them
+    /// first.
     pub fn apply(&self, node_ref: &mut Node) {
         for bound_value in self.values.iter() {
-            match bound_value.binding {
-                ValueBinding::Position => {
-                    if let TrackValue::Vector3(v) = bound_value.value {
-                        node_ref.local_transform_mut().set_position(v);
-                    } else {
-                        Log::err(
-                            "Unable to apply position, because underlying type is not Vector3!",
-                        )
-                    }
-                }
-                ValueBinding::Scale => {
-                    if let TrackValue::Vector3(v) = bound_value.value {
-                        node_ref.local_transform_mut().set_scale(v);
-                    } else {
-                        Log::err("Unable to apply scaling, because underlying type is not Vector3!")
-                    }
+            bound_value.apply(node_ref);
+        }
+    }
+
+    /// Tries to add each value from the collection to the respective property (by binding) of the given scene node.
+    /// Values are treated as deltas (see [`TrackValue::relative_to`]): positions and scales are added componentwise,
+    /// rotations are multiplied. Values bound to arbitrary properties and blend shapes are set as is. Disabled
+    /// components (see [`ComponentMask`]) of the deltas are ignored. Deltas are scaled by the weights of the values.
+    /// Coordinate space of the values is ignored, deltas are always applied in local space.
+    pub fn apply_additive(&self, node_ref: &mut Node) {
+        for bound_value in self.values.iter() {
+            let components = &bound_value.components;
+            let weight = bound_value.weight.clamp(0.0, 1.0);
+            match (&bound_value.binding, &bound_value.value) {
+                (ValueBinding::Position, TrackValue::Vector3(delta)) => {
+                    let position = **node_ref.local_transform().position();
+                    node_ref.local_transform_mut().set_position(
+                        position + components.select(&Vector3::zeros(), &delta.scale(weight)),
+                    );
                 }
-                ValueBinding::Rotation => {
-                    if let TrackValue::UnitQuaternion(v) = bound_value.value {
-                        node_ref.local_transform_mut().set_rotation(v);
-                    } else {
-                        Log::err("Unable to apply rotation, because underlying type is not UnitQuaternion!")
-                    }
+                (ValueBinding::Scale, TrackValue::Vector3(delta)) => {
+                    let scale = **node_ref.local_transform().scale();
+                    node_ref.local_transform_mut().set_scale(
+                        scale + components.select(&Vector3::zeros(), &delta.scale(weight)),
+                    );
                 }
-                ValueBinding::Property {
-                    name: ref property_name,
-                    value_type,
-                } => {
-                    if let Some(casted) = bound_value.value.numeric_type_cast(value_type) {
-                        if let Err(err) = node_ref
-                            .as_reflect_mut()
-                            .set_field_by_path(property_name, casted)
-                        {
-                            match err {
-                                SetFieldByPathError::InvalidPath { reason, .. } => {
-                                    Log::err(format!(
-                                        "Failed to set property {}! Invalid path: {}",
-                                        property_name, reason
-                                    ));
-                                }
-                                SetFieldByPathError::InvalidValue(_) => {
-                                    Log::err(format!(
-                                        "Failed to set property {}! Types mismatch!",
-                                        property_name
-                                    ));
-                                }
-                            }
-                        }
-                    }
+                (ValueBinding::Rotation, TrackValue::UnitQuaternion(delta)) => {
+                    let rotation = **node_ref.local_transform().rotation();
+                    let identity = UnitQuaternion::identity();
+                    let delta = quat_nlerp_shortest(&identity, delta, weight);
+                    let delta = components.select_rotation(&identity, &delta);
+                    // Keep the rotation normalized, otherwise floating-point errors accumulate over time.
+                    let mut rotation = rotation * delta;
+                    rotation.renormalize();
+                    node_ref.local_transform_mut().set_rotation(rotation);
                 }
+                _ => bound_value.apply(node_ref),
             }
         }
     }
@@ -11,6 +11,7 @@ use crate::{
         resource_manager::{
             container::{Container, ResourceContainer},
             loader::{
+                absm::AbsmLoader,
                 curve::CurveLoader,
                 model::ModelLoader,
                 shader::ShaderLoader,
@@ -24,6 +25,7 @@ use crate::{
     },
     material::shader::{Shader, ShaderImportOptions},
     resource::{
+        absm::{AbsmImportOptions, AbsmResource},
         curve::{CurveImportOptions, CurveResource},
         model::{Model, ModelImportOptions},
         texture::{Texture, TextureError, TextureImportOptions, TextureState},
@@ -60,6 +62,9 @@ pub struct ContainersStorage {
 
     /// Container for curve resources.
     pub curves: ResourceContainer<CurveResource, CurveImportOptions>,
+
+    /// Container for animation blending state machine resources.
+    pub absms: ResourceContainer<AbsmResource, AbsmImportOptions>,
 }
 
 impl ContainersStorage {
@@ -103,11 +108,20 @@ impl ContainersStorage {
         self.curves.set_loader(loader);
     }
 
+    /// Sets a custom animation blending state machine loader.
+    pub fn set_absm_loader<L>(&mut self, loader: L)
+    where
+        L: 'static + ResourceLoader<AbsmResource, AbsmImportOptions>,
+    {
+        self.absms.set_loader(loader);
+    }
+
     /// Wait until all resources are loaded (or failed to load).
     pub fn get_wait_context(&self) -> ResourceWaitContext {
         ResourceWaitContext {
             models: self.models.resources(),
             curves: self.curves.resources(),
+            absms: self.absms.resources(),
             shaders: self.shaders.resources(),
             textures: self.textures.resources(),
             sound_buffers: self.sound_buffers.resources(),
@@ -120,6 +134,7 @@ impl ContainersStorage {
 pub struct ResourceWaitContext {
     models: Vec<Model>,
     curves: Vec<CurveResource>,
+    absms: Vec<AbsmResource>,
     shaders: Vec<Shader>,
     textures: Vec<Texture>,
     sound_buffers: Vec<SoundBufferResource>,
@@ -151,6 +166,7 @@ impl ResourceWaitContext {
 
         check_container(&self.models)
             && check_container(&self.curves)
+            && check_container(&self.absms)
             && check_container(&self.shaders)
             && check_container(&self.textures)
             && check_container(&self.sound_buffers)
@@ -219,7 +235,8 @@ impl ResourceManager {
             ),
             sound_buffers: ResourceContainer::new(task_pool.clone(), Box::new(SoundBufferLoader)),
             shaders: ResourceContainer::new(task_pool.clone(), Box::new(ShaderLoader)),
-            curves: ResourceContainer::new(task_pool, Box::new(CurveLoader)),
+            curves: ResourceContainer::new(task_pool.clone(), Box::new(CurveLoader)),
+            absms: ResourceContainer::new(task_pool, Box::new(AbsmLoader)),
         });
 
         resource_manager
@@ -349,6 +366,18 @@ impl ResourceManager {
         self.state().containers_mut().curves.request(path)
     }
 
+    /// Tries to load a new animation blending state machine resource from given path or get instance of existing, if
+    /// any. This method is asynchronous, it immediately returns a resource which can be shared across multiple places,
+    /// the loading may fail, but it is internal state of the resource. Use
+    /// [`crate::animation::machine::asset::MachineAsset::instantiate`] to create a machine for a particular character.
+    ///
+    /// # Async/.await
+    ///
+    /// Each resource implements Future trait and can be used in async contexts.
+    pub fn request_absm<P: AsRef<Path>>(&self, path: P) -> AbsmResource {
+        self.state().containers_mut().absms.request(path)
+    }
+
     /// Reloads every loaded texture. This method is asynchronous, internally it uses thread pool
     /// to run reload on separate thread per texture.
     pub async fn reload_textures(&self) {
@@ -377,6 +406,13 @@ impl ResourceManager {
         join_all(resources).await;
     }
 
+    /// Reloads every loaded animation blending state machine resource. This method is asynchronous, internally it uses
+    /// thread pool to run reload on separate thread per resource.
+    pub async fn reload_absm_resources(&self) {
+        let resources = self.state().containers_mut().absms.reload_resources();
+        join_all(resources).await;
+    }
+
     /// Reloads every loaded sound buffer. This method is asynchronous, internally it uses thread pool
     /// to run reload on separate thread per sound buffer.
     pub async fn reload_sound_buffers(&self) {
@@ -398,6 +434,7 @@ impl ResourceManager {
             self.reload_sound_buffers(),
             self.reload_shaders(),
             self.reload_curve_resources(),
+            self.reload_absm_resources(),
         );
     }
 }
@@ -440,6 +477,7 @@ impl ResourceManagerState {
             + containers.models.count_pending_resources()
             + containers.shaders.count_pending_resources()
             + containers.curves.count_pending_resources()
+            + containers.absms.count_pending_resources()
     }
 
     /// Returns total amount of loaded resources.
@@ -450,6 +488,7 @@ impl ResourceManagerState {
             + containers.models.count_loaded_resources()
             + containers.shaders.count_loaded_resources()
             + containers.curves.count_loaded_resources()
+            + containers.absms.count_loaded_resources()
     }
 
     /// Returns total amount of registered resources.
@@ -460,6 +499,7 @@ impl ResourceManagerState {
             + containers.models.len()
             + containers.shaders.len()
             + containers.curves.len()
+            + containers.absms.len()
     }
 
     /// Returns percentage of loading progress. This method is useful to show progress on
@@ -483,6 +523,7 @@ impl ResourceManagerState {
         containers.textures.destroy_unused();
         containers.shaders.destroy_unused();
         containers.curves.destroy_unused();
+        containers.absms.destroy_unused();
     }
 
     /// Update resource containers and do hot-reloading.
@@ -499,6 +540,7 @@ impl ResourceManagerState {
         containers.sound_buffers.update(dt);
         containers.shaders.update(dt);
         containers.curves.update(dt);
+        containers.absms.update(dt);
 
         if let Some(watcher) = self.watcher.as_ref() {
             if let Some(evt) = watcher.try_get_event() {
@@ -512,6 +554,7 @@ impl ResourceManagerState {
                                 &mut containers.sound_buffers as &mut dyn Container,
                                 &mut containers.shaders as &mut dyn Container,
                                 &mut containers.curves as &mut dyn Container,
+                                &mut containers.absms as &mut dyn Container,
                             ] {
                                 if container.try_reload_resource_from_path(&relative_path) {
                                     Log::info(format!(
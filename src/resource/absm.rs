@@ -0,0 +1,82 @@
+//! Animation blending state machine resource holds a [`MachineAsset`], that could be shared across multiple
+//! characters. See [`MachineAsset`] docs for more info.
+
+use crate::{
+    animation::machine::asset::MachineAsset,
+    asset::{define_new_resource, Resource, ResourceData},
+    core::{reflect::prelude::*, visitor::prelude::*},
+    engine::resource_manager::options::ImportOptions,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter},
+    path::{Path, PathBuf},
+};
+
+/// An error that may occur during animation blending state machine resource loading.
+#[derive(Debug)]
+pub enum AbsmResourceError {
+    /// An error that may occur due to version incompatibilities or i/o errors.
+    Visit(VisitError),
+}
+
+impl Display for AbsmResourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbsmResourceError::Visit(v) => {
+                write!(
+                    f,
+                    "An error that may occur due to version incompatibilities. {v:?}"
+                )
+            }
+        }
+    }
+}
+
+impl From<VisitError> for AbsmResourceError {
+    fn from(e: VisitError) -> Self {
+        Self::Visit(e)
+    }
+}
+
+/// State of the [`AbsmResource`]
+#[derive(Debug, Visit, Default)]
+pub struct AbsmResourceState {
+    pub(crate) path: PathBuf,
+    /// Actual machine asset.
+    pub asset: MachineAsset,
+}
+
+impl ResourceData for AbsmResourceState {
+    fn path(&self) -> Cow<Path> {
+        Cow::Borrowed(&self.path)
+    }
+
+    fn set_path(&mut self, path: PathBuf) {
+        self.path = path;
+    }
+}
+
+impl AbsmResourceState {
+    /// Load a machine asset from the specific file path.
+    pub async fn from_file(path: &Path) -> Result<Self, AbsmResourceError> {
+        Ok(Self {
+            asset: MachineAsset::load(path).await?,
+            path: path.to_path_buf(),
+        })
+    }
+}
+
+define_new_resource!(
+    /// See module docs.
+    #[derive(Reflect)]
+    #[reflect(hide_all)]
+    AbsmResource<AbsmResourceState, AbsmResourceError>
+);
+
+/// Import options for animation blending state machine resource.
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct AbsmImportOptions {}
+
+impl ImportOptions for AbsmImportOptions {}
@@ -543,7 +543,7 @@ async fn convert_model(
     model: &FbxModel,
     resource_manager: ResourceManager,
     graph: &mut Graph,
-    animation: &mut Animation,
+    takes: &mut [FbxTake],
     model_path: &Path,
     model_import_options: &ModelImportOptions,
 ) -> Result<Handle<Node>, FbxError> {
@@ -567,126 +567,189 @@ async fn convert_model(
         PivotBuilder::new(base).build(graph)
     };
 
-    // Convert animations
-    if !model.animation_curve_nodes.is_empty() {
-        // Find supported curve nodes (translation, rotation, scale)
-        let mut lcl_translation = None;
-        let mut lcl_rotation = None;
-        let mut lcl_scale = None;
-        for &anim_curve_node_handle in model.animation_curve_nodes.iter() {
-            let component = fbx_scene.get(anim_curve_node_handle);
-            if let FbxComponent::AnimationCurveNode(curve_node) = component {
-                if curve_node.actual_type == FbxAnimationCurveNodeType::Rotation {
-                    lcl_rotation = Some(curve_node);
-                } else if curve_node.actual_type == FbxAnimationCurveNodeType::Translation {
-                    lcl_translation = Some(curve_node);
-                } else if curve_node.actual_type == FbxAnimationCurveNodeType::Scale {
-                    lcl_scale = Some(curve_node);
+    for take in takes.iter_mut() {
+        convert_model_animation(fbx_scene, model, node_handle, take);
+    }
+
+    Ok(node_handle)
+}
+
+/// A take (animation stack) of an FBX file, that is converted to a separate animation.
+struct FbxTake {
+    animation: Animation,
+    /// Curve nodes of the take, `None` means that every curve node of the file belongs to the take.
+    curve_nodes: Option<FxHashSet<Handle<FbxComponent>>>,
+}
+
+impl FbxTake {
+    fn contains(&self, curve_node: Handle<FbxComponent>) -> bool {
+        self.curve_nodes
+            .as_ref()
+            .map_or(true, |curve_nodes| curve_nodes.contains(&curve_node))
+    }
+}
+
+/// Collects every take of the file, curve nodes of every layer of a take are merged.
+fn collect_takes(fbx_scene: &FbxScene) -> Vec<FbxTake> {
+    let mut takes = Vec::new();
+    for (_, component) in fbx_scene.pair_iter() {
+        if let FbxComponent::AnimationStack(stack) = component {
+            let mut curve_nodes = FxHashSet::default();
+            for &layer_handle in stack.layers.iter() {
+                if let FbxComponent::AnimationLayer(layer) = fbx_scene.get(layer_handle) {
+                    curve_nodes.extend(layer.curve_nodes.iter().cloned());
                 }
             }
+
+            let mut animation = Animation::default();
+            animation.set_name(&stack.name);
+            takes.push(FbxTake {
+                animation,
+                curve_nodes: Some(curve_nodes),
+            });
         }
+    }
 
-        fn fill_track<F: Fn(f32) -> f32>(
-            track: &mut Track,
-            fbx_scene: &FbxScene,
-            fbx_track: &FbxAnimationCurveNode,
-            default: Vector3<f32>,
-            transform_value: F,
-        ) {
-            let curves = track.data_container_mut().curves_mut();
-
-            if !fbx_track.curves.contains_key("d|X") {
-                curves[0].add_key(CurveKey::new(0.0, default.x, CurveKeyKind::Constant));
-            }
-            if !fbx_track.curves.contains_key("d|Y") {
-                curves[1].add_key(CurveKey::new(0.0, default.y, CurveKeyKind::Constant));
-            }
-            if !fbx_track.curves.contains_key("d|Z") {
-                curves[2].add_key(CurveKey::new(0.0, default.z, CurveKeyKind::Constant));
-            }
+    // Files without takes have every curve in a single implicit take.
+    if takes.is_empty() {
+        let mut animation = Animation::default();
+        animation.set_name("Animation");
+        takes.push(FbxTake {
+            animation,
+            curve_nodes: None,
+        });
+    }
 
-            for (id, curve_handle) in fbx_track.curves.iter() {
-                let index = match id.as_str() {
-                    "d|X" => Some(0),
-                    "d|Y" => Some(1),
-                    "d|Z" => Some(2),
-                    _ => None,
-                };
-
-                if let Some(index) = index {
-                    if let FbxComponent::AnimationCurve(fbx_curve) = fbx_scene.get(*curve_handle) {
-                        if fbx_curve.keys.is_empty() {
-                            curves[index].add_key(CurveKey::new(
-                                0.0,
-                                default[index],
-                                CurveKeyKind::Constant,
-                            ));
-                        } else {
-                            for pair in fbx_curve.keys.iter() {
-                                curves[index].add_key(CurveKey::new(
-                                    pair.time,
-                                    transform_value(pair.value),
-                                    CurveKeyKind::Linear,
-                                ))
-                            }
-                        }
-                    }
-                }
+    takes
+}
+
+/// Converts the curve nodes of the model, that belong to the given take, to the tracks of the animation of the take.
+/// Rotation curves animate local rotation of a node, pre- and post-rotations of the model are left in the transform of
+/// the node (see [`convert_model_to_base`]), so they're applied on top of the animated rotation as FBX requires.
+fn convert_model_animation(
+    fbx_scene: &FbxScene,
+    model: &FbxModel,
+    node_handle: Handle<Node>,
+    take: &mut FbxTake,
+) {
+    // Find supported curve nodes (translation, rotation, scale)
+    let mut has_curve_nodes = false;
+    let mut lcl_translation = None;
+    let mut lcl_rotation = None;
+    let mut lcl_scale = None;
+    for &anim_curve_node_handle in model.animation_curve_nodes.iter() {
+        if !take.contains(anim_curve_node_handle) {
+            continue;
+        }
+        let component = fbx_scene.get(anim_curve_node_handle);
+        if let FbxComponent::AnimationCurveNode(curve_node) = component {
+            has_curve_nodes = true;
+            if curve_node.actual_type == FbxAnimationCurveNodeType::Rotation {
+                lcl_rotation = Some(curve_node);
+            } else if curve_node.actual_type == FbxAnimationCurveNodeType::Translation {
+                lcl_translation = Some(curve_node);
+            } else if curve_node.actual_type == FbxAnimationCurveNodeType::Scale {
+                lcl_scale = Some(curve_node);
             }
         }
+    }
 
-        fn add_vec3_key(track: &mut Track, value: Vector3<f32>) {
-            let curves = track.data_container_mut().curves_mut();
-            curves[0].add_key(CurveKey::new(0.0, value.x, CurveKeyKind::Constant));
-            curves[1].add_key(CurveKey::new(0.0, value.y, CurveKeyKind::Constant));
-            curves[2].add_key(CurveKey::new(0.0, value.z, CurveKeyKind::Constant));
-        }
+    if !has_curve_nodes {
+        return;
+    }
 
-        // Convert to engine format
-        let mut translation_track = Track::new_position();
-        translation_track.set_target(node_handle);
-        if let Some(lcl_translation) = lcl_translation {
-            fill_track(
-                &mut translation_track,
-                fbx_scene,
-                lcl_translation,
-                model.translation,
-                |v| v,
-            );
-        } else {
-            add_vec3_key(&mut translation_track, model.translation);
+    fn fill_track<F: Fn(f32) -> f32>(
+        track: &mut Track,
+        fbx_scene: &FbxScene,
+        fbx_track: &FbxAnimationCurveNode,
+        default: Vector3<f32>,
+        transform_value: F,
+    ) {
+        let curves = track.data_container_mut().curves_mut();
+
+        if !fbx_track.curves.contains_key("d|X") {
+            curves[0].add_key(CurveKey::new(0.0, default.x, CurveKeyKind::Constant));
         }
-
-        let mut rotation_track = Track::new_rotation();
-        rotation_track.set_target(node_handle);
-        if let Some(lcl_rotation) = lcl_rotation {
-            fill_track(
-                &mut rotation_track,
-                fbx_scene,
-                lcl_rotation,
-                model.rotation,
-                |v| v.to_radians(),
-            );
-        } else {
-            add_vec3_key(&mut rotation_track, model.rotation);
+        if !fbx_track.curves.contains_key("d|Y") {
+            curves[1].add_key(CurveKey::new(0.0, default.y, CurveKeyKind::Constant));
+        }
+        if !fbx_track.curves.contains_key("d|Z") {
+            curves[2].add_key(CurveKey::new(0.0, default.z, CurveKeyKind::Constant));
         }
 
-        let mut scale_track = Track::new_scale();
-        scale_track.set_target(node_handle);
-        if let Some(lcl_scale) = lcl_scale {
-            fill_track(&mut scale_track, fbx_scene, lcl_scale, model.scale, |v| v);
-        } else {
-            add_vec3_key(&mut scale_track, model.scale);
+        for (id, curve_handle) in fbx_track.curves.iter() {
+            let index = match id.as_str() {
+                "d|X" => Some(0),
+                "d|Y" => Some(1),
+                "d|Z" => Some(2),
+                _ => None,
+            };
+
+            if let Some(index) = index {
+                if let FbxComponent::AnimationCurve(fbx_curve) = fbx_scene.get(*curve_handle) {
+                    if fbx_curve.keys.is_empty() {
+                        curves[index].add_key(CurveKey::new(
+                            0.0,
+                            default[index],
+                            CurveKeyKind::Constant,
+                        ));
+                    } else {
+                        for key in fbx_curve.curve_keys(&transform_value) {
+                            curves[index].add_key(key)
+                        }
+                    }
+                }
+            }
         }
+    }
 
-        animation.add_track(translation_track);
-        animation.add_track(rotation_track);
-        animation.add_track(scale_track);
+    fn add_vec3_key(track: &mut Track, value: Vector3<f32>) {
+        let curves = track.data_container_mut().curves_mut();
+        curves[0].add_key(CurveKey::new(0.0, value.x, CurveKeyKind::Constant));
+        curves[1].add_key(CurveKey::new(0.0, value.y, CurveKeyKind::Constant));
+        curves[2].add_key(CurveKey::new(0.0, value.z, CurveKeyKind::Constant));
     }
 
-    animation.fit_length_to_content();
+    // Convert to engine format
+    let mut translation_track = Track::new_position();
+    translation_track.set_target(node_handle);
+    if let Some(lcl_translation) = lcl_translation {
+        fill_track(
+            &mut translation_track,
+            fbx_scene,
+            lcl_translation,
+            model.translation,
+            |v| v,
+        );
+    } else {
+        add_vec3_key(&mut translation_track, model.translation);
+    }
 
-    Ok(node_handle)
+    let mut rotation_track = Track::new_rotation();
+    rotation_track.set_target(node_handle);
+    if let Some(lcl_rotation) = lcl_rotation {
+        fill_track(
+            &mut rotation_track,
+            fbx_scene,
+            lcl_rotation,
+            model.rotation,
+            |v| v.to_radians(),
+        );
+    } else {
+        add_vec3_key(&mut rotation_track, model.rotation);
+    }
+
+    let mut scale_track = Track::new_scale();
+    scale_track.set_target(node_handle);
+    if let Some(lcl_scale) = lcl_scale {
+        fill_track(&mut scale_track, fbx_scene, lcl_scale, model.scale, |v| v);
+    } else {
+        add_vec3_key(&mut scale_track, model.scale);
+    }
+
+    take.animation.add_track(translation_track);
+    take.animation.add_track(rotation_track);
+    take.animation.add_track(scale_track);
 }
 
 ///
@@ -701,8 +764,7 @@ async fn convert(
 ) -> Result<(), FbxError> {
     let root = scene.graph.get_root();
 
-    let mut animation = Animation::default();
-    animation.set_name("Animation");
+    let mut takes = collect_takes(fbx_scene);
 
     let mut fbx_model_to_node_map = FxHashMap::default();
     for (component_handle, component) in fbx_scene.pair_iter() {
@@ -712,7 +774,7 @@ async fn convert(
                 model,
                 resource_manager.clone(),
                 &mut scene.graph,
-                &mut animation,
+                &mut takes,
                 model_path,
                 model_import_options,
             )
@@ -722,10 +784,17 @@ async fn convert(
         }
     }
 
+    // Every take becomes a separate animation, takes without animation content are skipped.
+    let mut animations_container = AnimationContainer::new();
+    for mut take in takes {
+        if !take.animation.tracks().is_empty() {
+            take.animation.fit_length_to_content();
+            animations_container.add(take.animation);
+        }
+    }
+
     // Do not create animation player if there's no animation content.
-    if !animation.tracks().is_empty() {
-        let mut animations_container = AnimationContainer::new();
-        animations_container.add(animation);
+    if animations_container.iter().next().is_some() {
         AnimationPlayerBuilder::new(BaseBuilder::new().with_name("AnimationPlayer"))
             .with_animations(animations_container)
             .build(&mut scene.graph);
@@ -1,5 +1,8 @@
 use crate::{
-    core::pool::Handle,
+    core::{
+        curve::{CurveKey, CurveKeyKind},
+        pool::Handle,
+    },
     resource::fbx::{
         document::{FbxNode, FbxNodeContainer},
         scene::{FbxComponent, FBX_TIME_UNIT},
@@ -7,15 +10,65 @@ use crate::{
 };
 use fxhash::FxHashMap;
 
+// https://help.autodesk.com/view/FBX/2016/ENU/?guid=__cpp_ref_class_fbx_anim_curve_def_html
+const INTERPOLATION_CONSTANT: i32 = 0x0000_0002;
+const INTERPOLATION_CUBIC: i32 = 0x0000_0008;
+const TANGENT_USER: i32 = 0x0000_0400;
+const TANGENT_BREAK: i32 = 0x0000_0800;
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum FbxKeyInterpolation {
+    Constant,
+    Linear,
+    /// Cubic interpolation with tangents calculated automatically (auto or TCB tangents), FBX files do not store
+    /// such tangents.
+    CubicAuto,
+    /// Cubic interpolation with tangents set by an artist. Slopes are in values per second.
+    CubicUser {
+        right_slope: f32,
+        next_left_slope: f32,
+    },
+}
+
+impl FbxKeyInterpolation {
+    fn from_flags(flags: i32, right_slope: f32, next_left_slope: f32) -> Self {
+        if flags & INTERPOLATION_CONSTANT != 0 {
+            Self::Constant
+        } else if flags & INTERPOLATION_CUBIC != 0 {
+            if flags & (TANGENT_USER | TANGENT_BREAK) != 0 {
+                Self::CubicUser {
+                    right_slope,
+                    next_left_slope,
+                }
+            } else {
+                Self::CubicAuto
+            }
+        } else {
+            Self::Linear
+        }
+    }
+}
+
 pub struct FbxTimeValuePair {
     pub time: f32,
     pub value: f32,
+    pub interpolation: FbxKeyInterpolation,
 }
 
 pub struct FbxAnimationCurve {
     pub keys: Vec<FbxTimeValuePair>,
 }
 
+// Converts a slope in curve space to a tangent of a cubic key, see CurveKey::left_tangent for the convention.
+fn slope_to_tangent(slope: f32, left: &FbxTimeValuePair, right: &FbxTimeValuePair) -> f32 {
+    let delta = (right.value - left.value).abs();
+    if delta <= f32::EPSILON {
+        0.0
+    } else {
+        slope * (right.time - left.time) / delta
+    }
+}
+
 impl FbxAnimationCurve {
     pub(in crate::resource::fbx) fn read(
         curve_handle: Handle<FbxNode>,
@@ -33,17 +86,97 @@ impl FbxAnimationCurve {
             ));
         }
 
+        // Key attributes are optional, every key is linear if there are no attributes. Each attribute is shared by
+        // a run of adjacent keys, its length is stored in KeyAttrRefCount.
+        let mut interpolations = Vec::new();
+        if let (Ok(flags_handle), Ok(ref_count_handle)) = (
+            nodes.find(curve_handle, "KeyAttrFlags"),
+            nodes.find(curve_handle, "KeyAttrRefCount"),
+        ) {
+            let flags_array = nodes.get_by_name(flags_handle, "a")?;
+            let ref_count_array = nodes.get_by_name(ref_count_handle, "a")?;
+            // Four values per attribute: right slope, next left slope, tangent weights and velocity.
+            let data_array = nodes
+                .find(curve_handle, "KeyAttrDataFloat")
+                .and_then(|handle| nodes.get_by_name(handle, "a"))
+                .ok();
+            let data = |index: usize| {
+                data_array
+                    .and_then(|array| array.get_attrib(index).ok())
+                    .and_then(|value| value.as_f32().ok())
+                    .unwrap_or_default()
+            };
+
+            for i in 0..flags_array
+                .attrib_count()
+                .min(ref_count_array.attrib_count())
+            {
+                let interpolation = FbxKeyInterpolation::from_flags(
+                    flags_array.get_attrib(i)?.as_i32()?,
+                    data(i * 4),
+                    data(i * 4 + 1),
+                );
+                let ref_count = ref_count_array.get_attrib(i)?.as_i32()?.max(0) as usize;
+                interpolations.extend(std::iter::repeat(interpolation).take(ref_count));
+            }
+        }
+
         let mut curve = FbxAnimationCurve { keys: Vec::new() };
 
         for i in 0..key_value_array.attrib_count() {
             curve.keys.push(FbxTimeValuePair {
                 time: ((key_time_array.get_attrib(i)?.as_i64()? as f64) * FBX_TIME_UNIT) as f32,
                 value: key_value_array.get_attrib(i)?.as_f32()?,
+                interpolation: interpolations
+                    .get(i)
+                    .cloned()
+                    .unwrap_or(FbxKeyInterpolation::Linear),
             });
         }
 
         Ok(curve)
     }
+
+    /// Converts keys of the curve to the keys of engine's curve. Values are converted using the given function, it
+    /// must be linear (unit conversion), so the tangents stay valid. Auto tangents are approximated by Catmull-Rom
+    /// splines.
+    pub fn curve_keys<F: Fn(f32) -> f32>(&self, transform_value: F) -> Vec<CurveKey> {
+        let mut keys = self
+            .keys
+            .iter()
+            .map(|pair| {
+                let kind = match pair.interpolation {
+                    FbxKeyInterpolation::Constant => CurveKeyKind::Constant,
+                    FbxKeyInterpolation::Linear => CurveKeyKind::Linear,
+                    FbxKeyInterpolation::CubicAuto => CurveKeyKind::CatmullRom,
+                    FbxKeyInterpolation::CubicUser { .. } => CurveKeyKind::Cubic {
+                        left_tangent: 0.0,
+                        right_tangent: 0.0,
+                    },
+                };
+                CurveKey::new(pair.time, transform_value(pair.value), kind)
+            })
+            .collect::<Vec<_>>();
+
+        for (i, pair) in self.keys.windows(2).enumerate() {
+            if let FbxKeyInterpolation::CubicUser {
+                right_slope,
+                next_left_slope,
+            } = pair[0].interpolation
+            {
+                keys[i].set_right_tangent(slope_to_tangent(right_slope, &pair[0], &pair[1]));
+                if let CurveKeyKind::Cubic { .. } = keys[i + 1].kind {
+                    keys[i + 1].set_left_tangent(slope_to_tangent(
+                        next_left_slope,
+                        &pair[0],
+                        &pair[1],
+                    ));
+                }
+            }
+        }
+
+        keys
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -75,3 +208,31 @@ impl FbxAnimationCurveNode {
         })
     }
 }
+
+/// Animation stack (take) is a named clip, that consists of one or more animation layers.
+pub struct FbxAnimationStack {
+    pub name: String,
+    pub layers: Vec<Handle<FbxComponent>>,
+}
+
+impl FbxAnimationStack {
+    pub fn read(node_handle: Handle<FbxNode>, nodes: &FbxNodeContainer) -> Result<Self, String> {
+        let mut name = nodes.get(node_handle).get_attrib(1)?.as_string();
+
+        // Remove prefix
+        if name.starts_with("AnimStack::") {
+            name = name.chars().skip(11).collect();
+        }
+
+        Ok(Self {
+            name,
+            layers: Default::default(),
+        })
+    }
+}
+
+/// Animation layer is a set of curve nodes of an animation stack.
+#[derive(Default)]
+pub struct FbxAnimationLayer {
+    pub curve_nodes: Vec<Handle<FbxComponent>>,
+}
@@ -9,7 +9,9 @@ use crate::{
         error::FbxError,
         fix_index,
         scene::{
-            animation::{FbxAnimationCurve, FbxAnimationCurveNode},
+            animation::{
+                FbxAnimationCurve, FbxAnimationCurveNode, FbxAnimationLayer, FbxAnimationStack,
+            },
             geometry::FbxGeometry,
             light::FbxLight,
             model::FbxModel,
@@ -91,6 +93,15 @@ impl FbxScene {
                         FbxAnimationCurveNode::read(*object_handle, nodes)?,
                     ));
                 }
+                "AnimationStack" => {
+                    component_handle = components.spawn(FbxComponent::AnimationStack(
+                        FbxAnimationStack::read(*object_handle, nodes)?,
+                    ));
+                }
+                "AnimationLayer" => {
+                    component_handle = components
+                        .spawn(FbxComponent::AnimationLayer(FbxAnimationLayer::default()));
+                }
                 "Deformer" => match object.get_attrib(2)?.as_string().as_str() {
                     "Cluster" => {
                         component_handle = components.spawn(FbxComponent::SubDeformer(
@@ -170,6 +181,18 @@ fn link_child_with_parent_component(
                 anim_curve_node.curves.insert(property, child_handle);
             }
         }
+        // Link animation stack with animation layers
+        FbxComponent::AnimationStack(stack) => {
+            if let FbxComponent::AnimationLayer(_) = child {
+                stack.layers.push(child_handle);
+            }
+        }
+        // Link animation layer with animation curve nodes
+        FbxComponent::AnimationLayer(layer) => {
+            if let FbxComponent::AnimationCurveNode(_) = child {
+                layer.curve_nodes.push(child_handle);
+            }
+        }
         // Link deformer with sub-deformers
         FbxComponent::Deformer(deformer) => {
             if let FbxComponent::SubDeformer(_) = child {
@@ -203,6 +226,8 @@ pub enum FbxComponent {
     Material(FbxMaterial),
     AnimationCurveNode(FbxAnimationCurveNode),
     AnimationCurve(FbxAnimationCurve),
+    AnimationStack(FbxAnimationStack),
+    AnimationLayer(FbxAnimationLayer),
     Geometry(Box<FbxGeometry>),
 }
 
@@ -0,0 +1,288 @@
+//! Contains all methods to import animations from glTF 2.0 files. See [`import_animations`] docs for more info.
+//!
+//! The module is available only with `gltf` feature enabled.
+
+use crate::{
+    animation::{track::Track, Animation, AnimationContainer},
+    core::{
+        algebra::{Quaternion, UnitQuaternion},
+        curve::{Curve, CurveKey, CurveKeyKind},
+        pool::Handle,
+    },
+    scene::{graph::Graph, node::Node},
+    utils::log::Log,
+};
+use ::gltf::animation::{util::ReadOutputs, Interpolation};
+use std::{
+    f32::consts::PI,
+    fmt::{Display, Formatter},
+    path::Path,
+};
+
+/// All possible errors that can occur during glTF animation import.
+#[derive(Debug)]
+pub enum GltfError {
+    /// An error has occurred during parsing of a glTF file or loading of its buffers.
+    Gltf(::gltf::Error),
+}
+
+impl Display for GltfError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GltfError::Gltf(v) => {
+                write!(f, "glTF: An error has occurred: {v}")
+            }
+        }
+    }
+}
+
+impl From<::gltf::Error> for GltfError {
+    fn from(err: ::gltf::Error) -> Self {
+        GltfError::Gltf(err)
+    }
+}
+
+/// A sampled channel, `values` contain `components` values per key (or `3 * components` for cubic splines, where each
+/// key is stored as in-tangent, value, out-tangent).
+struct Samples {
+    times: Vec<f32>,
+    values: Vec<f32>,
+    components: usize,
+    interpolation: Interpolation,
+}
+
+impl Samples {
+    fn key_value(&self, key: usize, component: usize) -> f32 {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.values[(3 * key + 1) * self.components + component],
+            Interpolation::Linear | Interpolation::Step => {
+                self.values[key * self.components + component]
+            }
+        }
+    }
+
+    fn key_tangents(&self, key: usize, component: usize) -> (f32, f32) {
+        (
+            self.values[3 * key * self.components + component],
+            self.values[(3 * key + 2) * self.components + component],
+        )
+    }
+
+    fn keys_count(&self) -> usize {
+        let stride = match self.interpolation {
+            Interpolation::CubicSpline => 3 * self.components,
+            Interpolation::Linear | Interpolation::Step => self.components,
+        };
+        self.times.len().min(self.values.len() / stride.max(1))
+    }
+
+    /// Converts glTF cubic spline tangent (value change per second) to the tangent format used by
+    /// [`CurveKeyKind::Cubic`], which is scaled by the value change over the span.
+    fn span_tangent(&self, from: usize, to: usize, component: usize, tangent: f32) -> f32 {
+        let duration = self.times[to] - self.times[from];
+        let delta = (self.key_value(to, component) - self.key_value(from, component)).abs();
+        if delta > f32::EPSILON {
+            tangent * duration / delta
+        } else {
+            0.0
+        }
+    }
+
+    fn make_curve(&self, component: usize) -> Curve {
+        let count = self.keys_count();
+        let mut keys = Vec::with_capacity(count);
+        for key in 0..count {
+            let kind = match self.interpolation {
+                Interpolation::Step => CurveKeyKind::Constant,
+                Interpolation::Linear => CurveKeyKind::Linear,
+                Interpolation::CubicSpline => {
+                    let (in_tangent, out_tangent) = self.key_tangents(key, component);
+                    CurveKeyKind::Cubic {
+                        left_tangent: if key > 0 {
+                            self.span_tangent(key - 1, key, component, in_tangent)
+                        } else {
+                            0.0
+                        },
+                        right_tangent: if key + 1 < count {
+                            self.span_tangent(key, key + 1, component, out_tangent)
+                        } else {
+                            0.0
+                        },
+                    }
+                }
+            };
+            keys.push(CurveKey::new(
+                self.times[key],
+                self.key_value(key, component),
+                kind,
+            ));
+        }
+        Curve::from(keys)
+    }
+}
+
+/// Converts a set of quaternion keys to Euler angles, that are used by rotation tracks. Angles are "unwrapped", so
+/// there are no sudden jumps of 360 degrees between adjacent keys.
+fn rotations_to_euler(rotations: &[[f32; 4]]) -> Vec<f32> {
+    let mut angles = Vec::with_capacity(rotations.len() * 3);
+    let mut prev: Option<[f32; 3]> = None;
+    for &[x, y, z, w] in rotations {
+        let (roll, pitch, yaw) =
+            UnitQuaternion::from_quaternion(Quaternion::new(w, x, y, z)).euler_angles();
+        let mut current = [roll, pitch, yaw];
+        if let Some(prev) = prev {
+            for (angle, prev) in current.iter_mut().zip(prev.iter()) {
+                while *angle - *prev > PI {
+                    *angle -= 2.0 * PI;
+                }
+                while *angle - *prev < -PI {
+                    *angle += 2.0 * PI;
+                }
+            }
+        }
+        angles.extend_from_slice(&current);
+        prev = Some(current);
+    }
+    angles
+}
+
+/// Imports every animation from a glTF 2.0 file (both `.gltf` and `.glb` are supported) and converts them to
+/// [`Animation`]s. The tracks of the animations are bound to the nodes of the given graph (starting from `root`), that
+/// have the same names as the target nodes of glTF animation channels. Channels, whose target nodes cannot be found
+/// in the graph, are skipped with a warning.
+///
+/// # Conversion details
+///
+/// - Translation and scale channels are converted to respective tracks, key kinds are mapped as follows: `STEP` -
+/// [`CurveKeyKind::Constant`], `LINEAR` - [`CurveKeyKind::Linear`], `CUBICSPLINE` - [`CurveKeyKind::Cubic`] with the
+/// tangents taken from the file.
+/// - Rotation channels are converted to tracks that use Euler angles (see [`crate::animation::container::TrackValueKind::UnitQuaternion`]). Since
+/// quaternion tangents cannot be mapped to Euler angles, `CUBICSPLINE` rotations use the values of the keys with linear
+/// interpolation. `STEP` rotations use [`CurveKeyKind::Constant`] keys.
+/// - Morph target weights are not supported and skipped with a warning.
+///
+/// Time slice of every animation is fitted to the range of its keys.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use fyrox::{
+///     core::pool::Handle,
+///     resource::gltf,
+///     scene::{animation::AnimationPlayerBuilder, base::BaseBuilder, graph::Graph, node::Node},
+/// };
+///
+/// fn add_gltf_animations(graph: &mut Graph, character_root: Handle<Node>) -> Handle<Node> {
+///     let animations =
+///         gltf::import_animations("data/character.glb", graph, character_root).unwrap();
+///     AnimationPlayerBuilder::new(BaseBuilder::new())
+///         .with_animations(animations)
+///         .build(graph)
+/// }
+/// ```
+pub fn import_animations<P: AsRef<Path>>(
+    path: P,
+    graph: &Graph,
+    root: Handle<Node>,
+) -> Result<AnimationContainer, GltfError> {
+    let (document, buffers, _) = ::gltf::import(path.as_ref())?;
+
+    let mut container = AnimationContainer::new();
+
+    for (index, gltf_animation) in document.animations().enumerate() {
+        let mut animation = Animation::default();
+        animation.set_name(
+            gltf_animation
+                .name()
+                .map(|name| name.to_owned())
+                .unwrap_or_else(|| format!("Animation{index}")),
+        );
+
+        for channel in gltf_animation.channels() {
+            let target_name = channel.target().node().name().unwrap_or_default();
+            let target = if let Some((handle, _)) = graph.find_by_name(root, target_name) {
+                handle
+            } else {
+                Log::warn(format!(
+                    "glTF: Channel of animation {} was skipped, because there is no node {} in the graph!",
+                    animation.name(),
+                    target_name
+                ));
+                continue;
+            };
+
+            let reader = channel.reader(|buffer| Some(buffers[buffer.index()].0.as_slice()));
+            let times = if let Some(inputs) = reader.read_inputs() {
+                inputs.collect::<Vec<_>>()
+            } else {
+                continue;
+            };
+            let interpolation = channel.sampler().interpolation();
+
+            let (mut track, samples) = match reader.read_outputs() {
+                Some(ReadOutputs::Translations(translations)) => (
+                    Track::new_position(),
+                    Samples {
+                        times,
+                        values: translations.flatten().collect(),
+                        components: 3,
+                        interpolation,
+                    },
+                ),
+                Some(ReadOutputs::Scales(scales)) => (
+                    Track::new_scale(),
+                    Samples {
+                        times,
+                        values: scales.flatten().collect(),
+                        components: 3,
+                        interpolation,
+                    },
+                ),
+                Some(ReadOutputs::Rotations(rotations)) => {
+                    let mut rotations = rotations.into_f32().collect::<Vec<_>>();
+                    let interpolation = if matches!(interpolation, Interpolation::CubicSpline) {
+                        // Keep only the values, skipping in- and out-tangents.
+                        rotations = rotations.into_iter().skip(1).step_by(3).collect();
+                        Interpolation::Linear
+                    } else {
+                        interpolation
+                    };
+                    (
+                        Track::new_rotation(),
+                        Samples {
+                            times,
+                            values: rotations_to_euler(&rotations),
+                            components: 3,
+                            interpolation,
+                        },
+                    )
+                }
+                Some(ReadOutputs::MorphTargetWeights(_)) => {
+                    Log::warn(format!(
+                        "glTF: Morph target weights channel of animation {} was skipped, because it is not supported!",
+                        animation.name()
+                    ));
+                    continue;
+                }
+                None => continue,
+            };
+
+            for (component, curve) in track
+                .data_container_mut()
+                .curves_mut()
+                .iter_mut()
+                .enumerate()
+            {
+                *curve = samples.make_curve(component);
+            }
+            track.set_target(target);
+            animation.add_track(track);
+        }
+
+        animation.fit_time_slice_to_tracks();
+
+        container.add(animation);
+    }
+
+    Ok(container)
+}
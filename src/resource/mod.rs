@@ -2,7 +2,10 @@
 
 #![warn(missing_docs)]
 
+pub mod absm;
 pub mod curve;
 pub mod fbx;
+#[cfg(feature = "gltf")]
+pub mod gltf;
 pub mod model;
 pub mod texture;
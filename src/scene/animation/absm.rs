@@ -13,7 +13,7 @@ use crate::{
     },
     engine::resource_manager::ResourceManager,
     scene::{
-        animation::AnimationPlayer,
+        animation::{run_pose_post_processors, AnimationPlayer},
         base::{Base, BaseBuilder},
         graph::Graph,
         node::{Node, NodeTrait, TypeUuidProvider, UpdateContext},
@@ -188,12 +188,33 @@ impl NodeTrait for AnimationBlendingStateMachine {
             // do than instead.
             animation_player.set_auto_apply(false);
 
-            let pose = self
-                .machine
-                .get_value_mut_silent()
-                .evaluate_pose(&animation_player.animations, context.dt);
+            let dt = animation_player.scaled_delta_time(context.dt);
+            let machine = self.machine.get_value_mut_silent();
+            machine.evaluate_pose(&animation_player.animations, dt);
+            machine.apply_state_speeds(animation_player.animations.get_value_mut_silent());
+            let pose = machine.pose();
+
+            // Post-processors must be detached from the player, because they need access to every node.
+            let mut post_processors = std::mem::take(&mut animation_player.pose_post_processors);
 
             pose.apply_internal(context.nodes);
+
+            if !post_processors.is_empty() {
+                run_pose_post_processors(
+                    &mut post_processors,
+                    *self.animation_player,
+                    context.nodes,
+                    dt,
+                );
+
+                if let Some(animation_player) = context
+                    .nodes
+                    .try_borrow_mut(*self.animation_player)
+                    .and_then(|n| n.query_component_mut::<AnimationPlayer>())
+                {
+                    animation_player.pose_post_processors = post_processors;
+                }
+            }
         }
     }
 
@@ -2,9 +2,10 @@
 //! See [`AnimationPlayer`] docs for more info.
 
 use crate::{
-    animation::AnimationContainer,
+    animation::{headless::AnimationUpdate, Animation, AnimationContainer, LoopMode},
     core::{
         math::aabb::AxisAlignedBoundingBox,
+        parking_lot::Mutex,
         pool::Handle,
         reflect::prelude::*,
         uuid::{uuid, Uuid},
@@ -14,14 +15,281 @@ use crate::{
     engine::resource_manager::ResourceManager,
     scene::{
         base::{Base, BaseBuilder},
-        graph::Graph,
+        graph::{Graph, NodePool},
         node::{Node, NodeTrait, TypeUuidProvider, UpdateContext},
+        Scene,
     },
 };
-use std::ops::{Deref, DerefMut};
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
+use strum_macros::{AsRefStr, EnumString, EnumVariantNames};
 
 pub mod absm;
 
+/// Defines how often an animation player updates its animations. Reduced update rate is a performance lever for large
+/// scenes with lots of animated characters (crowds): distant or off-screen characters could be animated less often.
+/// Skipped time is accumulated, so the animations advance exactly the same amount of time in total, just with larger
+/// steps. Between the updates, animated nodes keep the last applied pose. The time, that passes while the player is
+/// culled, is not accumulated - the animations continue from where they were stopped.
+#[derive(Clone, Debug, PartialEq, Visit, Reflect, AsRefStr, EnumString, EnumVariantNames)]
+pub enum UpdateMode {
+    /// Animations are updated every frame.
+    Always,
+
+    /// Animations are updated once per given amount of frames. Zero is treated as one.
+    EveryNthFrame(u32),
+
+    /// Update rate depends on the distance between the animation player and an observer node (usually a camera).
+    /// Within `near` distance the animations are updated every frame, the interval between updates then grows
+    /// linearly up to `max_interval` frames at `far` distance. Beyond `far` distance the player is fully culled -
+    /// the animations are not updated and their poses are not applied at all, until the observer comes closer. If
+    /// the observer node does not exist, the animations are updated every frame.
+    DistanceBased {
+        /// A handle of an observer node.
+        observer: Handle<Node>,
+        /// A distance within which the animations are updated every frame.
+        near: f32,
+        /// A distance beyond which the animation player is culled.
+        far: f32,
+        /// Max interval (in frames) between the updates.
+        max_interval: u32,
+    },
+}
+
+impl Default for UpdateMode {
+    fn default() -> Self {
+        Self::Always
+    }
+}
+
+/// Defines how the playheads of the animations of an animation player are synchronized with some other clock. It
+/// allows you to keep multiple animation players in lockstep (for example, for a dance of several characters or for
+/// networked synchronization), regardless of when each of them was started. Animations are aligned right before they
+/// are advanced by the time delta of a frame. Small drift (less than the tolerance, see
+/// [`AnimationPlayer::set_sync_tolerance`]) is not corrected to avoid jitter, larger drift is corrected by moving the
+/// playhead.
+#[derive(Clone, Debug, PartialEq, Visit, Reflect, AsRefStr, EnumString, EnumVariantNames)]
+pub enum TimeSync {
+    /// Animations advance independently.
+    None,
+
+    /// Playheads of the animations follow the playheads of the animations with the same names of another animation
+    /// player (the time master). Animations that have no counterpart in the master player advance independently.
+    /// Keep in mind, that the players are updated one-by-one, so the playheads could differ by the time delta of a
+    /// single frame, if the master player is updated first.
+    Master(Handle<Node>),
+
+    /// Playheads of the animations follow a shared external clock, that is set by [`AnimationPlayer::set_sync_time`].
+    /// The time of the clock is relative to the beginning of the time slice of each animation.
+    External,
+}
+
+impl Default for TimeSync {
+    fn default() -> Self {
+        Self::None
+    }
+}
+
+/// Moves the playhead of the animation to the given time position, if it drifted away further than `tolerance`.
+/// Drift of looping animations is measured along the shortest path around the loop.
+fn align_playhead(animation: &mut Animation, target: f32, tolerance: f32) {
+    let mut delta = target - animation.time_position();
+    if animation.loop_mode() == LoopMode::Loop {
+        let length = animation.length();
+        if length > 0.0 {
+            delta = (delta + length * 0.5).rem_euclid(length) - length * 0.5;
+        }
+    }
+
+    if delta.abs() > tolerance {
+        let time_position = animation.time_position();
+        animation.set_time_position(time_position + delta);
+    }
+}
+
+/// A context of [`PosePostProcessor::process`].
+pub struct PosePostProcessContext<'a> {
+    /// A handle of the animation player, that owns the post-processor.
+    pub player: Handle<Node>,
+
+    /// A reference to a pool with nodes from a scene graph. Animation poses are already applied to the nodes.
+    pub nodes: &'a mut NodePool,
+
+    /// An amount of time (in seconds), that the animations were advanced by.
+    pub dt: f32,
+}
+
+/// Pose post-processor is a hook, that is invoked every frame right after the animation poses were applied to scene
+/// nodes, but before rendering. It is the integration point for procedural adjustments of animated poses, such as
+/// inverse kinematics (foot planting, look-at, etc.). Post-processors are owned by an animation player (see
+/// [`AnimationPlayer::add_pose_post_processor`]) and are invoked in the order of registration:
+///
+/// - by the animation player itself, if it applied any pose - either automatically (see
+/// [`AnimationPlayer::set_auto_apply`]) or the pose of an animation that opted in for automatic applying (see
+/// [`Animation::set_auto_apply`]);
+/// - by an animation blending state machine that uses the animation player, right after the machine applied its
+/// pose.
+///
+/// Post-processors are not serialized, they must be registered from code (for example, from a script).
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::{
+///     core::{algebra::UnitQuaternion, pool::Handle},
+///     scene::{
+///         animation::{PosePostProcessContext, PosePostProcessor},
+///         node::Node,
+///     },
+/// };
+///
+/// // Overrides animated rotation of a head bone.
+/// #[derive(Clone, Debug)]
+/// struct LockHead {
+///     head: Handle<Node>,
+///     rotation: UnitQuaternion<f32>,
+/// }
+///
+/// impl PosePostProcessor for LockHead {
+///     fn process(&mut self, context: &mut PosePostProcessContext) {
+///         if let Some(head) = context.nodes.try_borrow_mut(self.head) {
+///             head.local_transform_mut().set_rotation(self.rotation);
+///         }
+///     }
+///
+///     fn clone_box(&self) -> Box<dyn PosePostProcessor> {
+///         Box::new(self.clone())
+///     }
+/// }
+/// ```
+pub trait PosePostProcessor: Send + Debug + 'static {
+    /// Adjusts the pose of animated nodes.
+    fn process(&mut self, context: &mut PosePostProcessContext);
+
+    /// Creates exact copy of the post-processor.
+    fn clone_box(&self) -> Box<dyn PosePostProcessor>;
+}
+
+impl Clone for Box<dyn PosePostProcessor> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+fn run_pose_post_processors(
+    post_processors: &mut [Box<dyn PosePostProcessor>],
+    player: Handle<Node>,
+    nodes: &mut NodePool,
+    dt: f32,
+) {
+    let mut context = PosePostProcessContext { player, nodes, dt };
+    for post_processor in post_processors.iter_mut() {
+        post_processor.process(&mut context);
+    }
+}
+
+/// Time source defines how much time the animations of an animation player advance every frame. By default, an
+/// animation player uses real frame time, a custom time source allows you to drive the animations from an arbitrary
+/// clock - game time (that could be paused independently of rendering), a sequencer, a rewindable replay, etc. The
+/// time delta of the source is then multiplied by the time scale of the player (see
+/// [`AnimationPlayer::set_time_scale`]).
+///
+/// The source is queried by the animation player and by every animation blending state machine that uses the player,
+/// so it must return the same delta during the same frame. Use shared state (see [`ManualTime`]) to control the source
+/// from outside. Time sources are not serialized, they must be set from code (for example, from a script).
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::scene::animation::TimeSource;
+///
+/// // Plays animations at a fixed rate, no matter how long frames are.
+/// #[derive(Clone, Debug)]
+/// struct FixedStep {
+///     step: f32,
+/// }
+///
+/// impl TimeSource for FixedStep {
+///     fn delta_time(&self, _dt: f32) -> f32 {
+///         self.step
+///     }
+///
+///     fn clone_box(&self) -> Box<dyn TimeSource> {
+///         Box::new(self.clone())
+///     }
+/// }
+/// ```
+pub trait TimeSource: Send + Debug + 'static {
+    /// Returns an amount of time (in seconds) the animations must be advanced by in current frame. `dt` is the real
+    /// time, that has passed since the last frame. Negative values move the animations backwards, the same way as
+    /// negative playback speed does.
+    fn delta_time(&self, dt: f32) -> f32;
+
+    /// Creates exact copy of the time source.
+    fn clone_box(&self) -> Box<dyn TimeSource>;
+}
+
+impl Clone for Box<dyn TimeSource> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// A time source, that is fully controlled from code - it ignores real frame time and reports the time delta that
+/// was set using [`Self::set_delta`]. The delta is kept until it is changed, zero delta pauses the animations. Clones
+/// of the source share the same clock, so one clone could be given to an animation player and the other one could be
+/// kept by a sequencer or a replay system.
+///
+/// # Example
+///
+/// ```rust
+/// use fyrox::scene::animation::{AnimationPlayer, ManualTime};
+///
+/// fn attach_replay_clock(animation_player: &mut AnimationPlayer) -> ManualTime {
+///     let clock = ManualTime::new();
+///     animation_player.set_time_source(Some(Box::new(clock.clone())));
+///     clock
+/// }
+///
+/// fn rewind(clock: &ManualTime, dt: f32) {
+///     clock.set_delta(-dt);
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ManualTime {
+    delta: Arc<Mutex<f32>>,
+}
+
+impl ManualTime {
+    /// Creates new time source with zero delta.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets time delta (in seconds), that will be reported every frame until changed.
+    pub fn set_delta(&self, delta: f32) {
+        *self.delta.lock() = delta;
+    }
+
+    /// Returns current time delta of the source.
+    pub fn delta(&self) -> f32 {
+        *self.delta.lock()
+    }
+}
+
+impl TimeSource for ManualTime {
+    fn delta_time(&self, _dt: f32) -> f32 {
+        self.delta()
+    }
+
+    fn clone_box(&self) -> Box<dyn TimeSource> {
+        Box::new(self.clone())
+    }
+}
+
 /// Animation player is a node that contains multiple animations. It updates and plays all the animations.
 /// The node could be a source of animations for animation blending state machines. To learn more about
 /// animations, see [`crate::animation::Animation`] docs.
@@ -96,6 +364,47 @@ pub struct AnimationPlayer {
     base: Base,
     animations: InheritableVariable<AnimationContainer>,
     auto_apply: bool,
+
+    #[reflect(min_value = 0.0, step = 0.05)]
+    #[reflect(setter = "set_time_scale")]
+    #[visit(optional)] // Backward compatibility
+    time_scale: InheritableVariable<f32>,
+
+    #[visit(optional)] // Backward compatibility
+    update_mode: InheritableVariable<UpdateMode>,
+
+    #[visit(optional)] // Backward compatibility
+    time_sync: InheritableVariable<TimeSync>,
+
+    #[reflect(min_value = 0.0, step = 0.01)]
+    #[reflect(setter = "set_sync_tolerance")]
+    #[visit(optional)] // Backward compatibility
+    sync_tolerance: InheritableVariable<f32>,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    #[visit(skip)]
+    sync_time: f32,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    #[visit(skip)]
+    accumulated_dt: f32,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    #[visit(skip)]
+    skipped_frames: u32,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    #[visit(skip)]
+    pose_post_processors: Vec<Box<dyn PosePostProcessor>>,
+
+    // Non-serialized
+    #[reflect(hidden)]
+    #[visit(skip)]
+    time_source: Option<Box<dyn TimeSource>>,
 }
 
 impl Default for AnimationPlayer {
@@ -104,15 +413,28 @@ impl Default for AnimationPlayer {
             base: Default::default(),
             animations: Default::default(),
             auto_apply: true,
+            time_scale: InheritableVariable::new(1.0),
+            update_mode: Default::default(),
+            time_sync: Default::default(),
+            sync_tolerance: InheritableVariable::new(DEFAULT_SYNC_TOLERANCE),
+            sync_time: 0.0,
+            accumulated_dt: 0.0,
+            skipped_frames: 0,
+            pose_post_processors: Default::default(),
+            time_source: None,
         }
     }
 }
 
+/// Default drift tolerance of synchronized animation players (in seconds), see [`AnimationPlayer::set_sync_tolerance`].
+pub const DEFAULT_SYNC_TOLERANCE: f32 = 0.05;
+
 impl AnimationPlayer {
     /// Enables or disables automatic animation pose applying. Every animation in the node is updated first, and
     /// then their output pose could be applied to the graph, so the animation takes effect. Automatic applying
     /// is useful when you need your animations to be applied immediately to the graph, but in some cases (if you're
-    /// using animation blending state machines for example) this functionality is undesired.
+    /// using animation blending state machines for example) this functionality is undesired. Poses of the animations,
+    /// that opted in for automatic applying (see [`Animation::set_auto_apply`]), are applied regardless of this flag.
     pub fn set_auto_apply(&mut self, auto_apply: bool) {
         self.auto_apply = auto_apply;
     }
@@ -123,6 +445,269 @@ impl AnimationPlayer {
         self.auto_apply
     }
 
+    /// Registers a new pose post-processor, that will be invoked every frame after animation poses are applied. See
+    /// [`PosePostProcessor`] docs for more info.
+    pub fn add_pose_post_processor<P: PosePostProcessor>(&mut self, post_processor: P) {
+        self.pose_post_processors.push(Box::new(post_processor));
+    }
+
+    /// Returns a reference to the list of registered pose post-processors.
+    pub fn pose_post_processors(&self) -> &[Box<dyn PosePostProcessor>] {
+        &self.pose_post_processors
+    }
+
+    /// Returns a mutable reference to the list of registered pose post-processors. It could be used to remove or to
+    /// reorder post-processors.
+    pub fn pose_post_processors_mut(&mut self) -> &mut Vec<Box<dyn PosePostProcessor>> {
+        &mut self.pose_post_processors
+    }
+
+    /// Sets new time scale of the animation player and returns the old one. The time scale multiplies the time delta
+    /// that is used to update every animation of the player (as well as animation blending state machines that use the
+    /// player), so it affects all the animations at once. It is useful for slow-motion ("bullet time") effects. Zero
+    /// time scale pauses all the animations, but their poses are still applied, so animated nodes are "frozen" in
+    /// their current pose. Negative values are clamped to zero, use [`Animation::set_speed`] or
+    /// [`Animation::set_direction`] to play animations in reverse.
+    pub fn set_time_scale(&mut self, time_scale: f32) -> f32 {
+        self.time_scale
+            .set_value_and_mark_modified(time_scale.max(0.0))
+    }
+
+    /// Returns current time scale of the animation player. Default value is 1.0.
+    pub fn time_scale(&self) -> f32 {
+        *self.time_scale
+    }
+
+    /// Sets new time source of the animation player and returns the old one. `None` means that the animations are
+    /// advanced by real frame time (default behaviour). See [`TimeSource`] docs for more info.
+    pub fn set_time_source(
+        &mut self,
+        time_source: Option<Box<dyn TimeSource>>,
+    ) -> Option<Box<dyn TimeSource>> {
+        std::mem::replace(&mut self.time_source, time_source)
+    }
+
+    /// Returns current time source of the animation player (if any).
+    pub fn time_source(&self) -> Option<&dyn TimeSource> {
+        self.time_source.as_deref()
+    }
+
+    /// Returns an amount of time (in seconds) the animations of the player are advanced by, when the given amount of
+    /// real time has passed. It takes the time source and the time scale of the player into account.
+    pub fn scaled_delta_time(&self, dt: f32) -> f32 {
+        let dt = self
+            .time_source
+            .as_ref()
+            .map_or(dt, |time_source| time_source.delta_time(dt));
+        dt * *self.time_scale
+    }
+
+    /// Sets new update mode of the animation player and returns the old one. See [`UpdateMode`] docs for more info.
+    /// Keep in mind, that animation blending state machines that use the player are updated separately, the update
+    /// mode affects only the animation player itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     core::pool::Handle,
+    ///     scene::{
+    ///         animation::{AnimationPlayer, UpdateMode},
+    ///         node::Node,
+    ///     },
+    /// };
+    ///
+    /// fn enable_lod(animation_player: &mut AnimationPlayer, camera: Handle<Node>) {
+    ///     animation_player.set_update_mode(UpdateMode::DistanceBased {
+    ///         observer: camera,
+    ///         near: 10.0,
+    ///         far: 100.0,
+    ///         max_interval: 8,
+    ///     });
+    /// }
+    /// ```
+    pub fn set_update_mode(&mut self, update_mode: UpdateMode) -> UpdateMode {
+        self.update_mode.set_value_and_mark_modified(update_mode)
+    }
+
+    /// Returns current update mode of the animation player.
+    pub fn update_mode(&self) -> &UpdateMode {
+        &self.update_mode
+    }
+
+    /// Sets new time synchronization mode of the animation player and returns the old one. See [`TimeSync`] docs for
+    /// more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     core::pool::Handle,
+    ///     scene::{
+    ///         animation::{AnimationPlayer, TimeSync},
+    ///         graph::Graph,
+    ///         node::Node,
+    ///     },
+    /// };
+    ///
+    /// fn synchronize_dancers(graph: &mut Graph, lead: Handle<Node>, dancers: &[Handle<Node>]) {
+    ///     for &dancer in dancers {
+    ///         if let Some(player) = graph[dancer].query_component_mut::<AnimationPlayer>() {
+    ///             player.set_time_sync(TimeSync::Master(lead));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn set_time_sync(&mut self, time_sync: TimeSync) -> TimeSync {
+        self.time_sync.set_value_and_mark_modified(time_sync)
+    }
+
+    /// Returns current time synchronization mode of the animation player.
+    pub fn time_sync(&self) -> &TimeSync {
+        &self.time_sync
+    }
+
+    /// Sets max allowed difference (in seconds) between the playheads of the animations and the clock they're
+    /// synchronized with (see [`TimeSync`]) and returns the old one. Larger difference is corrected by moving the
+    /// playheads. Negative values are clamped to zero, default value is [`DEFAULT_SYNC_TOLERANCE`].
+    pub fn set_sync_tolerance(&mut self, tolerance: f32) -> f32 {
+        self.sync_tolerance
+            .set_value_and_mark_modified(tolerance.max(0.0))
+    }
+
+    /// Returns current drift tolerance of the time synchronization.
+    pub fn sync_tolerance(&self) -> f32 {
+        *self.sync_tolerance
+    }
+
+    /// Sets current time of the shared external clock, that is used by [`TimeSync::External`] mode. The time should be
+    /// set every frame before the scene is updated (for example, from a timeline or from the time of a server).
+    pub fn set_sync_time(&mut self, time: f32) {
+        self.sync_time = time;
+    }
+
+    /// Returns current time of the shared external clock.
+    pub fn sync_time(&self) -> f32 {
+        self.sync_time
+    }
+
+    /// Aligns the playheads of the animations with the clock, defined by the time synchronization mode.
+    fn synchronize(&mut self, nodes: &NodePool) {
+        let tolerance = *self.sync_tolerance;
+        match *self.time_sync {
+            TimeSync::None => (),
+            TimeSync::Master(master) => {
+                if let Some(master) = nodes
+                    .try_borrow(master)
+                    .and_then(|master| master.query_component_ref::<AnimationPlayer>())
+                {
+                    for animation in self.animations.get_value_mut_silent().iter_mut() {
+                        if let Some((_, master_animation)) =
+                            master.animations.find_by_name_ref(animation.name())
+                        {
+                            align_playhead(animation, master_animation.time_position(), tolerance);
+                        }
+                    }
+                }
+            }
+            TimeSync::External => {
+                let time = self.sync_time;
+                for animation in self.animations.get_value_mut_silent().iter_mut() {
+                    let target = animation.time_slice().start + time;
+                    align_playhead(animation, target, tolerance);
+                }
+            }
+        }
+    }
+
+    /// Returns an interval (in frames) between the updates of the animations, or `None` if the player is culled.
+    fn update_interval(&self, nodes: &NodePool) -> Option<u32> {
+        match *self.update_mode {
+            UpdateMode::Always => Some(1),
+            UpdateMode::EveryNthFrame(interval) => Some(interval.max(1)),
+            UpdateMode::DistanceBased {
+                observer,
+                near,
+                far,
+                max_interval,
+            } => {
+                let distance = if let Some(observer) = nodes.try_borrow(observer) {
+                    observer
+                        .global_position()
+                        .metric_distance(&self.global_position())
+                } else {
+                    return Some(1);
+                };
+
+                if distance > far {
+                    None
+                } else if distance <= near {
+                    Some(1)
+                } else {
+                    let t = if far > near {
+                        (distance - near) / (far - near)
+                    } else {
+                        1.0
+                    };
+                    let extra = max_interval.max(1) - 1;
+                    Some(1 + (t * extra as f32).round() as u32)
+                }
+            }
+        }
+    }
+
+    /// Applies current output poses of the animations to the given graph immediately, without advancing the
+    /// animations. Weights and masks of the animations are respected, see [`AnimationContainer::apply_poses`] docs
+    /// for more info. It could be useful if automatic pose applying is disabled (see [`Self::set_auto_apply`]), but
+    /// the "raw" pose of the animations is still needed occasionally (for example for a preview).
+    ///
+    /// # Example
+    ///
+    /// Animation player is usually a node of the same graph, that is animated by the player. In this case the node
+    /// should be temporarily taken out of the graph:
+    ///
+    /// ```rust
+    /// use fyrox::{
+    ///     core::pool::Handle,
+    ///     scene::{animation::AnimationPlayer, graph::Graph, node::Node},
+    /// };
+    ///
+    /// fn preview_raw_pose(graph: &mut Graph, animation_player: Handle<Node>) {
+    ///     let (ticket, node) = graph.take_reserve(animation_player);
+    ///     if let Some(animation_player) = node.query_component_ref::<AnimationPlayer>() {
+    ///         animation_player.apply_pose_now(graph);
+    ///     }
+    ///     graph.put_back(ticket, node);
+    /// }
+    /// ```
+    pub fn apply_pose_now(&self, graph: &mut Graph) {
+        self.animations.apply_poses(graph);
+    }
+
+    /// Advances the animations of the player by the given time delta (see [`Self::scaled_delta_time`]) without
+    /// applying their poses, and returns their events and root motion. It allows the player to run headless - on a
+    /// dedicated server, for example, where there is no scene graph to animate. The player must not be a part of a
+    /// graph that is updated, otherwise the animations will be advanced twice. See
+    /// [`AnimationContainer::update_headless`] docs for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::scene::animation::AnimationPlayer;
+    ///
+    /// fn server_tick(animation_player: &mut AnimationPlayer, dt: f32) {
+    ///     for update in animation_player.update_headless(dt) {
+    ///         if let Some(root_motion) = update.root_motion {
+    ///             // Move the physical body of the character.
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn update_headless(&mut self, dt: f32) -> Vec<AnimationUpdate> {
+        let dt = self.scaled_delta_time(dt);
+        self.animations.get_value_mut_silent().update_headless(dt)
+    }
+
     /// Returns a reference to internal animations container.
     pub fn animations(&self) -> &InheritableVariable<AnimationContainer> {
         &self.animations
@@ -138,6 +723,69 @@ impl AnimationPlayer {
     pub fn set_animations(&mut self, animations: AnimationContainer) {
         self.animations.set_value_and_mark_modified(animations);
     }
+
+    /// Returns an iterator that yields handles of currently playing animations - every enabled animation that is not
+    /// paused and has not ended yet. Looping animations are always playing while enabled and not paused. See
+    /// [`Animation::has_ended`] and [`Animation::pause`] for more info.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::{animation::Animation, core::pool::Handle, scene::animation::AnimationPlayer};
+    ///
+    /// fn is_attacking(animation_player: &AnimationPlayer, attack: Handle<Animation>) -> bool {
+    ///     animation_player
+    ///         .playing_animations()
+    ///         .any(|animation| animation == attack)
+    /// }
+    /// ```
+    pub fn playing_animations(&self) -> impl Iterator<Item = Handle<Animation>> + '_ {
+        self.animations
+            .pair_iter()
+            .filter(|(_, animation)| {
+                animation.is_enabled() && !animation.is_paused() && !animation.has_ended()
+            })
+            .map(|(handle, _)| handle)
+    }
+
+    /// Returns a handle of the enabled animation with the longest time slice (see [`Animation::length`]), or
+    /// `None` if there are no such animations. Looping animations (both [`LoopMode::Loop`] and
+    /// [`LoopMode::PingPong`]) are treated as a single loop if `include_looping` is `true`, or excluded otherwise.
+    pub fn longest_animation(&self, include_looping: bool) -> Option<Handle<Animation>> {
+        self.animations
+            .pair_iter()
+            .filter(|(_, animation)| {
+                animation.is_enabled()
+                    && (include_looping || animation.loop_mode() == LoopMode::Once)
+            })
+            .fold(
+                None,
+                |longest: Option<(Handle<Animation>, f32)>, (handle, animation)| match longest {
+                    Some((_, length)) if length >= animation.length() => longest,
+                    _ => Some((handle, animation.length())),
+                },
+            )
+            .map(|(handle, _)| handle)
+    }
+
+    /// Returns the maximum length (in seconds) of time slices of the enabled animations of the player, or zero if
+    /// there are no such animations. Playback speed of the animations is not taken into account. See
+    /// [`Self::longest_animation`] for more info about `include_looping` flag.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fyrox::scene::animation::AnimationPlayer;
+    ///
+    /// // A cutscene is finished when its longest non-looping clip is finished.
+    /// fn is_cutscene_finished(player: &AnimationPlayer, elapsed: f32) -> bool {
+    ///     elapsed >= player.total_duration(false)
+    /// }
+    /// ```
+    pub fn total_duration(&self, include_looping: bool) -> f32 {
+        self.longest_animation(include_looping)
+            .map_or(0.0, |handle| self.animations[handle].length())
+    }
 }
 
 impl TypeUuidProvider for AnimationPlayer {
@@ -180,11 +828,54 @@ impl NodeTrait for AnimationPlayer {
     }
 
     fn update(&mut self, context: &mut UpdateContext) {
-        self.animations.get_value_mut_silent().update_animations(
-            context.nodes,
-            self.auto_apply,
-            context.dt,
-        );
+        let interval = if let Some(interval) = self.update_interval(context.nodes) {
+            interval
+        } else {
+            // Culled players are frozen, otherwise the animations would make a huge step when the player becomes
+            // visible again.
+            self.accumulated_dt = 0.0;
+            self.skipped_frames = 0;
+            return;
+        };
+
+        self.accumulated_dt += self.scaled_delta_time(context.dt);
+        self.skipped_frames += 1;
+
+        if self.skipped_frames >= interval {
+            self.synchronize(context.nodes);
+
+            let applied = self.animations.get_value_mut_silent().update_animations(
+                context.nodes,
+                self.auto_apply,
+                self.accumulated_dt,
+            );
+
+            // Post-processors adjust applied poses, even if only some of the animations were applied.
+            if applied {
+                run_pose_post_processors(
+                    &mut self.pose_post_processors,
+                    self.base.self_handle,
+                    context.nodes,
+                    self.accumulated_dt,
+                );
+            }
+
+            self.accumulated_dt = 0.0;
+            self.skipped_frames = 0;
+        }
+    }
+
+    fn validate(&self, scene: &Scene) -> Result<(), String> {
+        let issues = self.animations.validate(&scene.graph);
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
     }
 }
 
@@ -193,6 +884,11 @@ pub struct AnimationPlayerBuilder {
     base_builder: BaseBuilder,
     animations: AnimationContainer,
     auto_apply: bool,
+    time_scale: f32,
+    update_mode: UpdateMode,
+    time_sync: TimeSync,
+    sync_tolerance: f32,
+    time_source: Option<Box<dyn TimeSource>>,
 }
 
 impl AnimationPlayerBuilder {
@@ -202,6 +898,11 @@ impl AnimationPlayerBuilder {
             base_builder,
             animations: AnimationContainer::new(),
             auto_apply: true,
+            time_scale: 1.0,
+            update_mode: Default::default(),
+            time_sync: Default::default(),
+            sync_tolerance: DEFAULT_SYNC_TOLERANCE,
+            time_source: None,
         }
     }
 
@@ -217,12 +918,52 @@ impl AnimationPlayerBuilder {
         self
     }
 
+    /// Sets desired time scale. See [`AnimationPlayer::set_time_scale`] docs for more info.
+    pub fn with_time_scale(mut self, time_scale: f32) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Sets desired update mode. See [`AnimationPlayer::set_update_mode`] docs for more info.
+    pub fn with_update_mode(mut self, update_mode: UpdateMode) -> Self {
+        self.update_mode = update_mode;
+        self
+    }
+
+    /// Sets desired time synchronization mode. See [`AnimationPlayer::set_time_sync`] docs for more info.
+    pub fn with_time_sync(mut self, time_sync: TimeSync) -> Self {
+        self.time_sync = time_sync;
+        self
+    }
+
+    /// Sets desired drift tolerance of time synchronization. See [`AnimationPlayer::set_sync_tolerance`] docs for
+    /// more info.
+    pub fn with_sync_tolerance(mut self, tolerance: f32) -> Self {
+        self.sync_tolerance = tolerance;
+        self
+    }
+
+    /// Sets desired time source. See [`AnimationPlayer::set_time_source`] docs for more info.
+    pub fn with_time_source<S: TimeSource>(mut self, time_source: S) -> Self {
+        self.time_source = Some(Box::new(time_source));
+        self
+    }
+
     /// Creates an instance of [`AnimationPlayer`] node.
     pub fn build_node(self) -> Node {
         Node::new(AnimationPlayer {
             base: self.base_builder.build_base(),
             animations: self.animations.into(),
             auto_apply: self.auto_apply,
+            time_scale: self.time_scale.max(0.0).into(),
+            update_mode: self.update_mode.into(),
+            time_sync: self.time_sync.into(),
+            sync_tolerance: self.sync_tolerance.max(0.0).into(),
+            sync_time: 0.0,
+            accumulated_dt: 0.0,
+            skipped_frames: 0,
+            pose_post_processors: Default::default(),
+            time_source: self.time_source,
         })
     }
 
@@ -231,3 +972,452 @@ impl AnimationPlayerBuilder {
         graph.add_node(self.build_node())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        animation::{track::Track, Animation, AnimationContainer, LoopMode},
+        core::{
+            algebra::{Vector2, Vector3},
+            curve::{Curve, CurveKey, CurveKeyKind},
+            pool::Handle,
+        },
+        scene::{
+            animation::{
+                AnimationPlayer, AnimationPlayerBuilder, ManualTime, PosePostProcessContext,
+                PosePostProcessor, TimeSync, UpdateMode,
+            },
+            base::BaseBuilder,
+            graph::Graph,
+            node::Node,
+            pivot::PivotBuilder,
+        },
+    };
+    use std::ops::Range;
+
+    #[derive(Clone, Debug)]
+    struct Lift {
+        node: Handle<Node>,
+    }
+
+    impl PosePostProcessor for Lift {
+        fn process(&mut self, context: &mut PosePostProcessContext) {
+            let transform = context.nodes[self.node].local_transform_mut();
+            let position = **transform.position();
+            transform.set_position(position + Vector3::new(0.0, 5.0, 0.0));
+        }
+
+        fn clone_box(&self) -> Box<dyn PosePostProcessor> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_pose_post_processor() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut track = Track::new_position();
+        track.set_target(node);
+        track.data_container_mut().curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(0.0, 1.0, CurveKeyKind::Linear)]);
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+        let mut animations = AnimationContainer::new();
+        animations.add(animation);
+
+        let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+            .with_animations(animations)
+            .build(&mut graph);
+        graph[player]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap()
+            .add_pose_post_processor(Lift { node });
+
+        // The post-processor must see already applied pose.
+        for _ in 0..2 {
+            graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+            assert_eq!(
+                **graph[node].local_transform().position(),
+                Vector3::new(1.0, 5.0, 0.0)
+            );
+        }
+    }
+
+    #[test]
+    fn test_pose_post_processor_with_animation_auto_apply() {
+        let mut graph = Graph::new();
+        let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut track = Track::new_position();
+        track.set_target(node);
+        track.data_container_mut().curves_mut()[0] =
+            Curve::from(vec![CurveKey::new(0.0, 1.0, CurveKeyKind::Linear)]);
+        let mut animation = Animation::default();
+        animation.add_track(track);
+        animation.set_time_slice(0.0..1.0);
+        let mut animations = AnimationContainer::new();
+        let animation = animations.add(animation);
+
+        let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+            .with_animations(animations)
+            .with_auto_apply(false)
+            .build(&mut graph);
+        graph[player]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap()
+            .add_pose_post_processor(Lift { node });
+
+        // Nothing is applied, so the post-processor is not invoked.
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert_eq!(
+            **graph[node].local_transform().position(),
+            Vector3::default()
+        );
+
+        // The animation is applied regardless of the flag of the player, the post-processor follows.
+        graph[player]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap()
+            .animations_mut()[animation]
+            .set_auto_apply(true);
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert_eq!(
+            **graph[node].local_transform().position(),
+            Vector3::new(1.0, 5.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_time_sync() {
+        let make_player = |graph: &mut Graph, time_sync: TimeSync, time_position: f32| {
+            let mut animation = Animation::default();
+            animation.set_name("Dance");
+            animation.set_time_slice(0.0..10.0);
+            animation.set_time_position(time_position);
+            let mut animations = AnimationContainer::new();
+            let dance = animations.add(animation);
+            let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+                .with_animations(animations)
+                .with_time_sync(time_sync)
+                .build(graph);
+            (player, dance)
+        };
+        let time_position = |graph: &Graph, (player, animation)| {
+            graph[player]
+                .query_component_ref::<AnimationPlayer>()
+                .unwrap()
+                .animations()[animation]
+                .time_position()
+        };
+
+        let mut graph = Graph::new();
+        let master = make_player(&mut graph, TimeSync::None, 0.0);
+        // The follower was started later.
+        let follower = make_player(&mut graph, TimeSync::Master(master.0), 3.0);
+        let external = make_player(&mut graph, TimeSync::External, 0.0);
+        graph[external.0]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap()
+            .set_sync_time(5.0);
+
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+
+        // Playheads could differ by at most one frame.
+        let master_time = time_position(&graph, master);
+        assert!((time_position(&graph, follower) - master_time).abs() <= 0.1 + 1.0e-4);
+        assert!((time_position(&graph, external) - 5.1).abs() < 1.0e-4);
+
+        // Small drift is tolerated.
+        graph[external.0]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap()
+            .set_sync_time(5.12);
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert!((time_position(&graph, external) - 5.2).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_time_source() {
+        let mut animation = Animation::default();
+        animation.set_time_slice(0.0..10.0);
+        let mut animations = AnimationContainer::new();
+        let animation = animations.add(animation);
+
+        let clock = ManualTime::new();
+        let mut graph = Graph::new();
+        let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+            .with_animations(animations)
+            .with_time_scale(2.0)
+            .with_time_source(clock.clone())
+            .build(&mut graph);
+        let time_position = |graph: &Graph| {
+            graph[player]
+                .query_component_ref::<AnimationPlayer>()
+                .unwrap()
+                .animations()[animation]
+                .time_position()
+        };
+
+        // Real frame time is ignored, the time scale is still applied.
+        clock.set_delta(0.5);
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert!((time_position(&graph) - 1.0).abs() < 1.0e-4);
+
+        // Paused.
+        clock.set_delta(0.0);
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert!((time_position(&graph) - 1.0).abs() < 1.0e-4);
+
+        // Rewind.
+        clock.set_delta(-0.25);
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert!((time_position(&graph) - 0.5).abs() < 1.0e-4);
+
+        // Default behaviour is restored.
+        graph[player]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap()
+            .set_time_source(None);
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert!((time_position(&graph) - 0.7).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn test_update_modes() {
+        let mut graph = Graph::new();
+        let observer = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+
+        let mut players = Vec::new();
+        for update_mode in [
+            UpdateMode::Always,
+            UpdateMode::EveryNthFrame(3),
+            UpdateMode::EveryNthFrame(0),
+            UpdateMode::DistanceBased {
+                observer,
+                near: 10.0,
+                far: 100.0,
+                max_interval: 5,
+            },
+            UpdateMode::DistanceBased {
+                observer: Handle::NONE,
+                near: 10.0,
+                far: 100.0,
+                max_interval: 5,
+            },
+        ] {
+            let mut animation = Animation::default();
+            animation.set_time_slice(0.0..100.0);
+            let mut animations = AnimationContainer::new();
+            let animation = animations.add(animation);
+            let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+                .with_animations(animations)
+                .with_update_mode(update_mode)
+                .build(&mut graph);
+            players.push((player, animation));
+        }
+
+        let update = |graph: &mut Graph, observer_distance: f32, frames: usize| {
+            graph[observer]
+                .local_transform_mut()
+                .set_position(Vector3::new(observer_distance, 0.0, 0.0));
+            for _ in 0..frames {
+                graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+            }
+            players
+                .iter()
+                .map(|(player, animation)| {
+                    let time = graph[*player]
+                        .query_component_ref::<AnimationPlayer>()
+                        .unwrap()
+                        .animations()[*animation]
+                        .time_position();
+                    (time * 10.0).round() / 10.0
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Skipped time is accumulated.
+        assert_eq!(update(&mut graph, 5.0, 2), vec![0.2, 0.0, 0.2, 0.2, 0.2]);
+        assert_eq!(update(&mut graph, 5.0, 1), vec![0.3, 0.3, 0.3, 0.3, 0.3]);
+
+        // Halfway between near and far distances the interval is 3 frames.
+        assert_eq!(update(&mut graph, 55.0, 2), vec![0.5, 0.3, 0.5, 0.3, 0.5]);
+        assert_eq!(update(&mut graph, 55.0, 1), vec![0.6, 0.6, 0.6, 0.6, 0.6]);
+
+        // Culled players do not accumulate time.
+        assert_eq!(update(&mut graph, 500.0, 10), vec![1.6, 1.5, 1.6, 0.6, 1.6]);
+        assert_eq!(update(&mut graph, 5.0, 1), vec![1.7, 1.5, 1.7, 0.7, 1.7]);
+    }
+
+    #[test]
+    fn test_playing_animations() {
+        let mut animations = AnimationContainer::new();
+        let mut add = |loop_mode: LoopMode, length: f32| {
+            let mut animation = Animation::default();
+            animation
+                .set_loop_mode(loop_mode)
+                .set_time_slice(0.0..length);
+            animations.add(animation)
+        };
+        let looping = add(LoopMode::Loop, 0.05);
+        let ping_pong = add(LoopMode::PingPong, 0.05);
+        let long = add(LoopMode::Once, 10.0);
+        let ended = add(LoopMode::Once, 0.05);
+        let paused = add(LoopMode::Loop, 0.05);
+        let disabled = add(LoopMode::Loop, 0.05);
+        animations[paused].pause();
+        animations[disabled].set_enabled(false);
+
+        let mut graph = Graph::new();
+        let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+            .with_animations(animations)
+            .build(&mut graph);
+        let playing_animations = |graph: &Graph| {
+            let mut playing = graph[player]
+                .query_component_ref::<AnimationPlayer>()
+                .unwrap()
+                .playing_animations()
+                .collect::<Vec<_>>();
+            playing.sort_by_key(|handle| handle.index());
+            playing
+        };
+
+        assert_eq!(
+            playing_animations(&graph),
+            vec![looping, ping_pong, long, ended]
+        );
+
+        // The non-looping animation reaches its end, looping animations are playing infinitely.
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert_eq!(playing_animations(&graph), vec![looping, ping_pong, long]);
+
+        {
+            let animations = graph[player]
+                .query_component_mut::<AnimationPlayer>()
+                .unwrap()
+                .animations_mut();
+            animations[paused].resume();
+            animations[disabled].set_enabled(true);
+            animations[looping].pause();
+            animations[long].set_enabled(false);
+        }
+        assert_eq!(
+            playing_animations(&graph),
+            vec![ping_pong, paused, disabled]
+        );
+    }
+
+    #[test]
+    fn test_time_scale() {
+        let mut graph = Graph::new();
+        let players = [0.0, 0.5, 1.0, 2.0]
+            .iter()
+            .map(|time_scale| {
+                let node = PivotBuilder::new(BaseBuilder::new()).build(&mut graph);
+                let mut track = Track::new_position();
+                track.set_target(node);
+                track.data_container_mut().curves_mut()[0] = Curve::from(vec![
+                    CurveKey::new(0.0, 0.0, CurveKeyKind::Linear),
+                    CurveKey::new(10.0, 10.0, CurveKeyKind::Linear),
+                ]);
+                let mut animation = Animation::default();
+                animation.add_track(track);
+                animation.set_time_slice(0.0..10.0);
+                let mut animations = AnimationContainer::new();
+                let animation = animations.add(animation);
+                let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+                    .with_animations(animations)
+                    .with_time_scale(*time_scale)
+                    .build(&mut graph);
+                (player, animation, node)
+            })
+            .collect::<Vec<_>>();
+
+        for (&(player, _, _), expected) in players.iter().zip([0.0, 0.05, 0.1, 0.2]) {
+            let player = graph[player]
+                .query_component_ref::<AnimationPlayer>()
+                .unwrap();
+            assert!((player.scaled_delta_time(0.1) - expected).abs() < 1.0e-6);
+        }
+
+        for _ in 0..2 {
+            graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        }
+        for (&(player, animation, node), expected) in players.iter().zip([0.0, 0.1, 0.2, 0.4]) {
+            let time_position = graph[player]
+                .query_component_ref::<AnimationPlayer>()
+                .unwrap()
+                .animations()[animation]
+                .time_position();
+            assert!((time_position - expected).abs() < 1.0e-4);
+            assert!((graph[node].local_transform().position().x - expected).abs() < 1.0e-4);
+        }
+
+        // Zero time scale freezes the animation, but its pose is still applied.
+        let (frozen, _, node) = players[0];
+        graph[node]
+            .local_transform_mut()
+            .set_position(Vector3::new(100.0, 0.0, 0.0));
+        graph.update(Vector2::new(800.0, 600.0), 0.1, Default::default());
+        assert_eq!(
+            **graph[node].local_transform().position(),
+            Vector3::default()
+        );
+
+        // Negative time scale is clamped to zero.
+        let player = graph[frozen]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap();
+        assert_eq!(player.set_time_scale(-1.0), 0.0);
+        assert_eq!(player.set_time_scale(0.5), 0.0);
+        assert_eq!(player.time_scale(), 0.5);
+    }
+
+    #[test]
+    fn test_longest_animation() {
+        let mut graph = Graph::new();
+
+        let empty = AnimationPlayerBuilder::new(BaseBuilder::new()).build(&mut graph);
+        let empty = graph[empty]
+            .query_component_ref::<AnimationPlayer>()
+            .unwrap();
+        for include_looping in [false, true] {
+            assert_eq!(empty.longest_animation(include_looping), None);
+            assert_eq!(empty.total_duration(include_looping), 0.0);
+        }
+
+        let mut animations = AnimationContainer::new();
+        let mut add = |time_slice: Range<f32>, speed: f32, loop_mode: LoopMode| {
+            let mut animation = Animation::default();
+            animation.set_time_slice(time_slice);
+            animation.set_speed(speed).set_loop_mode(loop_mode);
+            animations.add(animation)
+        };
+        add(0.0..2.0, 1.0, LoopMode::Once);
+        // Speed does not affect the length, the slow animation is still shorter than the next one.
+        add(0.0..2.5, 0.1, LoopMode::Once);
+        let once = add(1.0..4.0, 2.0, LoopMode::Once);
+        let looping = add(0.0..5.0, 1.0, LoopMode::PingPong);
+        let disabled = add(0.0..10.0, 1.0, LoopMode::Once);
+        animations[disabled].set_enabled(false);
+
+        let player = AnimationPlayerBuilder::new(BaseBuilder::new())
+            .with_animations(animations)
+            .build(&mut graph);
+        let player = graph[player]
+            .query_component_mut::<AnimationPlayer>()
+            .unwrap();
+
+        assert_eq!(player.longest_animation(false), Some(once));
+        assert_eq!(player.total_duration(false), 3.0);
+        assert_eq!(player.longest_animation(true), Some(looping));
+        assert_eq!(player.total_duration(true), 5.0);
+
+        // The first of the animations with the same length is used.
+        player.animations_mut()[looping].set_time_slice(0.0..3.0);
+        assert_eq!(player.longest_animation(true), Some(once));
+        assert_eq!(player.total_duration(true), 3.0);
+    }
+}
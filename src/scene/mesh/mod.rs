@@ -83,6 +83,32 @@ impl RenderPath {
     }
 }
 
+/// A blend shape (also known as morph target) of a mesh. Blend shapes are identified by their names, the weights of
+/// blend shapes are usually animated (see [`crate::animation::value::ValueBinding::BlendShape`]) to make facial
+/// animation and other fine deformations that are hard to achieve with skinning.
+///
+/// Keep in mind, that the mesh stores only the names and the weights of its blend shapes, the built-in renderer does
+/// not deform the surfaces of the mesh by the weights yet. The weights could be read by scripts or custom render
+/// passes (see [`Mesh::blend_shapes`]).
+#[derive(Debug, Default, Reflect, Clone, PartialEq, Visit)]
+pub struct BlendShape {
+    /// A name of the blend shape, it must be unique within a mesh.
+    pub name: String,
+    /// A weight of the blend shape in `[0; 1]` range.
+    #[reflect(min_value = 0.0, max_value = 1.0, step = 0.05)]
+    pub weight: f32,
+}
+
+impl BlendShape {
+    /// Creates a new blend shape with the given name and weight, the weight is clamped to `[0; 1]` range.
+    pub fn new<S: AsRef<str>>(name: S, weight: f32) -> Self {
+        Self {
+            name: name.as_ref().to_owned(),
+            weight: weight.clamp(0.0, 1.0),
+        }
+    }
+}
+
 /// See module docs.
 #[derive(Debug, Reflect, Clone, Visit)]
 pub struct Mesh {
@@ -98,6 +124,10 @@ pub struct Mesh {
     #[reflect(setter = "set_decal_layer_index")]
     decal_layer_index: InheritableVariable<u8>,
 
+    #[visit(optional)] // Backward compatibility
+    #[reflect(setter = "set_blend_shapes")]
+    blend_shapes: InheritableVariable<Vec<BlendShape>>,
+
     #[reflect(hidden)]
     #[visit(skip)]
     local_bounding_box: Cell<AxisAlignedBoundingBox>,
@@ -121,6 +151,7 @@ impl Default for Mesh {
             local_bounding_box_dirty: Cell::new(true),
             render_path: InheritableVariable::new(RenderPath::Deferred),
             decal_layer_index: InheritableVariable::new(0),
+            blend_shapes: Default::default(),
         }
     }
 }
@@ -259,6 +290,40 @@ impl Mesh {
     pub fn decal_layer_index(&self) -> u8 {
         *self.decal_layer_index
     }
+
+    /// Sets new blend shapes of the mesh. See [`BlendShape`] docs for more info.
+    pub fn set_blend_shapes(&mut self, blend_shapes: Vec<BlendShape>) -> Vec<BlendShape> {
+        self.blend_shapes.set_value_and_mark_modified(blend_shapes)
+    }
+
+    /// Returns a reference to blend shapes of the mesh.
+    pub fn blend_shapes(&self) -> &[BlendShape] {
+        &self.blend_shapes
+    }
+
+    /// Returns a weight of the blend shape with the given name, `None` if there is no such blend shape.
+    pub fn blend_shape_weight(&self, name: &str) -> Option<f32> {
+        self.blend_shapes
+            .iter()
+            .find(|blend_shape| blend_shape.name == name)
+            .map(|blend_shape| blend_shape.weight)
+    }
+
+    /// Sets a weight of the blend shape with the given name, the weight is clamped to `[0; 1]` range. Returns `false`
+    /// if there is no such blend shape.
+    pub fn set_blend_shape_weight(&mut self, name: &str, weight: f32) -> bool {
+        if let Some(blend_shape) = self
+            .blend_shapes
+            .get_value_mut_and_mark_modified()
+            .iter_mut()
+            .find(|blend_shape| blend_shape.name == name)
+        {
+            blend_shape.weight = weight.clamp(0.0, 1.0);
+            true
+        } else {
+            false
+        }
+    }
 }
 
 impl NodeTrait for Mesh {
@@ -332,6 +397,7 @@ pub struct MeshBuilder {
     surfaces: Vec<Surface>,
     render_path: RenderPath,
     decal_layer_index: u8,
+    blend_shapes: Vec<BlendShape>,
 }
 
 impl MeshBuilder {
@@ -342,6 +408,7 @@ impl MeshBuilder {
             surfaces: Default::default(),
             render_path: RenderPath::Deferred,
             decal_layer_index: 0,
+            blend_shapes: Default::default(),
         }
     }
 
@@ -364,6 +431,12 @@ impl MeshBuilder {
         self
     }
 
+    /// Sets desired blend shapes for mesh.
+    pub fn with_blend_shapes(mut self, blend_shapes: Vec<BlendShape>) -> Self {
+        self.blend_shapes = blend_shapes;
+        self
+    }
+
     /// Creates new mesh.
     pub fn build_node(self) -> Node {
         Node::new(Mesh {
@@ -373,6 +446,7 @@ impl MeshBuilder {
             local_bounding_box_dirty: Cell::new(true),
             render_path: self.render_path.into(),
             decal_layer_index: self.decal_layer_index.into(),
+            blend_shapes: self.blend_shapes.into(),
             world_bounding_box: Default::default(),
         })
     }